@@ -201,6 +201,18 @@ impl Save {
     pub fn slot_in_max(&self, deck: usize) -> u32 {
         byteorder::LittleEndian::read_u32(&self.buf[deck_base(deck) + SLOT_IN_OFFSET..][..4])
     }
+
+    /// Set the deck's MB-capacity bonus, as
+    /// [`mb_capacity_bonus`](Self::mb_capacity_bonus) reads it.
+    pub fn set_mb_capacity_bonus(&mut self, deck: usize, bonus: u16) {
+        byteorder::LittleEndian::write_u16(&mut self.buf[deck_base(deck) + MB_BONUS_OFFSET..][..2], bonus);
+    }
+
+    /// Set the deck's slot-in MB budget, as
+    /// [`slot_in_max`](Self::slot_in_max) reads it.
+    pub fn set_slot_in_max(&mut self, deck: usize, max: u32) {
+        byteorder::LittleEndian::write_u32(&mut self.buf[deck_base(deck) + SLOT_IN_OFFSET..][..4], max);
+    }
 }
 
 /// Byte offset of deck `deck`'s block.
@@ -245,6 +257,14 @@ impl dv_save::Save for Save {
         std::borrow::Cow::Borrowed(&self.buf)
     }
 
+    fn edit_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    fn edit_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.buf
+    }
+
     fn to_sram_dump(&self) -> Vec<u8> {
         let mut sram = vec![0xff; SRAM_SIZE];
         let sum = self.buf.iter().map(|&b| b as u32).sum::<u32>();
@@ -270,28 +290,28 @@ impl dv_save::Save for Save {
 /// The deck's navi, for the save strip beside Play. BCC has no navi
 /// roster in the ROM sense (no emblems, no navi table) — the navi *is*
 /// a chip — so this reports the equipped navi chip and its HP, which
-/// is the deck's battle HP, and nothing else. Read-only on purpose:
-/// `view_navi_mut` staying `None` keeps the shared change-navi picker
-/// (which needs emblems this game doesn't have) out of the way, while
-/// the deck board does the swapping through [`NAVI_SLOT`].
-pub struct NaviView<'a> {
-    save: &'a Save,
+/// is the deck's battle HP, and nothing else.
+///
+/// What the save keeps of the navi is edited elsewhere: the navi itself
+/// through [`NAVI_SLOT`] on the chips view (the deck board does the
+/// swapping), and the deck's two MB budgets through
+/// [`Save::set_mb_capacity_bonus`] and [`Save::set_slot_in_max`]. The HP
+/// is the chip's ROM stat, not anything the save keeps, so there is no
+/// `view_navi_mut` to write it through.
+pub struct NaviView<S> {
+    save: S,
 }
 
-impl NaviView<'_> {
+impl<S: std::ops::Deref<Target = Save>> NaviView<S> {
     /// The navi chip bound in the socket, falling back to the byte —
     /// the same resolution [`ChipsView::chip`] does for [`NAVI_SLOT`].
     fn navi_chip(&self) -> Option<usize> {
-        dv_save::ChipsView::chip(
-            &ChipsView { save: self.save },
-            dv_save::ChipsView::equipped_folder_index(&ChipsView { save: self.save }),
-            NAVI_SLOT,
-        )
-        .map(|c| c.id)
+        let chips = ChipsView { save: &*self.save };
+        dv_save::ChipsView::chip(&chips, dv_save::ChipsView::equipped_folder_index(&chips), NAVI_SLOT).map(|c| c.id)
     }
 }
 
-impl dv_save::NaviView for NaviView<'_> {
+impl<S: std::ops::Deref<Target = Save>> dv_save::NaviView for NaviView<S> {
     fn navi(&self) -> usize {
         self.navi_chip().unwrap_or_default()
     }
@@ -538,3 +558,23 @@ impl<S: std::ops::DerefMut<Target = Save>> dv_save::ChipsViewMut for ChipsView<S
         // BCC has no anti-cheat shadow copy.
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dv_save::Save as _;
+
+    #[test]
+    fn deck_budgets_round_trip_through_sram() {
+        let mut save = Save::from_wram(&[0; SAVE_SIZE]).unwrap();
+        save.set_mb_capacity_bonus(1, 160);
+        save.set_slot_in_max(1, 80);
+
+        let reloaded = Save::new(&save.to_sram_dump()).unwrap();
+        assert_eq!(reloaded.mb_capacity_bonus(1), 160);
+        assert_eq!(reloaded.slot_in_max(1), 80);
+        // The other decks keep their own.
+        assert_eq!(reloaded.mb_capacity_bonus(0), 0);
+        assert_eq!(reloaded.slot_in_max(2), 0);
+    }
+}
@@ -29,9 +29,14 @@
 //!
 //! Save-side, `ChipsView` slot index `i` is deck-array position `i + 1`;
 //! the columns below hold slot indexes.
+//!
+//! The two budgets the header prices the deck against — the memory
+//! upgrade bonus on the capacity and the slot-in max — are the save's
+//! own numbers, so the editor steps them under the board.
 
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use iced::widget::canvas::{self, Canvas};
 use iced::widget::{button, column, container, row, text, Space};
@@ -44,6 +49,7 @@ use tango_gamesupport_common_ui::editor::view::{
 };
 use tango_gamesupport_common_ui::editor::BuildReport;
 use tango_gamesupport_common_ui::editor::OpaqueBuildWarnings;
+use tango_gamesupport_common_ui::model::edit::{GameEdit, Invalidation};
 use tango_gamesupport_common_ui::style::{self, TEXT_BODY, TEXT_CAPTION};
 use tango_gamesupport_common_ui::t;
 use tango_gamesupport_common_ui::widgets::{self, muted_color, muted_text_style};
@@ -287,6 +293,35 @@ fn limits(loaded: &OpenSave, navi: &Option<ChipEntry>) -> Option<Limits> {
     })
 }
 
+/// How far one press of a budget stepper moves it. The save takes any
+/// value; every budget seen in a played save is a multiple of this.
+const BUDGET_STEP: u32 = 10;
+
+/// Set the equipped deck's two budgets: the memory-upgrade bonus on its
+/// MB capacity and its slot-in max.
+#[derive(Debug)]
+struct SetDeckBudgets {
+    mb_bonus: u16,
+    slot_in: u32,
+}
+
+impl GameEdit for SetDeckBudgets {
+    fn apply(&self, model: &mut tango_gamesupport_common_ui::model::SaveModel) -> Invalidation {
+        let Some(deck) = model.save.view_chips().map(|v| v.equipped_folder_index()) else {
+            return Invalidation::default();
+        };
+        if let Some(save) = model
+            .save
+            .as_any_mut()
+            .downcast_mut::<tango_gamesupport_bcc_dataview::save::Save>()
+        {
+            save.set_mb_capacity_bonus(deck, self.mb_bonus);
+            save.set_slot_in_max(deck, self.slot_in);
+        }
+        Invalidation::default()
+    }
+}
+
 /// MB used by the wired run — slots 0..[`R_SLOT`], the game's positions
 /// 1–9. The navi and the R/L pair don't count against the capacity.
 fn wired_mb(chips: &[Option<ChipEntry>]) -> u32 {
@@ -805,6 +840,63 @@ fn header_captions<'a>(
     captions
 }
 
+/// The equipped deck's budgets between −/+ buttons, each press a
+/// [`SetDeckBudgets`] moving one of them by [`BUDGET_STEP`].
+fn budget_steppers<'a>(lang: &LanguageIdentifier, loaded: &OpenSave) -> Option<Element<'a, Action>> {
+    let save = loaded
+        .save
+        .as_any()
+        .downcast_ref::<tango_gamesupport_bcc_dataview::save::Save>()?;
+    let deck = loaded.save.view_chips()?.equipped_folder_index();
+    let mb_bonus = save.mb_capacity_bonus(deck);
+    let slot_in = save.slot_in_max(deck);
+    let set = move |mb_bonus: u16, slot_in: u32| Action::Game(Arc::new(SetDeckBudgets { mb_bonus, slot_in }));
+    let stepper = |label: String, value: u32, down: Option<Action>, up: Option<Action>| -> Element<'a, Action> {
+        row![
+            text(label)
+                .size(TEXT_CAPTION)
+                .style(muted_text_style)
+                .wrapping(text::Wrapping::None),
+            widgets::icon_button_maybe(
+                lucide_icons::Icon::Minus,
+                t!(lang, "deck-budget-decrease", step = BUDGET_STEP as i64),
+                down,
+                style::CONTROL_PADDING,
+            ),
+            text(value.to_string()).size(TEXT_BODY).wrapping(text::Wrapping::None),
+            widgets::icon_button_maybe(
+                lucide_icons::Icon::Plus,
+                t!(lang, "deck-budget-increase", step = BUDGET_STEP as i64),
+                up,
+                style::CONTROL_PADDING,
+            ),
+        ]
+        .spacing(5)
+        .align_y(Alignment::Center)
+        .into()
+    };
+    let step = BUDGET_STEP as u16;
+    Some(
+        row![
+            stepper(
+                t!(lang, "deck-mb-bonus"),
+                mb_bonus as u32,
+                (mb_bonus >= step).then(|| set(mb_bonus - step, slot_in)),
+                mb_bonus.checked_add(step).map(|v| set(v, slot_in)),
+            ),
+            stepper(
+                t!(lang, "deck-slot-in-max"),
+                slot_in,
+                (slot_in >= BUDGET_STEP).then(|| set(mb_bonus, slot_in - BUDGET_STEP)),
+                slot_in.checked_add(BUDGET_STEP).map(|v| set(mb_bonus, v)),
+            ),
+        ]
+        .spacing(16)
+        .align_y(Alignment::Center)
+        .into(),
+    )
+}
+
 /// The read-only Program Deck tab: the wired board, headerless like
 /// the read-only folder list — the budget captions live in the
 /// editor's header, where they can be acted on.
@@ -858,6 +950,7 @@ pub fn render_edit<'a>(lang: &'a LanguageIdentifier, loaded: &'a OpenSave, state
             selected,
             deck_slot_issues(lang, loaded)
         )]
+        .push_maybe(budget_steppers(lang, loaded))
         .spacing(8),
     );
 
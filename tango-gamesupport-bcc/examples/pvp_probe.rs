@@ -205,10 +205,14 @@ fn main() {
             let Some((start, end)) = stats.round_span(i, None) else { continue };
             let within = |t: u32| t >= start && t < end;
             println!(
-                "round {i}: {start}..{end} outcome={:?} hp_points={} custom_spans={} chip_uses={:?}",
+                "round {i}: {start}..{end} outcome={:?} hp_points={} custom_spans={:?} chip_uses={:?}",
                 stats.rounds[i].outcome,
                 stats.hp.iter().filter(|p| within(p.tick)).count(),
-                stats.custom.iter().filter(|&&(a, _)| within(a)).count(),
+                stats
+                    .custom
+                    .iter()
+                    .map(|v| v.iter().filter(|&&(a, _)| within(a)).count())
+                    .collect::<Vec<_>>(),
                 stats
                     .chip_uses
                     .iter()
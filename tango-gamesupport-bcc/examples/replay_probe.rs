@@ -137,10 +137,14 @@ fn main() {
         let within = |t: u32| t >= start && t < end;
         let hp: Vec<_> = stats.hp.iter().filter(|p| within(p.tick)).collect();
         println!(
-            "round {i}: {start}..{end} outcome={:?} hp_points={} custom={} uses={:?}",
+            "round {i}: {start}..{end} outcome={:?} hp_points={} custom={:?} uses={:?}",
             stats.rounds[i].outcome,
             hp.len(),
-            stats.custom.iter().filter(|&&(a, _)| within(a)).count(),
+            stats
+                .custom
+                .iter()
+                .map(|v| v.iter().filter(|&&(a, _)| within(a)).count())
+                .collect::<Vec<_>>(),
             stats
                 .chip_uses
                 .iter()
@@ -150,6 +154,12 @@ fn main() {
         for side in 0..2 {
             let uses: Vec<_> = stats.chip_uses[side].iter().filter(|&&(t, _)| within(t)).collect();
             println!("   side{side}: {uses:?}");
+            // The attribution, for checking against what the recording
+            // shows: every hit this side dealt, then the per-source sums.
+            for hit in stats.hits[side].iter().filter(|h| within(h.tick)) {
+                println!("      hit @{} {:?} -{}", hit.tick, hit.source, hit.amount);
+            }
+            println!("      damage: {:?}", stats.damage_totals(side, Some((start, end))));
         }
         for p in hp.iter().take(30) {
             println!("      hp @{} = {}/{}", p.tick, p.local, p.remote);
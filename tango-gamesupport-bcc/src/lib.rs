@@ -60,6 +60,7 @@ pub static BCC: Game = Game {
     rom_size: 0x800000,
     region: Region::US,
     parse_save_fn: parse_save,
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::A89E_00,
@@ -87,6 +88,7 @@ pub static EXEBCGP: Game = Game {
     rom_size: 0x800000,
     region: Region::JP,
     parse_save_fn: parse_save,
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::A89J_00,
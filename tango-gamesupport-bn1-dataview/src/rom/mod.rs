@@ -1,6 +1,7 @@
 mod msg;
 
 use tango_gamesupport_common_dataview::rom::LegalChips;
+use tango_gamesupport_common_dataview::save::NaviStatRange;
 
 const LEGAL_CHIPS: LegalChips = LegalChips::from_ranges(&[
     1..=34,
@@ -20,6 +21,18 @@ const LEGAL_CHIPS: LegalChips = LegalChips::from_ranges(&[
     157..=199,
 ]);
 
+/// BN1's base max HP as the editor bounds it: 100 to start, +20 per HP
+/// Memory, up to 1000.
+///
+/// Not read from this ROM — its HP Memory handler isn't located yet. The
+/// start and step are the ones the BN3 crate derives max HP from, and
+/// 1000 is the editor's ceiling rather than a cap in the game.
+const BASE_MAX_HP: NaviStatRange = NaviStatRange {
+    min: 100,
+    max: 1000,
+    step: 20,
+};
+
 pub struct Offsets {
     legal_chips: LegalChips,
     chip_data: u32,
@@ -28,11 +41,13 @@ pub struct Offsets {
     chip_icon_palette_pointer: u32,
     element_icon_palette_pointer: u32,
     element_icons_pointer: u32,
+    base_max_hp: NaviStatRange,
 }
 
 #[rustfmt::skip]
 pub static AREE_00: Offsets = Offsets {
     legal_chips:                    LEGAL_CHIPS,
+    base_max_hp:                    BASE_MAX_HP,
     chip_data:                      0x08007d70,
     chip_names_pointer:             0x080145f4,
     chip_descriptions_pointer:      0x08016104,
@@ -44,6 +59,7 @@ pub static AREE_00: Offsets = Offsets {
 #[rustfmt::skip]
 pub static AREJ_00: Offsets = Offsets {
     legal_chips:                    LEGAL_CHIPS,
+    base_max_hp:                    BASE_MAX_HP,
     chip_data:                      0x08007d3c,
     chip_names_pointer:             0x08014578,
     chip_descriptions_pointer:      0x08016088,
@@ -277,6 +293,10 @@ impl tango_gamesupport_common_dataview::rom::Assets for Assets {
         self.offsets.legal_chips.contains(id)
     }
 
+    fn base_max_hp_range(&self) -> Option<NaviStatRange> {
+        Some(self.offsets.base_max_hp)
+    }
+
     fn chip(&self, id: usize) -> Option<Box<dyn tango_gamesupport_common_dataview::rom::Chip + '_>> {
         if id >= self.num_chips() {
             return None;
@@ -91,10 +91,22 @@ impl save::Save for Save {
         Some(Box::new(NaviView { save: self }))
     }
 
+    fn view_navi_mut(&mut self) -> Option<Box<dyn tango_gamesupport_common_dataview::save::NaviViewMut + '_>> {
+        Some(Box::new(NaviView { save: self }))
+    }
+
     fn as_raw_wram(&self) -> std::borrow::Cow<'_, [u8]> {
         std::borrow::Cow::Borrowed(&self.buf)
     }
 
+    fn edit_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    fn edit_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.buf
+    }
+
     fn to_sram_dump(&self) -> Vec<u8> {
         let mut buf = vec![0; 65536];
         buf[..SAVE_SIZE].copy_from_slice(&self.buf);
@@ -216,6 +228,12 @@ impl<S: std::ops::Deref<Target = Save>> tango_gamesupport_common_dataview::save:
         bytemuck::pod_read_unaligned::<u16>(&self.save.buf[0x022e..][..std::mem::size_of::<u16>()])
     }
 
+    fn base_max_hp(&self, _assets: &dyn tango_gamesupport_common_dataview::rom::Assets) -> Option<u16> {
+        Some(bytemuck::pod_read_unaligned::<u16>(
+            &self.save.buf[0x022e..][..std::mem::size_of::<u16>()],
+        ))
+    }
+
     fn folder_limits(
         &self,
         _assets: &dyn tango_gamesupport_common_dataview::rom::Assets,
@@ -227,3 +245,73 @@ impl<S: std::ops::Deref<Target = Save>> tango_gamesupport_common_dataview::save:
         }
     }
 }
+
+impl<S: std::ops::DerefMut<Target = Save>> tango_gamesupport_common_dataview::save::NaviViewMut for NaviView<S> {
+    fn set_base_max_hp(&mut self, hp: u16, assets: &dyn tango_gamesupport_common_dataview::rom::Assets) -> bool {
+        let Some(range) = assets.base_max_hp_range() else {
+            return false;
+        };
+        if range.clamp(hp) != hp {
+            return false;
+        }
+        self.save.buf[0x022e..][..std::mem::size_of::<u16>()].copy_from_slice(bytemuck::bytes_of(&hp));
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tango_gamesupport_common_dataview::save::Save as _;
+
+    /// The US ROM's assets over a blank image whose every word points back
+    /// at the image, so the palette pointers `Assets::new` follows land.
+    /// Nothing here reads chips; the HP range is the ROM entry's own.
+    fn assets() -> crate::rom::Assets {
+        let rom = 0x0800_0000u32.to_le_bytes().repeat(0x80000);
+        crate::rom::Assets::new(&crate::rom::AREE_00, &[], rom, vec![0; 0x40000])
+    }
+
+    fn blank_save() -> Save {
+        let mut buf = vec![0; SAVE_SIZE];
+        buf[GAME_NAME_OFFSET..][..20].copy_from_slice(b"ROCKMAN EXE 20010727");
+        let mut save = Save::from_wram(&buf, GameInfo { region: Region::US }).unwrap();
+        save.rebuild_checksum();
+        save
+    }
+
+    #[test]
+    fn base_max_hp_round_trips_through_sram() {
+        let assets = assets();
+        let mut save = blank_save();
+        assert!(save.view_navi_mut().unwrap().set_base_max_hp(340, &assets));
+        save.rebuild_checksum();
+
+        let reloaded = Save::new(&save.to_sram_dump()).unwrap();
+        let navi = reloaded.view_navi().unwrap();
+        assert_eq!(navi.base_max_hp(&assets), Some(340));
+        assert_eq!(navi.max_hp(&assets), 340);
+    }
+
+    #[test]
+    fn base_max_hp_outside_the_rom_range_is_refused() {
+        let assets = assets();
+        let mut save = blank_save();
+        let before = save.edit_bytes().to_vec();
+        let mut navi = save.view_navi_mut().unwrap();
+        assert!(!navi.set_base_max_hp(80, &assets));
+        assert!(!navi.set_base_max_hp(1020, &assets));
+        assert!(!navi.set_base_max_hp(110, &assets));
+        drop(navi);
+        assert_eq!(save.edit_bytes(), &before[..]);
+    }
+
+    #[test]
+    fn base_max_hp_needs_the_rom_range() {
+        let mut save = blank_save();
+        assert!(!save
+            .view_navi_mut()
+            .unwrap()
+            .set_base_max_hp(340, &tango_gamesupport_common_dataview::rom::EmptyAssets));
+    }
+}
@@ -1,4 +1,4 @@
-//! BN1's save-editor UI: just the chip folder (no navicust, no patch cards).
+//! BN1's save-editor UI: the chip folder and the pack behind it (no navicust, no patch cards).
 
 use tango_gamesupport_common_ui::editor::loaded::OpenSave;
 use tango_gamesupport_common_ui::editor::view as sv;
@@ -14,7 +14,7 @@ pub static SAVE_EDITOR: SaveEditorShell<Ui> = SaveEditorShell(Ui);
 impl GameSaveEditor for Ui {
     fn tabs(&self, loaded: &OpenSave) -> Vec<Tab> {
         let _ = loaded;
-        vec![Tab::Folder]
+        vec![Tab::Folder, Tab::Pack]
     }
 
     fn render<'a>(
@@ -28,6 +28,7 @@ impl GameSaveEditor for Ui {
         match tab {
             Tab::Cover => sv::cover::render_cover(lang, loaded),
             Tab::Folder => sv::folder::render_folder(lang, loaded, opts.folder_grouped),
+            Tab::Pack => sv::pack::render_pack(lang, loaded),
             _ => sv::placeholder(tango_gamesupport_common_ui::t!(lang, "save-empty")),
         }
     }
@@ -41,6 +42,7 @@ impl GameSaveEditor for Ui {
     ) -> iced::Element<'a, Action> {
         match tab {
             Tab::Folder => sv::folder::render_folder_edit(lang, loaded, state),
+            Tab::Pack => sv::pack::render_pack_edit(lang, loaded, state),
             _ => sv::placeholder(tango_gamesupport_common_ui::t!(lang, "save-empty")),
         }
     }
@@ -49,6 +51,7 @@ impl GameSaveEditor for Ui {
         let _ = opts;
         match tab {
             Tab::Folder => sv::folder::as_text(loaded, opts),
+            Tab::Pack => sv::pack::as_text(loaded),
             _ => None,
         }
     }
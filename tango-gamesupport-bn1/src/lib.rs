@@ -67,6 +67,7 @@ pub static EXE1: Game = Game {
         }
         Ok(tango_gamesupport_common_dataview::wrap_save(Box::new(save)))
     },
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::AREJ_00,
@@ -118,6 +119,7 @@ pub static BN1: Game = Game {
         }
         Ok(tango_gamesupport_common_dataview::wrap_save(Box::new(save)))
     },
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::AREE_00,
@@ -1,9 +1,22 @@
 mod msg;
 
 use tango_gamesupport_common_dataview::rom::LegalChips;
+use tango_gamesupport_common_dataview::save::NaviStatRange;
 
 const LEGAL_CHIPS: LegalChips = LegalChips::from_ranges(&[1..=265, 270..=270]);
 
+/// BN2's base max HP as the editor bounds it: 100 to start, +20 per HP
+/// Memory, up to 1000.
+///
+/// Every revision carries these until the HP Memory handler is found in
+/// the ROM: the step is BN3's, and the ceiling is the editor's, not the
+/// game's.
+const BASE_MAX_HP: NaviStatRange = NaviStatRange {
+    min: 100,
+    max: 1000,
+    step: 20,
+};
+
 pub struct Offsets {
     legal_chips: LegalChips,
     chip_data: u32,
@@ -13,11 +26,13 @@ pub struct Offsets {
     element_icon_palette_pointer: u32,
     element_icons_pointer: u32,
     key_items_names_pointer: u32,
+    base_max_hp: NaviStatRange,
 }
 
 #[rustfmt::skip]
 pub static AE2E_00: Offsets = Offsets {
     legal_chips:                    LEGAL_CHIPS,
+    base_max_hp:                    BASE_MAX_HP,
     chip_data:                      0x0800e450,
     chip_names_pointers:            0x0800b528,
     chip_descriptions_pointers:     0x08026df4,
@@ -30,6 +45,7 @@ pub static AE2E_00: Offsets = Offsets {
 #[rustfmt::skip]
 pub static AE2J_00_AC: Offsets = Offsets {
     legal_chips:                    LEGAL_CHIPS,
+    base_max_hp:                    BASE_MAX_HP,
     chip_data:                      0x0800e2fc,
     chip_names_pointers:            0x0800b528,
     chip_descriptions_pointers:     0x0800affc,
@@ -308,6 +324,10 @@ impl tango_gamesupport_common_dataview::rom::Assets for Assets {
         self.offsets.legal_chips.contains(id)
     }
 
+    fn base_max_hp_range(&self) -> Option<NaviStatRange> {
+        Some(self.offsets.base_max_hp)
+    }
+
     fn chip(&self, id: usize) -> Option<Box<dyn tango_gamesupport_common_dataview::rom::Chip + '_>> {
         if id >= self.num_chips() {
             return None;
@@ -78,6 +78,7 @@ pub static EXE2: Game = Game {
             dataview::save::Save::new(data)?,
         )))
     },
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::AE2J_00_AC,
@@ -105,6 +106,7 @@ pub static BN2: Game = Game {
             dataview::save::Save::new(data)?,
         )))
     },
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::AE2E_00,
@@ -1,7 +1,8 @@
 //! The EX/Mod-Code system is BN3's own — no other game has it — so its
-//! model lives here, not in the shared dataview traits. See the ability
-//! compiler notes for how the game rebuilds the 0x5770 ability array
-//! from style + navicust + excode.
+//! model lives here, not in the shared dataview traits. The save's
+//! navicust view recompiles the 0x5770 ability array from navicust +
+//! excode after an edit, as the game's compiler does, and carries the
+//! style's share over.
 
 /// What an EX/Mod code grants.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -25,6 +26,33 @@ pub enum ExCodeEffect {
     Humor,
 }
 
+impl ExCodeEffect {
+    /// The NaviCust program effect this code grants the same way: an
+    /// EX code's bonus goes through the same `set_stat` handlers.
+    pub(crate) fn as_navicust_effect(self) -> super::navicust::NavicustEffect {
+        use super::navicust::NavicustEffect as N;
+        match self {
+            ExCodeEffect::MaxHp(n) => N::MaxHp(n),
+            ExCodeEffect::SuperArmor => N::SuperArmor,
+            ExCodeEffect::BreakBuster => N::BreakBuster,
+            ExCodeEffect::BreakCharge => N::BreakCharge,
+            ExCodeEffect::ShadowShoes => N::ShadowShoes,
+            ExCodeEffect::FloatShoes => N::FloatShoes,
+            ExCodeEffect::AirShoes => N::AirShoes,
+            ExCodeEffect::UnderShirt => N::UnderShirt,
+            ExCodeEffect::Block => N::Block,
+            ExCodeEffect::Shield => N::Shield,
+            ExCodeEffect::Reflect => N::Reflect,
+            ExCodeEffect::AntiDamage => N::AntiDamage,
+            ExCodeEffect::MegaFolder(n) => N::MegaLimit(n),
+            ExCodeEffect::GigaFolder(n) => N::GigaLimit(n),
+            ExCodeEffect::FastGauge => N::FastGauge,
+            ExCodeEffect::SneakRun => N::SneakRun,
+            ExCodeEffect::Humor => N::Humor,
+        }
+    }
+}
+
 /// The drawback a code carries, if any.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ExCodeBug {
@@ -3,10 +3,24 @@ mod msg;
 pub mod navicust;
 
 use tango_gamesupport_common_dataview::rom::LegalChips;
+use tango_gamesupport_common_dataview::save::NaviStatRange;
 
 const WHITE_LEGAL_CHIPS: LegalChips = LegalChips::from_ranges(&[1..=301, 304..=308, 312..=312]);
 const BLUE_LEGAL_CHIPS: LegalChips = LegalChips::from_ranges(&[1..=303, 309..=312]);
 
+/// BN3's base max HP as the editor bounds it: 100 to start, +20 per HP
+/// Memory (the save keeps the count, not the HP), up to 1000.
+///
+/// Not read from the ROM: the HP Memory handler's immediates aren't
+/// located, so every revision's entry carries these. 100 and +20 are what
+/// this crate derived max HP from before it was editable (100 + 20 × the
+/// count); 1000 is the editor's own ceiling, not a cap found in the game.
+const BASE_MAX_HP: NaviStatRange = NaviStatRange {
+    min: 100,
+    max: 1000,
+    step: 20,
+};
+
 pub struct Offsets {
     legal_chips: LegalChips,
     chip_data: u32,
@@ -20,6 +34,7 @@ pub struct Offsets {
     element_icons_pointer: u32,
     key_items_names_pointer: u32,
     navicust_bg: image::Rgba<u8>,
+    base_max_hp: NaviStatRange,
 }
 
 const NAVICUST_BG_W: image::Rgba<u8> = image::Rgba([0x4a, 0x63, 0x7b, 0xff]);
@@ -28,6 +43,7 @@ const NAVICUST_BG_B: image::Rgba<u8> = image::Rgba([0x5a, 0x5a, 0x5a, 0xff]);
 #[rustfmt::skip]
 pub static A6BJ_01: Offsets = Offsets {
     legal_chips:                    WHITE_LEGAL_CHIPS,
+    base_max_hp:                    BASE_MAX_HP,
     chip_data:                      0x08011474,
     chip_names_pointers:            0x08027c34,
     chip_descriptions_pointers:     0x0800e3e8,
@@ -45,6 +61,7 @@ pub static A6BJ_01: Offsets = Offsets {
 #[rustfmt::skip]
 pub static A3XJ_01: Offsets = Offsets {
     legal_chips:                    BLUE_LEGAL_CHIPS,
+    base_max_hp:                    BASE_MAX_HP,
     chip_data:                      0x08011474,
     chip_names_pointers:            0x08027c1c,
     chip_descriptions_pointers:     0x0800e3e8,
@@ -62,6 +79,7 @@ pub static A3XJ_01: Offsets = Offsets {
 #[rustfmt::skip]
 pub static A6BE_00: Offsets = Offsets {
     legal_chips:                    WHITE_LEGAL_CHIPS,
+    base_max_hp:                    BASE_MAX_HP,
     chip_data:                      0x08011510,
     chip_names_pointers:            0x08027ad4,
     chip_descriptions_pointers:     0x0800e46c,
@@ -79,6 +97,7 @@ pub static A6BE_00: Offsets = Offsets {
 #[rustfmt::skip]
 pub static A3XE_00: Offsets = Offsets {
     legal_chips:                    BLUE_LEGAL_CHIPS,
+    base_max_hp:                    BASE_MAX_HP,
     chip_data:                      0x08011510,
     chip_names_pointers:            0x08027abc,
     chip_descriptions_pointers:     0x0800e46c,
@@ -513,6 +532,10 @@ impl tango_gamesupport_common_dataview::rom::Assets for Assets {
         self.offsets.legal_chips.contains(id)
     }
 
+    fn base_max_hp_range(&self) -> Option<NaviStatRange> {
+        Some(self.offsets.base_max_hp)
+    }
+
     fn chip<'a>(&'a self, id: usize) -> Option<Box<dyn tango_gamesupport_common_dataview::rom::Chip + 'a>> {
         if id >= self.num_chips() {
             return None;
@@ -296,3 +296,162 @@ pub fn navicust_group_bugs(group: EffectGroup) -> &'static [NavicustBug] {
         G::DarkLicense => &[B::LoseCustomSlot],
     }
 }
+
+/// Navi-stats byte offsets in the ability array (see the module doc).
+pub(crate) mod field {
+    pub const SUPER_ARMOR: usize = 0x01;
+    pub const SHOES: usize = 0x02;
+    pub const AIR_SHOES: usize = 0x03;
+    pub const UNDER_SHIRT: usize = 0x04;
+    pub const BREAK_BUSTER: usize = 0x06;
+    pub const ATTACK: usize = 0x08;
+    pub const SPEED: usize = 0x09;
+    pub const CHARGE: usize = 0x0a;
+    pub const POWER_ATTACK_LEVEL: usize = 0x0d;
+    pub const BREAK_CHARGE: usize = 0x0e;
+    pub const B_LEFT_ABILITY: usize = 0x0f;
+    pub const REG_MEMORY: usize = 0x12;
+    pub const CUSTOM_GAUGE: usize = 0x13;
+    pub const MEGA_LIMIT: usize = 0x14;
+    pub const GIGA_LIMIT: usize = 0x15;
+    pub const PANEL_SET: usize = 0x17;
+    pub const FAST_GAUGE: usize = 0x18;
+    pub const SNEAK_RUN: usize = 0x1a;
+    pub const ELEMENT_ATTRACT: usize = 0x1b;
+    pub const SUPPORT_NAVI: usize = 0x1c;
+    pub const COLLECT: usize = 0x1d;
+    pub const DARK_LICENSE: usize = 0x20;
+    pub const BLACK_MIND: usize = 0x21;
+    pub const HUMOR: usize = 0x22;
+    pub const BUG_STOP: usize = 0x23;
+    pub const ENERGY_CHANGE: usize = 0x24;
+    pub const ALPHA: usize = 0x25;
+    pub const PRESS: usize = 0x28;
+
+    /// Every field an effect handler writes, in offset order. The bug
+    /// fields and the bytes between aren't decoded.
+    pub const ALL: &[usize] = &[
+        SUPER_ARMOR,
+        SHOES,
+        AIR_SHOES,
+        UNDER_SHIRT,
+        BREAK_BUSTER,
+        ATTACK,
+        SPEED,
+        CHARGE,
+        POWER_ATTACK_LEVEL,
+        BREAK_CHARGE,
+        B_LEFT_ABILITY,
+        REG_MEMORY,
+        CUSTOM_GAUGE,
+        MEGA_LIMIT,
+        GIGA_LIMIT,
+        PANEL_SET,
+        FAST_GAUGE,
+        SNEAK_RUN,
+        ELEMENT_ATTRACT,
+        SUPPORT_NAVI,
+        COLLECT,
+        DARK_LICENSE,
+        BLACK_MIND,
+        HUMOR,
+        BUG_STOP,
+        ENERGY_CHANGE,
+        ALPHA,
+        PRESS,
+    ];
+
+    /// The fields handlers raise rather than set; every other field holds
+    /// a flag or a mode, and the last handler to write it wins.
+    pub const COUNTERS: &[usize] = &[
+        ATTACK,
+        SPEED,
+        CHARGE,
+        POWER_ATTACK_LEVEL,
+        REG_MEMORY,
+        CUSTOM_GAUGE,
+        MEGA_LIMIT,
+        GIGA_LIMIT,
+    ];
+
+    /// Buster stats clamp here.
+    pub const BUSTER_MAX: u8 = 4;
+}
+
+impl NavicustEffect {
+    /// Write this effect into the navi-stats array the way its handler's
+    /// `set_stat` call does. Max HP isn't a navi-stats field (the handler
+    /// writes HP through a pointer), so it writes nothing here.
+    pub(crate) fn apply(self, stats: &mut [u8]) {
+        use field::*;
+        use NavicustEffect::*;
+        let raise = |stats: &mut [u8], f: usize, n: u8| stats[f] = stats[f].saturating_add(n);
+        let buster = |stats: &mut [u8], f: usize, n: u8| stats[f] = stats[f].saturating_add(n).min(BUSTER_MAX);
+        match self {
+            MaxHp(_) => {}
+            MegaLimit(n) => raise(stats, MEGA_LIMIT, n),
+            GigaLimit(n) => raise(stats, GIGA_LIMIT, n),
+            CustomGauge(n) => raise(stats, CUSTOM_GAUGE, n),
+            RegMemory(n) => raise(stats, REG_MEMORY, n),
+            Attack(n) => buster(stats, ATTACK, n),
+            Speed(n) => buster(stats, SPEED, n),
+            Charge(n) => buster(stats, CHARGE, n),
+            BusterMax => {
+                for f in [ATTACK, SPEED, CHARGE] {
+                    stats[f] = BUSTER_MAX;
+                }
+            }
+            WeaponLevel(n) => raise(stats, POWER_ATTACK_LEVEL, n),
+            SuperArmor => stats[SUPER_ARMOR] = 1,
+            BreakBuster => stats[BREAK_BUSTER] = 1,
+            BreakCharge => stats[BREAK_CHARGE] = 1,
+            Block => stats[B_LEFT_ABILITY] = 2,
+            Shield => stats[B_LEFT_ABILITY] = 4,
+            Reflect => stats[B_LEFT_ABILITY] = 6,
+            AntiDamage => stats[B_LEFT_ABILITY] = 8,
+            ShadowShoes => stats[SHOES] = 1,
+            FloatShoes => stats[SHOES] = 2,
+            AirShoes => stats[AIR_SHOES] = 1,
+            UnderShirt => stats[UNDER_SHIRT] = 1,
+            SetGreen => stats[PANEL_SET] = 0x36,
+            SetIce => stats[PANEL_SET] = 0x37,
+            SetLava => stats[PANEL_SET] = 0x38,
+            SetSand => stats[PANEL_SET] = 0x3a,
+            SetMetal => stats[PANEL_SET] = 0x35,
+            SetHoly => stats[PANEL_SET] = 0x19,
+            SneakRun => stats[SNEAK_RUN] = 1,
+            Battery => stats[ELEMENT_ATTRACT] = 1,
+            OilBody => stats[ELEMENT_ATTRACT] = 2,
+            Fish => stats[ELEMENT_ATTRACT] = 3,
+            Jungle => stats[ELEMENT_ATTRACT] = 4,
+            Collect => stats[COLLECT] = 1,
+            FastGauge => stats[FAST_GAUGE] = 1,
+            Press => stats[PRESS] = 1,
+            EnergyChange => stats[ENERGY_CHANGE] = 1,
+            Alpha => stats[ALPHA] = 1,
+            Humor => stats[HUMOR] = 1,
+            BugStop => stats[BUG_STOP] = 1,
+            BlackMind => stats[BLACK_MIND] = 1,
+            DarkLicense => stats[DARK_LICENSE] = 1,
+            Rush => stats[SUPPORT_NAVI] = 1,
+            Beat => stats[SUPPORT_NAVI] = 2,
+            Tango => stats[SUPPORT_NAVI] = 3,
+            // HubBatc calls its constituent handlers.
+            HubStyle => {
+                for effect in [
+                    SuperArmor,
+                    BreakBuster,
+                    BreakCharge,
+                    CustomGauge(1),
+                    MegaLimit(1),
+                    Shield,
+                    FloatShoes,
+                    UnderShirt,
+                    AirShoes,
+                ] {
+                    effect.apply(stats);
+                }
+            }
+        }
+    }
+}
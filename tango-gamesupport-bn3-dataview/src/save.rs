@@ -1,12 +1,18 @@
 use bitvec::view::BitView;
 
 use crate::rom::extra_ncp_color;
-use tango_gamesupport_common_dataview::save::{ChipsView as _, Save as _};
+use tango_gamesupport_common_dataview::save::{ChipsView as _, NavicustView as _, Save as _};
 
 pub const SAVE_SIZE: usize = 0x57b0;
 pub const GAME_NAME_OFFSET: usize = 0x1e00;
 pub const CHECKSUM_OFFSET: usize = 0x1dd8;
 
+/// The navi-stats ability array the game compiles from style + navicust +
+/// EX code (see `rom::navicust::field`). Every field the compiler writes
+/// sits below `ABILITIES_LEN`.
+const ABILITIES_OFFSET: usize = 0x5770;
+const ABILITIES_LEN: usize = 0x2c;
+
 #[derive(PartialEq, Debug, Copy, Clone)]
 pub enum Variant {
     White,
@@ -29,6 +35,10 @@ pub struct GameInfo {
 pub struct Save {
     buf: [u8; SAVE_SIZE],
     game_info: GameInfo,
+    /// The programs the grid and ability array were last compiled from,
+    /// kept from the first part edit until the grid is rebuilt: the
+    /// rebuild tells the style's share of the array from theirs.
+    compiled_parts: Option<Vec<Option<tango_gamesupport_common_dataview::save::NavicustPart>>>,
 }
 
 fn compute_raw_checksum(buf: &[u8]) -> u32 {
@@ -69,7 +79,11 @@ impl Save {
             }
         };
 
-        let save = Self { buf, game_info };
+        let save = Self {
+            buf,
+            game_info,
+            compiled_parts: None,
+        };
 
         Ok(save)
     }
@@ -81,6 +95,7 @@ impl Save {
                 .and_then(|buf| buf.try_into().ok())
                 .ok_or(tango_gamesupport_common_dataview::save::Error::InvalidSize(buf.len()))?,
             game_info,
+            compiled_parts: None,
         })
     }
 
@@ -102,7 +117,6 @@ impl Save {
         self.buf[0x0030 + i / 8].view_bits::<bitvec::order::Msb0>()[i % 8]
     }
 
-    #[allow(dead_code)]
     fn set_flag(&mut self, i: usize, v: bool) {
         self.buf[0x0030 + i / 8]
             .view_bits_mut::<bitvec::order::Msb0>()
@@ -115,6 +129,10 @@ impl tango_gamesupport_common_dataview::save::Save for Save {
         Some(Box::new(NaviView { save: self }))
     }
 
+    fn view_navi_mut(&mut self) -> Option<Box<dyn tango_gamesupport_common_dataview::save::NaviViewMut + '_>> {
+        Some(Box::new(NaviView { save: self }))
+    }
+
     fn as_raw_wram(&self) -> std::borrow::Cow<'_, [u8]> {
         std::borrow::Cow::Borrowed(&self.buf)
     }
@@ -131,6 +149,18 @@ impl tango_gamesupport_common_dataview::save::Save for Save {
         Some(Box::new(NavicustView { save: self }))
     }
 
+    fn view_navicust_mut(&mut self) -> Option<Box<dyn tango_gamesupport_common_dataview::save::NavicustViewMut + '_>> {
+        Some(Box::new(NavicustView { save: self }))
+    }
+
+    fn edit_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    fn edit_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.buf
+    }
+
     fn to_sram_dump(&self) -> Vec<u8> {
         let mut buf = vec![0; 65536];
         buf[..SAVE_SIZE].copy_from_slice(&self.buf);
@@ -320,6 +350,9 @@ impl<S: std::ops::Deref<Target = Save>> tango_gamesupport_common_dataview::save:
         tango_gamesupport_common_dataview::navicust::materialized_from_wram(&self.save.buf[0x1d90..][..(5 * 5)], [5, 5])
     }
 
+    // The fourth colour comes with the style, so it isn't editable on its
+    // own; changing the style would change its share of the ability array,
+    // which isn't decoded (see `Save::rebuild_abilities`).
     fn navicust_color_bar(&self) -> Vec<Option<tango_gamesupport_common_dataview::rom::NavicustPartColor>> {
         vec![
             Some(tango_gamesupport_common_dataview::rom::NavicustPartColor::White),
@@ -330,6 +363,165 @@ impl<S: std::ops::Deref<Target = Save>> tango_gamesupport_common_dataview::save:
     }
 }
 
+impl<S: std::ops::DerefMut<Target = Save>> tango_gamesupport_common_dataview::save::NavicustViewMut
+    for NavicustView<S>
+{
+    fn set_navicust_part(
+        &mut self,
+        i: usize,
+        part: Option<tango_gamesupport_common_dataview::save::NavicustPart>,
+    ) -> bool {
+        if i >= self.count() {
+            return false;
+        }
+        if self.save.compiled_parts.is_none() {
+            self.save.compiled_parts = Some((0..self.count()).map(|i| self.navicust_part(i)).collect());
+        }
+        let raw = match &part {
+            Some(part) => {
+                if part.id == 0 || part.id >= super::NUM_NAVICUST_PARTS {
+                    return false;
+                }
+                RawNavicustPart {
+                    id: part.id as u8,
+                    col: part.col,
+                    row: part.row,
+                    rot: part.rot,
+                    ..Default::default()
+                }
+            }
+            // An all-zero part (id 0) reads back as an empty slot.
+            None => RawNavicustPart::default(),
+        };
+        self.save.buf[0x1300 + i * std::mem::size_of::<RawNavicustPart>()..][..std::mem::size_of::<RawNavicustPart>()]
+            .copy_from_slice(bytemuck::bytes_of(&raw));
+
+        // BN3 keeps compression per part id, not per slot: it's the flag the
+        // compression code sets, so every copy of a part shares it.
+        if let Some(part) = part {
+            self.save.set_flag(0x02e0 + part.id, part.compressed);
+        }
+
+        true
+    }
+
+    fn clear_materialized(&mut self) {
+        self.save.buf[0x1d90..][..(5 * 5)].copy_from_slice(&[0; 5 * 5]);
+    }
+
+    fn rebuild_materialized(&mut self, assets: &dyn tango_gamesupport_common_dataview::rom::Assets) {
+        let compiled_parts = self.save.compiled_parts.take();
+        let materialized = tango_gamesupport_common_dataview::navicust::materialize(&*self, [5, 5], assets)
+            .into_iter()
+            .map(|v| v.map(|v| v + 1).unwrap_or(0) as u8)
+            .collect::<Vec<_>>();
+
+        // An unchanged grid runs the same programs, so the ability array
+        // stays exactly as the game compiled it.
+        if self.save.buf[0x1d90..][..(5 * 5)] == materialized[..] {
+            return;
+        }
+        let old_grid = self.materialized();
+        let old_parts = compiled_parts.unwrap_or_else(|| (0..self.count()).map(|i| self.navicust_part(i)).collect());
+        self.save.buf[0x1d90..][..(5 * 5)].copy_from_slice(&materialized);
+
+        // The color bar is the style's, not the parts': nothing to rebuild.
+        // The ability array is compiled from the grid, so it follows.
+        self.save.rebuild_abilities(&old_parts, &old_grid, assets);
+    }
+}
+
+impl Save {
+    /// Recompile the ability array for the grid now in the save, from the
+    /// `old_parts` placed as `old_grid` it was last compiled for.
+    ///
+    /// Only the fields an effect handler writes are replaced, and one no
+    /// installed program writes is cleared, so removing a program removes
+    /// its ability. The bug handlers' values aren't decoded, so the
+    /// bug-only fields keep what the game wrote, as does an EX code's
+    /// drawback. Max HP isn't in the array (`NaviView::max_hp` derives it).
+    ///
+    /// The style's handlers aren't decoded either, so its share is carried
+    /// over rather than recompiled: whatever a field holds beyond what the
+    /// old programs compile to is the style's. A counter keeps that surplus
+    /// on top of the new count, and a flag or mode keeps the style's value
+    /// over the programs', so no grid edit loses what the style grants.
+    fn rebuild_abilities(
+        &mut self,
+        old_parts: &[Option<tango_gamesupport_common_dataview::save::NavicustPart>],
+        old_grid: &tango_gamesupport_common_dataview::navicust::MaterializedNavicust,
+        assets: &dyn tango_gamesupport_common_dataview::rom::Assets,
+    ) {
+        use super::rom::navicust::field;
+
+        let before = self.compile_abilities(old_parts, old_grid, assets);
+        let nc = NavicustView { save: &*self };
+        let parts = (0..nc.count()).map(|i| nc.navicust_part(i)).collect::<Vec<_>>();
+        let after = self.compile_abilities(&parts, &nc.materialized(), assets);
+
+        for &f in field::ALL {
+            let held = self.buf[ABILITIES_OFFSET + f];
+            self.buf[ABILITIES_OFFSET + f] = if field::COUNTERS.contains(&f) {
+                let v = after[f].saturating_add(held.saturating_sub(before[f]));
+                if [field::ATTACK, field::SPEED, field::CHARGE].contains(&f) {
+                    v.min(field::BUSTER_MAX)
+                } else {
+                    v
+                }
+            } else if held != before[f] && held != 0 {
+                held
+            } else {
+                after[f]
+            };
+        }
+    }
+
+    /// The ability array the programs in `parts`, placed as `grid`, and
+    /// the EX code compile to: every program runs its effect handler in
+    /// slot order, then the EX code's. A solid program only runs when it
+    /// touches the command line.
+    fn compile_abilities(
+        &self,
+        parts: &[Option<tango_gamesupport_common_dataview::save::NavicustPart>],
+        grid: &tango_gamesupport_common_dataview::navicust::MaterializedNavicust,
+        assets: &dyn tango_gamesupport_common_dataview::rom::Assets,
+    ) -> [u8; ABILITIES_LEN] {
+        use super::rom::navicust::navicust_part_effects;
+
+        let command_line = assets.navicust_layout().map(|layout| layout.command_line);
+
+        let mut stats = [0u8; ABILITIES_LEN];
+        for (slot, part) in parts.iter().enumerate() {
+            let Some(part) = part else {
+                continue;
+            };
+            let Some(info) = assets.navicust_part(part.id) else {
+                continue;
+            };
+            let mut rows = grid
+                .indexed_iter()
+                .filter(|(_, cell)| **cell == Some(slot))
+                .map(|((row, _), _)| row)
+                .peekable();
+            if rows.peek().is_none() {
+                continue;
+            }
+            if info.is_solid() && !rows.any(|row| Some(row) == command_line) {
+                continue;
+            }
+
+            for effect in navicust_part_effects(part.id) {
+                effect.apply(&mut stats);
+            }
+        }
+
+        if let Some(ex_code) = super::rom::ex_codes::ex_code(self.buf[0x1270]) {
+            ex_code.effect.as_navicust_effect().apply(&mut stats);
+        }
+        stats
+    }
+}
+
 pub struct NaviView<S> {
     save: S,
 }
@@ -341,8 +533,8 @@ impl<S: std::ops::Deref<Target = Save>> tango_gamesupport_common_dataview::save:
         0
     }
 
-    fn max_hp(&self, _assets: &dyn tango_gamesupport_common_dataview::rom::Assets) -> u16 {
-        let mut base_max_hp = 100 + 20 * (self.save.buf[0x1a20] as u16);
+    fn max_hp(&self, assets: &dyn tango_gamesupport_common_dataview::rom::Assets) -> u16 {
+        let mut base_max_hp = self.base_max_hp(assets).unwrap_or_default();
 
         if let Some(navicust) = self.save.view_navicust() {
             let grid = navicust.materialized();
@@ -372,6 +564,29 @@ impl<S: std::ops::Deref<Target = Save>> tango_gamesupport_common_dataview::save:
         base_max_hp
     }
 
+    fn buster_stats(
+        &self,
+        _assets: &dyn tango_gamesupport_common_dataview::rom::Assets,
+    ) -> Option<tango_gamesupport_common_dataview::save::NaviBusterStats> {
+        // BN3's buster only grows through NaviCust programs and EX codes, so
+        // the levels are the compiled ability array's (0-based there). Not
+        // editable for that reason: the next compile puts back what the
+        // programs grant.
+        use super::rom::navicust::field;
+        let level = |f: usize| self.save.buf[ABILITIES_OFFSET + f].min(field::BUSTER_MAX) + 1;
+        Some(tango_gamesupport_common_dataview::save::NaviBusterStats {
+            attack: level(field::ATTACK),
+            speed: level(field::SPEED),
+            charge: level(field::CHARGE),
+            b_power_attack: 0,
+        })
+    }
+
+    fn base_max_hp(&self, assets: &dyn tango_gamesupport_common_dataview::rom::Assets) -> Option<u16> {
+        let range = assets.base_max_hp_range()?;
+        Some(range.min + range.step * (self.save.buf[0x1a20] as u16))
+    }
+
     fn folder_limits(
         &self,
         assets: &dyn tango_gamesupport_common_dataview::rom::Assets,
@@ -452,3 +667,311 @@ impl<S: std::ops::Deref<Target = Save>> tango_gamesupport_common_dataview::save:
         }
     }
 }
+
+impl<S: std::ops::DerefMut<Target = Save>> tango_gamesupport_common_dataview::save::NaviViewMut for NaviView<S> {
+    fn set_base_max_hp(&mut self, hp: u16, assets: &dyn tango_gamesupport_common_dataview::rom::Assets) -> bool {
+        let Some(range) = assets.base_max_hp_range() else {
+            return false;
+        };
+        if range.clamp(hp) != hp {
+            return false;
+        }
+        self.save.buf[0x1a20] = ((hp - range.min) / range.step) as u8;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rom::navicust::field;
+    use tango_gamesupport_common_dataview::save::{NavicustPart, NavicustViewMut as _};
+
+    const COMMAND_LINE: usize = 2;
+
+    /// One-cell programs; the listed templates are solid.
+    struct Parts(&'static [usize]);
+
+    struct Part {
+        solid: bool,
+    }
+
+    impl tango_gamesupport_common_dataview::rom::NavicustPart for Part {
+        fn name(&self) -> Option<String> {
+            None
+        }
+
+        fn description(&self) -> Option<String> {
+            None
+        }
+
+        fn color(&self) -> Option<tango_gamesupport_common_dataview::rom::NavicustPartColor> {
+            None
+        }
+
+        fn is_solid(&self) -> bool {
+            self.solid
+        }
+
+        fn compressed_bitmap(&self) -> Option<tango_gamesupport_common_dataview::rom::NavicustBitmap> {
+            None
+        }
+
+        fn uncompressed_bitmap(&self) -> tango_gamesupport_common_dataview::rom::NavicustBitmap {
+            ndarray::Array2::from_elem((1, 1), true)
+        }
+    }
+
+    impl tango_gamesupport_common_dataview::rom::Assets for Parts {
+        fn navicust_part(
+            &self,
+            id: usize,
+        ) -> Option<Box<dyn tango_gamesupport_common_dataview::rom::NavicustPart + '_>> {
+            Some(Box::new(Part {
+                solid: self.0.contains(&(id >> 2)),
+            }))
+        }
+
+        fn navicust_layout(&self) -> Option<tango_gamesupport_common_dataview::rom::NavicustLayout> {
+            Some(tango_gamesupport_common_dataview::rom::NavicustLayout {
+                command_line: COMMAND_LINE,
+                has_out_of_bounds: false,
+                background: image::Rgba([0, 0, 0, 0]),
+            })
+        }
+    }
+
+    fn empty_save() -> Save {
+        Save::from_wram(
+            &[0; SAVE_SIZE],
+            GameInfo {
+                variant: Variant::White,
+            },
+        )
+        .unwrap()
+    }
+
+    /// Install programs by template (`id >> 2`) at `(row, col)`, one per
+    /// slot, and rebuild the grid.
+    fn install(save: &mut Save, assets: &Parts, programs: &[(usize, u8, u8)]) {
+        let mut nc = NavicustView { save };
+        for slot in 0..nc.count() {
+            let part = programs.get(slot).map(|&(template, row, col)| NavicustPart {
+                id: template << 2,
+                col,
+                row,
+                rot: 0,
+                compressed: false,
+            });
+            nc.set_navicust_part(slot, part);
+        }
+        nc.rebuild_materialized(assets);
+    }
+
+    fn ability(save: &Save, f: usize) -> u8 {
+        save.buf[ABILITIES_OFFSET + f]
+    }
+
+    #[test]
+    fn unchanged_grid_keeps_the_compiled_array() {
+        let assets = Parts(&[]);
+        let mut save = empty_save();
+        install(&mut save, &assets, &[(1, 2, 2)]);
+
+        // Whatever the game compiled stays byte for byte.
+        save.buf[ABILITIES_OFFSET..][..ABILITIES_LEN].fill(0xaa);
+        let before = save.buf;
+        install(&mut save, &assets, &[(1, 2, 2)]);
+        assert!(save.buf == before);
+    }
+
+    #[test]
+    fn effects_write_their_handler_values() {
+        let mut save = empty_save();
+        // SetLava, Shield, Custom2.
+        install(&mut save, &Parts(&[]), &[(6, 0, 0), (15, 0, 1), (11, 0, 2)]);
+        assert_eq!(ability(&save, field::PANEL_SET), 0x38);
+        assert_eq!(ability(&save, field::B_LEFT_ABILITY), 4);
+        assert_eq!(ability(&save, field::CUSTOM_GAUGE), 2);
+    }
+
+    #[test]
+    fn exclusive_fields_take_the_last_slot() {
+        let mut save = empty_save();
+        // FlotShoe then ShdwShoe: each handler sets the same field.
+        install(&mut save, &Parts(&[]), &[(18, 0, 0), (17, 0, 1)]);
+        assert_eq!(ability(&save, field::SHOES), 1);
+    }
+
+    #[test]
+    fn buster_stats_clamp_at_four() {
+        let mut save = empty_save();
+        // Five Atk+1 and one Speed+1.
+        install(
+            &mut save,
+            &Parts(&[]),
+            &[(41, 0, 0), (41, 0, 1), (41, 0, 2), (41, 0, 3), (41, 0, 4), (42, 1, 0)],
+        );
+        assert_eq!(ability(&save, field::ATTACK), field::BUSTER_MAX);
+        assert_eq!(ability(&save, field::SPEED), 1);
+
+        // BustrMAX maxes all three.
+        install(&mut save, &Parts(&[]), &[(47, 0, 0)]);
+        for f in [field::ATTACK, field::SPEED, field::CHARGE] {
+            assert_eq!(ability(&save, f), field::BUSTER_MAX);
+        }
+    }
+
+    #[test]
+    fn hub_style_runs_its_constituents() {
+        let mut save = empty_save();
+        install(&mut save, &Parts(&[]), &[(49, 0, 0)]);
+        for (f, v) in [
+            (field::SUPER_ARMOR, 1),
+            (field::BREAK_BUSTER, 1),
+            (field::BREAK_CHARGE, 1),
+            (field::CUSTOM_GAUGE, 1),
+            (field::MEGA_LIMIT, 1),
+            (field::B_LEFT_ABILITY, 4),
+            (field::SHOES, 2),
+            (field::UNDER_SHIRT, 1),
+            (field::AIR_SHOES, 1),
+        ] {
+            assert_eq!(ability(&save, f), v, "field {f:#04x}");
+        }
+    }
+
+    #[test]
+    fn solid_programs_need_the_command_line() {
+        let assets = Parts(&[1]);
+        let mut save = empty_save();
+        install(&mut save, &assets, &[(1, 0, 0)]);
+        assert_eq!(ability(&save, field::SUPER_ARMOR), 0);
+
+        install(&mut save, &assets, &[(1, COMMAND_LINE as u8, 0)]);
+        assert_eq!(ability(&save, field::SUPER_ARMOR), 1);
+    }
+
+    #[test]
+    fn ex_code_runs_after_the_programs() {
+        let mut save = empty_save();
+        // The Shield code over a Block program.
+        save.buf[0x1270] = 0x33;
+        install(&mut save, &Parts(&[]), &[(14, 0, 0)]);
+        assert_eq!(ability(&save, field::B_LEFT_ABILITY), 4);
+    }
+
+    #[test]
+    fn removing_a_program_clears_its_ability() {
+        let assets = Parts(&[]);
+        let mut save = empty_save();
+        install(&mut save, &assets, &[(1, 0, 0), (23, 0, 1)]);
+        install(&mut save, &assets, &[(23, 0, 1)]);
+        assert_eq!(ability(&save, field::SUPER_ARMOR), 0);
+        assert_eq!(ability(&save, field::SNEAK_RUN), 1);
+    }
+
+    /// A synthetic styled save: the array holds a Shield-style B+Left and
+    /// a Custom-style gauge point over what its one program compiles to.
+    /// No real styled save is in the tree to check this against.
+    #[test]
+    fn style_share_survives_a_grid_edit() {
+        let assets = Parts(&[]);
+        let mut save = empty_save();
+        // Custom1.
+        install(&mut save, &assets, &[(10, 0, 0)]);
+        save.buf[ABILITIES_OFFSET + field::B_LEFT_ABILITY] = 4;
+        save.buf[ABILITIES_OFFSET + field::CUSTOM_GAUGE] += 1;
+
+        // Custom1 and SneakRun.
+        install(&mut save, &assets, &[(10, 0, 0), (23, 0, 1)]);
+        assert_eq!(ability(&save, field::B_LEFT_ABILITY), 4);
+        assert_eq!(ability(&save, field::CUSTOM_GAUGE), 2);
+        assert_eq!(ability(&save, field::SNEAK_RUN), 1);
+
+        // Every program gone, the style's share stays.
+        install(&mut save, &assets, &[]);
+        assert_eq!(ability(&save, field::B_LEFT_ABILITY), 4);
+        assert_eq!(ability(&save, field::CUSTOM_GAUGE), 1);
+        assert_eq!(ability(&save, field::SNEAK_RUN), 0);
+
+        // A program writing the same field doesn't take it from the style.
+        install(&mut save, &assets, &[(14, 0, 0)]);
+        assert_eq!(ability(&save, field::B_LEFT_ABILITY), 4);
+    }
+
+    #[test]
+    fn bug_fields_are_left_alone() {
+        let mut save = empty_save();
+        // Buster and encounter-rate bug fields, and an undecoded byte.
+        for f in [0x07, 0x19, 0x05] {
+            save.buf[ABILITIES_OFFSET + f] = 3;
+        }
+        install(&mut save, &Parts(&[]), &[(41, 0, 0)]);
+        for f in [0x07, 0x19, 0x05] {
+            assert_eq!(ability(&save, f), 3);
+        }
+    }
+
+    /// The US White ROM's assets over a blank image whose every word points
+    /// back at the image, so the palette pointers `Assets::new` follows
+    /// land. Nothing here reads chips; the HP range is the ROM entry's own.
+    fn rom_assets() -> crate::rom::Assets {
+        let rom = 0x0800_0000u32.to_le_bytes().repeat(0x200000);
+        crate::rom::Assets::new(&crate::rom::A6BE_00, &[], rom, vec![0; 0x40000])
+    }
+
+    /// `save` written out and parsed back the way a loader would.
+    fn reload(save: &mut Save) -> Save {
+        use tango_gamesupport_common_dataview::save::Save as _;
+        save.rebuild_checksum();
+        Save::new(&save.to_sram_dump()).unwrap()
+    }
+
+    #[test]
+    fn base_max_hp_round_trips_as_a_count() {
+        use tango_gamesupport_common_dataview::save::{NaviView as _, NaviViewMut as _};
+        let assets = rom_assets();
+        let mut save = empty_save();
+        save.buf[GAME_NAME_OFFSET..][..20].copy_from_slice(b"ROCKMANEXE3 20021002");
+        assert!(NaviView { save: &mut save }.set_base_max_hp(500, &assets));
+        assert_eq!(save.buf[0x1a20], 20);
+
+        let reloaded = reload(&mut save);
+        let navi = NaviView { save: &reloaded };
+        assert_eq!(navi.base_max_hp(&assets), Some(500));
+        assert_eq!(navi.max_hp(&assets), 500);
+    }
+
+    #[test]
+    fn base_max_hp_off_the_rom_steps_is_refused() {
+        use tango_gamesupport_common_dataview::save::NaviViewMut as _;
+        let assets = rom_assets();
+        let mut save = empty_save();
+        let mut navi = NaviView { save: &mut save };
+        assert!(!navi.set_base_max_hp(90, &assets));
+        assert!(!navi.set_base_max_hp(510, &assets));
+        assert!(!navi.set_base_max_hp(1020, &assets));
+        assert_eq!(save.buf[0x1a20], 0);
+    }
+
+    /// The levels are the compiled array's, one up from its 0-based
+    /// values.
+    #[test]
+    fn buster_stats_read_the_compiled_levels() {
+        use tango_gamesupport_common_dataview::save::{NaviBusterStats, NaviView as _};
+        let mut save = empty_save();
+        // Two Atk+1 and a Charge+1.
+        install(&mut save, &Parts(&[]), &[(41, 0, 0), (41, 0, 1), (43, 1, 0)]);
+        let navi = NaviView { save: &save };
+        assert_eq!(
+            navi.buster_stats(&Parts(&[])),
+            Some(NaviBusterStats {
+                attack: 3,
+                speed: 1,
+                charge: 2,
+                b_power_attack: 0,
+            })
+        );
+    }
+}
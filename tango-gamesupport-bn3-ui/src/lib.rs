@@ -1,4 +1,4 @@
-//! BN3's save-editor UI: the style-bearing navicust (read-only) and the chip folder.
+//! BN3's save-editor UI: the style-bearing navicust, the chip folder and the pack.
 
 use tango_gamesupport_common_ui::editor::loaded::OpenSave;
 use tango_gamesupport_common_ui::editor::view as sv;
@@ -20,6 +20,7 @@ impl GameSaveEditor for Ui {
                 tabs.push(Tab::Navicust);
             }
             tabs.push(Tab::Folder);
+            tabs.push(Tab::Pack);
             tabs
         }
     }
@@ -36,6 +37,7 @@ impl GameSaveEditor for Ui {
             Tab::Cover => sv::cover::render_cover(lang, loaded),
             Tab::Navicust => sv::navicust::render_navicust_tab(lang, loaded),
             Tab::Folder => sv::folder::render_folder(lang, loaded, opts.folder_grouped),
+            Tab::Pack => sv::pack::render_pack(lang, loaded),
             _ => sv::placeholder(tango_gamesupport_common_ui::t!(lang, "save-empty")),
         }
     }
@@ -48,7 +50,9 @@ impl GameSaveEditor for Ui {
         state: &'a State,
     ) -> iced::Element<'a, Action> {
         match tab {
+            Tab::Navicust => sv::navicust::render_navicust_edit(lang, loaded, state),
             Tab::Folder => sv::folder::render_folder_edit(lang, loaded, state),
+            Tab::Pack => sv::pack::render_pack_edit(lang, loaded, state),
             _ => sv::placeholder(tango_gamesupport_common_ui::t!(lang, "save-empty")),
         }
     }
@@ -58,6 +62,7 @@ impl GameSaveEditor for Ui {
         match tab {
             Tab::Navicust => sv::navicust::navicust_as_text(loaded),
             Tab::Folder => sv::folder::as_text(loaded, opts),
+            Tab::Pack => sv::pack::as_text(loaded),
             _ => None,
         }
     }
@@ -321,6 +321,7 @@ pub static EXE3W: Game = Game {
     rom_size: 0x800000,
     region: Region::JP,
     parse_save_fn: |data| parse_save(data, dataview::save::Variant::White),
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::A6BJ_01,
@@ -344,6 +345,7 @@ pub static EXE3B: Game = Game {
     rom_size: 0x800000,
     region: Region::JP,
     parse_save_fn: |data| parse_save(data, dataview::save::Variant::Blue),
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::A3XJ_01,
@@ -367,6 +369,7 @@ pub static BN3W: Game = Game {
     rom_size: 0x800000,
     region: Region::US,
     parse_save_fn: |data| parse_save(data, dataview::save::Variant::White),
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::A6BE_00,
@@ -390,6 +393,7 @@ pub static BN3B: Game = Game {
     rom_size: 0x800000,
     region: Region::US,
     parse_save_fn: |data| parse_save(data, dataview::save::Variant::Blue),
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::A3XE_00,
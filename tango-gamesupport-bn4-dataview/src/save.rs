@@ -220,6 +220,14 @@ impl tango_gamesupport_common_dataview::save::Save for Save {
         std::borrow::Cow::Borrowed(&self.buf)
     }
 
+    fn edit_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    fn edit_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.buf
+    }
+
     fn to_sram_dump(&self) -> Vec<u8> {
         let mut buf = vec![0; 65536];
         buf[..SAVE_SIZE].copy_from_slice(&self.buf);
@@ -532,6 +540,12 @@ impl<S: std::ops::Deref<Target = Save>> PatchCard4sView<S> {
         };
         Some(tango_gamesupport_common_dataview::save::PatchCard { id, enabled })
     }
+
+    /// Every slot in order, `None` where it's empty — what a build code
+    /// carries (`BuildCode::mod_cards`).
+    pub fn slots(&self) -> Vec<Option<tango_gamesupport_common_dataview::save::PatchCard>> {
+        (0..PATCH_CARD4_SLOTS).map(|slot| self.patch_card(slot)).collect()
+    }
 }
 
 impl<S: std::ops::DerefMut<Target = Save>> PatchCard4sView<S> {
@@ -1,10 +1,12 @@
-//! BN4's save-editor UI: navicust, folder, the six-slot Mod Card (patch
+//! BN4's save-editor UI: navicust, folder, pack, the six-slot Mod Card (patch
 //! card 4) form — BN4's own, not the BN5/BN6 list — and auto battle data.
 
+use tango_gamesupport_common_ui::dataview::build_code::BuildCode;
 use tango_gamesupport_common_ui::editor::loaded::OpenSave;
 use tango_gamesupport_common_ui::editor::view as sv;
 use tango_gamesupport_common_ui::editor::view::{Action, RenderOpts, State, Tab};
 use tango_gamesupport_common_ui::editor::{BuildReport, GameSaveEditor, SaveEditorShell};
+use tango_gamesupport_common_ui::model::Edit;
 use unic_langid::LanguageIdentifier;
 
 mod patch_cards4;
@@ -41,6 +43,7 @@ impl GameSaveEditor for Ui {
             tabs.push(Tab::Navicust);
         }
         tabs.push(Tab::Folder);
+        tabs.push(Tab::Pack);
         // Every BN4 save has the six Mod Card slots (there's no link
         // navi to take them away).
         tabs.push(Tab::PatchCards);
@@ -70,6 +73,7 @@ impl GameSaveEditor for Ui {
             Tab::Cover => sv::cover::render_cover(lang, loaded),
             Tab::Navicust => sv::navicust::render_navicust_tab(lang, loaded),
             Tab::Folder => sv::folder::render_folder(lang, loaded, opts.folder_grouped),
+            Tab::Pack => sv::pack::render_pack(lang, loaded),
             Tab::PatchCards => patch_cards4::render(lang, loaded),
             Tab::AutoBattleData => sv::abd::render_auto_battle_data(lang, loaded),
             _ => sv::placeholder(tango_gamesupport_common_ui::t!(lang, "save-empty")),
@@ -86,6 +90,7 @@ impl GameSaveEditor for Ui {
         match tab {
             Tab::Navicust => sv::navicust::render_navicust_edit(lang, loaded, state),
             Tab::Folder => sv::folder::render_folder_edit(lang, loaded, state),
+            Tab::Pack => sv::pack::render_pack_edit(lang, loaded, state),
             Tab::PatchCards => patch_cards4::render_edit(lang, loaded, state),
             Tab::AutoBattleData => sv::abd::render_auto_battle_data_edit(lang, loaded, state),
             _ => sv::placeholder(tango_gamesupport_common_ui::t!(lang, "save-empty")),
@@ -96,6 +101,7 @@ impl GameSaveEditor for Ui {
         match tab {
             Tab::Navicust => sv::navicust::navicust_as_text(loaded),
             Tab::Folder => sv::folder::as_text(loaded, opts),
+            Tab::Pack => sv::pack::as_text(loaded),
             Tab::PatchCards => patch_cards4::as_text(loaded),
             Tab::AutoBattleData => sv::abd::as_text(lang, loaded),
             _ => None,
@@ -109,6 +115,22 @@ impl GameSaveEditor for Ui {
         }
     }
 
+    /// The shared build plus the Mod Cards, which only this crate can
+    /// read.
+    fn build_code(&self, loaded: &OpenSave) -> BuildCode {
+        let mut code = BuildCode::from_save(loaded.build_code_game(), loaded.save.as_ref());
+        code.mod_cards = patch_cards4::slots(loaded);
+        code
+    }
+
+    fn build_code_edits(&self, code: &BuildCode, loaded: &OpenSave) -> Vec<Edit> {
+        let mut edits = tango_gamesupport_common_ui::model::build_code::edits(code, loaded);
+        if let Some(slots) = &code.mod_cards {
+            edits.push(Edit::Game(std::sync::Arc::new(PatchCard4Edit::SetSlots(slots.clone()))));
+        }
+        edits
+    }
+
     fn build_report(&self, loaded: &OpenSave) -> BuildReport {
         let save = loaded.save.as_ref();
         let assets = loaded.assets.as_ref();
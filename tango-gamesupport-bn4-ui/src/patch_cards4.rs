@@ -423,6 +423,11 @@ pub fn render_edit<'a>(lang: &'a LanguageIdentifier, loaded: &'a OpenSave, state
     .into()
 }
 
+/// The Mod Card slots for a build code.
+pub fn slots(loaded: &OpenSave) -> Option<Vec<Option<tango_gamesupport_common_dataview::save::PatchCard>>> {
+    Some(bn4_save(loaded)?.view_patch_card4s().slots())
+}
+
 /// The Mod Card tab as TSV text.
 pub fn as_text(loaded: &OpenSave) -> Option<String> {
     let save = bn4_save(loaded)?;
@@ -456,6 +461,8 @@ pub enum PatchCard4Edit {
     ToggleCard { slot: usize },
     /// Empty every slot.
     ClearAll,
+    /// Write every slot at once, as a build code carries them.
+    SetSlots(Vec<Option<tango_gamesupport_common_dataview::save::PatchCard>>),
 }
 
 impl GameEdit for PatchCard4Edit {
@@ -510,6 +517,11 @@ impl GameEdit for PatchCard4Edit {
                     v.set_patch_card(slot, None);
                 }
             }
+            PatchCard4Edit::SetSlots(slots) => {
+                for slot in 0..PATCH_CARD4_SLOT_LABELS.len() {
+                    v.set_patch_card(slot, slots.get(slot).cloned().flatten());
+                }
+            }
         }
 
         // Keep the anti-cheat mirror in sync with the edit.
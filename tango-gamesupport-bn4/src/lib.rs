@@ -104,6 +104,7 @@ pub static EXE4RS: Game = Game {
     rom_size: 0x800000,
     region: Region::JP,
     parse_save_fn: |data| parse_save(data, true, dataview::save::Variant::RedSun),
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::B4WJ_01,
@@ -151,6 +152,7 @@ pub static EXE4BM: Game = Game {
     rom_size: 0x800000,
     region: Region::JP,
     parse_save_fn: |data| parse_save(data, true, dataview::save::Variant::BlueMoon),
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::B4BJ_01,
@@ -198,6 +200,7 @@ pub static BN4RS: Game = Game {
     rom_size: 0x800000,
     region: Region::US,
     parse_save_fn: |data| parse_save(data, false, dataview::save::Variant::RedSun),
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::B4WE_00,
@@ -245,6 +248,7 @@ pub static BN4BM: Game = Game {
     rom_size: 0x800000,
     region: Region::US,
     parse_save_fn: |data| parse_save(data, false, dataview::save::Variant::BlueMoon),
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::B4BE_00,
@@ -272,6 +272,14 @@ impl tango_gamesupport_common_dataview::save::Save for Save {
         std::borrow::Cow::Borrowed(&self.buf)
     }
 
+    fn edit_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    fn edit_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.buf
+    }
+
     fn to_sram_dump(&self) -> Vec<u8> {
         let mut buf = vec![0; 65536];
         buf[SAVE_START_OFFSET..][..SAVE_SIZE].copy_from_slice(&self.buf);
@@ -850,6 +858,10 @@ impl<S: std::ops::Deref<Target = Save>> tango_gamesupport_common_dataview::save:
 }
 
 impl<S: std::ops::DerefMut<Target = Save>> tango_gamesupport_common_dataview::save::NaviViewMut for NaviView<S> {
+    fn has_roster(&self) -> bool {
+        true
+    }
+
     fn set_navi(&mut self, navi: usize) -> bool {
         self.save.buf[0x2941] = navi as u8;
         true
@@ -1,4 +1,4 @@
-//! BN5's save-editor UI: navicust, folder (with Dark chips), patch cards (56-style) and auto battle data. Link navis drop the navicust/patch-card tabs at runtime.
+//! BN5's save-editor UI: navicust, folder (with Dark chips), pack, patch cards (56-style) and auto battle data. Link navis drop the navicust/patch-card tabs at runtime.
 
 use tango_gamesupport_common_ui::editor::loaded::OpenSave;
 use tango_gamesupport_common_ui::editor::view as sv;
@@ -21,6 +21,7 @@ impl GameSaveEditor for Ui {
                 tabs.push(Tab::Navicust);
             }
             tabs.push(Tab::Folder);
+            tabs.push(Tab::Pack);
             if save.view_patch_card56s().is_some() {
                 tabs.push(Tab::PatchCards);
             }
@@ -43,6 +44,7 @@ impl GameSaveEditor for Ui {
             Tab::Cover => sv::cover::render_cover(lang, loaded),
             Tab::Navicust => sv::navicust::render_navicust_tab(lang, loaded),
             Tab::Folder => sv::folder::render_folder(lang, loaded, opts.folder_grouped),
+            Tab::Pack => sv::pack::render_pack(lang, loaded),
             Tab::PatchCards => sv::patch_cards::render_patch_cards56(lang, loaded),
             Tab::AutoBattleData => sv::abd::render_auto_battle_data(lang, loaded),
             _ => sv::placeholder(tango_gamesupport_common_ui::t!(lang, "save-empty")),
@@ -59,6 +61,7 @@ impl GameSaveEditor for Ui {
         match tab {
             Tab::Navicust => sv::navicust::render_navicust_edit(lang, loaded, state),
             Tab::Folder => sv::folder::render_folder_edit(lang, loaded, state),
+            Tab::Pack => sv::pack::render_pack_edit(lang, loaded, state),
             Tab::PatchCards => sv::patch_cards::render_patch_cards56_edit(lang, loaded, state),
             Tab::AutoBattleData => sv::abd::render_auto_battle_data_edit(lang, loaded, state),
             _ => sv::placeholder(tango_gamesupport_common_ui::t!(lang, "save-empty")),
@@ -70,6 +73,7 @@ impl GameSaveEditor for Ui {
         match tab {
             Tab::Navicust => sv::navicust::navicust_as_text(loaded),
             Tab::Folder => sv::folder::as_text(loaded, opts),
+            Tab::Pack => sv::pack::as_text(loaded),
             Tab::PatchCards => sv::patch_cards::as_text56(loaded),
             Tab::AutoBattleData => sv::abd::as_text(lang, loaded),
             _ => None,
@@ -90,6 +90,7 @@ pub static EXE5B: Game = Game {
     rom_size: 0x800000,
     region: Region::JP,
     parse_save_fn: |data| parse_save(data, dataview::save::Region::JP, dataview::save::Variant::Protoman),
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::BRBJ_00,
@@ -129,6 +130,7 @@ pub static EXE5C: Game = Game {
     rom_size: 0x800000,
     region: Region::JP,
     parse_save_fn: |data| parse_save(data, dataview::save::Region::JP, dataview::save::Variant::Colonel),
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::BRKJ_00,
@@ -168,6 +170,7 @@ pub static BN5P: Game = Game {
     rom_size: 0x800000,
     region: Region::US,
     parse_save_fn: |data| parse_save(data, dataview::save::Region::US, dataview::save::Variant::Protoman),
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::BRBE_00,
@@ -207,6 +210,7 @@ pub static BN5C: Game = Game {
     rom_size: 0x800000,
     region: Region::US,
     parse_save_fn: |data| parse_save(data, dataview::save::Region::US, dataview::save::Variant::Colonel),
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::BRKE_00,
@@ -1264,6 +1264,14 @@ impl tango_gamesupport_common_dataview::save::Save for Save {
         Some(Box::new(AutoBattleDataView { save: self }))
     }
 
+    fn edit_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    fn edit_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
     fn to_sram_dump(&self) -> Vec<u8> {
         self.data.clone()
     }
@@ -1,4 +1,4 @@
-//! BN5DS's save-editor UI: the NaviCust, the chip folder, the
+//! BN5DS's save-editor UI: the NaviCust, the chip folder and pack, the
 //! auto-battle data, the GBA-slot cross in the identity slot BN5/BN6
 //! name their navi in, and — while editing — a switcher for which of
 //! the cartridge's two in-game files is the one being played.
@@ -1106,6 +1106,7 @@ impl GameSaveEditor for Ui {
         }
         if save.view_chips().is_some() {
             tabs.push(Tab::Folder);
+            tabs.push(Tab::Pack);
         }
         if file_of(loaded).is_some() {
             tabs.push(Tab::Party);
@@ -1153,7 +1154,7 @@ impl GameSaveEditor for Ui {
         actions: iced::Element<'a, Action>,
     ) -> iced::Element<'a, Action> {
         let Some(save) = file_of(loaded) else {
-            return sv::navi::render_navi_strip(lang, loaded, edit, actions);
+            return sv::navi::render_navi_strip(lang, loaded, edit, editing, actions);
         };
         let card = if editing {
             cross_picker(lang, loaded, save)
@@ -1181,6 +1182,7 @@ impl GameSaveEditor for Ui {
             Tab::Cover => sv::cover::render_cover(lang, loaded),
             Tab::Navicust => sv::navicust::render_navicust_tab(lang, loaded),
             Tab::Folder => sv::folder::render_folder(lang, loaded, opts.folder_grouped),
+            Tab::Pack => sv::pack::render_pack(lang, loaded),
             Tab::Party => render_party(lang, loaded),
             Tab::AutoBattleData => sv::abd::render_auto_battle_data(lang, loaded),
             _ => sv::placeholder(tango_gamesupport_common_ui::t!(lang, "save-empty")),
@@ -1197,6 +1199,7 @@ impl GameSaveEditor for Ui {
         match tab {
             Tab::Navicust => sv::navicust::render_navicust_edit(lang, loaded, state),
             Tab::Folder => sv::folder::render_folder_edit(lang, loaded, state),
+            Tab::Pack => sv::pack::render_pack_edit(lang, loaded, state),
             Tab::Party => render_party_edit(lang, loaded),
             Tab::AutoBattleData => sv::abd::render_auto_battle_data_edit(lang, loaded, state),
             _ => sv::placeholder(tango_gamesupport_common_ui::t!(lang, "save-empty")),
@@ -1207,6 +1210,7 @@ impl GameSaveEditor for Ui {
         match tab {
             Tab::Navicust => sv::navicust::navicust_as_text(loaded),
             Tab::Folder => sv::folder::as_text(loaded, opts),
+            Tab::Pack => sv::pack::as_text(loaded),
             Tab::Party => party_as_text(loaded),
             Tab::AutoBattleData => sv::abd::as_text(lang, loaded),
             _ => None,
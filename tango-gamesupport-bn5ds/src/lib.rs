@@ -118,6 +118,7 @@ pub static BN5DS: Game = Game {
     parse_save_fn: |data| parse_save(data),
     // The DS cart has no wram-derived assets — everything comes off
     // the cart image itself.
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, _wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::A5TE_00,
@@ -146,6 +147,7 @@ pub static EXE5DS: Game = Game {
     region: Region::JP,
 
     parse_save_fn: |data| parse_save(data),
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, _wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::A5TJ_00,
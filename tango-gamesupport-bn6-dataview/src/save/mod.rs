@@ -174,6 +174,66 @@ impl Save {
             &self.buf[self.navi_stats_offset(id)..][..std::mem::size_of::<RawNaviStats>()],
         )
     }
+
+    /// The operating link navi's build, as its stats block in the save
+    /// carries it, or `None` while MegaMan operates. A link navi has no
+    /// NaviCust in the save: this block is its whole build, seeded from
+    /// [`link_navi_template`] on equip.
+    pub fn link_navi_stats(&self) -> Option<LinkNaviStats> {
+        let navi = (NaviView { save: self }).navi();
+        if navi == 0 {
+            return None;
+        }
+        Some(LinkNaviStats::from_raw(&self.navi_stats(navi)))
+    }
+}
+
+/// A link navi's fixed build: what its stats block holds in place of the
+/// NaviCust-derived values MegaMan computes. Buster levels are the game's
+/// own 1-based levels.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LinkNaviStats {
+    pub navi: usize,
+    /// The max HP the navi battles with — the block's effective max HP,
+    /// not its HP-Memory base.
+    pub max_hp: u16,
+    pub attack: u8,
+    pub speed: u8,
+    pub charge: u8,
+    pub custom_level: u8,
+    pub mega_limit: u8,
+    pub giga_limit: u8,
+    pub float_shoes: bool,
+    pub air_shoes: bool,
+    pub super_armor: bool,
+}
+
+impl LinkNaviStats {
+    fn from_raw(raw: &RawNaviStats) -> Self {
+        Self {
+            navi: raw.navi_id as usize,
+            max_hp: raw.effective_max_hp,
+            attack: raw.attack,
+            speed: raw.speed,
+            charge: raw.charge,
+            custom_level: raw.custom_level,
+            mega_limit: raw.mega_limit,
+            giga_limit: raw.giga_limit,
+            float_shoes: raw.float_shoes != 0,
+            air_shoes: raw.air_shoes != 0,
+            super_armor: raw.super_armor != 0,
+        }
+    }
+}
+
+/// The build the game stamps into a link navi's stats block when it's
+/// equipped (see [`NaviViewMut::set_navi`](tango_gamesupport_common_dataview::save::NaviViewMut::set_navi)),
+/// or `None` for MegaMan and ids past the roster.
+pub fn link_navi_template(navi: usize) -> Option<LinkNaviStats> {
+    if navi == 0 {
+        return None;
+    }
+    link_navis::NAVI_STATS.get(navi).map(LinkNaviStats::from_raw)
 }
 
 /// The operative-navi stats block (BN6 `NaviStats`), one per slot. Field names
@@ -341,6 +401,14 @@ impl tango_gamesupport_common_dataview::save::Save for Save {
         }
     }
 
+    fn edit_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    fn edit_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.buf
+    }
+
     fn to_sram_dump(&self) -> Vec<u8> {
         let mut buf = vec![0; 65536];
         buf[SAVE_START_OFFSET..][..SAVE_SIZE].copy_from_slice(&self.as_raw_wram());
@@ -1011,6 +1079,10 @@ impl<S: std::ops::Deref<Target = Save>> tango_gamesupport_common_dataview::save:
 }
 
 impl<S: std::ops::DerefMut<Target = Save>> tango_gamesupport_common_dataview::save::NaviViewMut for NaviView<S> {
+    fn has_roster(&self) -> bool {
+        true
+    }
+
     fn set_navi(&mut self, navi: usize) -> bool {
         if navi >= link_navis::NAVI_STATS.len() {
             return false;
@@ -1046,3 +1118,58 @@ impl<S: std::ops::DerefMut<Target = Save>> tango_gamesupport_common_dataview::sa
         true
     }
 }
+
+#[cfg(test)]
+mod link_navi_tests {
+    use super::*;
+
+    #[test]
+    fn every_template_is_its_own_navi() {
+        assert_eq!(link_navi_template(0), None);
+        for navi in 1..link_navis::NAVI_STATS.len() {
+            assert_eq!(link_navi_template(navi).map(|stats| stats.navi), Some(navi));
+        }
+        assert_eq!(link_navi_template(link_navis::NAVI_STATS.len()), None);
+    }
+
+    /// The US Gregar save template tango ships: a real game save, with
+    /// MegaMan operating.
+    fn template_save() -> Save {
+        Save::from_wram(
+            include_bytes!("../../../tango-gamesupport-bn6/src/saves/g_us.raw"),
+            GameInfo {
+                region: Region::US,
+                variant: Variant::Gregar,
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn operating_a_link_navi_round_trips_on_a_real_save() {
+        use tango_gamesupport_common_dataview::save::Save as _;
+        let mut save = template_save();
+        assert_eq!(save.link_navi_stats(), None);
+        assert!(save.view_navicust().is_some());
+
+        assert!(save.view_navi_mut().unwrap().set_navi(2));
+        save.rebuild_checksum();
+        let reloaded = Save::new(&save.to_sram_dump()).unwrap();
+        assert_eq!(reloaded.view_navi().unwrap().navi(), 2);
+        assert_eq!(reloaded.link_navi_stats(), link_navi_template(2));
+        assert!(reloaded.view_navicust().is_none());
+    }
+
+    #[test]
+    fn megaman_gets_his_navicust_back_on_a_real_save() {
+        use tango_gamesupport_common_dataview::save::Save as _;
+        let mut save = template_save();
+        let navicust = save.view_navicust().unwrap().materialized();
+
+        assert!(save.view_navi_mut().unwrap().set_navi(1));
+        assert!(save.view_navi_mut().unwrap().set_navi(0));
+        assert_eq!(save.view_navi().unwrap().navi(), 0);
+        assert_eq!(save.link_navi_stats(), None);
+        assert_eq!(save.view_navicust().unwrap().materialized(), navicust);
+    }
+}
@@ -1,4 +1,4 @@
-//! BN6's save-editor UI: navicust, folder and patch cards (56-style). A link navi drops the navicust/patch-card tabs at runtime.
+//! BN6's save-editor UI: navicust, folder, pack, patch cards (56-style) and the link navis. A link navi drops the navicust/patch-card tabs at runtime; the [`link_navi`] tab shows the build it brings instead.
 
 use tango_gamesupport_common_ui::editor::loaded::OpenSave;
 use tango_gamesupport_common_ui::editor::view as sv;
@@ -6,6 +6,8 @@ use tango_gamesupport_common_ui::editor::view::{Action, RenderOpts, State, Tab};
 use tango_gamesupport_common_ui::editor::{GameSaveEditor, SaveEditorShell};
 use unic_langid::LanguageIdentifier;
 
+pub mod link_navi;
+
 pub struct Ui;
 
 /// The instance tango's per-family registry hands out.
@@ -21,13 +23,26 @@ impl GameSaveEditor for Ui {
                 tabs.push(Tab::Navicust);
             }
             tabs.push(Tab::Folder);
+            tabs.push(Tab::Pack);
             if save.view_patch_card56s().is_some() {
                 tabs.push(Tab::PatchCards);
             }
+            if link_navi::file_of(loaded).is_some() {
+                tabs.push(Tab::LinkNavi);
+            }
             tabs
         }
     }
 
+    /// Switching navis is the shared navi edit, so the Link Navi tab is
+    /// editable exactly when the navi is.
+    fn tab_editable(&self, tab: Tab, loaded: &OpenSave) -> bool {
+        match tab {
+            Tab::LinkNavi => loaded.editability.navi && link_navi::file_of(loaded).is_some(),
+            tab => tab.editable_on(&loaded.editability),
+        }
+    }
+
     fn render<'a>(
         &self,
         lang: &'a LanguageIdentifier,
@@ -40,7 +55,9 @@ impl GameSaveEditor for Ui {
             Tab::Cover => sv::cover::render_cover(lang, loaded),
             Tab::Navicust => sv::navicust::render_navicust_tab(lang, loaded),
             Tab::Folder => sv::folder::render_folder(lang, loaded, opts.folder_grouped),
+            Tab::Pack => sv::pack::render_pack(lang, loaded),
             Tab::PatchCards => sv::patch_cards::render_patch_cards56(lang, loaded),
+            Tab::LinkNavi => link_navi::render(lang, loaded),
             _ => sv::placeholder(tango_gamesupport_common_ui::t!(lang, "save-empty")),
         }
     }
@@ -55,7 +72,9 @@ impl GameSaveEditor for Ui {
         match tab {
             Tab::Navicust => sv::navicust::render_navicust_edit(lang, loaded, state),
             Tab::Folder => sv::folder::render_folder_edit(lang, loaded, state),
+            Tab::Pack => sv::pack::render_pack_edit(lang, loaded, state),
             Tab::PatchCards => sv::patch_cards::render_patch_cards56_edit(lang, loaded, state),
+            Tab::LinkNavi => link_navi::render_edit(lang, loaded),
             _ => sv::placeholder(tango_gamesupport_common_ui::t!(lang, "save-empty")),
         }
     }
@@ -65,7 +84,9 @@ impl GameSaveEditor for Ui {
         match tab {
             Tab::Navicust => sv::navicust::navicust_as_text(loaded),
             Tab::Folder => sv::folder::as_text(loaded, opts),
+            Tab::Pack => sv::pack::as_text(loaded),
             Tab::PatchCards => sv::patch_cards::as_text56(loaded),
+            Tab::LinkNavi => link_navi::as_text(loaded),
             _ => None,
         }
     }
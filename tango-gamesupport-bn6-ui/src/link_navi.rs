@@ -0,0 +1,256 @@
+//! The Link Navi tab: every navi this version can operate, with the build
+//! each one fights with.
+//!
+//! MegaMan's build is his NaviCust; a link navi has none. The game seeds
+//! its stats block from a fixed template when it's equipped and never
+//! writes a NaviCust for it, so what the tab shows per link navi is that
+//! block — the save's own while the navi operates, the template otherwise.
+//! MegaMan's NaviCust stays in the save the whole time and is his again
+//! the moment he operates.
+//!
+//! Switching is the same [`Action::SetNavi`] the identity strip's picker
+//! stages, offered per row while editing.
+//!
+//! Unlock state isn't part of this tab: no per-navi unlock flag is mapped
+//! in the save model, and a toggle over a guessed one could write bytes
+//! the game reads as something else.
+
+use iced::widget::{column, container, row, scrollable, text, Image, Space};
+use iced::{Alignment, Element, Fill, Length};
+use tango_gamesupport_bn6_dataview::save::{self, LinkNaviStats, Save};
+use tango_gamesupport_common_dataview::save::Save as _;
+use tango_gamesupport_common_ui::editor::loaded::OpenSave;
+use tango_gamesupport_common_ui::editor::view as sv;
+use tango_gamesupport_common_ui::editor::view::Action;
+use tango_gamesupport_common_ui::style::{CONTROL_PADDING, PANE_PADDING, TEXT_BODY, TEXT_CAPTION};
+use tango_gamesupport_common_ui::t;
+use tango_gamesupport_common_ui::widgets::muted_text_style;
+use unic_langid::LanguageIdentifier;
+
+const MEGAMAN_NAVI: usize = 0;
+const EMBLEM_SIZE: f32 = 32.0;
+
+/// This save, when the loaded save is one of ours.
+pub(crate) fn file_of(loaded: &OpenSave) -> Option<&Save> {
+    loaded.save.as_ref().as_any().downcast_ref::<Save>()
+}
+
+fn navi_name(loaded: &OpenSave, navi: usize) -> String {
+    loaded
+        .assets
+        .navi(navi)
+        .and_then(|n| n.name())
+        .unwrap_or_else(|| format!("Navi #{navi}"))
+}
+
+/// Every navi the ROM lets this version equip, MegaMan first, in the
+/// order the change-navi picker lays them out.
+fn roster(loaded: &OpenSave) -> Vec<usize> {
+    let mut navis = vec![MEGAMAN_NAVI];
+    navis.extend(
+        loaded
+            .assets
+            .navi_order()
+            .iter()
+            .flat_map(|r| r.iter().copied())
+            .filter(|&id| id != MEGAMAN_NAVI),
+    );
+    navis
+}
+
+/// The build `navi` fights with: the save's own stats block while it
+/// operates, the equip template otherwise. `None` for MegaMan.
+fn build_of(save: &Save, navi: usize) -> Option<LinkNaviStats> {
+    save.link_navi_stats()
+        .filter(|stats| stats.navi == navi)
+        .or_else(|| save::link_navi_template(navi))
+}
+
+fn operating(loaded: &OpenSave) -> usize {
+    loaded.save.view_navi().map(|nv| nv.navi()).unwrap_or(MEGAMAN_NAVI)
+}
+
+/// The abilities a build turns on, by their game names.
+fn abilities(lang: &LanguageIdentifier, stats: &LinkNaviStats) -> Vec<String> {
+    let mut out = vec![];
+    if stats.float_shoes {
+        out.push(t!(lang, "link-navi-float-shoes"));
+    }
+    if stats.air_shoes {
+        out.push(t!(lang, "link-navi-air-shoes"));
+    }
+    if stats.super_armor {
+        out.push(t!(lang, "link-navi-super-armor"));
+    }
+    out
+}
+
+/// One navi's row: emblem, name over its build, and on the right either
+/// the operating badge or — while editing — the button that equips it.
+fn navi_row(
+    lang: &LanguageIdentifier,
+    loaded: &OpenSave,
+    save: &Save,
+    navi: usize,
+    index: usize,
+    editing: bool,
+) -> Element<'static, Action> {
+    let is_operating = operating(loaded) == navi;
+
+    let emblem: Element<'static, Action> = match loaded.navi_emblems.get(&navi) {
+        Some(handle) => Image::new(handle.clone())
+            .width(Length::Fixed(EMBLEM_SIZE))
+            .height(Length::Fixed(EMBLEM_SIZE))
+            .filter_method(iced::widget::image::FilterMethod::Nearest)
+            .into(),
+        None => Space::new()
+            .width(Length::Fixed(EMBLEM_SIZE))
+            .height(Length::Fixed(EMBLEM_SIZE))
+            .into(),
+    };
+
+    let mut stats = row![].spacing(12).align_y(Alignment::End);
+    match build_of(save, navi) {
+        Some(build) => {
+            stats = stats
+                .push(sv::stat::<Action>(
+                    t!(lang, "link-navi-max-hp"),
+                    build.max_hp.to_string(),
+                ))
+                .push(sv::stat::<Action>(
+                    t!(lang, "navi-buster-attack"),
+                    build.attack.to_string(),
+                ))
+                .push(sv::stat::<Action>(
+                    t!(lang, "navi-buster-rapid"),
+                    build.speed.to_string(),
+                ))
+                .push(sv::stat::<Action>(
+                    t!(lang, "navi-buster-charge"),
+                    build.charge.to_string(),
+                ))
+                .push(sv::stat::<Action>(
+                    t!(lang, "link-navi-custom"),
+                    build.custom_level.to_string(),
+                ))
+                .push(sv::stat::<Action>(
+                    t!(lang, "link-navi-mega"),
+                    build.mega_limit.to_string(),
+                ))
+                .push(sv::stat::<Action>(
+                    t!(lang, "link-navi-giga"),
+                    build.giga_limit.to_string(),
+                ));
+            let abilities = abilities(lang, &build);
+            if !abilities.is_empty() {
+                stats = stats.push(text(abilities.join(" · ")).size(TEXT_CAPTION).style(muted_text_style));
+            }
+        }
+        None => {
+            stats = stats.push(
+                text(t!(lang, "link-navi-megaman-build"))
+                    .size(TEXT_CAPTION)
+                    .style(muted_text_style),
+            );
+        }
+    }
+
+    let status: Element<'static, Action> = if is_operating {
+        text(t!(lang, "link-navi-operating")).size(TEXT_CAPTION).into()
+    } else if editing {
+        tango_gamesupport_common_ui::widgets::labeled_icon_button(
+            lucide_icons::Icon::ArrowLeftRight,
+            t!(lang, "link-navi-operate"),
+            Action::SetNavi(navi),
+            CONTROL_PADDING,
+            tango_gamesupport_common_ui::widgets::neutral,
+        )
+    } else {
+        Space::new().into()
+    };
+
+    let mut name = text(navi_name(loaded, navi)).size(TEXT_BODY);
+    if !is_operating {
+        name = name.style(muted_text_style);
+    }
+
+    sv::card_wrap(
+        container(
+            row![
+                emblem,
+                column![name, stats].spacing(2),
+                Space::new().width(Fill),
+                status,
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        )
+        .padding([6.0, 8.0])
+        .into(),
+        None,
+        index,
+    )
+}
+
+fn render_list<'a>(lang: &'a LanguageIdentifier, loaded: &'a OpenSave, editing: bool) -> Element<'a, Action> {
+    let Some(save) = file_of(loaded) else {
+        return sv::placeholder(t!(lang, "save-empty"));
+    };
+    let mut rows = column![].width(Fill);
+    for (index, navi) in roster(loaded).into_iter().enumerate() {
+        rows = rows.push(navi_row(lang, loaded, save, navi, index, editing));
+    }
+    let body = column![
+        rows,
+        text(t!(lang, "link-navi-no-navicust"))
+            .size(TEXT_CAPTION)
+            .style(muted_text_style),
+    ]
+    .spacing(12)
+    .width(Fill);
+
+    container(
+        scrollable(container(body).padding(PANE_PADDING).width(Fill))
+            .style(tango_gamesupport_common_ui::widgets::chunky_scrollable)
+            .height(Fill)
+            .width(Fill),
+    )
+    .style(tango_gamesupport_common_ui::widgets::pane)
+    .width(Fill)
+    .height(Fill)
+    .into()
+}
+
+/// The tab's read-only body.
+pub fn render<'a>(lang: &'a LanguageIdentifier, loaded: &'a OpenSave) -> Element<'a, Action> {
+    render_list(lang, loaded, false)
+}
+
+/// The same list with an Operate button on every navi not already out.
+pub fn render_edit<'a>(lang: &'a LanguageIdentifier, loaded: &'a OpenSave) -> Element<'a, Action> {
+    render_list(lang, loaded, true)
+}
+
+/// The tab as clipboard text: one navi per line, its build tab-separated,
+/// the operating one marked.
+pub fn as_text(loaded: &OpenSave) -> Option<String> {
+    let save = file_of(loaded)?;
+    let current = operating(loaded);
+    Some(
+        roster(loaded)
+            .into_iter()
+            .map(|navi| {
+                let mark = if navi == current { "*" } else { "" };
+                let name = navi_name(loaded, navi);
+                match build_of(save, navi) {
+                    Some(b) => format!(
+                        "{mark}{name}\t{}\t{}/{}/{}\t{}\t{}\t{}",
+                        b.max_hp, b.attack, b.speed, b.charge, b.custom_level, b.mega_limit, b.giga_limit,
+                    ),
+                    None => format!("{mark}{name}"),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
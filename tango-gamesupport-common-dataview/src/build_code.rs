@@ -0,0 +1,712 @@
+//! Build codes: a save's build — equipped folder, navicust, patch cards,
+//! navi — as one short pasteable string, read through the shared save
+//! views and written back as staged edits by the editor. Headless, so a
+//! bot or a CLI can mint and read codes with no UI toolkit linked.
+//!
+//! A code names the game it came from (family, variant, ROM revision,
+//! and the patch and version applied to it): chip and part ids are only
+//! meaningful against that ROM, so a code from one version — or from a
+//! patch that rewrote the chip tables — is refused by the other rather
+//! than loading as a different build. BN4's Mod Cards are that game's own model; the
+//! shared reader leaves [`BuildCode::mod_cards`] for BN4's crates to
+//! fill.
+//!
+//! Text form: `TB` then unpadded URL-safe base64 of the payload and a
+//! trailing CRC-32 of it. The payload is a version byte, the game tag,
+//! then tagged, length-prefixed sections.
+
+use crate::{rom, save};
+
+// Format history — bump on any layout change; readers reject every
+// other version.
+// v1: initial layout.
+// v2: game tag carries the applied patch's name and version.
+pub const FORMAT_VERSION: u8 = 2;
+
+/// Text prefix of every code.
+pub const PREFIX: &str = "TB";
+
+const SECTION_FOLDER: u8 = 1;
+const SECTION_NAVICUST: u8 = 2;
+const SECTION_PATCH_CARDS: u8 = 3;
+const SECTION_MOD_CARDS: u8 = 4;
+const SECTION_NAVI: u8 = 5;
+
+const FOLDER_HAS_REGULAR: u8 = 1 << 0;
+const FOLDER_HAS_TAGS: u8 = 1 << 1;
+
+/// Which ROM a code was made against.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GameTag {
+    /// [`tango_gamesupport::Family::id`], e.g. `"bn6"`.
+    pub family: String,
+    /// [`tango_gamesupport::Game::variant`].
+    pub variant: u8,
+    /// [`tango_gamesupport::Game::revision`].
+    pub revision: u8,
+    /// The patch applied over that ROM, `None` for the unpatched game.
+    pub patch: Option<PatchTag>,
+}
+
+/// A patch by name and version. The version is kept as its text so this
+/// crate needn't parse it; two tags match only on the exact string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PatchTag {
+    pub name: String,
+    pub version: String,
+}
+
+impl GameTag {
+    pub fn of(game: tango_gamesupport::GameRef, patch: Option<PatchTag>) -> Self {
+        Self {
+            family: game.family.id.to_string(),
+            variant: game.variant,
+            revision: game.revision,
+            patch,
+        }
+    }
+}
+
+impl std::fmt::Display for GameTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} v{} rev {}", self.family, self.variant, self.revision)?;
+        if let Some(patch) = &self.patch {
+            write!(f, " + {} {}", patch.name, patch.version)?;
+        }
+        Ok(())
+    }
+}
+
+/// The equipped folder, slot by slot. Trailing empty slots are left
+/// off; a gap before the last chip is kept (BCC's program deck has
+/// them).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Folder {
+    pub chips: Vec<Option<save::Chip>>,
+    pub regular: Option<usize>,
+    pub tags: Option<[usize; 2]>,
+}
+
+impl Folder {
+    /// The save's equipped folder, or `None` for a save without chips.
+    pub fn of(save: &dyn save::Save) -> Option<Self> {
+        let chips = save.view_chips()?;
+        let idx = chips.equipped_folder_index();
+        let mut slots: Vec<_> = (0..chips.folder_size()).map(|i| chips.chip(idx, i)).collect();
+        while slots.last().is_some_and(|c| c.is_none()) {
+            slots.pop();
+        }
+        Some(Folder {
+            chips: slots,
+            regular: chips.regular_chip_index(idx).flatten(),
+            tags: chips.tag_chip_indexes(idx).flatten(),
+        })
+    }
+
+    /// The chip in `slot`, if the slot exists and is filled.
+    pub fn chip(&self, slot: usize) -> Option<&save::Chip> {
+        self.chips.get(slot)?.as_ref()
+    }
+}
+
+/// A decoded (or about-to-be-encoded) build. Each section is `None`
+/// when the save has no such thing — a link navi has no navicust, BN1
+/// has no patch cards.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BuildCode {
+    pub game: GameTag,
+    pub folder: Option<Folder>,
+    /// Installed navicust parts in slot order.
+    pub navicust: Option<Vec<save::NavicustPart>>,
+    /// The BN5/BN6 registered patch-card list.
+    pub patch_cards: Option<Vec<save::PatchCard>>,
+    /// BN4's six Mod Card slots. Never filled by [`BuildCode::from_save`].
+    pub mod_cards: Option<Vec<Option<save::PatchCard>>>,
+    pub navi: Option<usize>,
+}
+
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum Error {
+    #[error("not a build code")]
+    NotACode,
+    #[error("build code is damaged (checksum mismatch)")]
+    Checksum,
+    #[error("unsupported build code version {0} (want {FORMAT_VERSION})")]
+    UnsupportedVersion(u8),
+    #[error("build code is malformed")]
+    Malformed,
+    #[error("build code is for {found}, not {expected}")]
+    WrongGame {
+        expected: Box<GameTag>,
+        found: Box<GameTag>,
+    },
+    #[error("build code section is too large to encode")]
+    TooLarge,
+}
+
+impl BuildCode {
+    /// Read the build out of `save` through the shared views. `game` is
+    /// the ROM the save is open against, patch included.
+    pub fn from_save(game: GameTag, save: &dyn save::Save) -> Self {
+        let folder = Folder::of(save);
+        let navicust = save
+            .view_navicust()
+            .map(|nc| (0..nc.count()).filter_map(|i| nc.navicust_part(i)).collect());
+        let patch_cards = save
+            .view_patch_card56s()
+            .map(|pc| (0..pc.count()).filter_map(|i| pc.patch_card(i)).collect());
+        let navi = save.view_navi().map(|nv| nv.navi());
+        Self {
+            game,
+            folder,
+            navicust,
+            patch_cards,
+            mod_cards: None,
+            navi,
+        }
+    }
+
+    /// Refuse a code made against any other game, revision or patch
+    /// than `expected`.
+    pub fn check_game(&self, expected: &GameTag) -> Result<(), Error> {
+        if &self.game != expected {
+            return Err(Error::WrongGame {
+                expected: Box::new(expected.clone()),
+                found: Box::new(self.game.clone()),
+            });
+        }
+        Ok(())
+    }
+
+    /// The code's text form. Fails only on a build no save holds (a
+    /// count past `u16::MAX`, a navicust cell past 15).
+    pub fn encode(&self) -> Result<String, Error> {
+        let mut w = Vec::new();
+        w.push(FORMAT_VERSION);
+        let family = self.game.family.as_bytes();
+        w.push(u8::try_from(family.len()).map_err(|_| Error::TooLarge)?);
+        w.extend_from_slice(family);
+        w.push(self.game.variant);
+        w.push(self.game.revision);
+        // No patch is an empty name; every applied patch has one.
+        let (name, version) = match &self.game.patch {
+            Some(p) => (p.name.as_bytes(), p.version.as_bytes()),
+            None => (&[][..], &[][..]),
+        };
+        for s in [name, version] {
+            w.push(u8::try_from(s.len()).map_err(|_| Error::TooLarge)?);
+            w.extend_from_slice(s);
+        }
+
+        if let Some(folder) = &self.folder {
+            let mut s = Vec::new();
+            put_count(&mut s, folder.chips.len())?;
+            for chip in &folder.chips {
+                put_varint(
+                    &mut s,
+                    chip.as_ref().map_or(0, |c| (((c.id as u64) << 5) | c.code as u64) + 1),
+                );
+            }
+            let flags = folder.regular.map_or(0, |_| FOLDER_HAS_REGULAR) | folder.tags.map_or(0, |_| FOLDER_HAS_TAGS);
+            s.push(flags);
+            if let Some(regular) = folder.regular {
+                put_count(&mut s, regular)?;
+            }
+            if let Some([a, b]) = folder.tags {
+                put_count(&mut s, a)?;
+                put_count(&mut s, b)?;
+            }
+            put_section(&mut w, SECTION_FOLDER, &s);
+        }
+        if let Some(parts) = &self.navicust {
+            let mut s = Vec::new();
+            put_count(&mut s, parts.len())?;
+            for p in parts {
+                if p.col > 0xf || p.row > 0xf || p.rot > 0x7f {
+                    return Err(Error::TooLarge);
+                }
+                put_varint(&mut s, p.id as u64);
+                s.push((p.col << 4) | p.row);
+                s.push((p.rot << 1) | p.compressed as u8);
+            }
+            put_section(&mut w, SECTION_NAVICUST, &s);
+        }
+        if let Some(cards) = &self.patch_cards {
+            let mut s = Vec::new();
+            put_count(&mut s, cards.len())?;
+            for c in cards {
+                put_varint(&mut s, ((c.id as u64) << 1) | c.enabled as u64);
+            }
+            put_section(&mut w, SECTION_PATCH_CARDS, &s);
+        }
+        if let Some(slots) = &self.mod_cards {
+            let mut s = Vec::new();
+            put_count(&mut s, slots.len())?;
+            for c in slots {
+                put_varint(
+                    &mut s,
+                    c.as_ref().map_or(0, |c| (((c.id as u64) << 1) | c.enabled as u64) + 1),
+                );
+            }
+            put_section(&mut w, SECTION_MOD_CARDS, &s);
+        }
+        if let Some(navi) = self.navi {
+            let mut s = Vec::new();
+            put_varint(&mut s, navi as u64);
+            put_section(&mut w, SECTION_NAVI, &s);
+        }
+
+        let crc = crc32fast::hash(&w);
+        w.extend_from_slice(&crc.to_le_bytes());
+        Ok(format!("{PREFIX}{}", base64url_encode(&w)))
+    }
+
+    /// Parse a code's text form. Whitespace anywhere is ignored, so a
+    /// code a chat client wrapped still reads.
+    pub fn decode(code: &str) -> Result<Self, Error> {
+        let code: String = code.chars().filter(|c| !c.is_whitespace()).collect();
+        let body = code.strip_prefix(PREFIX).ok_or(Error::NotACode)?;
+        let bytes = base64url_decode(body).ok_or(Error::NotACode)?;
+        if bytes.len() < 4 {
+            return Err(Error::NotACode);
+        }
+        let (payload, crc) = bytes.split_at(bytes.len() - 4);
+        if crc32fast::hash(payload).to_le_bytes() != crc {
+            return Err(Error::Checksum);
+        }
+
+        let mut r = Reader(payload);
+        let version = r.u8()?;
+        if version != FORMAT_VERSION {
+            return Err(Error::UnsupportedVersion(version));
+        }
+        let family_len = r.u8()? as usize;
+        let family = std::str::from_utf8(r.take(family_len)?).map_err(|_| Error::Malformed)?;
+        let variant = r.u8()?;
+        let revision = r.u8()?;
+        let mut text = || -> Result<String, Error> {
+            let len = r.u8()? as usize;
+            Ok(std::str::from_utf8(r.take(len)?)
+                .map_err(|_| Error::Malformed)?
+                .to_string())
+        };
+        let name = text()?;
+        let version = text()?;
+        let patch = if name.is_empty() {
+            if !version.is_empty() {
+                return Err(Error::Malformed);
+            }
+            None
+        } else {
+            Some(PatchTag { name, version })
+        };
+        let game = GameTag {
+            family: family.to_string(),
+            variant,
+            revision,
+            patch,
+        };
+        let mut out = Self {
+            game,
+            folder: None,
+            navicust: None,
+            patch_cards: None,
+            mod_cards: None,
+            navi: None,
+        };
+
+        while !r.0.is_empty() {
+            let tag = r.u8()?;
+            let len = r.count()?;
+            let mut s = Reader(r.take(len)?);
+            match tag {
+                SECTION_FOLDER if out.folder.is_none() => {
+                    let n = s.count()?;
+                    let chips = (0..n)
+                        .map(|_| match s.varint()? {
+                            0 => Ok(None),
+                            v => chip_from_packed(v - 1).map(Some),
+                        })
+                        .collect::<Result<_, _>>()?;
+                    let flags = s.u8()?;
+                    let regular = if flags & FOLDER_HAS_REGULAR != 0 {
+                        Some(s.count()?)
+                    } else {
+                        None
+                    };
+                    let tags = if flags & FOLDER_HAS_TAGS != 0 {
+                        Some([s.count()?, s.count()?])
+                    } else {
+                        None
+                    };
+                    out.folder = Some(Folder { chips, regular, tags });
+                }
+                SECTION_NAVICUST if out.navicust.is_none() => {
+                    let n = s.count()?;
+                    let parts = (0..n)
+                        .map(|_| {
+                            let id = s.count()?;
+                            let pos = s.u8()?;
+                            let orient = s.u8()?;
+                            Ok(save::NavicustPart {
+                                id,
+                                col: pos >> 4,
+                                row: pos & 0xf,
+                                rot: orient >> 1,
+                                compressed: orient & 1 != 0,
+                            })
+                        })
+                        .collect::<Result<_, Error>>()?;
+                    out.navicust = Some(parts);
+                }
+                SECTION_PATCH_CARDS if out.patch_cards.is_none() => {
+                    let n = s.count()?;
+                    let cards = (0..n)
+                        .map(|_| s.varint().and_then(card_from_packed))
+                        .collect::<Result<_, _>>()?;
+                    out.patch_cards = Some(cards);
+                }
+                SECTION_MOD_CARDS if out.mod_cards.is_none() => {
+                    let n = s.count()?;
+                    let slots = (0..n)
+                        .map(|_| match s.varint()? {
+                            0 => Ok(None),
+                            v => card_from_packed(v - 1).map(Some),
+                        })
+                        .collect::<Result<_, _>>()?;
+                    out.mod_cards = Some(slots);
+                }
+                SECTION_NAVI if out.navi.is_none() => {
+                    out.navi = Some(s.count()?);
+                }
+                // An unknown or repeated section: a v1 writer never
+                // produces either.
+                _ => return Err(Error::Malformed),
+            }
+            if !s.0.is_empty() {
+                return Err(Error::Malformed);
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// Decode `code` and refuse it unless it was made against `game` — what
+/// a caller about to apply a code to a save wants in one step.
+pub fn decode_for(code: &str, game: &GameTag) -> Result<BuildCode, Error> {
+    let build = BuildCode::decode(code)?;
+    build.check_game(game)?;
+    Ok(build)
+}
+
+/// The folder chips `code` uses beyond what `save` owns — copies in its
+/// pack plus copies in any of its folders — as each chip and how many
+/// copies it is short, in the folder's order. A chip the save keeps no
+/// pack count for (a game with no pack) is never short.
+///
+/// What a template's pack holds is what a save made from it can field,
+/// so this is the check a build pasted onto a template answers to.
+pub fn pack_shortfall(code: &BuildCode, save: &dyn save::Save, assets: &dyn rom::Assets) -> Vec<(save::Chip, usize)> {
+    let (Some(folder), Some(chips)) = (&code.folder, save.view_chips()) else {
+        return vec![];
+    };
+    let chips = chips.as_ref();
+    let in_folders = (0..chips.num_folders())
+        .flat_map(|folder| (0..chips.folder_size()).filter_map(move |slot| chips.chip(folder, slot)))
+        .collect::<Vec<_>>();
+    shortfall(folder.chips.iter().flatten(), |chip| {
+        let variant = assets
+            .chip(chip.id)?
+            .codes()
+            .into_iter()
+            .position(|c| save::ChipCode::from_char(c) == Some(chip.code))?;
+        let packed = chips.pack_count(chip.id, variant)?;
+        Some(packed + in_folders.iter().filter(|c| *c == chip).count())
+    })
+}
+
+/// Each distinct chip in `needed` that `owned` holds fewer copies of
+/// than `needed` names, with the difference. `owned` answering `None`
+/// means the chip isn't counted.
+fn shortfall<'a>(
+    needed: impl Iterator<Item = &'a save::Chip>,
+    owned: impl Fn(&save::Chip) -> Option<usize>,
+) -> Vec<(save::Chip, usize)> {
+    let mut counts: Vec<(save::Chip, usize)> = vec![];
+    for chip in needed {
+        match counts.iter_mut().find(|(c, _)| c == chip) {
+            Some((_, n)) => *n += 1,
+            None => counts.push((chip.clone(), 1)),
+        }
+    }
+    counts
+        .into_iter()
+        .filter_map(|(chip, n)| {
+            let short = n.saturating_sub(owned(&chip)?);
+            (short > 0).then_some((chip, short))
+        })
+        .collect()
+}
+
+fn chip_from_packed(v: u64) -> Result<save::Chip, Error> {
+    let code = num_traits::FromPrimitive::from_u64(v & 0x1f).ok_or(Error::Malformed)?;
+    Ok(save::Chip {
+        id: usize::try_from(v >> 5).map_err(|_| Error::Malformed)?,
+        code,
+    })
+}
+
+fn card_from_packed(v: u64) -> Result<save::PatchCard, Error> {
+    Ok(save::PatchCard {
+        id: usize::try_from(v >> 1).map_err(|_| Error::Malformed)?,
+        enabled: v & 1 != 0,
+    })
+}
+
+fn put_section(w: &mut Vec<u8>, tag: u8, body: &[u8]) {
+    w.push(tag);
+    put_varint(w, body.len() as u64);
+    w.extend_from_slice(body);
+}
+
+/// A count or index; every one a save holds fits a byte or two.
+fn put_count(w: &mut Vec<u8>, n: usize) -> Result<(), Error> {
+    if n > u16::MAX as usize {
+        return Err(Error::TooLarge);
+    }
+    put_varint(w, n as u64);
+    Ok(())
+}
+
+/// LEB128: seven bits per byte, low first, high bit set on all but the
+/// last.
+fn put_varint(w: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        w.push(v as u8 | 0x80);
+        v >>= 7;
+    }
+    w.push(v as u8);
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], Error> {
+        if n > self.0.len() {
+            return Err(Error::Malformed);
+        }
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+        Ok(head)
+    }
+
+    fn u8(&mut self) -> Result<u8, Error> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn varint(&mut self) -> Result<u64, Error> {
+        let mut v = 0u64;
+        for shift in (0..64).step_by(7) {
+            let b = self.u8()?;
+            v |= ((b & 0x7f) as u64) << shift;
+            if b & 0x80 == 0 {
+                return Ok(v);
+            }
+        }
+        Err(Error::Malformed)
+    }
+
+    fn count(&mut self) -> Result<usize, Error> {
+        usize::try_from(self.varint()?)
+            .ok()
+            .filter(|&n| n <= u16::MAX as usize)
+            .ok_or(Error::Malformed)
+    }
+}
+
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+fn base64url_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &b)| n | ((b as u32) << (16 - 8 * i)));
+        for i in 0..=chunk.len() {
+            out.push(BASE64URL[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+        }
+    }
+    out
+}
+
+fn base64url_decode(text: &str) -> Option<Vec<u8>> {
+    let digits = text
+        .bytes()
+        .map(|c| BASE64URL.iter().position(|&d| d == c).map(|d| d as u32))
+        .collect::<Option<Vec<_>>>()?;
+    if digits.len() % 4 == 1 {
+        return None;
+    }
+    let mut out = Vec::with_capacity(digits.len() * 3 / 4);
+    for chunk in digits.chunks(4) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &d)| n | (d << (18 - 6 * i)));
+        for i in 0..chunk.len() - 1 {
+            out.push((n >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(variant: u8) -> GameTag {
+        GameTag {
+            family: "bn6".to_string(),
+            variant,
+            revision: 0,
+            patch: None,
+        }
+    }
+
+    fn patched(version: &str) -> GameTag {
+        GameTag {
+            patch: Some(PatchTag {
+                name: "exe6_balance".to_string(),
+                version: version.to_string(),
+            }),
+            ..tag(1)
+        }
+    }
+
+    fn build() -> BuildCode {
+        let chip = |id, code| Some(save::Chip { id, code });
+        BuildCode {
+            game: tag(1),
+            folder: Some(Folder {
+                chips: vec![chip(1, save::ChipCode::A), None, chip(300, save::ChipCode::Star)],
+                regular: Some(2),
+                tags: Some([0, 2]),
+            }),
+            navicust: Some(vec![save::NavicustPart {
+                id: 17,
+                col: 3,
+                row: 6,
+                rot: 3,
+                compressed: true,
+            }]),
+            patch_cards: Some(vec![
+                save::PatchCard { id: 130, enabled: true },
+                save::PatchCard { id: 2, enabled: false },
+            ]),
+            mod_cards: Some(vec![None, Some(save::PatchCard { id: 40, enabled: true })]),
+            navi: Some(0),
+        }
+    }
+
+    /// Every section survives the text form, and so does a code with
+    /// no sections at all.
+    #[test]
+    fn code_roundtrips() {
+        let b = build();
+        let code = b.encode().unwrap();
+        assert!(code.starts_with(PREFIX));
+        assert_eq!(BuildCode::decode(&code).unwrap(), b);
+
+        let empty = BuildCode {
+            folder: None,
+            navicust: None,
+            patch_cards: None,
+            mod_cards: None,
+            navi: None,
+            ..build()
+        };
+        assert_eq!(BuildCode::decode(&empty.encode().unwrap()).unwrap(), empty);
+    }
+
+    /// A wrapped code still reads; a changed character fails the
+    /// checksum rather than decoding as some other build.
+    #[test]
+    fn damage_is_caught() {
+        let code = build().encode().unwrap();
+        let (a, b) = code.split_at(code.len() / 2);
+        assert_eq!(BuildCode::decode(&format!(" {a}\n{b} ")).unwrap(), build());
+
+        let mut bytes = code.into_bytes();
+        let i = PREFIX.len() + 6;
+        bytes[i] = if bytes[i] == b'A' { b'B' } else { b'A' };
+        assert!(matches!(
+            BuildCode::decode(std::str::from_utf8(&bytes).unwrap()),
+            Err(Error::Checksum)
+        ));
+        assert!(matches!(BuildCode::decode("hello"), Err(Error::NotACode)));
+    }
+
+    /// The other version of the same family is refused.
+    #[test]
+    fn other_variant_is_refused() {
+        let b = BuildCode::decode(&build().encode().unwrap()).unwrap();
+        assert!(b.check_game(&tag(1)).is_ok());
+        assert!(matches!(b.check_game(&tag(0)), Err(Error::WrongGame { .. })));
+    }
+
+    /// The patch survives the text form, and a code only loads against
+    /// the same patch at the same version — never the unpatched game.
+    #[test]
+    fn other_patch_is_refused() {
+        let b = BuildCode {
+            game: patched("1.2.0"),
+            ..build()
+        };
+        let code = b.encode().unwrap();
+        assert_eq!(BuildCode::decode(&code).unwrap(), b);
+        assert!(decode_for(&code, &patched("1.2.0")).is_ok());
+        assert!(matches!(
+            decode_for(&code, &patched("1.3.0")),
+            Err(Error::WrongGame { .. })
+        ));
+        assert!(matches!(decode_for(&code, &tag(1)), Err(Error::WrongGame { .. })));
+        assert!(matches!(
+            decode_for(&build().encode().unwrap(), &patched("1.2.0")),
+            Err(Error::WrongGame { .. })
+        ));
+    }
+
+    /// Copies are counted per chip and code, and a chip with no pack
+    /// count is never short.
+    #[test]
+    fn shortfall_counts_copies_per_code() {
+        let chip = |id, code| save::Chip { id, code };
+        let needed = [
+            chip(1, save::ChipCode::A),
+            chip(1, save::ChipCode::A),
+            chip(1, save::ChipCode::A),
+            chip(1, save::ChipCode::B),
+            chip(2, save::ChipCode::Star),
+            chip(3, save::ChipCode::C),
+        ];
+        let owned = |c: &save::Chip| match (c.id, c.code) {
+            (1, save::ChipCode::A) => Some(1),
+            (1, save::ChipCode::B) => Some(4),
+            (2, _) => Some(0),
+            _ => None,
+        };
+        assert_eq!(
+            shortfall(needed.iter(), owned),
+            vec![(chip(1, save::ChipCode::A), 2), (chip(2, save::ChipCode::Star), 1)]
+        );
+    }
+
+    #[test]
+    fn base64url_roundtrips_every_tail_length() {
+        for n in 0..8u8 {
+            let bytes: Vec<u8> = (0..n).map(|i| i.wrapping_mul(97)).collect();
+            assert_eq!(base64url_decode(&base64url_encode(&bytes)).unwrap(), bytes);
+        }
+    }
+}
@@ -0,0 +1,328 @@
+//! What changed between two saves of the same game: the equipped folder
+//! as a bag of chips (plus its REG/TAG picks), navicust parts installed,
+//! removed or placed differently, patch cards registered, dropped or
+//! toggled, and the equipped navi — read through the shared save views,
+//! so every game with those views diffs without saying anything of its
+//! own.
+//!
+//! Ids are compared raw: both saves must be of the same ROM for the
+//! result to mean anything, which the caller guarantees.
+
+use crate::build_code::Folder;
+use crate::save;
+
+/// One value on the left save and what it became on the right.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change<T> {
+    pub from: T,
+    pub to: T,
+}
+
+/// The folder as a bag: a chip moved to another slot is no change, one
+/// swapped for another code is a removal and an addition.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FolderDiff {
+    /// Chips only the left folder has, with how many more copies.
+    pub removed: Vec<(save::Chip, usize)>,
+    /// Chips only the right folder has, with how many more copies.
+    pub added: Vec<(save::Chip, usize)>,
+    /// The Regular chip, when it names a different chip.
+    pub regular: Option<Change<Option<save::Chip>>>,
+    /// The Tag pair, when it names a different pair (either way round
+    /// is the same pair).
+    pub tags: Option<Change<Option<[save::Chip; 2]>>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NavicustDiff {
+    pub removed: Vec<save::NavicustPart>,
+    pub added: Vec<save::NavicustPart>,
+    /// Parts installed on both sides but at a different place, turn, or
+    /// compression.
+    pub moved: Vec<Change<save::NavicustPart>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PatchCardDiff {
+    pub removed: Vec<save::PatchCard>,
+    pub added: Vec<save::PatchCard>,
+    /// Cards registered on both sides, switched on or off — as the right
+    /// save has them.
+    pub toggled: Vec<save::PatchCard>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SaveDiff {
+    pub navi: Option<Change<usize>>,
+    pub folder: FolderDiff,
+    pub navicust: NavicustDiff,
+    pub patch_cards: PatchCardDiff,
+}
+
+impl FolderDiff {
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty() && self.regular.is_none() && self.tags.is_none()
+    }
+}
+
+impl NavicustDiff {
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty() && self.moved.is_empty()
+    }
+}
+
+impl PatchCardDiff {
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty() && self.toggled.is_empty()
+    }
+}
+
+impl SaveDiff {
+    pub fn is_empty(&self) -> bool {
+        self.navi.is_none() && self.folder.is_empty() && self.navicust.is_empty() && self.patch_cards.is_empty()
+    }
+}
+
+/// Everything a diff looks at, read off one save. A section the save
+/// lacks reads as empty, so a link navi's missing navicust diffs as
+/// every part removed.
+#[derive(Default)]
+struct Snapshot {
+    folder: Option<Folder>,
+    navicust: Vec<save::NavicustPart>,
+    patch_cards: Vec<save::PatchCard>,
+    navi: Option<usize>,
+}
+
+impl Snapshot {
+    fn of(save: &dyn save::Save) -> Self {
+        Self {
+            folder: Folder::of(save),
+            navicust: save
+                .view_navicust()
+                .map(|nc| (0..nc.count()).filter_map(|i| nc.navicust_part(i)).collect())
+                .unwrap_or_default(),
+            patch_cards: save
+                .view_patch_card56s()
+                .map(|pc| (0..pc.count()).filter_map(|i| pc.patch_card(i)).collect())
+                .unwrap_or_default(),
+            navi: save.view_navi().map(|nv| nv.navi()),
+        }
+    }
+}
+
+/// What changed going from `left` to `right`.
+pub fn diff(left: &dyn save::Save, right: &dyn save::Save) -> SaveDiff {
+    diff_snapshots(&Snapshot::of(left), &Snapshot::of(right))
+}
+
+fn diff_snapshots(left: &Snapshot, right: &Snapshot) -> SaveDiff {
+    SaveDiff {
+        navi: match (left.navi, right.navi) {
+            (Some(from), Some(to)) if from != to => Some(Change { from, to }),
+            _ => None,
+        },
+        folder: diff_folders(left.folder.as_ref(), right.folder.as_ref()),
+        navicust: diff_navicusts(&left.navicust, &right.navicust),
+        patch_cards: diff_patch_cards(&left.patch_cards, &right.patch_cards),
+    }
+}
+
+fn diff_folders(left: Option<&Folder>, right: Option<&Folder>) -> FolderDiff {
+    let chips = |f: Option<&Folder>| -> Vec<save::Chip> {
+        f.map(|f| f.chips.iter().flatten().cloned().collect())
+            .unwrap_or_default()
+    };
+    let regular = |f: Option<&Folder>| f.and_then(|f| f.chip(f.regular?)).cloned();
+    let tags = |f: Option<&Folder>| {
+        let f = f?;
+        let [a, b] = f.tags?;
+        Some([f.chip(a)?.clone(), f.chip(b)?.clone()])
+    };
+
+    let (removed, added) = bag_diff(&chips(left), &chips(right));
+    let (from, to) = (regular(left), regular(right));
+    let regular = (from != to).then_some(Change { from, to });
+    let (from, to) = (tags(left), tags(right));
+    let same_pair = match (&from, &to) {
+        (Some([a, b]), Some([c, d])) => (a == c && b == d) || (a == d && b == c),
+        (None, None) => true,
+        _ => false,
+    };
+    let tags = (!same_pair).then_some(Change { from, to });
+    FolderDiff {
+        removed,
+        added,
+        regular,
+        tags,
+    }
+}
+
+fn diff_navicusts(left: &[save::NavicustPart], right: &[save::NavicustPart]) -> NavicustDiff {
+    // Parts sitting exactly where they were cancel out first; what's left
+    // of the same part on both sides was moved, in install order.
+    let mut right_left: Vec<Option<&save::NavicustPart>> = right.iter().map(Some).collect();
+    let mut unmatched = vec![];
+    for part in left {
+        match right_left.iter().position(|p| *p == Some(part)) {
+            Some(i) => right_left[i] = None,
+            None => unmatched.push(part),
+        }
+    }
+    let mut diff = NavicustDiff::default();
+    for part in unmatched {
+        match right_left.iter().position(|p| p.is_some_and(|p| p.id == part.id)) {
+            Some(i) => diff.moved.push(Change {
+                from: part.clone(),
+                to: right_left[i].take().unwrap().clone(),
+            }),
+            None => diff.removed.push(part.clone()),
+        }
+    }
+    diff.added = right_left.into_iter().flatten().cloned().collect();
+    diff
+}
+
+fn diff_patch_cards(left: &[save::PatchCard], right: &[save::PatchCard]) -> PatchCardDiff {
+    let mut right_left: Vec<Option<&save::PatchCard>> = right.iter().map(Some).collect();
+    let mut diff = PatchCardDiff::default();
+    for card in left {
+        match right_left.iter().position(|c| c.is_some_and(|c| c.id == card.id)) {
+            Some(i) => {
+                let other = right_left[i].take().unwrap();
+                if other.enabled != card.enabled {
+                    diff.toggled.push(other.clone());
+                }
+            }
+            None => diff.removed.push(card.clone()),
+        }
+    }
+    diff.added = right_left.into_iter().flatten().cloned().collect();
+    diff
+}
+
+/// Distinct entries of a multiset, each with its count.
+type Counted<T> = Vec<(T, usize)>;
+
+/// `(only in left, only in right)` of two multisets, each entry with its
+/// surplus count, in first-seen order.
+fn bag_diff<T: Clone + Eq + std::hash::Hash>(left: &[T], right: &[T]) -> (Counted<T>, Counted<T>) {
+    let mut counts = std::collections::HashMap::<&T, isize>::new();
+    for x in left {
+        *counts.entry(x).or_default() += 1;
+    }
+    for x in right {
+        *counts.entry(x).or_default() -= 1;
+    }
+    let surplus = |items: &[T], sign: isize| {
+        let mut seen = std::collections::HashSet::new();
+        items
+            .iter()
+            .filter(|x| seen.insert(*x))
+            .filter_map(|x| {
+                let n = counts[x] * sign;
+                (n > 0).then(|| (x.clone(), n as usize))
+            })
+            .collect::<Vec<_>>()
+    };
+    (surplus(left, 1), surplus(right, -1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chip(id: usize, code: save::ChipCode) -> save::Chip {
+        save::Chip { id, code }
+    }
+
+    fn folder(chips: &[save::Chip], regular: Option<usize>, tags: Option<[usize; 2]>) -> Folder {
+        Folder {
+            chips: chips.iter().cloned().map(Some).collect(),
+            regular,
+            tags,
+        }
+    }
+
+    fn part(id: usize, col: u8) -> save::NavicustPart {
+        save::NavicustPart {
+            id,
+            col,
+            row: 0,
+            rot: 0,
+            compressed: true,
+        }
+    }
+
+    /// Reordering is no change; a code swap and an extra copy are.
+    #[test]
+    fn folder_is_a_bag() {
+        use save::ChipCode::*;
+        let left = folder(&[chip(1, A), chip(1, A), chip(2, B), chip(3, C)], Some(2), None);
+        let right = folder(&[chip(3, C), chip(2, B), chip(1, A), chip(2, Star)], Some(1), None);
+        let d = diff_folders(Some(&left), Some(&right));
+        assert_eq!(d.removed, vec![(chip(1, A), 1)]);
+        assert_eq!(d.added, vec![(chip(2, Star), 1)]);
+        // REG stayed on the same chip, just in another slot.
+        assert_eq!(d.regular, None);
+        assert_eq!(d.tags, None);
+
+        let swapped = folder(
+            &right.chips.iter().flatten().cloned().collect::<Vec<_>>(),
+            Some(0),
+            Some([1, 2]),
+        );
+        let d = diff_folders(Some(&right), Some(&swapped));
+        assert!(d.removed.is_empty() && d.added.is_empty());
+        assert_eq!(
+            d.regular,
+            Some(Change {
+                from: Some(chip(2, B)),
+                to: Some(chip(3, C))
+            })
+        );
+        let flipped = Folder {
+            tags: Some([2, 1]),
+            ..swapped.clone()
+        };
+        assert_eq!(diff_folders(Some(&swapped), Some(&flipped)).tags, None);
+    }
+
+    #[test]
+    fn navicust_pairs_moved_parts() {
+        let d = diff_navicusts(
+            &[part(1, 0), part(2, 0), part(3, 0)],
+            &[part(2, 0), part(1, 3), part(4, 0)],
+        );
+        assert_eq!(
+            d.moved,
+            vec![Change {
+                from: part(1, 0),
+                to: part(1, 3)
+            }]
+        );
+        assert_eq!(d.removed, vec![part(3, 0)]);
+        assert_eq!(d.added, vec![part(4, 0)]);
+    }
+
+    #[test]
+    fn patch_cards_and_navi() {
+        let card = |id, enabled| save::PatchCard { id, enabled };
+        let left = Snapshot {
+            patch_cards: vec![card(1, true), card(2, true), card(3, false)],
+            navi: Some(0),
+            ..Default::default()
+        };
+        let right = Snapshot {
+            patch_cards: vec![card(3, true), card(1, true), card(9, true)],
+            navi: Some(4),
+            ..Default::default()
+        };
+        let d = diff_snapshots(&left, &right);
+        assert_eq!(d.navi, Some(Change { from: 0, to: 4 }));
+        assert_eq!(d.patch_cards.removed, vec![card(2, true)]);
+        assert_eq!(d.patch_cards.added, vec![card(9, true)]);
+        assert_eq!(d.patch_cards.toggled, vec![card(3, true)]);
+        assert!(diff_snapshots(&right, &right).is_empty());
+    }
+}
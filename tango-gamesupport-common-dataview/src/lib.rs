@@ -14,10 +14,13 @@
 
 pub mod auto_battle_data;
 pub mod build;
+pub mod build_code;
+pub mod diff;
 pub mod msg;
 pub mod navicust;
 pub mod nds;
 pub mod rom;
+pub mod rom_diff;
 pub mod save;
 
 #[cfg(target_endian = "big")]
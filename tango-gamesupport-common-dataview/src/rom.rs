@@ -273,6 +273,14 @@ pub trait Assets: crate::save::AsAny {
     fn navicust_layout(&self) -> Option<NavicustLayout> {
         None
     }
+    /// The range the player navi's base max HP is edited within — the
+    /// starting value, the HP Memory increment and the ceiling — for the
+    /// games whose save keeps that HP as a number to edit (BN1–BN3). Each
+    /// ROM revision answers for itself; BCC's HP is the navi chip's ROM
+    /// stat, so it has none.
+    fn base_max_hp_range(&self) -> Option<crate::save::NaviStatRange> {
+        None
+    }
     /// The game's own concrete assets, for game-specific UIs to downcast
     /// (BN4's Mod Card catalog lives there, not on this trait). Layering
     /// wrappers (the patch-override layer) forward to what they wrap;
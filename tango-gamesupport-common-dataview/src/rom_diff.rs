@@ -0,0 +1,211 @@
+//! What changed between two ROMs' asset tables: chips (names, codes,
+//! damage, MB, class, element, legality), navicust parts (names, colour,
+//! shape) and patch cards (names, MB, effects) — read through the shared
+//! [`rom::Assets`] view, so every game diffs without saying anything of
+//! its own.
+//!
+//! Rows are compared by id: both sides must be the same game, patched
+//! differently (or not at all), for the result to mean anything, which
+//! the caller guarantees.
+
+use crate::diff::Change;
+use crate::rom;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChipInfo {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub codes: Vec<char>,
+    pub element: usize,
+    pub class: rom::ChipClass,
+    pub dark: bool,
+    pub mb: u8,
+    pub attack_power: u32,
+    pub legal: bool,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct NavicustPartInfo {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub color: Option<rom::NavicustPartColor>,
+    pub is_solid: bool,
+    pub compressed: Option<rom::NavicustBitmap>,
+    pub uncompressed: rom::NavicustBitmap,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EffectInfo {
+    pub id: usize,
+    pub kind: rom::PatchCard56EffectKind,
+    pub name: Option<String>,
+    pub parameter: u8,
+    pub is_ability: bool,
+    pub is_debuff: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PatchCardInfo {
+    pub name: Option<String>,
+    pub mb: u8,
+    pub effects: Vec<EffectInfo>,
+}
+
+/// One row that differs, by id. `None` on a side whose table doesn't
+/// have the row (a patch that grows a table adds rows).
+pub type RowChange<T> = (usize, Change<Option<T>>);
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AssetsDiff {
+    pub chips: Vec<RowChange<ChipInfo>>,
+    pub navicust_parts: Vec<RowChange<NavicustPartInfo>>,
+    pub patch_cards: Vec<RowChange<PatchCardInfo>>,
+}
+
+impl AssetsDiff {
+    pub fn is_empty(&self) -> bool {
+        self.chips.is_empty() && self.navicust_parts.is_empty() && self.patch_cards.is_empty()
+    }
+}
+
+/// Every table a diff looks at, read off one set of assets.
+#[derive(Default)]
+struct Tables {
+    chips: Vec<Option<ChipInfo>>,
+    navicust_parts: Vec<Option<NavicustPartInfo>>,
+    patch_cards: Vec<Option<PatchCardInfo>>,
+}
+
+impl Tables {
+    fn of(assets: &dyn rom::Assets) -> Self {
+        Self {
+            chips: (0..assets.num_chips())
+                .map(|id| {
+                    assets.chip(id).map(|c| ChipInfo {
+                        name: c.name(),
+                        description: c.description(),
+                        codes: c.codes(),
+                        element: c.element(),
+                        class: c.class(),
+                        dark: c.dark(),
+                        mb: c.mb(),
+                        attack_power: c.attack_power(),
+                        legal: assets.chip_is_legal(id),
+                    })
+                })
+                .collect(),
+            navicust_parts: (0..assets.num_navicust_parts())
+                .map(|id| {
+                    assets.navicust_part(id).map(|p| NavicustPartInfo {
+                        name: p.name(),
+                        description: p.description(),
+                        color: p.color(),
+                        is_solid: p.is_solid(),
+                        compressed: p.compressed_bitmap(),
+                        uncompressed: p.uncompressed_bitmap(),
+                    })
+                })
+                .collect(),
+            patch_cards: (0..assets.num_patch_card56s())
+                .map(|id| {
+                    assets.patch_card56(id).map(|c| PatchCardInfo {
+                        name: c.name(),
+                        mb: c.mb(),
+                        effects: c
+                            .effects()
+                            .into_iter()
+                            .map(|e| EffectInfo {
+                                id: e.id,
+                                kind: e.kind,
+                                name: e.name,
+                                parameter: e.parameter,
+                                is_ability: e.is_ability,
+                                is_debuff: e.is_debuff,
+                            })
+                            .collect(),
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// What changed going from `left` to `right`.
+pub fn diff(left: &dyn rom::Assets, right: &dyn rom::Assets) -> AssetsDiff {
+    diff_tables(&Tables::of(left), &Tables::of(right))
+}
+
+fn diff_tables(left: &Tables, right: &Tables) -> AssetsDiff {
+    AssetsDiff {
+        chips: diff_rows(&left.chips, &right.chips),
+        navicust_parts: diff_rows(&left.navicust_parts, &right.navicust_parts),
+        patch_cards: diff_rows(&left.patch_cards, &right.patch_cards),
+    }
+}
+
+fn diff_rows<T: Clone + PartialEq>(left: &[Option<T>], right: &[Option<T>]) -> Vec<RowChange<T>> {
+    (0..left.len().max(right.len()))
+        .filter_map(|id| {
+            let from = left.get(id).cloned().flatten();
+            let to = right.get(id).cloned().flatten();
+            (from != to).then_some((id, Change { from, to }))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chip(name: &str, attack_power: u32) -> ChipInfo {
+        ChipInfo {
+            name: Some(name.to_owned()),
+            description: None,
+            codes: vec!['A', 'B'],
+            element: 0,
+            class: rom::ChipClass::Standard,
+            dark: false,
+            mb: 10,
+            attack_power,
+            legal: true,
+        }
+    }
+
+    #[test]
+    fn rows_pair_by_id() {
+        let left = Tables {
+            chips: vec![Some(chip("Cannon", 40)), None, Some(chip("Sword", 80))],
+            ..Default::default()
+        };
+        let right = Tables {
+            chips: vec![
+                Some(chip("Cannon", 60)),
+                None,
+                Some(chip("Sword", 80)),
+                Some(chip("NewChip", 100)),
+            ],
+            ..Default::default()
+        };
+        let d = diff_tables(&left, &right);
+        assert_eq!(
+            d.chips,
+            vec![
+                (
+                    0,
+                    Change {
+                        from: Some(chip("Cannon", 40)),
+                        to: Some(chip("Cannon", 60))
+                    }
+                ),
+                (
+                    3,
+                    Change {
+                        from: None,
+                        to: Some(chip("NewChip", 100))
+                    }
+                ),
+            ]
+        );
+        assert!(diff_tables(&right, &right).is_empty());
+    }
+}
@@ -72,6 +72,14 @@ where
 
     fn rebuild_checksum(&mut self);
 
+    /// The in-memory bytes every `view_*_mut` write lands in. No edit
+    /// resizes them or moves anything outside them, so writing back
+    /// bytes read here puts the save back exactly as it was — an edit
+    /// session's undo history keeps diffs of these, not whole saves.
+    fn edit_bytes(&self) -> &[u8];
+
+    fn edit_bytes_mut(&mut self) -> &mut [u8];
+
     fn view_chips(&self) -> Option<Box<dyn ChipsView + '_>> {
         None
     }
@@ -311,9 +319,10 @@ pub trait PatchCard56sViewMut: PatchCard56sView {
 pub trait NaviView {
     fn navi(&self) -> usize;
 
-    /// The navi's base max HP — from HP Memories / leveling only, excluding
-    /// NaviCust and Mod-Code HP bonuses. For the games without a link-navi
-    /// roster (BN1–4) this is the player navi's HP.
+    /// The navi's max HP — from HP Memories / leveling, plus whatever
+    /// NaviCust, Mod Code and Patch Card bonuses the game folds in (see
+    /// [`base_max_hp`](Self::base_max_hp) for the save's own part). For the
+    /// games without a link-navi roster (BN1–4) this is the player navi's HP.
     fn max_hp(&self, assets: &dyn crate::rom::Assets) -> u16;
 
     /// The navi's live MegaBuster levels and B-button power attack, or `None`
@@ -322,6 +331,14 @@ pub trait NaviView {
         None
     }
 
+    /// The max HP the save itself keeps — before the NaviCust, Mod Code
+    /// and Patch Card bonuses [`max_hp`](Self::max_hp) may fold in — or
+    /// `None` where the game doesn't keep one apart.
+    fn base_max_hp(&self, assets: &dyn crate::rom::Assets) -> Option<u16> {
+        let _ = assets;
+        None
+    }
+
     /// The folder-construction limits for this navi — the class caps (navi/
     /// mega/giga/dark), the per-chip copy cap, and the Regular/Tag memory
     /// budgets. These depend on the equipped navi (its style, NaviCust, and
@@ -347,10 +364,46 @@ pub struct NaviBusterStats {
     pub b_power_attack: u8,
 }
 
+/// The values a navi editor may write to one stat: `min..=max`, in
+/// `step`s up from `min` — the game's own increments (an HP Memory's
+/// worth), so an edited save reads like one the game could have made.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NaviStatRange {
+    pub min: u16,
+    pub max: u16,
+    pub step: u16,
+}
+
+impl NaviStatRange {
+    /// `value` clamped into the range and snapped down onto a step.
+    pub fn clamp(&self, value: u16) -> u16 {
+        let value = value.clamp(self.min, self.max);
+        value - (value - self.min) % self.step.max(1)
+    }
+}
+
 pub trait NaviViewMut: NaviView {
+    /// Whether this game has a navi roster to pick from (BN5/BN6/EXE4.5),
+    /// i.e. whether [`set_navi`](Self::set_navi) can do anything. The
+    /// roster-less games still have a writable navi for its stats.
+    fn has_roster(&self) -> bool {
+        false
+    }
+
     /// Set the equipped navi index. Returns `false` (no write) if `navi`
-    /// is out of range for this game.
-    fn set_navi(&mut self, navi: usize) -> bool;
+    /// is out of range for this game, or the game has no roster.
+    fn set_navi(&mut self, navi: usize) -> bool {
+        let _ = navi;
+        false
+    }
+
+    /// Set the base max HP, as [`NaviView::base_max_hp`] reads it.
+    /// Returns `false` (no write) if the game's HP isn't writable or `hp`
+    /// isn't in the ROM's [`base_max_hp_range`](crate::rom::Assets::base_max_hp_range).
+    fn set_base_max_hp(&mut self, hp: u16, assets: &dyn crate::rom::Assets) -> bool {
+        let _ = (hp, assets);
+        false
+    }
 }
 
 #[derive(Clone, Debug, std::hash::Hash, Eq, PartialEq)]
@@ -12,6 +12,43 @@ save-copy-image = Copy as image
 save-edit = Edit
 save-edit-save = Save
 save-edit-cancel = Cancel
+save-edit-undo = Undo
+save-edit-redo = Redo
+save-copy-build-code = Copy build code
+save-paste-build-code = Load build code from clipboard
+build-code-invalid = That isn't a build code, or it was damaged in copying
+build-code-version = That build code is from a newer version of the app
+build-code-wrong-game = That build code is for { $game }
+build-code-pack-short = { $count ->
+    [one] { $chip }: one more copy than the pack holds
+   *[other] { $chip }: { $count } more copies than the pack holds
+}
+save-diff-identical = These saves have the same build
+save-diff-navi = Navi
+save-diff-regular = Regular chip
+save-diff-tags = Tag chips
+save-diff-none = none
+save-diff-moved = { $part } was moved
+save-diff-enabled = { $card } was switched on
+save-diff-disabled = { $card } was switched off
+patch-diff-chips = Chips
+patch-diff-row = { $name }: { $changes }
+patch-diff-added = { $name } (new)
+patch-diff-removed = { $name } (removed)
+patch-diff-renamed = renamed to { $name }
+patch-diff-damage = damage { $from } → { $to }
+patch-diff-mb = { $from }MB → { $to }MB
+patch-diff-codes = codes { $from } → { $to }
+patch-diff-element = element changed
+patch-diff-class = class changed
+patch-diff-now-legal = now legal
+patch-diff-now-illegal = no longer legal
+patch-diff-description = description changed
+patch-diff-color = color changed
+patch-diff-shape = shape changed
+patch-diff-effects = effects changed
+patch-diff-breaks = New problem: { $warning }
+patch-diff-fixes = Fixed: { $warning }
 folder-edit-search = Search chips…
 folder-edit-folder = Folder
 folder-edit-count = { $count } / { $limit }
@@ -21,6 +58,21 @@ folder-edit-giga = Giga { $used } / { $limit }
 folder-edit-dark = Dark { $used } / { $limit }
 folder-edit-reg-memory = Reg { $mb }MB
 folder-edit-tag-memory = Tag { $mb }MB
+folder-edit-import = Import folder from clipboard
+folder-import-read = { $count ->
+    [one] Imported 1 chip
+   *[other] Imported { $count } chips
+}
+folder-import-issues = { $count ->
+    [one] 1 legality issue
+   *[other] { $count } legality issues
+}
+folder-import-dropped = { $count ->
+    [one] 1 chip didn't fit
+   *[other] { $count } chips didn't fit
+}
+folder-import-unknown = Line { $line }: couldn't read "{ $text }"
+folder-import-dismiss = Dismiss
 build-chip-unknown = Chip #{ $id }
 build-patch-card-unknown = Patch Card #{ $id }
 build-navicust-part-unknown = NaviCust part #{ $id }
@@ -79,6 +131,8 @@ navi-buster-attack = Attack
 navi-buster-rapid = Rapid
 navi-buster-charge = Charge
 navi-edit-select = Navi
+navi-hp-decrease = −{ $step } HP
+navi-hp-increase = +{ $step } HP
 navicust-grid-size = Grid: { $cols } × { $rows }
 navicust-edit-grid = NaviCust
 navicust-edit-count = { $count ->
@@ -110,17 +164,41 @@ auto-battle-data-combos = Combos
 auto-battle-data-program-advance = Program advance
 auto-battle-data-edit-used = Used
 auto-battle-data-edit-secondary = Sec.
+auto-battle-data-edit-import = Import deck from clipboard
 auto-battle-data-edit-count = { $count ->
     [one] 1 chip
    *[other] { $count } chips
 }
+pack-empty = The pack holds no chips.
+pack-edit-owned = Owned
+pack-edit-count = { $count ->
+    [one] 1 copy
+   *[other] { $count } copies
+}
 save-empty = This save has no data for this view.
 
 save-tab-program-deck = Program Deck
 save-tab-party = Party
+save-tab-link-navi = Link Navi
+link-navi-operating = Operating
+link-navi-operate = Operate
+link-navi-megaman-build = Build from the NaviCust
+link-navi-max-hp = Max HP
+link-navi-custom = Custom
+link-navi-mega = Mega
+link-navi-giga = Giga
+link-navi-float-shoes = FloatShoes
+link-navi-air-shoes = AirShoes
+link-navi-super-armor = SuperArmor
+link-navi-no-navicust = Link navis bring a fixed build and have no NaviCust of their own; MegaMan's is kept for when he operates again.
+save-tab-pack = Pack
 deck-mb = { $used }/{ $capacity }MB
 deck-mb-uncapped = { $used }MB
 deck-slot-in = Slot-in { $max }MB
+deck-mb-bonus = Memory bonus
+deck-slot-in-max = Slot-in max
+deck-budget-decrease = −{ $step }MB
+deck-budget-increase = +{ $step }MB
 
 # BN5DS: which of the cartridge's two teams a file plays, named by its
 # leader the way the cart's own file select names it. The navi comes off
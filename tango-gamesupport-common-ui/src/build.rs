@@ -411,6 +411,121 @@ pub fn warnings(
     }
 }
 
+/// Every way `save` breaks a tournament's `rules`: banned chips and
+/// over-limit copies in the equipped folder, banned NaviCust parts, and
+/// the navi's max HP over the cap.
+pub fn rule_breaches(
+    save: &crate::editor::Save,
+    assets: &crate::editor::Assets,
+    rules: &tango_gamesupport::BuildRules,
+) -> Vec<tango_gamesupport::RuleBreach> {
+    let folder = equipped_folder_chips(save);
+    let parts = save
+        .view_navicust()
+        .map(|nc| {
+            (0..nc.count())
+                .filter_map(|slot| nc.navicust_part(slot))
+                .map(|part| part.id)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let max_hp = save.view_navi().map(|nv| nv.max_hp(assets));
+    breaches(
+        &folder,
+        &parts,
+        max_hp,
+        rules,
+        |id| assets.chip(id).and_then(|info| info.name()),
+        |id| assets.navicust_part(id).and_then(|info| info.name()),
+    )
+}
+
+/// The equipped navi and folder, named, for
+/// [`tango_gamesupport::SaveEditor::summary`].
+pub fn summary(save: &crate::editor::Save, assets: &crate::editor::Assets) -> tango_gamesupport::SaveSummary {
+    let folder = equipped_folder_chips(save);
+    tango_gamesupport::SaveSummary {
+        navi: save
+            .view_navi()
+            .map(|nv| assets.navi(nv.navi()).and_then(|navi| navi.name())),
+        folder: tally(&folder)
+            .into_iter()
+            .map(|(chip, count)| (assets.chip(chip).and_then(|info| info.name()), count))
+            .collect(),
+    }
+}
+
+/// The chip ids in the save's equipped folder, in slot order. Empty for a
+/// save with no folder.
+pub(crate) fn equipped_folder_chips(save: &crate::editor::Save) -> Vec<usize> {
+    save.view_chips()
+        .map(|chips| {
+            let folder = chips.equipped_folder_index();
+            (0..chips.folder_size())
+                .filter_map(|slot| chips.chip(folder, slot))
+                .map(|chip| chip.id)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// [`rule_breaches`] over what it read off the save. One breach per
+/// offending id, in the order the id first appears; a banned chip isn't
+/// also reported for its copies.
+fn breaches(
+    folder: &[usize],
+    parts: &[usize],
+    max_hp: Option<u16>,
+    rules: &tango_gamesupport::BuildRules,
+    chip_name: impl Fn(usize) -> Option<String>,
+    part_name: impl Fn(usize) -> Option<String>,
+) -> Vec<tango_gamesupport::RuleBreach> {
+    use tango_gamesupport::RuleBreach;
+
+    let mut out = vec![];
+    for (chip, count) in tally(folder) {
+        if rules.banned_chips.contains(&chip) {
+            out.push(RuleBreach::BannedChip {
+                chip,
+                name: chip_name(chip),
+            });
+        } else if let Some(limit) = rules.max_copies.filter(|&limit| count > limit) {
+            out.push(RuleBreach::TooManyCopies {
+                chip,
+                name: chip_name(chip),
+                count,
+                limit,
+            });
+        }
+    }
+    for (part, _) in tally(parts) {
+        if rules.banned_navicust_parts.contains(&part) {
+            out.push(RuleBreach::BannedNavicustPart {
+                part,
+                name: part_name(part),
+            });
+        }
+    }
+    if let (Some(hp), Some(cap)) = (max_hp, rules.max_hp) {
+        if hp > cap {
+            out.push(RuleBreach::MaxHpOverCap { hp, cap });
+        }
+    }
+    out
+}
+
+/// Each distinct id with how many times it occurs, in first-seen order.
+fn tally(ids: &[usize]) -> Vec<(usize, usize)> {
+    let mut out: Vec<(usize, usize)> = vec![];
+    for &id in ids {
+        match out.iter_mut().find(|(seen, _)| *seen == id) {
+            Some((_, count)) => *count += 1,
+            None => out.push((id, 1)),
+        }
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -680,4 +795,51 @@ mod tests {
             ["Attack+1: Placed on grid with invalid shape."]
         );
     }
+
+    #[test]
+    fn rule_breaches_name_each_offending_id_once() {
+        use tango_gamesupport::{BuildRules, RuleBreach};
+
+        let rules = BuildRules {
+            banned_chips: vec![7],
+            banned_navicust_parts: vec![3],
+            max_copies: Some(2),
+            max_hp: Some(1000),
+        };
+        let found = breaches(
+            &[1, 7, 1, 7, 1, 2, 2],
+            &[3, 3, 4],
+            Some(1200),
+            &rules,
+            |id| (id == 1).then(|| "Cannon".to_string()),
+            |_| None,
+        );
+        assert_eq!(
+            found,
+            vec![
+                RuleBreach::TooManyCopies {
+                    chip: 1,
+                    name: Some("Cannon".to_string()),
+                    count: 3,
+                    limit: 2,
+                },
+                RuleBreach::BannedChip { chip: 7, name: None },
+                RuleBreach::BannedNavicustPart { part: 3, name: None },
+                RuleBreach::MaxHpOverCap { hp: 1200, cap: 1000 },
+            ]
+        );
+    }
+
+    #[test]
+    fn no_rules_no_breaches() {
+        let found = breaches(
+            &[1, 1, 1, 1, 1],
+            &[3],
+            Some(u16::MAX),
+            &tango_gamesupport::BuildRules::default(),
+            |_| None,
+            |_| None,
+        );
+        assert!(found.is_empty());
+    }
 }
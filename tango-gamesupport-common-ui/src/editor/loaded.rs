@@ -42,6 +42,9 @@ pub struct OpenSave {
     /// the same way round whichever one is loaded). Built once here so
     /// the per-frame view() just clones the handles.
     pub logos: Vec<(u32, u32, iced_image::Handle)>,
+    /// The open edit session's undo/redo stacks. Lives beside the model
+    /// it steps: Cancel rebuilds this whole bundle, and Commit clears it.
+    pub history: crate::model::History,
 }
 
 /// Reopen the private model/art bundle carried by the public loaded-save
@@ -201,6 +204,7 @@ pub fn from_model(
         navicust_render,
         navicust_part_icons,
         logos,
+        history: crate::model::History::default(),
     }
 }
 
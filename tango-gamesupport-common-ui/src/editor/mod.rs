@@ -90,7 +90,8 @@ pub trait GameSaveEditor: Send + Sync {
     ///
     /// The default is the shared navi strip — the card BN5/BN6 name
     /// their navi in, becoming the change-navi button when `edit` is
-    /// `Some`. A game whose identity is not a navi off that roster
+    /// `Some`, and holding the HP stepper for BN1–3 while `editing`.
+    /// A game whose identity is not a navi off that roster
     /// overrides this and builds its own card instead, handing it to
     /// [`view::navi::render_identity_strip`] so the strip stays one
     /// strip: BN5DS names the GBA-slot cross there, and a save with no
@@ -109,8 +110,7 @@ pub trait GameSaveEditor: Send + Sync {
         editing: bool,
         actions: Element<'a, Action>,
     ) -> Element<'a, Action> {
-        let _ = editing;
-        crate::editor::view::navi::render_navi_strip(lang, loaded, edit, actions)
+        crate::editor::view::navi::render_navi_strip(lang, loaded, edit, editing, actions)
     }
 
     /// Whether this save has something to edit that the shared
@@ -168,6 +168,24 @@ pub trait GameSaveEditor: Send + Sync {
         None
     }
 
+    /// This save's build as a shareable code, read through the shared
+    /// views. A game with build state outside them (BN4's Mod Cards)
+    /// adds it here.
+    fn build_code(&self, loaded: &OpenSave) -> crate::dataview::build_code::BuildCode {
+        crate::dataview::build_code::BuildCode::from_save(loaded.build_code_game(), loaded.save.as_ref())
+    }
+
+    /// The staged edits that load `code` into this save — the way back
+    /// from [`build_code`](Self::build_code). The code's game has
+    /// already been checked.
+    fn build_code_edits(
+        &self,
+        code: &crate::dataview::build_code::BuildCode,
+        loaded: &OpenSave,
+    ) -> Vec<crate::model::Edit> {
+        crate::model::build_code::edits(code, loaded)
+    }
+
     /// Game-owned legality collapsed to shared UI metadata and opaque warning
     /// messages. Concrete rule variants never cross this interface.
     fn build_report(&self, loaded: &OpenSave) -> BuildReport {
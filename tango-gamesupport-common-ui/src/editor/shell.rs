@@ -8,6 +8,7 @@
 use crate::editor::loaded::{self, OpenSave};
 use crate::editor::view::{Action, Outcome, State};
 use crate::editor::GameSaveEditor;
+use crate::i18n::t;
 use tango_gamesupport::LoadedSave;
 use unic_langid::LanguageIdentifier;
 
@@ -76,6 +77,50 @@ impl<G: GameSaveEditor + 'static> tango_gamesupport::SaveEditor for SaveEditorSh
         })
     }
 
+    fn check_rules(
+        &self,
+        prepared: &tango_gamesupport::PreparedSave,
+        rules: &tango_gamesupport::BuildRules,
+    ) -> Vec<tango_gamesupport::RuleBreach> {
+        if !prepared.game.has_save_model() {
+            return if *rules == tango_gamesupport::BuildRules::default() {
+                vec![]
+            } else {
+                vec![tango_gamesupport::RuleBreach::Unreadable]
+            };
+        }
+        let save = crate::dataview::save_ref(prepared.save.as_ref());
+        let assets = crate::dataview::assets_ref(prepared.assets.as_ref());
+        crate::build::rule_breaches(save, assets, rules)
+    }
+
+    fn summary(&self, prepared: &tango_gamesupport::PreparedSave) -> tango_gamesupport::SaveSummary {
+        let save = crate::dataview::save_ref(prepared.save.as_ref());
+        let assets = crate::dataview::assets_ref(prepared.assets.as_ref());
+        crate::build::summary(save, assets)
+    }
+
+    fn patch_diff(
+        &self,
+        left: &tango_gamesupport::PreparedSave,
+        right: &tango_gamesupport::PreparedSave,
+    ) -> tango_gamesupport::OpaquePatchDiff {
+        let [left, right] = [left, right].map(|prepared| {
+            (
+                crate::dataview::save_ref(prepared.save.as_ref()),
+                crate::dataview::assets_ref(prepared.assets.as_ref()),
+            )
+        });
+        crate::patch_diff::new(
+            left.0,
+            [left.1, right.1],
+            [
+                self.0.validate_save(left.0, left.1),
+                self.0.validate_save(right.0, right.1),
+            ],
+        )
+    }
+
     fn load(
         &'static self,
         prepared: tango_gamesupport::PreparedSave,
@@ -117,6 +162,16 @@ impl<G: GameSaveEditor + 'static> tango_gamesupport::SaveEditor for SaveEditorSh
         .map(wrap)
     }
 
+    fn diff_view<'a>(
+        &self,
+        lang: &'a LanguageIdentifier,
+        left: &'a LoadedSave,
+        right: &'a LoadedSave,
+        labels: [String; 2],
+    ) -> iced::Element<'a, std::sync::Arc<dyn tango_gamesupport::SaveEditorMessage>> {
+        crate::editor::view::diff::render(lang, loaded::open(left), loaded::open(right), labels)
+    }
+
     fn update(
         &self,
         lang: &LanguageIdentifier,
@@ -141,12 +196,29 @@ impl<G: GameSaveEditor + 'static> tango_gamesupport::SaveEditor for SaveEditorSh
             // Staged edits land in the loaded bundle right here — the
             // app never sees them, it just keeps rendering.
             Some(Outcome::Edit(edit)) => {
-                let invalidated = crate::model::apply_edit(&mut open.model, edit);
+                let invalidated = open.history.apply(&mut open.model, edit);
                 if invalidated.navicust_render {
                     crate::editor::loaded::rebuild_navicust_render(open);
                 }
                 None
             }
+            // Stepping swaps a whole save in, so whatever the edit it
+            // crosses invalidated is invalid again — a navi swap's grid
+            // art — and the session's scratch is re-read from it.
+            Some(step @ (Outcome::Undo | Outcome::Redo)) => {
+                let history = &mut open.history;
+                let stepped = match step {
+                    Outcome::Undo => history.undo(&mut open.model),
+                    _ => history.redo(&mut open.model),
+                };
+                if let Some(invalidated) = stepped {
+                    if invalidated.navicust_render {
+                        crate::editor::loaded::rebuild_navicust_render(open);
+                    }
+                    state.resync_edit(open);
+                }
+                None
+            }
             Some(Outcome::Commit) => {
                 // Every staged edit already kept its derived caches in
                 // sync; commit recomputes the whole-SRAM checksum and
@@ -156,6 +228,7 @@ impl<G: GameSaveEditor + 'static> tango_gamesupport::SaveEditor for SaveEditorSh
                 // until reselection.
                 open.save.rebuild_checksum();
                 crate::editor::loaded::rebuild_navicust_render(open);
+                open.history.clear();
                 Some(Out::Commit {
                     sram: open.save.to_sram_dump(),
                 })
@@ -170,6 +243,40 @@ impl<G: GameSaveEditor + 'static> tango_gamesupport::SaveEditor for SaveEditorSh
         (task.map(wrap), outcome)
     }
 
+    fn stage_build_code(
+        &self,
+        lang: &LanguageIdentifier,
+        data: &mut LoadedSave,
+        code: &str,
+    ) -> Result<Vec<String>, String> {
+        let open = loaded::open_mut(&mut *data.payload);
+        let code = crate::dataview::build_code::decode_for(code.trim(), &open.build_code_game())
+            .map_err(|err| crate::editor::view::build_code_error_text(lang, &err))?;
+        // Counted before the build lands: its folder replaces one of the
+        // save's own, whose chips the pack is owed.
+        let short = crate::dataview::build_code::pack_shortfall(&code, open.save.as_ref(), open.assets.as_ref())
+            .into_iter()
+            .map(|(chip, count)| {
+                let name = open
+                    .assets
+                    .chip(chip.id)
+                    .and_then(|info| info.name())
+                    .unwrap_or_else(|| t!(lang, "build-chip-unknown", id = chip.id as i64));
+                t!(
+                    lang,
+                    "build-code-pack-short",
+                    chip = format!("{name} {}", chip.code),
+                    count = count as i64
+                )
+            })
+            .collect();
+        let edits = self.0.build_code_edits(&code, open);
+        if crate::model::apply_edit(&mut open.model, crate::model::Edit::Batch(edits)).navicust_render {
+            loaded::rebuild_navicust_render(open);
+        }
+        Ok(short)
+    }
+
     fn carry_view_position(
         &self,
         from: &dyn tango_gamesupport::SaveEditorState,
@@ -216,6 +323,14 @@ mod tests {
         fn rebuild_checksum(&mut self) {
             self.checksum = self.value;
         }
+
+        fn edit_bytes(&self) -> &[u8] {
+            std::slice::from_ref(&self.value)
+        }
+
+        fn edit_bytes_mut(&mut self) -> &mut [u8] {
+            std::slice::from_mut(&mut self.value)
+        }
     }
 
     #[test]
@@ -228,4 +343,31 @@ mod tests {
         assert_eq!(session_sram(&staged), vec![0x42, 0x42]);
         assert_eq!(staged.to_sram_dump(), vec![0x42, 0x11]);
     }
+
+    /// A game with no save model can't be checked, so any rule that needs
+    /// its save refuses it; a rule set constraining nothing lets it by.
+    #[test]
+    fn rules_refuse_a_game_with_no_save_model() {
+        use tango_gamesupport::{BuildRules, RuleBreach, SaveEditor as _};
+
+        let prepared = tango_gamesupport::PreparedSave {
+            game: &crate::testing::GAME,
+            save_path: Default::default(),
+            patch: None,
+            save: crate::dataview::wrap_save(Box::new(crate::testing::BytesSave(vec![0; 4]))),
+            assets: crate::dataview::wrap_assets(Box::new(crate::dataview::rom::EmptyAssets)),
+        };
+        let rules = BuildRules {
+            banned_chips: vec![1],
+            ..Default::default()
+        };
+        assert_eq!(
+            crate::editor::EMPTY_SAVE_EDITOR.check_rules(&prepared, &rules),
+            vec![RuleBreach::Unreadable]
+        );
+        assert_eq!(
+            crate::editor::EMPTY_SAVE_EDITOR.check_rules(&prepared, &BuildRules::default()),
+            vec![]
+        );
+    }
 }
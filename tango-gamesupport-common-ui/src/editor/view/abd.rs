@@ -261,10 +261,17 @@ pub fn render_auto_battle_data_edit<'a>(
     let count = text(t!(lang, "auto-battle-data-edit-count", count = distinct as i64))
         .size(TEXT_CAPTION)
         .style(muted_text_style);
+    // Paste a deck back from the Copy button's text.
+    let import = crate::widgets::icon_button(
+        lucide_icons::Icon::ClipboardPaste,
+        t!(lang, "auto-battle-data-edit-import"),
+        Action::ImportAutoBattleDataText,
+        style::CONTROL_PADDING,
+    );
     let deck_header = editor_header(
         lang,
         t!(lang, "save-tab-auto-battle-data"),
-        vec![count.into()],
+        vec![count.into(), import],
         Action::ClearAutoBattleData,
     );
     let deck_pane = editor_pane(deck_header, deck);
@@ -335,8 +342,18 @@ pub fn as_text(lang: &LanguageIdentifier, loaded: &OpenSave) -> Option<String> {
     let assets = loaded.assets.as_ref();
     let view = loaded.save.view_auto_battle_data()?;
     let grouped = crate::dataview::auto_battle_data::GroupedAutoBattleData::materialize(view.as_ref(), assets);
+    Some(deck_text(lang, &grouped, assets))
+}
+
+/// The deck as [`as_text`] writes it, which
+/// [`crate::model::import::auto_battle_data_from_text`] reads back.
+fn deck_text(
+    lang: &LanguageIdentifier,
+    grouped: &crate::dataview::auto_battle_data::GroupedAutoBattleData,
+    assets: &dyn crate::dataview::rom::Assets,
+) -> String {
     let mut out = String::new();
-    for (title, runs) in abd_grouped_sections(lang, &grouped) {
+    for (title, runs) in abd_grouped_sections(lang, grouped) {
         out.push_str(&format!("[{title}]\n"));
         for (id, count) in runs {
             let name = match id {
@@ -350,5 +367,83 @@ pub fn as_text(lang: &LanguageIdentifier, loaded: &OpenSave) -> Option<String> {
         }
         out.push('\n');
     }
-    Some(out)
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataview::auto_battle_data::GroupedAutoBattleData;
+    use crate::dataview::rom::ChipClass;
+    use crate::testing::ChipAssets;
+
+    /// Per-chip use counts, for `GroupedAutoBattleData::materialize`.
+    struct Counts {
+        primary: Vec<usize>,
+        secondary: Vec<usize>,
+    }
+
+    impl crate::dataview::save::AutoBattleDataView for Counts {
+        fn chip_use_count(&self, id: usize) -> Option<usize> {
+            self.primary.get(id).copied()
+        }
+
+        fn secondary_chip_use_count(&self, id: usize) -> Option<usize> {
+            self.secondary.get(id).copied()
+        }
+
+        fn materialized(&self) -> crate::dataview::auto_battle_data::MaterializedAutoBattleData {
+            unreachable!("the grouped deck is built from the counts")
+        }
+    }
+
+    /// Copying the deck as text and importing it restages counts that
+    /// rank the same deck, unnamed chips and all.
+    #[test]
+    fn copied_deck_imports_back() {
+        let assets = ChipAssets(vec![
+            ("Cannon", "ABC", ChipClass::Standard),
+            ("AirShot", "A*", ChipClass::Standard),
+            ("Bass X", "X", ChipClass::Standard),
+            ("", "A", ChipClass::Standard),
+            ("Roll", "R", ChipClass::Mega),
+            ("Bass", "X", ChipClass::Giga),
+            ("Z-Saber", "Z", ChipClass::ProgramAdvance),
+        ]);
+        let deck = Counts {
+            primary: vec![9, 3, 3, 5, 2, 1, 4],
+            secondary: vec![0, 6, 0, 2, 0, 0, 0],
+        };
+        let grouped = GroupedAutoBattleData::materialize(&deck, &assets);
+        let english = "en-US".parse().unwrap();
+        let text = deck_text(&english, &grouped, &assets);
+
+        // Chip 3 has no name, so it's written as its id.
+        assert!(text.contains("\t#3\n"));
+        let import = crate::model::import::read_auto_battle_data(&text, &assets);
+        assert!(import.unknown.is_empty(), "{:?}", import.unknown);
+
+        let mut staged = Counts {
+            primary: vec![0; 7],
+            secondary: vec![0; 7],
+        };
+        let crate::model::Edit::Batch(edits) = import.edit() else {
+            panic!("an import stages one batch");
+        };
+        for edit in edits {
+            match edit {
+                crate::model::Edit::AutoBattleData(crate::model::AutoBattleDataEdit::SetUseCount { id, count }) => {
+                    staged.primary[id] = count
+                }
+                crate::model::Edit::AutoBattleData(crate::model::AutoBattleDataEdit::SetSecondaryUseCount {
+                    id,
+                    count,
+                }) => staged.secondary[id] = count,
+                crate::model::Edit::AutoBattleData(crate::model::AutoBattleDataEdit::ClearAll) => {}
+                edit => panic!("unexpected {edit:?}"),
+            }
+        }
+        let restaged = GroupedAutoBattleData::materialize(&staged, &assets);
+        assert_eq!(deck_text(&english, &restaged, &assets), text);
+    }
 }
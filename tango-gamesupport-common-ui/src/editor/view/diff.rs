@@ -0,0 +1,204 @@
+use super::*;
+use crate::dataview::diff::{Change, SaveDiff};
+use crate::dataview::save::{Chip, NavicustPart, PatchCard};
+use sweeten::widget::{column, row};
+
+// ---------- Save diff ----------
+
+/// Two saves of one game side by side: what only `left` has in the left
+/// column, what only `right` has in the right, and what changed in place
+/// (REG/TAG, a moved part, a toggled card) across both. Read-only —
+/// nothing here sends an action. Each side names ids from its own
+/// assets, so a chip a patch renamed reads as that save sees it.
+pub fn render<M: 'static>(
+    lang: &LanguageIdentifier,
+    left: &OpenSave,
+    right: &OpenSave,
+    labels: [String; 2],
+) -> Element<'static, M> {
+    let diff = crate::dataview::diff::diff(left.save.as_ref(), right.save.as_ref());
+    let [left_label, right_label] = labels;
+    let header = sides(
+        text(left_label).size(TEXT_HEADING).into(),
+        text(right_label).size(TEXT_HEADING).into(),
+    );
+    let mut body = column![header].spacing(12);
+    if diff.is_empty() {
+        body = body.push(placeholder(t!(lang, "save-diff-identical")));
+    }
+    for section in sections(lang, left, right, &diff) {
+        body = body.push(section);
+    }
+    container(body).width(Fill).padding(style::PANE_PADDING).into()
+}
+
+fn sections<M: 'static>(
+    lang: &LanguageIdentifier,
+    left: &OpenSave,
+    right: &OpenSave,
+    diff: &SaveDiff,
+) -> Vec<Element<'static, M>> {
+    let mut out = vec![];
+    if let Some(Change { from, to }) = diff.navi {
+        out.push(section(
+            t!(lang, "save-diff-navi"),
+            vec![],
+            vec![],
+            vec![format!("{} → {}", navi_name(left, from), navi_name(right, to))],
+        ));
+    }
+    let folder = &diff.folder;
+    if !folder.is_empty() {
+        let mut changes = vec![];
+        if let Some(Change { from, to }) = &folder.regular {
+            changes.push(format!(
+                "{}: {} → {}",
+                t!(lang, "save-diff-regular"),
+                chip_or_none(lang, left, from.as_ref()),
+                chip_or_none(lang, right, to.as_ref()),
+            ));
+        }
+        if let Some(Change { from, to }) = &folder.tags {
+            let pair = |loaded: &OpenSave, tags: &Option<[Chip; 2]>| match tags {
+                Some([a, b]) => format!("{} + {}", chip_name(lang, loaded, a), chip_name(lang, loaded, b)),
+                None => t!(lang, "save-diff-none"),
+            };
+            changes.push(format!(
+                "{}: {} → {}",
+                t!(lang, "save-diff-tags"),
+                pair(left, from),
+                pair(right, to)
+            ));
+        }
+        let counted = |loaded: &OpenSave, chips: &[(Chip, usize)]| {
+            chips
+                .iter()
+                .map(|(chip, n)| format!("{n}× {}", chip_name(lang, loaded, chip)))
+                .collect()
+        };
+        out.push(section(
+            t!(lang, "save-tab-folder"),
+            counted(left, &folder.removed),
+            counted(right, &folder.added),
+            changes,
+        ));
+    }
+    let navicust = &diff.navicust;
+    if !navicust.is_empty() {
+        let parts =
+            |loaded: &OpenSave, parts: &[NavicustPart]| parts.iter().map(|p| part_name(lang, loaded, p.id)).collect();
+        let changes = navicust
+            .moved
+            .iter()
+            .map(|Change { to, .. }| t!(lang, "save-diff-moved", part = part_name(lang, right, to.id)))
+            .collect();
+        out.push(section(
+            t!(lang, "save-tab-navicust"),
+            parts(left, &navicust.removed),
+            parts(right, &navicust.added),
+            changes,
+        ));
+    }
+    let cards = &diff.patch_cards;
+    if !cards.is_empty() {
+        let names =
+            |loaded: &OpenSave, cards: &[PatchCard]| cards.iter().map(|c| card_name(lang, loaded, c.id)).collect();
+        let changes = cards
+            .toggled
+            .iter()
+            .map(|c| {
+                let card = card_name(lang, right, c.id);
+                if c.enabled {
+                    t!(lang, "save-diff-enabled", card = card)
+                } else {
+                    t!(lang, "save-diff-disabled", card = card)
+                }
+            })
+            .collect();
+        out.push(section(
+            t!(lang, "save-tab-patch-cards"),
+            names(left, &cards.removed),
+            names(right, &cards.added),
+            changes,
+        ));
+    }
+    out
+}
+
+/// One section: its heading, the removed | added columns (skipped when
+/// both are empty), then the in-place changes full width.
+fn section<M: 'static>(
+    heading: String,
+    removed: Vec<String>,
+    added: Vec<String>,
+    changes: Vec<String>,
+) -> Element<'static, M> {
+    let mut col = column![text(heading).size(TEXT_BODY).style(muted_text_style)].spacing(4);
+    if !removed.is_empty() || !added.is_empty() {
+        let lines =
+            |items: Vec<String>, mark: &'static str, style: fn(&iced::Theme) -> text::Style| -> Element<'static, M> {
+                column(
+                    items
+                        .into_iter()
+                        .map(|s| text(format!("{mark} {s}")).size(TEXT_BODY).style(style).into()),
+                )
+                .spacing(2)
+                .into()
+            };
+        col = col.push(sides(
+            lines(removed, "−", crate::widgets::danger_text_style),
+            lines(added, "+", crate::widgets::success_text_style),
+        ));
+    }
+    for change in changes {
+        col = col.push(text(change).size(TEXT_BODY));
+    }
+    container(col)
+        .width(Fill)
+        .padding(style::ROW_PADDING)
+        .style(crate::widgets::pane)
+        .into()
+}
+
+fn sides<M: 'static>(left: Element<'static, M>, right: Element<'static, M>) -> Element<'static, M> {
+    row![container(left).width(Fill), container(right).width(Fill)]
+        .spacing(12)
+        .into()
+}
+
+fn chip_name(lang: &LanguageIdentifier, loaded: &OpenSave, chip: &Chip) -> String {
+    let name = loaded
+        .assets
+        .chip(chip.id)
+        .and_then(|info| info.name())
+        .unwrap_or_else(|| t!(lang, "build-chip-unknown", id = chip.id as i64));
+    format!("{name} {}", chip.code)
+}
+
+fn chip_or_none(lang: &LanguageIdentifier, loaded: &OpenSave, chip: Option<&Chip>) -> String {
+    chip.map_or_else(|| t!(lang, "save-diff-none"), |c| chip_name(lang, loaded, c))
+}
+
+fn part_name(lang: &LanguageIdentifier, loaded: &OpenSave, id: usize) -> String {
+    loaded
+        .assets
+        .navicust_part(id)
+        .and_then(|info| info.name())
+        .unwrap_or_else(|| t!(lang, "build-navicust-part-unknown", id = id as i64))
+}
+
+fn card_name(lang: &LanguageIdentifier, loaded: &OpenSave, id: usize) -> String {
+    loaded
+        .assets
+        .patch_card56(id)
+        .and_then(|info| info.name())
+        .unwrap_or_else(|| t!(lang, "build-patch-card-unknown", id = id as i64))
+}
+
+fn navi_name(loaded: &OpenSave, id: usize) -> String {
+    loaded
+        .assets
+        .navi(id)
+        .and_then(|n| n.name())
+        .unwrap_or_else(|| format!("Navi #{id}"))
+}
@@ -266,10 +266,19 @@ pub fn render_folder_edit<'a>(
         ),
         filled < folder_size,
     );
+    // Paste a folder list from the clipboard — the Copy button's text,
+    // or the looser lists players trade.
+    let import = crate::widgets::icon_button(
+        lucide_icons::Icon::ClipboardPaste,
+        t!(lang, "folder-edit-import"),
+        Action::ImportFolderText,
+        style::CONTROL_PADDING,
+    );
     let header_row = row![
         text(t!(lang, "folder-edit-folder")).size(TEXT_BODY),
         count,
         Space::new().width(Fill),
+        import,
         clear_all,
     ]
     .spacing(8)
@@ -325,7 +334,10 @@ pub fn render_folder_edit<'a>(
         }
         r
     };
-    let header_col = column![header_row, stats_row].spacing(4);
+    let mut header_col = column![header_row, stats_row].spacing(4);
+    if let Some(import) = &edit.folder_import {
+        header_col = header_col.push(import_report(lang, import));
+    }
     let folder_header = container(header_col).width(Fill).padding(style::HEADER_PADDING);
     let folder_pane = editor_pane(folder_header, folder_list);
 
@@ -575,7 +587,7 @@ pub fn library_sort_label(sort: LibrarySort, lang: &LanguageIdentifier) -> Strin
     }
 }
 
-fn sorted_library_entries(
+pub(super) fn sorted_library_entries(
     loaded: &OpenSave,
     sort: LibrarySort,
 ) -> Vec<(usize, String, crate::dataview::save::ChipCode)> {
@@ -1057,6 +1069,50 @@ pub fn chip_tooltip_style(accent: Option<iced::Color>) -> impl Fn(&iced::Theme)
     }
 }
 
+/// What the last text import did: how many chips it read, which lines
+/// named nothing, what didn't fit, and how many legality findings the
+/// imported folder has (each marked on its slot like any other).
+fn import_report<'a>(lang: &LanguageIdentifier, import: &crate::model::import::FolderImport) -> Element<'a, Action> {
+    let mut lines = column![].spacing(2);
+    let mut summary = row![limit_caption(
+        t!(lang, "folder-import-read", count = import.chips.len() as i64),
+        import.chips.is_empty()
+    )]
+    .spacing(8)
+    .align_y(Alignment::Center);
+    if !import.violations.is_empty() {
+        summary = summary.push(limit_caption(
+            t!(lang, "folder-import-issues", count = import.violations.len() as i64),
+            true,
+        ));
+    }
+    if import.dropped > 0 {
+        summary = summary.push(limit_caption(
+            t!(lang, "folder-import-dropped", count = import.dropped as i64),
+            true,
+        ));
+    }
+    summary = summary.push(Space::new().width(Fill)).push(crate::widgets::icon_button(
+        lucide_icons::Icon::X,
+        t!(lang, "folder-import-dismiss"),
+        Action::DismissFolderImport,
+        [2.0, 4.0],
+    ));
+    lines = lines.push(summary);
+    for (number, line) in &import.unknown {
+        lines = lines.push(limit_caption(
+            t!(
+                lang,
+                "folder-import-unknown",
+                line = *number as i64,
+                text = line.clone()
+            ),
+            true,
+        ));
+    }
+    lines.into()
+}
+
 /// The folder tab as TSV text for clipboard "copy as text".
 pub fn as_text(loaded: &OpenSave, opts: RenderOpts) -> Option<String> {
     let assets = loaded.assets.as_ref();
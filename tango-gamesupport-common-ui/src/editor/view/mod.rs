@@ -16,10 +16,13 @@ use unic_langid::LanguageIdentifier;
 
 pub mod abd;
 pub mod cover;
+pub mod diff;
 pub mod folder;
 pub mod navi;
 pub mod navicust;
+pub mod pack;
 pub mod patch_cards;
+pub mod shortcuts;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Tab {
@@ -28,6 +31,9 @@ pub enum Tab {
     Folder,
     PatchCards,
     AutoBattleData,
+    /// The chip pack: how many copies of each chip code the save owns
+    /// outside its folders.
+    Pack,
     /// Battle Chip Challenge's wired deck board — BCC's replacement for
     /// the flat Folder list.
     ProgramDeck,
@@ -37,6 +43,10 @@ pub enum Tab {
     /// ([`crate::editor::GameSaveEditor::tab_editable`]), not the
     /// shared model.
     Party,
+    /// BN6's link navis: each navi the version can operate, with the
+    /// fixed build its stats block carries in place of a NaviCust.
+    /// Editable through its UI crate, like [`Tab::Party`].
+    LinkNavi,
 }
 
 impl Tab {
@@ -47,9 +57,9 @@ impl Tab {
     /// there.
     pub fn editable_on(self, e: &crate::model::Editability) -> bool {
         match self {
-            Tab::Cover | Tab::Party => false,
+            Tab::Cover | Tab::Party | Tab::LinkNavi => false,
             Tab::Navicust => e.navicust,
-            Tab::Folder | Tab::ProgramDeck => e.folder,
+            Tab::Folder | Tab::ProgramDeck | Tab::Pack => e.folder,
             Tab::PatchCards => e.patch_cards,
             Tab::AutoBattleData => e.auto_battle_data,
         }
@@ -152,10 +162,18 @@ pub struct EditState {
     pub patch_card56_filter: String,
     /// Auto-battle-data editor: chip library filter text.
     pub auto_battle_data_filter: String,
+    /// Pack editor: chip list filter text.
+    pub pack_filter: String,
     /// Slot-targeted editors (BCC's program deck board): the deck slot
     /// the library pane is currently aimed at, or `None` when no slot is
     /// picked. Slot indexes are the game's `ChipsView` slot indexes.
     pub selected_deck_slot: Option<usize>,
+    /// Folder editor: the last text import, kept for its report (lines
+    /// that named no chip, chips that didn't fit) until dismissed.
+    pub folder_import: Option<crate::model::import::FolderImport>,
+    /// Why the last pasted build code was refused, shown beside the
+    /// session's buttons until the next paste succeeds.
+    pub build_code_error: Option<crate::dataview::build_code::Error>,
 }
 
 impl EditState {
@@ -226,6 +244,10 @@ pub enum Outcome {
     Commit,
     /// Discard all staged edits, reloading the on-disk original.
     Cancel,
+    /// Step the staged edits back one (see [`crate::model::History`]).
+    Undo,
+    /// Re-apply the last undone staged edit.
+    Redo,
 }
 
 /// User-driven changes the embedded save view wants to surface. The
@@ -273,6 +295,11 @@ pub enum Action {
     /// Discard all staged edits (reverts the loaded save to the
     /// on-disk original) and leave edit mode.
     CancelEdit,
+    /// Step back one staged edit (the Undo button, Ctrl+Z).
+    Undo,
+    /// Re-apply the last undone edit (the Redo button, Ctrl+Shift+Z /
+    /// Ctrl+Y).
+    Redo,
     /// Library pane: add this chip+code to the first empty folder slot.
     AddChip {
         chip_id: usize,
@@ -288,6 +315,20 @@ pub enum Action {
     ReorderChips(sweeten::widget::drag::DragEvent),
     /// Folder pane: empty every slot (and clear REG/TAG).
     ClearFolder,
+    /// Folder pane: read the clipboard as a folder list (see
+    /// [`crate::model::import`]).
+    ImportFolderText,
+    /// The clipboard's text, read for [`Action::ImportFolderText`].
+    FolderTextPasted(Option<String>),
+    /// Folder pane: dismiss the last import's report.
+    DismissFolderImport,
+    /// Copy the save's build as a build code (see
+    /// [`crate::dataview::build_code`]).
+    CopyBuildCode,
+    /// Read the clipboard as a build code and stage it over the save.
+    PasteBuildCode,
+    /// The clipboard's text, read for [`Action::PasteBuildCode`].
+    BuildCodePasted(Option<String>),
     /// Toggle `slot` as the folder's Regular chip — set it, or clear it
     /// if it's already the regular chip.
     ToggleRegular {
@@ -353,6 +394,8 @@ pub enum Action {
     // ----- Navi editor (only emitted when `editable` is set) -----
     /// Set the equipped navi to this index.
     SetNavi(usize),
+    /// Set the navi's base max HP (the roster-less games' HP stepper).
+    SetBaseMaxHp(u16),
     /// Palette: the filter text changed.
     NavicustFilterChanged(String),
     /// Palette: the sort order changed.
@@ -397,10 +440,28 @@ pub enum Action {
     },
     /// Deck pane: zero every chip's use counts, emptying the deck.
     ClearAutoBattleData,
+    /// Deck pane: read the clipboard as a deck (see
+    /// [`crate::model::import`]).
+    ImportAutoBattleDataText,
+    /// The clipboard's text, read for [`Action::ImportAutoBattleDataText`].
+    AutoBattleDataTextPasted(Option<String>),
     /// Library pane: the filter text changed.
     AutoBattleDataFilterChanged(String),
     /// Library pane: the sort order changed.
     AutoBattleDataSortChanged(AutoBattleDataSort),
+    // ----- Pack editor (only emitted when `editable` is set) -----
+    /// Set how many copies of chip `id` in its `variant`-th code the pack
+    /// holds.
+    SetPackCount {
+        id: usize,
+        variant: usize,
+        count: usize,
+    },
+    /// Pack pane: empty the pack.
+    ClearPack,
+    /// Library pane: the filter text changed. The pack shares the
+    /// folder library's sort ([`Action::LibrarySortChanged`]).
+    PackFilterChanged(String),
 }
 
 // ---------------------------------------------------------------------
@@ -707,8 +768,10 @@ fn tab_icon(tab: Tab) -> lucide_icons::Icon {
         Tab::Folder => Icon::Files,
         Tab::PatchCards => Icon::CreditCard,
         Tab::AutoBattleData => Icon::Bot,
+        Tab::Pack => Icon::Package,
         Tab::ProgramDeck => Icon::Network,
         Tab::Party => Icon::Users,
+        Tab::LinkNavi => Icon::Link,
     }
 }
 
@@ -786,9 +849,30 @@ pub fn view<'a>(
     let mut actions = row![].spacing(6).align_y(Alignment::Center);
     if render_edit_buttons {
         if inline_actions {
-            actions = actions.push(edit_buttons(lang, can_save));
+            if let Some(err) = state.editing.as_ref().and_then(|e| e.build_code_error.as_ref()) {
+                actions = actions.push(limit_caption(build_code_error_text(lang, err), true));
+            }
+            actions = actions.push(edit_buttons(
+                lang,
+                can_save,
+                loaded.history.can_undo(),
+                loaded.history.can_redo(),
+            ));
         }
     } else {
+        // Every build a code carries has a folder; a save without one
+        // has nothing worth sharing.
+        if inline_actions && loaded.save.view_chips().is_some() {
+            actions = actions.push(widgets::copy_icon_button(
+                BUILD_CODE_FLASH_KEY,
+                lucide_icons::Icon::Share2,
+                TEXT_BODY,
+                t!(lang, "save-copy-build-code"),
+                t!(lang, "copied"),
+                Some(Action::CopyBuildCode),
+                [4.0, 8.0],
+            ));
+        }
         if inline_actions && save_editable {
             actions = actions.push(widgets::labeled_icon_button(
                 lucide_icons::Icon::Pencil,
@@ -891,8 +975,10 @@ pub fn view<'a>(
             Tab::Folder => t!(lang, "save-tab-folder"),
             Tab::PatchCards => t!(lang, "save-tab-patch-cards"),
             Tab::AutoBattleData => t!(lang, "save-tab-auto-battle-data"),
+            Tab::Pack => t!(lang, "save-tab-pack"),
             Tab::ProgramDeck => t!(lang, "save-tab-program-deck"),
             Tab::Party => t!(lang, "save-tab-party"),
+            Tab::LinkNavi => t!(lang, "save-tab-link-navi"),
         };
         let legality_errors = build_report
             .error_tabs
@@ -1051,17 +1137,39 @@ pub fn view<'a>(
     if fill {
         col = col.height(Fill);
     }
-    col.into()
+    // Undo / redo keys only mean something while a session is staging
+    // edits; a read-only embed lets them pass. Wrapped either way, so
+    // toggling edit mode keeps the widget tree's state.
+    shortcuts::edit_shortcuts(col, editing_session)
 }
 
 /// The global edit mode's Save / Cancel pair, shown at the navi
 /// header's right edge while edit mode is on (or sliding out). One
 /// pair for the whole save: they commit / discard the edits on *all*
 /// tabs at once. Any save-editor error disables Save until it is resolved.
-fn edit_buttons(lang: &LanguageIdentifier, can_save: bool) -> Element<'_, Action> {
+/// Undo / Redo lead the cluster, greyed out while their stack is empty.
+fn edit_buttons(lang: &LanguageIdentifier, can_save: bool, can_undo: bool, can_redo: bool) -> Element<'_, Action> {
     use crate::widgets;
     use lucide_icons::Icon;
     row![
+        widgets::icon_button(
+            Icon::ClipboardPaste,
+            t!(lang, "save-paste-build-code"),
+            Action::PasteBuildCode,
+            [4.0, 8.0],
+        ),
+        widgets::icon_button_maybe(
+            Icon::Undo2,
+            t!(lang, "save-edit-undo"),
+            can_undo.then_some(Action::Undo),
+            [4.0, 8.0],
+        ),
+        widgets::icon_button_maybe(
+            Icon::Redo2,
+            t!(lang, "save-edit-redo"),
+            can_redo.then_some(Action::Redo),
+            [4.0, 8.0],
+        ),
         widgets::labeled_icon_button(
             Icon::X,
             t!(lang, "save-edit-cancel"),
@@ -1107,7 +1215,9 @@ fn extra_kinds(tab: Tab) -> Vec<ExtraKind> {
         // The navi card copies as text only; the navicust grid also
         // copies as an image.
         Tab::Navicust => vec![ExtraKind::CopyImage, ExtraKind::Copy],
-        Tab::PatchCards | Tab::AutoBattleData | Tab::ProgramDeck | Tab::Party => vec![ExtraKind::Copy],
+        Tab::PatchCards | Tab::AutoBattleData | Tab::Pack | Tab::ProgramDeck | Tab::Party | Tab::LinkNavi => {
+            vec![ExtraKind::Copy]
+        }
         Tab::Cover => vec![],
     }
 }
@@ -1496,6 +1606,29 @@ impl State {
         self.edit_anim.set(true, now);
     }
 
+    /// Re-align the session's scratch with a save an undo/redo just
+    /// swapped in: the staged tag toggles are re-read from the folder
+    /// (the step may have moved or dropped the tagged chips), and a held
+    /// navicust part is let go — it was lifted off the grid by a staged
+    /// edit, and stepping across that edit would otherwise leave the part
+    /// both held and installed. Needs the stepped save, so the host
+    /// calls this after stepping rather than routing it through
+    /// [`Self::apply`].
+    pub fn resync_edit(&mut self, loaded: &OpenSave) {
+        let Some(edit) = self.editing.as_mut() else { return };
+        edit.tags = loaded
+            .save
+            .view_chips()
+            .and_then(|v| {
+                let folder = v.equipped_folder_index();
+                v.tag_chip_indexes(folder)
+            })
+            .flatten()
+            .map(|[a, b]| vec![a, b])
+            .unwrap_or_default();
+        edit.held_part = None;
+    }
+
     /// Drop any in-progress edit without animation bookkeeping
     /// beyond the exit transition — used by hosts that reset the
     /// edit state out-of-band (e.g. the App when the loaded save
@@ -1654,6 +1787,14 @@ impl State {
                 self.library_sort = *s;
                 iced::Task::none()
             }
+            Action::ImportFolderText => iced::clipboard::read().map(Action::FolderTextPasted),
+            Action::PasteBuildCode => iced::clipboard::read().map(Action::BuildCodePasted),
+            Action::DismissFolderImport => {
+                if let Some(e) = self.editing.as_mut() {
+                    e.folder_import = None;
+                }
+                iced::Task::none()
+            }
             // ----- Navicust editor: state-local folds -----
             Action::PickUpPalettePart { id } => {
                 if let Some(e) = self.editing.as_mut() {
@@ -1756,6 +1897,14 @@ impl State {
                 self.auto_battle_data_sort = *s;
                 iced::Task::none()
             }
+            Action::ImportAutoBattleDataText => iced::clipboard::read().map(Action::AutoBattleDataTextPasted),
+            // ----- Pack editor: state-local folds -----
+            Action::PackFilterChanged(s) => {
+                if let Some(e) = self.editing.as_mut() {
+                    e.pack_filter = s.clone();
+                }
+                iced::Task::none()
+            }
             // Toggle the navi picker, which shows over the [tab strip + body]
             // region: it slides up while the tab content drops, and clicking
             // the card again drops it back to the tab underneath — `active_tab`
@@ -1792,6 +1941,9 @@ impl State {
             | Action::RemoveChip { .. }
             | Action::ReorderChips(_)
             | Action::ClearFolder
+            | Action::FolderTextPasted(_)
+            | Action::CopyBuildCode
+            | Action::BuildCodePasted(_)
             | Action::ToggleRegular { .. }
             | Action::ToggleTag { .. }
             | Action::PlaceHeld { .. }
@@ -1805,6 +1957,12 @@ impl State {
             | Action::SetChipUseCount { .. }
             | Action::SetSecondaryChipUseCount { .. }
             | Action::ClearAutoBattleData
+            | Action::AutoBattleDataTextPasted(_)
+            | Action::SetPackCount { .. }
+            | Action::ClearPack
+            | Action::SetBaseMaxHp(_)
+            | Action::Undo
+            | Action::Redo
             | Action::CopyTab(_)
             | Action::CopyTabImage(_)
             | Action::PlayClicked
@@ -1850,6 +2008,33 @@ impl State {
                 crate::copy_feedback::flash(&copy_flash_key(*tab, true));
                 Some(Outcome::CopyImage(img))
             }
+            Action::CopyBuildCode => {
+                let l = loaded?;
+                let code = l.save_editor.build_code(l).encode().ok()?;
+                crate::copy_feedback::flash(BUILD_CODE_FLASH_KEY);
+                Some(Outcome::CopyText(code))
+            }
+            Action::BuildCodePasted(text) => {
+                let l = loaded?;
+                let e = self.editing.as_mut()?;
+                match crate::dataview::build_code::decode_for(text.as_deref().unwrap_or_default(), &l.build_code_game())
+                {
+                    Ok(code) => {
+                        e.build_code_error = None;
+                        // The build replaces the grid and the folder under
+                        // any held part and staged tag toggles.
+                        e.held_part = None;
+                        if let Some(folder) = &code.folder {
+                            e.tags = folder.tags.map(|[a, b]| vec![a, b]).unwrap_or_default();
+                        }
+                        Some(Outcome::Edit(Edit::Batch(l.save_editor.build_code_edits(&code, l))))
+                    }
+                    Err(err) => {
+                        e.build_code_error = Some(err);
+                        None
+                    }
+                }
+            }
             Action::PlayClicked => Some(Outcome::Play),
             Action::TrainingClicked => Some(Outcome::Training),
             // ----- Folder editor -----
@@ -1879,6 +2064,10 @@ impl State {
                 .filter(|loaded| !loaded_save_has_build_issue(loaded))
                 .map(|_| Outcome::Commit),
             Action::CancelEdit => Some(Outcome::Cancel),
+            // Only within a session: outside one there's nothing staged,
+            // and a read-only embed never steps anyone's history.
+            Action::Undo => self.editing.as_ref().map(|_| Outcome::Undo),
+            Action::Redo => self.editing.as_ref().map(|_| Outcome::Redo),
             Action::AddChip { chip_id, code } => {
                 // New chips are inserted at the top, sliding the existing
                 // run down into the first empty slot — so shift the staged
@@ -1941,6 +2130,17 @@ impl State {
                 }
                 Some(Outcome::Edit(Edit::Chips(ChipEdit::ClearFolder)))
             }
+            Action::FolderTextPasted(text) => {
+                let import = crate::model::import::folder_from_text(loaded?, text.as_deref()?)?;
+                let e = self.editing.as_mut()?;
+                // Nothing resolved: report it, but don't wipe the folder.
+                let edit = (!import.chips.is_empty()).then(|| Outcome::Edit(Edit::Chips(import.edit())));
+                if edit.is_some() {
+                    e.tags = import.tags.map(|[a, b]| vec![a, b]).unwrap_or_default();
+                }
+                e.folder_import = Some(import);
+                edit
+            }
             // ----- Slot-targeted deck editor (BCC program deck) -----
             Action::SetDeckChip { slot, chip_id, code } => Some(Outcome::Edit(Edit::Chips(ChipEdit::SetChip {
                 slot: *slot,
@@ -2050,8 +2250,21 @@ impl State {
                 AutoBattleDataEdit::SetSecondaryUseCount { id: *id, count: *count },
             ))),
             Action::ClearAutoBattleData => Some(Outcome::Edit(Edit::AutoBattleData(AutoBattleDataEdit::ClearAll))),
+            Action::AutoBattleDataTextPasted(text) => {
+                let import = crate::model::import::auto_battle_data_from_text(loaded?, text.as_deref()?)?;
+                // Nothing resolved: don't wipe the deck.
+                (!import.is_empty()).then(|| Outcome::Edit(import.edit()))
+            }
+            // ----- Pack editor -----
+            Action::SetPackCount { id, variant, count } => Some(Outcome::Edit(Edit::Chips(ChipEdit::SetPackCount {
+                id: *id,
+                variant: *variant,
+                count: *count,
+            }))),
+            Action::ClearPack => Some(Outcome::Edit(Edit::Chips(ChipEdit::ClearPack))),
             // ----- Navi editor -----
             Action::SetNavi(navi) => Some(Outcome::Edit(Edit::Navi(NaviEdit::SetNavi(*navi)))),
+            Action::SetBaseMaxHp(hp) => Some(Outcome::Edit(Edit::Navi(NaviEdit::SetBaseMaxHp(*hp)))),
             _ => None,
         }
     }
@@ -2071,6 +2284,19 @@ pub fn available_tabs(loaded: &OpenSave, streamer_mode: bool) -> Vec<Tab> {
     tabs
 }
 
+/// Copy-feedback key for the header's Copy build code button.
+const BUILD_CODE_FLASH_KEY: &str = "save-view-copy-build-code";
+
+/// A refused build code, as the line shown beside the edit buttons.
+pub(crate) fn build_code_error_text(lang: &LanguageIdentifier, err: &crate::dataview::build_code::Error) -> String {
+    use crate::dataview::build_code::Error;
+    match err {
+        Error::UnsupportedVersion(_) => t!(lang, "build-code-version"),
+        Error::WrongGame { found, .. } => t!(lang, "build-code-wrong-game", game = found.to_string()),
+        Error::NotACode | Error::Checksum | Error::Malformed | Error::TooLarge => t!(lang, "build-code-invalid"),
+    }
+}
+
 /// Stable copy-feedback key for a tab's copy buttons — shared between
 /// the view (which renders the "Copied!" flash) and the host tabs'
 /// update paths (which fire it once the copy actually lands on the
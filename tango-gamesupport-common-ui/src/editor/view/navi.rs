@@ -4,9 +4,10 @@ use sweeten::widget::{column, row};
 /// The navi card's inner content (no pane wrapper): emblem on the left, the
 /// navi's name stacked over its stats on the right — base max HP and, where the
 /// game exposes them (BN6), the live MegaBuster levels. The navi-less games
-/// (BN1–4) drop the emblem/name and just show the base HP. With `editing_hint`
-/// set, a small pencil sits by the name to signal the card is the change-navi
-/// button.
+/// (BN1–4) drop the emblem/name and just show the HP (and the buster, where
+/// the game reports it). With `editing_hint` set, a small pencil sits by the
+/// name to signal the card is the change-navi button; with `edit_stats` set,
+/// a roster-less navi's base HP becomes a stepper.
 ///
 /// Nothing in here wraps. Squeezed narrow — a dragged-in PvP setup drawer is
 /// the usual way — the card runs off its own edge and its host clips it; a
@@ -16,7 +17,11 @@ fn navi_card_content<M: 'static>(
     lang: &LanguageIdentifier,
     loaded: &OpenSave,
     editing_hint: bool,
-) -> Element<'static, M> {
+    edit_stats: bool,
+) -> Element<'static, M>
+where
+    Action: Into<M>,
+{
     let assets = loaded.assets.as_ref();
     // Every game has a player navi with a base max HP. Games with a link-navi
     // roster (BN5/BN6/EXE4.5) also report which navi is equipped (id + emblem +
@@ -66,15 +71,7 @@ fn navi_card_content<M: 'static>(
             stats = stats.push(super::stat::<M>(t!(lang, "navi-base-hp"), hp.to_string()));
         }
         if let Some(b) = buster {
-            stats = stats.push(
-                row![
-                    super::stat::<M>(t!(lang, "navi-buster-attack"), b.attack.to_string()),
-                    super::stat::<M>(t!(lang, "navi-buster-rapid"), b.speed.to_string()),
-                    super::stat::<M>(t!(lang, "navi-buster-charge"), b.charge.to_string()),
-                ]
-                .spacing(12)
-                .align_y(Alignment::End),
-            );
+            stats = stats.push(buster_stats::<M>(lang, b));
         }
 
         // Emblem on the left, name stacked over its stats on the right. While
@@ -97,15 +94,87 @@ fn navi_card_content<M: 'static>(
         let info = column![name_el, stats].spacing(4);
         row![emblem, info].spacing(10).align_y(Alignment::Center).into()
     } else {
-        // Roster-less games (BN1–4): just the base max HP, inline.
+        // Roster-less games (BN1–4): the max HP inline — a stepper over the
+        // save's own base HP while editing — then the buster, if reported.
         let mut card = row![].spacing(14).align_y(Alignment::Center);
-        if let Some(hp) = base_max_hp {
+        let hp_stepper = navi
+            .as_ref()
+            .filter(|_| edit_stats)
+            .and_then(|nv| Some((nv.base_max_hp(assets)?, assets.base_max_hp_range()?)))
+            .map(|(hp, range)| hp_stepper(lang, hp, range).map(Into::into));
+        if let Some(stepper) = hp_stepper {
+            card = card.push(stepper);
+        } else if let Some(hp) = base_max_hp {
             card = card.push(super::stat::<M>(t!(lang, "navi-base-hp"), hp.to_string()));
         }
+        if let Some(b) = buster {
+            card = card.push(buster_stats::<M>(lang, b));
+        }
         card.into()
     }
 }
 
+/// The MegaBuster levels (attack / rapid / charge) as one tight group.
+fn buster_stats<M: 'static>(
+    lang: &LanguageIdentifier,
+    b: crate::dataview::save::NaviBusterStats,
+) -> Element<'static, M> {
+    row![
+        super::stat::<M>(t!(lang, "navi-buster-attack"), b.attack.to_string()),
+        super::stat::<M>(t!(lang, "navi-buster-rapid"), b.speed.to_string()),
+        super::stat::<M>(t!(lang, "navi-buster-charge"), b.charge.to_string()),
+    ]
+    .spacing(12)
+    .align_y(Alignment::End)
+    .into()
+}
+
+/// The base max HP between −/+ buttons that step it by the ROM's own
+/// increment, each emitting [`Action::SetBaseMaxHp`].
+fn hp_stepper(
+    lang: &LanguageIdentifier,
+    hp: u16,
+    range: crate::dataview::save::NaviStatRange,
+) -> Element<'static, Action> {
+    stat_stepper(
+        t!(lang, "navi-base-hp"),
+        hp,
+        range,
+        [
+            t!(lang, "navi-hp-decrease", step = range.step as i64),
+            t!(lang, "navi-hp-increase", step = range.step as i64),
+        ],
+        Action::SetBaseMaxHp,
+    )
+}
+
+/// One stat between −/+ buttons (`tooltips` in that order) that step it
+/// through `range`, each emitting `on_step` with the new value; a button
+/// goes dead at its end of the range.
+fn stat_stepper(
+    label: String,
+    value: u16,
+    range: crate::dataview::save::NaviStatRange,
+    tooltips: [String; 2],
+    on_step: impl Fn(u16) -> Action,
+) -> Element<'static, Action> {
+    let [decrease, increase] = tooltips;
+    let down = (value > range.min).then(|| on_step(range.clamp(value.saturating_sub(range.step))));
+    let up = (value < range.max).then(|| on_step(range.clamp(value.saturating_add(range.step))));
+    row![
+        text(label)
+            .size(TEXT_CAPTION)
+            .style(muted_text_style)
+            .wrapping(text::Wrapping::None),
+        crate::widgets::icon_button_maybe(lucide_icons::Icon::Minus, decrease, down, style::CONTROL_PADDING),
+        text(value.to_string()).size(TEXT_BODY).wrapping(text::Wrapping::None),
+        crate::widgets::icon_button_maybe(lucide_icons::Icon::Plus, increase, up, style::CONTROL_PADDING),
+    ]
+    .spacing(5)
+    .align_y(Alignment::Center)
+    .into()
+}
+
 /// The persistent strip shown above the tab body on every save and the home
 /// for the save's primary actions: the navi identity card on the left (it
 /// replaces the old standalone Navi tab), the `actions` cluster (Edit / Play,
@@ -128,6 +197,7 @@ pub fn render_navi_strip<'a>(
     lang: &'a LanguageIdentifier,
     loaded: &'a OpenSave,
     edit: Option<Action>,
+    editing: bool,
     actions: Element<'a, Action>,
 ) -> Element<'a, Action> {
     // The pane pads its content off the edges with a uniform `6`; the card
@@ -136,16 +206,18 @@ pub fn render_navi_strip<'a>(
     // hover-highlight area). Both card modes pad identically so toggling edit
     // doesn't nudge it: flat press target in edit mode (with a pencil cue), plain
     // container otherwise.
+    // A roster-less navi with writable stats edits them in place instead.
+    let edit_stats = editing && loaded.editability.navi_stats;
     let card: Element<'a, Action> = if loaded.save.view_navi().is_none() {
         Space::new().into()
     } else {
         match edit {
-            Some(action) => button(navi_card_content::<Action>(lang, loaded, true))
+            Some(action) => button(navi_card_content::<Action>(lang, loaded, true, false))
                 .padding([4.0, 6.0])
                 .style(crate::widgets::flat)
                 .on_press(action)
                 .into(),
-            None => container(navi_card_content::<Action>(lang, loaded, false))
+            None => container(navi_card_content::<Action>(lang, loaded, false, edit_stats))
                 .padding([4.0, 6.0])
                 .into(),
         }
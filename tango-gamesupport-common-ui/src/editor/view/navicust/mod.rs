@@ -543,10 +543,17 @@ fn ncp_color_rank(color: &Option<NavicustPartColor>) -> u8 {
 /// filtered by `filter` (case-insensitive name match) and in `sort`
 /// order. Color/solidity are used for the Color sort but the palette
 /// reads the rest (shape, color) from the baked thumbnails. Ties fall
-/// back to id for a stable order.
+/// back to id for a stable order. A game whose navicust carries a style
+/// (BN3) fixes the color bar by that style, so only parts in its colors
+/// are offered.
 fn sorted_navicust_parts(loaded: &OpenSave, sort: NavicustSort, filter: &str) -> Vec<(usize, String, Option<String>)> {
     let assets = loaded.assets.as_ref();
     let filter = filter.to_lowercase();
+    let style_colors = loaded
+        .save
+        .view_navicust()
+        .filter(|v| v.style().is_some())
+        .map(|v| v.navicust_color_bar().into_iter().flatten().collect::<Vec<_>>());
     struct E {
         id: usize,
         name: String,
@@ -563,6 +570,9 @@ fn sorted_navicust_parts(loaded: &OpenSave, sort: NavicustSort, filter: &str) ->
         // Skip unused/padding slots: a real part has a color and a
         // non-empty shape. Placeholder entries have an all-zero bitmap.
         let Some(color) = info.color() else { continue };
+        if style_colors.as_ref().is_some_and(|colors| !colors.contains(&color)) {
+            continue;
+        }
         if !info.uncompressed_bitmap().iter().any(|&set| set) {
             continue;
         }
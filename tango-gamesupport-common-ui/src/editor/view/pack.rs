@@ -0,0 +1,241 @@
+use super::folder::{chip_icon, chip_row, chip_stat_cells, class_accent, with_chip_tooltip, GroupedChip};
+use super::*;
+use crate::dataview::save::ChipCode;
+use crate::model::MAX_PACK_COUNT;
+use sweeten::widget::{column, row, text_input};
+
+// ---------- Pack ----------
+
+/// One owned chip code: the chip, its code, the code's position in the
+/// ROM's code list (the pack's own index), and how many copies the pack
+/// holds.
+struct Owned {
+    id: usize,
+    code: ChipCode,
+    variant: usize,
+    count: usize,
+}
+
+/// Every chip code the pack holds at least one copy of, in id then code
+/// order. Reads every chip the ROM lists, legal or not: this is what the
+/// save owns, not what the editor offers.
+fn owned(loaded: &OpenSave) -> Vec<Owned> {
+    let Some(view) = loaded.save.view_chips() else {
+        return vec![];
+    };
+    let assets = loaded.assets.as_ref();
+    let mut out = vec![];
+    for id in 0..assets.num_chips() {
+        let Some(info) = assets.chip(id) else { continue };
+        for (variant, ch) in info.codes().into_iter().enumerate() {
+            let Some(code) = ChipCode::from_char(ch) else { continue };
+            let count = view.pack_count(id, variant).unwrap_or(0);
+            if count > 0 {
+                out.push(Owned {
+                    id,
+                    code,
+                    variant,
+                    count,
+                });
+            }
+        }
+    }
+    out
+}
+
+/// The pack's rows as the folder's grouped chip list draws them: one
+/// "N× chip code" row per owned code. Shared by the read-only viewer and
+/// the editor's live preview.
+fn owned_rows<M: 'static>(lang: &LanguageIdentifier, loaded: &OpenSave, owned: &[Owned]) -> Element<'static, M> {
+    let chips_have_mb = loaded.assets.chips_have_mb();
+    let mut col = column![].spacing(1).padding(0);
+    for (idx, o) in owned.iter().enumerate() {
+        let g = GroupedChip {
+            count: o.count,
+            ..GroupedChip::default()
+        };
+        col = col.push(chip_row(
+            lang,
+            loaded,
+            Some(o.id),
+            Some(o.code.to_string()),
+            &g,
+            true,
+            chips_have_mb,
+            idx,
+            vec![],
+        ));
+    }
+    col.into()
+}
+
+pub fn render_pack<M: 'static>(lang: &LanguageIdentifier, loaded: &OpenSave) -> Element<'static, M> {
+    let owned = owned(loaded);
+    if owned.is_empty() {
+        return placeholder(t!(lang, "pack-empty"));
+    }
+    container(owned_rows(lang, loaded, &owned))
+        .width(Fill)
+        .style(crate::widgets::pane)
+        .into()
+}
+
+/// A fixed-width numeric field for a pack count: shows `value` and emits
+/// the [`Action::SetPackCount`] for every edit (digits only, clamped to
+/// [`MAX_PACK_COUNT`]). The save is the source of truth, so no draft
+/// state.
+fn pack_count_input<'a>(id: usize, variant: usize, value: usize) -> Element<'a, Action> {
+    let s = value.to_string();
+    text_input("0", &s)
+        .on_input(move |t| {
+            let digits: String = t.chars().filter(|c| c.is_ascii_digit()).take(3).collect();
+            Action::SetPackCount {
+                id,
+                variant,
+                count: digits.parse::<usize>().unwrap_or(0).min(MAX_PACK_COUNT),
+            }
+        })
+        .width(Length::Fixed(46.0))
+        .padding([4, 8])
+        .size(TEXT_BODY)
+        .style(crate::widgets::chunky_text_input)
+        .into()
+}
+
+/// One chip code in the pack editor's library, laid out like the folder
+/// editor's library row (icon · name · element · code · ATK · MB) with the
+/// editable pack count appended.
+fn library_row<'a>(
+    lang: &'a LanguageIdentifier,
+    loaded: &'a OpenSave,
+    id: usize,
+    name: String,
+    code: ChipCode,
+    variant: usize,
+    count: usize,
+    chips_have_mb: bool,
+    row_idx: usize,
+) -> Element<'a, Action> {
+    let info = loaded.assets.chip(id);
+    let accent = class_accent(
+        info.as_ref().map(|i| i.class()),
+        info.as_ref().map(|i| i.dark()).unwrap_or(false),
+    );
+    let [element, atk, mb] = chip_stat_cells(loaded, id, chips_have_mb);
+    let code_cell = container(text(code.to_string()).size(TEXT_BODY).font(iced::Font::MONOSPACE))
+        .width(Length::Fixed(22.0))
+        .align_x(iced::alignment::Horizontal::Right);
+    let count_cell = row![
+        text(t!(lang, "pack-edit-owned"))
+            .size(TEXT_CAPTION)
+            .style(muted_text_style),
+        pack_count_input(id, variant, count),
+    ]
+    .spacing(4)
+    .align_y(Alignment::Center);
+
+    let inner = row![
+        chip_icon(loaded, Some(id)),
+        text(name).size(TEXT_BODY).width(Fill),
+        element,
+        code_cell,
+        atk,
+        mb,
+        count_cell,
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center)
+    .padding([3, 12]);
+    with_chip_tooltip(
+        loaded,
+        Some(id),
+        accent,
+        edit_row_wrap(inner.into(), accent, row_idx, None),
+    )
+}
+
+/// The pack editor: what the pack holds (left, a live preview in the
+/// folder's grouped shape) beside every offered chip code with its count
+/// (right). The library is the folder editor's — same legal chips, same
+/// sort — so whatever the folder can take, the pack can stock. Edits
+/// stage in the loaded save and are written to disk only on Save.
+pub fn render_pack_edit<'a>(
+    lang: &'a LanguageIdentifier,
+    loaded: &'a OpenSave,
+    state: &'a State,
+) -> Element<'a, Action> {
+    // Only reached while editing, so the EditState is present.
+    let Some(edit) = state.editing.as_ref() else {
+        return placeholder(t!(lang, "save-empty"));
+    };
+    let Some(view) = loaded.save.view_chips() else {
+        return placeholder(t!(lang, "save-empty"));
+    };
+
+    // ----- Left pane: the pack as it stands -----
+    let owned = owned(loaded);
+    let copies: usize = owned.iter().map(|o| o.count).sum();
+    let count = text(t!(lang, "pack-edit-count", count = copies as i64))
+        .size(TEXT_CAPTION)
+        .style(muted_text_style);
+    let pack_header = editor_header(lang, t!(lang, "save-tab-pack"), vec![count.into()], Action::ClearPack);
+    let pack_pane = editor_pane(pack_header, owned_rows::<Action>(lang, loaded, &owned));
+
+    // ----- Right pane: every offered chip code, with its count -----
+    let chips_have_mb = loaded.assets.chips_have_mb();
+    let filter = edit.pack_filter.to_lowercase();
+    let mut lib = column![].spacing(1).padding(0);
+    let mut shown = 0usize;
+    for (id, name, code) in folder::sorted_library_entries(loaded, state.library_sort) {
+        if !filter.is_empty() && !name.to_lowercase().contains(filter.as_str()) {
+            continue;
+        }
+        let Some(variant) = loaded.assets.chip(id).and_then(|info| {
+            info.codes()
+                .into_iter()
+                .position(|ch| ChipCode::from_char(ch) == Some(code))
+        }) else {
+            continue;
+        };
+        let count = view.pack_count(id, variant).unwrap_or(0);
+        lib = lib.push(library_row(
+            lang,
+            loaded,
+            id,
+            name,
+            code,
+            variant,
+            count,
+            chips_have_mb,
+            shown,
+        ));
+        shown += 1;
+    }
+    let lib_header = library_header(
+        lang,
+        t!(lang, "folder-edit-search"),
+        &edit.pack_filter,
+        Action::PackFilterChanged,
+        &LibrarySort::ALL,
+        state.library_sort,
+        folder::library_sort_label,
+        Action::LibrarySortChanged,
+    );
+    editor_panes(pack_pane, editor_pane(lib_header, lib))
+}
+
+/// The pack as TSV text for clipboard "copy as text", in the grouped
+/// folder text's `count\tname\tcode` shape.
+pub fn as_text(loaded: &OpenSave) -> Option<String> {
+    loaded.save.view_chips()?;
+    let assets = loaded.assets.as_ref();
+    let mut out = String::new();
+    for o in owned(loaded) {
+        let name = assets
+            .chip(o.id)
+            .and_then(|info| info.name())
+            .unwrap_or_else(|| "???".to_string());
+        out.push_str(&format!("{}\t{name}\t{}\n", o.count, o.code));
+    }
+    Some(out)
+}
@@ -0,0 +1,161 @@
+//! The edit session's keyboard shortcuts: a transparent wrapper around
+//! the editor that turns Ctrl+Z / Ctrl+Shift+Z / Ctrl+Y (⌘ on macOS)
+//! into [`Action::Undo`] / [`Action::Redo`].
+//!
+//! A widget rather than a subscription because the editor is embedded:
+//! the public `SaveEditor` API hands the app an element and nothing
+//! else, so the shortcuts have to travel inside it. Keys reach it only
+//! after the content had its turn, so a focused widget that claims one
+//! keeps it.
+//!
+//! The wrapper is there whether or not a session is open, so entering
+//! or leaving edit mode doesn't change the root widget and reset the
+//! tree's state (scroll positions, text inputs); only the key handling
+//! is switched.
+
+use super::Action;
+use iced::advanced::layout;
+use iced::advanced::overlay;
+use iced::advanced::renderer;
+use iced::advanced::widget::{Operation, Tree};
+use iced::advanced::{Clipboard, Layout, Shell, Widget};
+use iced::keyboard::{self, key, Key};
+use iced::{mouse, Element, Event, Length, Rectangle, Size, Vector};
+
+/// The undo/redo action a key press maps to, if any.
+pub fn edit_shortcut(event: &keyboard::Event) -> Option<Action> {
+    let keyboard::Event::KeyPressed { key, modifiers, .. } = event else {
+        return None;
+    };
+    if !modifiers.command() {
+        return None;
+    }
+    match key.as_ref() {
+        Key::Character(c) if c.eq_ignore_ascii_case("z") => {
+            Some(if modifiers.shift() { Action::Redo } else { Action::Undo })
+        }
+        Key::Character(c) if c.eq_ignore_ascii_case("y") => Some(Action::Redo),
+        Key::Named(key::Named::Undo) => Some(Action::Undo),
+        Key::Named(key::Named::Redo) => Some(Action::Redo),
+        _ => None,
+    }
+}
+
+/// Wrap `content` so uncaptured key presses matching [`edit_shortcut`]
+/// publish their action while `enabled`, and pass through otherwise.
+pub fn edit_shortcuts<'a>(content: impl Into<Element<'a, Action>>, enabled: bool) -> Element<'a, Action> {
+    Element::new(Shortcuts {
+        content: content.into(),
+        enabled,
+    })
+}
+
+struct Shortcuts<'a> {
+    content: Element<'a, Action>,
+    enabled: bool,
+}
+
+impl Widget<Action, iced::Theme, iced::Renderer> for Shortcuts<'_> {
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(std::slice::from_ref(&self.content));
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn layout(&mut self, tree: &mut Tree, renderer: &iced::Renderer, limits: &layout::Limits) -> layout::Node {
+        self.content
+            .as_widget_mut()
+            .layout(&mut tree.children[0], renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &iced::Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        self.content
+            .as_widget_mut()
+            .operate(&mut tree.children[0], layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &iced::Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Action>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+        if !self.enabled || shell.is_event_captured() {
+            return;
+        }
+        if let Event::Keyboard(kb) = event {
+            if let Some(action) = edit_shortcut(kb) {
+                shell.publish(action);
+                shell.capture_event();
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &iced::Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(&tree.children[0], layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut iced::Renderer,
+        theme: &iced::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .as_widget()
+            .draw(&tree.children[0], renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &iced::Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Action, iced::Theme, iced::Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(&mut tree.children[0], layout, renderer, viewport, translation)
+    }
+}
@@ -18,5 +18,9 @@ pub mod build;
 pub mod editor;
 pub mod i18n;
 pub mod model;
+pub mod patch_diff;
 pub mod style;
 pub mod widgets;
+
+#[cfg(test)]
+mod testing;
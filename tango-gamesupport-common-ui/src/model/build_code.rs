@@ -0,0 +1,48 @@
+//! A decoded [`BuildCode`] as staged edits. The code itself — its text
+//! form, the game tag, reading one off a save — is headless and lives in
+//! [`crate::dataview::build_code`]; this is only the way back in.
+
+use crate::dataview::build_code::BuildCode;
+use crate::model::{ChipEdit, Edit, NaviEdit, NavicustEdit, PatchCard56Edit, SaveModel};
+
+/// The edits that turn `save`'s build into `code`'s, one per section the
+/// code carries, in the order they must land: the navi first, since it
+/// decides whether there is a navicust to write at all. Mod Cards are
+/// BN4's own and left to its editor (see
+/// [`crate::editor::GameSaveEditor::build_code_edits`]).
+///
+/// Callers check the code's game first ([`BuildCode::check_game`]); the
+/// ids mean nothing against another ROM.
+pub fn edits(code: &BuildCode, save: &SaveModel) -> Vec<Edit> {
+    let mut edits = vec![];
+    if let Some(navi) = code.navi {
+        edits.push(Edit::Navi(NaviEdit::SetNavi(navi)));
+    }
+    if let Some(folder) = &code.folder {
+        if folder.chips.iter().all(Option::is_some) {
+            edits.push(Edit::Chips(ChipEdit::SetFolder {
+                chips: folder.chips.iter().flatten().cloned().collect(),
+                regular: folder.regular,
+                tags: folder.tags,
+            }));
+        } else {
+            // A gap is only legal in a slot-addressed deck (BCC's), which
+            // has no REG/TAG: write it slot by slot.
+            let folder_size = save.save.view_chips().map_or(0, |v| v.folder_size());
+            edits.extend((0..folder_size.max(folder.chips.len())).map(|slot| {
+                Edit::Chips(ChipEdit::SetChip {
+                    slot,
+                    chip: folder.chips.get(slot).cloned().flatten(),
+                })
+            }));
+        }
+    }
+    if let Some(parts) = &code.navicust {
+        edits.push(Edit::Navicust(NavicustEdit::ClearAll));
+        edits.extend(parts.iter().cloned().map(|p| Edit::Navicust(NavicustEdit::AddPart(p))));
+    }
+    if let Some(cards) = &code.patch_cards {
+        edits.push(Edit::PatchCard56s(PatchCard56Edit::SetCards(cards.clone())));
+    }
+    edits
+}
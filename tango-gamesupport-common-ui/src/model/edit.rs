@@ -10,6 +10,10 @@
 //! for drawing — it cannot know about, so `apply` reports what it
 //! invalidated and leaves the re-deriving to the caller. See
 //! [`Invalidation`].
+//!
+//! [`History`] wraps [`apply_edit`] for an edit session: it keeps the
+//! bytes each staged edit changed, so the session can step back and
+//! forth through them until Save.
 
 use crate::model::SaveModel;
 
@@ -45,6 +49,23 @@ pub enum ChipEdit {
         slot: usize,
         chip: Option<crate::dataview::save::Chip>,
     },
+    /// Replace the whole folder: `chips` fill it from the top (the rest
+    /// is emptied; any past the folder's size are dropped) and REG/TAG
+    /// are set outright, with no memory guards — the legality report
+    /// flags what doesn't fit. One edit, so a folder imported from text
+    /// (see [`crate::model::import`]) undoes in one step.
+    SetFolder {
+        chips: Vec<crate::dataview::save::Chip>,
+        regular: Option<usize>,
+        tags: Option<[usize; 2]>,
+    },
+    /// Set how many copies of chip `id` in its `variant`-th code (the
+    /// position in the ROM's code list) the pack holds. The pack is the
+    /// owned-chip inventory behind the folder, not a folder slot, so no
+    /// REG/TAG bookkeeping.
+    SetPackCount { id: usize, variant: usize, count: usize },
+    /// Empty the pack: every code of every chip the ROM lists to zero.
+    ClearPack,
 }
 
 /// A single navicust edit staged by the navicust editor. Applied to the
@@ -59,12 +80,15 @@ pub enum NavicustEdit {
     ClearAll,
 }
 
-/// A staged navi-selection edit. Applied to the save save in memory;
-/// not persisted to disk until the user hits Save.
+/// A staged navi edit: the equipped-navi selection, or a stat of the
+/// navi itself. Applied to the save save in memory; not persisted to
+/// disk until the user hits Save.
 #[derive(Debug, Clone)]
 pub enum NaviEdit {
     /// Set the equipped navi to this index.
     SetNavi(usize),
+    /// Set the base max HP, clamped and snapped to the ROM's range.
+    SetBaseMaxHp(u16),
 }
 
 /// A single BN5/BN6 patch-card edit staged by the editor. Applied to the
@@ -81,6 +105,9 @@ pub enum PatchCard56Edit {
     MoveCard { from: usize, to: usize },
     /// Unregister every patch card.
     ClearAll,
+    /// Replace the whole list, enabled flags included (a pasted build
+    /// code). Cards past the game's count and repeats are dropped.
+    SetCards(Vec<crate::dataview::save::PatchCard>),
 }
 
 /// A single auto-battle-data edit staged by the editor. Applied to the
@@ -122,6 +149,10 @@ pub enum Edit {
     /// shared model) — see [`GameEdit`]. `Arc` so `Edit` stays `Clone`.
     Game(std::sync::Arc<dyn GameEdit>),
     AutoBattleData(AutoBattleDataEdit),
+    /// Several edits staged as one, applied in order — so a whole build
+    /// pasted from a code (see [`crate::model::build_code`]) undoes in
+    /// one step.
+    Batch(Vec<Edit>),
 }
 
 /// What an applied edit invalidated in state the *frontend* derived from
@@ -173,9 +204,141 @@ pub fn apply_edit(save: &mut SaveModel, edit: Edit) -> Invalidation {
             apply_auto_battle_data_edit(save, e);
             Invalidation::default()
         }
+        Edit::Batch(edits) => edits.into_iter().fold(Invalidation::default(), |acc, e| {
+            let invalidation = apply_edit(save, e);
+            Invalidation {
+                navicust_render: acc.navicust_render || invalidation.navicust_render,
+            }
+        }),
     }
 }
 
+/// How many staged edits an edit session can step back through. The
+/// oldest step is dropped past this — far more moves than any one
+/// rework of a folder takes.
+pub const MAX_HISTORY: usize = 256;
+
+/// The undo/redo stacks of one edit session. Each step holds the runs
+/// of [`edit_bytes`](crate::dataview::save::Save::edit_bytes) an edit
+/// changed, as they stand on the other side of it, so stepping is a
+/// swap and needs no inverse edit: every edit kind — [`Edit::Game`]
+/// included, whose model this crate never sees — undoes the same way,
+/// derived mirrors and all.
+///
+/// Owned by whoever owns the edit session's save; a committed or
+/// discarded session starts the next one with a [`clear`](Self::clear)ed
+/// history.
+#[derive(Default)]
+pub struct History {
+    undo: std::collections::VecDeque<Step>,
+    redo: std::collections::VecDeque<Step>,
+}
+
+/// One side of a staged edit: the changed runs of the save's edit bytes
+/// to swap in, by offset, and what the edit invalidated — which
+/// stepping across it invalidates again.
+struct Step {
+    runs: Vec<(usize, Vec<u8>)>,
+    invalidation: Invalidation,
+}
+
+impl History {
+    /// [`apply_edit`], remembering the bytes it changed so the edit can
+    /// be undone. An edit that changed nothing (one its guards refused)
+    /// isn't recorded, and a recorded one drops whatever was undone
+    /// before it.
+    #[must_use = "an edit can invalidate frontend-derived art; see Invalidation"]
+    pub fn apply(&mut self, save: &mut SaveModel, edit: Edit) -> Invalidation {
+        let before = save.save.edit_bytes().to_vec();
+        let invalidation = apply_edit(save, edit);
+        let runs = changed_runs(&before, save.save.edit_bytes());
+        if !runs.is_empty() {
+            self.record(Step { runs, invalidation });
+        }
+        invalidation
+    }
+
+    /// Put back the save as it was before the last staged edit. `None`
+    /// when there is nothing to undo; otherwise act on the returned
+    /// [`Invalidation`] as after [`apply_edit`].
+    #[must_use = "an undo can invalidate frontend-derived art; see Invalidation"]
+    pub fn undo(&mut self, save: &mut SaveModel) -> Option<Invalidation> {
+        let invalidation = step(&mut self.undo, &mut self.redo, save.save.edit_bytes_mut())?;
+        // The step may cross a navi swap, which flips what's editable.
+        crate::model::refresh_editability(save);
+        Some(invalidation)
+    }
+
+    /// Re-apply the last undone edit. `None` when there is nothing to
+    /// redo.
+    #[must_use = "a redo can invalidate frontend-derived art; see Invalidation"]
+    pub fn redo(&mut self, save: &mut SaveModel) -> Option<Invalidation> {
+        let invalidation = step(&mut self.redo, &mut self.undo, save.save.edit_bytes_mut())?;
+        crate::model::refresh_editability(save);
+        Some(invalidation)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forget every step — the session's edits were committed or thrown
+    /// away.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    fn record(&mut self, step: Step) {
+        if self.undo.len() >= MAX_HISTORY {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(step);
+        self.redo.clear();
+    }
+}
+
+/// The runs where `after` differs from `before`, each holding `before`'s
+/// bytes. Edits never resize a save's edit bytes, so the two line up.
+fn changed_runs(before: &[u8], after: &[u8]) -> Vec<(usize, Vec<u8>)> {
+    debug_assert_eq!(before.len(), after.len());
+    let mut runs = vec![];
+    let mut i = 0;
+    while i < before.len() {
+        if before[i] == after[i] {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < before.len() && before[i] != after[i] {
+            i += 1;
+        }
+        runs.push((start, before[start..i].to_vec()));
+    }
+    runs
+}
+
+/// Pop a step off `from`, swap its runs into `bytes`, and push it onto
+/// `to` — now holding the bytes it replaced, which is the same step seen
+/// from the other side.
+fn step(
+    from: &mut std::collections::VecDeque<Step>,
+    to: &mut std::collections::VecDeque<Step>,
+    bytes: &mut [u8],
+) -> Option<Invalidation> {
+    let mut step = from.pop_back()?;
+    for (offset, run) in &mut step.runs {
+        bytes[*offset..][..run.len()].swap_with_slice(run);
+    }
+    let invalidation = step.invalidation;
+    to.push_back(step);
+    Some(invalidation)
+}
+
 /// Apply one staged [`ChipEdit`] to a save save's
 /// equipped folder, in memory. Guards that need the ROM assets (folder
 /// limits, chip MB) resolve against `save` first; the edit itself then
@@ -185,28 +348,6 @@ pub fn apply_edit(save: &mut SaveModel, edit: Edit) -> Invalidation {
 pub fn apply_chip_edit(save: &mut SaveModel, edit: ChipEdit) {
     use crate::dataview::save::Chip;
 
-    /// Rewrite the whole folder: every chip slot plus the REG/TAG pointers.
-    fn write_folder(
-        chips: &mut (dyn crate::dataview::save::ChipsViewMut + '_),
-        folder_idx: usize,
-        new_chips: &[Option<Chip>],
-        regular: Option<usize>,
-        tags: Option<[usize; 2]>,
-    ) {
-        for (slot, chip) in new_chips.iter().enumerate() {
-            match chip {
-                Some(chip) => {
-                    chips.set_chip(folder_idx, slot, chip.clone());
-                }
-                None => {
-                    chips.clear_chip(folder_idx, slot);
-                }
-            }
-        }
-        chips.set_regular_chip_index(folder_idx, regular);
-        chips.set_tag_chip_indexes(folder_idx, tags);
-    }
-
     let folder_idx = match save.save.view_chips() {
         Some(v) => v.equipped_folder_index(),
         None => return,
@@ -269,6 +410,15 @@ pub fn apply_chip_edit(save: &mut SaveModel, edit: ChipEdit) {
         _ => {}
     }
 
+    // Every (chip, code) pack slot the ROM lists, for emptying the pack —
+    // read off the assets before the mutable chips view borrows the save.
+    let pack_slots: Vec<(usize, usize)> = match &edit {
+        ChipEdit::ClearPack => (0..save.assets.num_chips())
+            .flat_map(|id| (0..save.assets.chip(id).map_or(0, |c| c.codes().len())).map(move |v| (id, v)))
+            .collect(),
+        _ => vec![],
+    };
+
     let Some(mut chips) = save.save.view_chips_mut() else {
         return;
     };
@@ -352,6 +502,14 @@ pub fn apply_chip_edit(save: &mut SaveModel, edit: ChipEdit) {
         ChipEdit::SetTags(pair) => {
             chips.set_tag_chip_indexes(folder_idx, pair);
         }
+        ChipEdit::SetFolder {
+            chips: new_chips,
+            regular,
+            tags,
+        } => {
+            let new_chips = folder_from_top(new_chips, folder_size);
+            write_folder(&mut *chips, folder_idx, &new_chips, regular, tags);
+        }
         ChipEdit::SetChip { slot, chip } => {
             // Direct slot write — no compaction. Slot validation is the
             // view's own: it keeps any cross-referenced state (BCC's
@@ -367,6 +525,14 @@ pub fn apply_chip_edit(save: &mut SaveModel, edit: ChipEdit) {
                 }
             }
         }
+        ChipEdit::SetPackCount { id, variant, count } => {
+            chips.set_pack_count(id, variant, count.min(MAX_PACK_COUNT));
+        }
+        ChipEdit::ClearPack => {
+            for (id, variant) in pack_slots {
+                chips.set_pack_count(id, variant, 0);
+            }
+        }
     }
 
     // Keep the anti-cheat folder/library mirror in sync with the edit, so
@@ -374,6 +540,43 @@ pub fn apply_chip_edit(save: &mut SaveModel, edit: ChipEdit) {
     chips.rebuild_anticheat();
 }
 
+/// The most copies of one chip code a pack holds: the games count to 99
+/// (and the BN1–BN3 readers take anything higher for padding).
+pub const MAX_PACK_COUNT: usize = 99;
+
+/// Rewrite the whole folder: every chip slot plus the REG/TAG pointers.
+pub(crate) fn write_folder(
+    chips: &mut (dyn crate::dataview::save::ChipsViewMut + '_),
+    folder_idx: usize,
+    new_chips: &[Option<crate::dataview::save::Chip>],
+    regular: Option<usize>,
+    tags: Option<[usize; 2]>,
+) {
+    for (slot, chip) in new_chips.iter().enumerate() {
+        match chip {
+            Some(chip) => {
+                chips.set_chip(folder_idx, slot, chip.clone());
+            }
+            None => {
+                chips.clear_chip(folder_idx, slot);
+            }
+        }
+    }
+    chips.set_regular_chip_index(folder_idx, regular);
+    chips.set_tag_chip_indexes(folder_idx, tags);
+}
+
+/// `chips` packed from the top of a `folder_size` folder, padded with
+/// empty slots or cut to fit.
+pub(crate) fn folder_from_top(
+    chips: Vec<crate::dataview::save::Chip>,
+    folder_size: usize,
+) -> Vec<Option<crate::dataview::save::Chip>> {
+    let mut folder: Vec<_> = chips.into_iter().map(Some).take(folder_size).collect();
+    folder.resize(folder_size, None);
+    folder
+}
+
 /// Apply one staged [`NavicustEdit`] to a save save's
 /// navicust, in memory. Writes the part slots, then rebuilds the
 /// materialized WRAM grid cache so it stays in sync with the edit (and
@@ -429,9 +632,9 @@ pub fn apply_navicust_edit(save: &mut SaveModel, edit: NavicustEdit) {
     nc.rebuild_materialized(assets);
 }
 
-/// Apply a staged [`NaviEdit`] (the equipped-navi selection) to
-/// the save save in memory. No disk I/O — the commit path checksums and
-/// writes. A no-op on saves without a writable navi view.
+/// Apply a staged [`NaviEdit`] to the save save in memory. No disk I/O —
+/// the commit path checksums and writes. A no-op on saves without a
+/// writable navi view.
 #[must_use = "swapping the navi invalidates any drawn navicust grid"]
 pub fn apply_navi_edit(save: &mut SaveModel, edit: NaviEdit) -> Invalidation {
     match edit {
@@ -440,6 +643,14 @@ pub fn apply_navi_edit(save: &mut SaveModel, edit: NaviEdit) -> Invalidation {
                 nv.set_navi(navi);
             }
         }
+        NaviEdit::SetBaseMaxHp(hp) => {
+            let assets = save.assets.as_ref();
+            if let (Some(mut nv), Some(range)) = (save.save.view_navi_mut(), assets.base_max_hp_range()) {
+                nv.set_base_max_hp(range.clamp(hp), assets);
+            }
+            // The navi stays the navi: nothing drawn goes stale.
+            return Invalidation::default();
+        }
     }
     // Switching the equipped navi flips whether an editable navicust and patch
     // card list exist: a link navi has neither, the player's own navi does. The
@@ -500,6 +711,14 @@ pub fn apply_patch_card56_edit(save: &mut SaveModel, edit: PatchCard56Edit) {
             new_cards.insert(to, card);
         }
         PatchCard56Edit::ClearAll => new_cards.clear(),
+        PatchCard56Edit::SetCards(cards) => {
+            new_cards.clear();
+            for card in cards {
+                if new_cards.len() < max && !new_cards.iter().any(|c| c.id == card.id) {
+                    new_cards.push(card);
+                }
+            }
+        }
     }
 
     // `set_patch_card` only writes slots below the current count, so
@@ -552,3 +771,172 @@ pub fn apply_auto_battle_data_edit(save: &mut SaveModel, edit: AutoBattleDataEdi
     // preview (which reads the materialized cache) shows the change live.
     v.rebuild_materialized(assets);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataview::save::{Chip, ChipsView, ChipsViewMut};
+    use crate::testing::{save_model, BytesSave, ChipAssets, Poke};
+    use std::borrow::Cow;
+
+    fn bytes(save: &SaveModel) -> &[u8] {
+        save.save.edit_bytes()
+    }
+
+    /// A save with a pack and an empty folder: copies of chip `id`'s
+    /// `variant`-th code at `id * 2 + variant`.
+    #[derive(Clone)]
+    struct PackSave(Vec<u8>);
+
+    struct Pack<T>(T);
+
+    impl<T: std::ops::Deref<Target = PackSave>> ChipsView for Pack<T> {
+        fn num_folders(&self) -> usize {
+            1
+        }
+
+        fn equipped_folder_index(&self) -> usize {
+            0
+        }
+
+        fn chip(&self, _folder_index: usize, _chip_index: usize) -> Option<Chip> {
+            None
+        }
+
+        fn pack_count(&self, id: usize, variant: usize) -> Option<usize> {
+            self.0 .0.get(id * 2 + variant).map(|&count| count as usize)
+        }
+    }
+
+    impl<T: std::ops::DerefMut<Target = PackSave>> ChipsViewMut for Pack<T> {
+        fn set_chip(&mut self, _folder_index: usize, _chip_index: usize, _chip: Chip) -> bool {
+            false
+        }
+
+        fn set_pack_count(&mut self, id: usize, variant: usize, count: usize) -> bool {
+            self.0 .0[id * 2 + variant] = count as u8;
+            true
+        }
+
+        fn rebuild_anticheat(&mut self) {}
+    }
+
+    impl crate::dataview::save::Save for PackSave {
+        fn to_sram_dump(&self) -> Vec<u8> {
+            self.0.clone()
+        }
+
+        fn as_raw_wram(&self) -> Cow<'_, [u8]> {
+            Cow::Borrowed(&self.0)
+        }
+
+        fn rebuild_checksum(&mut self) {}
+
+        fn edit_bytes(&self) -> &[u8] {
+            &self.0
+        }
+
+        fn edit_bytes_mut(&mut self) -> &mut [u8] {
+            &mut self.0
+        }
+
+        fn view_chips(&self) -> Option<Box<dyn ChipsView + '_>> {
+            Some(Box::new(Pack(self)))
+        }
+
+        fn view_chips_mut(&mut self) -> Option<Box<dyn ChipsViewMut + '_>> {
+            Some(Box::new(Pack(self)))
+        }
+    }
+
+    #[test]
+    fn pack_counts_clamp_and_clearing_empties_every_listed_code() {
+        use crate::dataview::rom::ChipClass::Standard;
+        let mut save = save_model(PackSave(vec![5; 6]));
+        save.assets = Box::new(ChipAssets(vec![("Cannon", "AB", Standard), ("AirShot", "A", Standard)]));
+
+        apply_chip_edit(
+            &mut save,
+            ChipEdit::SetPackCount {
+                id: 0,
+                variant: 1,
+                count: 250,
+            },
+        );
+        assert_eq!(bytes(&save), [5, 99, 5, 5, 5, 5]);
+
+        // AirShot comes in one code and chip 2 isn't in the ROM's table,
+        // so their other slots aren't the pack's to clear.
+        apply_chip_edit(&mut save, ChipEdit::ClearPack);
+        assert_eq!(bytes(&save), [0, 0, 0, 5, 5, 5]);
+    }
+
+    #[test]
+    fn undo_and_redo_step_through_the_edits() {
+        let mut save = save_model(BytesSave(vec![0; 8]));
+        let mut history = History::default();
+
+        let _ = history.apply(&mut save, Poke::edit(1, &[1, 2]));
+        let swap = Edit::Game(std::sync::Arc::new(Poke {
+            offset: 6,
+            bytes: vec![3],
+            invalidation: Invalidation::navicust_render(),
+        }));
+        assert_eq!(history.apply(&mut save, swap), Invalidation::navicust_render());
+        assert_eq!(bytes(&save), [0, 1, 2, 0, 0, 0, 3, 0]);
+
+        // Undoing the navi swap reports it again.
+        assert_eq!(history.undo(&mut save), Some(Invalidation::navicust_render()));
+        assert_eq!(bytes(&save), [0, 1, 2, 0, 0, 0, 0, 0]);
+        assert_eq!(history.undo(&mut save), Some(Invalidation::default()));
+        assert_eq!(bytes(&save), [0; 8]);
+        assert_eq!(history.undo(&mut save), None);
+        assert!(!history.can_undo());
+
+        assert_eq!(history.redo(&mut save), Some(Invalidation::default()));
+        assert_eq!(history.redo(&mut save), Some(Invalidation::navicust_render()));
+        assert_eq!(bytes(&save), [0, 1, 2, 0, 0, 0, 3, 0]);
+        assert_eq!(history.redo(&mut save), None);
+    }
+
+    #[test]
+    fn a_step_keeps_only_the_changed_runs() {
+        let mut save = save_model(BytesSave(vec![0; 4096]));
+        let mut history = History::default();
+
+        // Rewriting a byte with its own value is no change at all.
+        let _ = history.apply(&mut save, Poke::edit(0, &[7, 0, 0, 8]));
+        assert_eq!(history.undo[0].runs, [(0, vec![0]), (3, vec![0])]);
+        let _ = history.apply(&mut save, Poke::edit(0, &[7]));
+        assert_eq!(history.undo.len(), 1);
+    }
+
+    #[test]
+    fn a_new_edit_drops_the_undone_ones() {
+        let mut save = save_model(BytesSave(vec![0; 4]));
+        let mut history = History::default();
+        let _ = history.apply(&mut save, Poke::edit(0, &[1]));
+        let _ = history.apply(&mut save, Poke::edit(1, &[2]));
+        let _ = history.undo(&mut save);
+        assert!(history.can_redo());
+
+        let _ = history.apply(&mut save, Poke::edit(2, &[3]));
+        assert!(!history.can_redo());
+        assert_eq!(history.undo.len(), 2);
+        assert_eq!(bytes(&save), [1, 0, 3, 0]);
+    }
+
+    #[test]
+    fn history_keeps_the_latest_steps() {
+        let mut save = save_model(BytesSave(vec![0; 1]));
+        let mut history = History::default();
+        for i in 1..=MAX_HISTORY + 10 {
+            let _ = history.apply(&mut save, Poke::edit(0, &[i as u8]));
+        }
+        assert_eq!(history.undo.len(), MAX_HISTORY);
+        while history.undo(&mut save).is_some() {}
+        // The oldest ten steps are gone: undo stops at the save as the
+        // tenth edit left it.
+        assert_eq!(bytes(&save), [10]);
+    }
+}
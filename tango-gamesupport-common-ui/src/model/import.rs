@@ -0,0 +1,482 @@
+//! Reading a folder back from text: the Folder tab's "copy as text"
+//! TSV, grouped (`3\tCannon\tA\t[REG]`) or not (`Cannon\tA`), and the
+//! looser lists players paste around (`Cannon A x3`, `3x Cannon A`).
+//!
+//! Names resolve against the loaded ROM's own chip table, so a list
+//! reads in the language that game was released in. What can't be
+//! resolved is reported line by line; what can is checked with the
+//! shared legality rules on a scratch copy of the save, then staged as
+//! one [`ChipEdit::SetFolder`].
+//!
+//! The Auto Battle Data tab's "copy as text" reads back too: its
+//! `[Section]` headers place each listed chip, and the deck's order is
+//! restaged as use counts that rank it the same way.
+
+use crate::dataview::save::{Chip, ChipCode};
+use crate::model::{AutoBattleDataEdit, ChipEdit, Edit, SaveModel};
+
+/// A folder read from text, resolved and checked against one save.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FolderImport {
+    /// The folder, top to bottom, already cut to the folder's size.
+    pub chips: Vec<Chip>,
+    pub regular: Option<usize>,
+    pub tags: Option<[usize; 2]>,
+    /// Lines naming no chip this game has, as `(line number, text)`,
+    /// numbered from 1.
+    pub unknown: Vec<(usize, String)>,
+    /// Chips listed past the end of the folder.
+    pub dropped: usize,
+    /// The shared folder rules' findings on the imported folder —
+    /// illegal chips and codes, class and copy limits, a short folder.
+    pub violations: Vec<crate::dataview::build::BuildViolation>,
+}
+
+impl FolderImport {
+    /// The staged edit that installs this folder.
+    pub fn edit(&self) -> ChipEdit {
+        ChipEdit::SetFolder {
+            chips: self.chips.clone(),
+            regular: self.regular,
+            tags: self.tags,
+        }
+    }
+}
+
+/// One line of a folder list, before any name is resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Line {
+    count: usize,
+    /// `None` for an explicitly empty slot (`---`).
+    name: Option<String>,
+    code: Option<ChipCode>,
+    regular: bool,
+    tag1: bool,
+    tag2: bool,
+}
+
+/// Parse one line of either format. `None` for lines that carry no
+/// slots: blanks, `#` comments and `[Section]` headers.
+fn parse_line(line: &str) -> Option<Line> {
+    let mut rest = line.trim().to_string();
+    if rest.is_empty() || rest.starts_with('#') {
+        return None;
+    }
+    let mut marker = |tag: &str| {
+        let at = rest.to_ascii_uppercase().find(tag)?;
+        rest.replace_range(at..at + tag.len(), "");
+        Some(())
+    };
+    let regular = marker("[REG]").is_some();
+    let tag1 = marker("[TAG1]").is_some();
+    let tag2 = marker("[TAG2]").is_some();
+    let rest = rest.trim();
+    if rest.starts_with('[') && rest.ends_with(']') {
+        return None;
+    }
+
+    let (count, name, code) = if rest.contains('\t') {
+        let mut fields: Vec<&str> = rest.split('\t').map(str::trim).filter(|f| !f.is_empty()).collect();
+        let count = match fields.first().and_then(|f| f.parse::<usize>().ok()) {
+            Some(count) if fields.len() > 1 => {
+                fields.remove(0);
+                count
+            }
+            _ => 1,
+        };
+        let name = (*fields.first()?).to_string();
+        (count, name, fields.get(1).and_then(|c| code_token(c)))
+    } else {
+        let mut words: Vec<&str> = rest.split_whitespace().collect();
+        let mut count = 1;
+        if let Some(n) = words.first().and_then(|w| leading_count(w)).filter(|_| words.len() > 1) {
+            count = n;
+            words.remove(0);
+        }
+        if let Some(n) = words.last().and_then(|w| trailing_count(w)).filter(|_| words.len() > 1) {
+            count = n;
+            words.pop();
+        }
+        let code = words.last().and_then(|w| code_token(w)).filter(|_| words.len() > 1);
+        if code.is_some() {
+            words.pop();
+        }
+        (count, words.join(" "), code)
+    };
+    Some(Line {
+        count,
+        name: (name != "---").then_some(name),
+        code,
+        regular,
+        tag1,
+        tag2,
+    })
+}
+
+/// A lone code letter (`A`–`Z`, `*`), either case.
+fn code_token(token: &str) -> Option<ChipCode> {
+    let mut chars = token.chars();
+    let c = chars.next()?;
+    if chars.next().is_some() {
+        return None;
+    }
+    ChipCode::from_char(c.to_ascii_uppercase())
+}
+
+/// `3`, `3x` or `x3` ahead of a name.
+fn leading_count(token: &str) -> Option<usize> {
+    let token = token.to_ascii_lowercase();
+    let digits = token
+        .strip_suffix('x')
+        .or_else(|| token.strip_prefix('x'))
+        .unwrap_or(&token);
+    digits.parse().ok()
+}
+
+/// `x3` or `×3` after a name. A bare number there is more likely part
+/// of the name than a count.
+fn trailing_count(token: &str) -> Option<usize> {
+    let lower = token.to_ascii_lowercase();
+    lower
+        .strip_prefix('x')
+        .or_else(|| lower.strip_prefix('×'))?
+        .parse()
+        .ok()
+}
+
+/// How names are compared: case, spacing and hyphens don't count, so
+/// "Air Shot", "airshot" and "Air-Shot" are one chip.
+fn name_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// The ROM's chips by [`name_key`]. Several ids can share a name (a
+/// game's unused duplicates); lookups prefer a legal one that has the
+/// asked-for code.
+struct ChipIndex {
+    by_name: std::collections::HashMap<String, Vec<Entry>>,
+}
+
+struct Entry {
+    id: usize,
+    codes: Vec<ChipCode>,
+    legal: bool,
+}
+
+impl ChipIndex {
+    fn new(assets: &(dyn crate::dataview::rom::Assets + Send + Sync)) -> Self {
+        Self::from_entries((0..assets.num_chips()).filter_map(|id| {
+            let chip = assets.chip(id)?;
+            let name = chip.name()?;
+            let codes = chip.codes().into_iter().filter_map(ChipCode::from_char).collect();
+            Some((name, id, codes, assets.chip_is_legal(id)))
+        }))
+    }
+
+    fn from_entries(entries: impl IntoIterator<Item = (String, usize, Vec<ChipCode>, bool)>) -> Self {
+        let mut by_name: std::collections::HashMap<String, Vec<Entry>> = Default::default();
+        for (name, id, codes, legal) in entries {
+            by_name
+                .entry(name_key(&name))
+                .or_default()
+                .push(Entry { id, codes, legal });
+        }
+        Self { by_name }
+    }
+
+    /// The chip `name` names, with `code` — or, given none, the chip's
+    /// first code. A code the chip doesn't come in is kept: that's for
+    /// the legality report to flag, not for the import to quietly fix.
+    fn resolve(&self, name: &str, code: Option<ChipCode>) -> Option<Chip> {
+        let entries = self.by_name.get(&name_key(name))?;
+        let has_code = |e: &&Entry| code.is_none_or(|code| e.codes.contains(&code));
+        let entry = entries
+            .iter()
+            .find(|e| e.legal && has_code(e))
+            .or_else(|| entries.iter().find(|e| e.legal))
+            .or_else(|| entries.first())?;
+        Some(Chip {
+            id: entry.id,
+            code: code.or_else(|| entry.codes.first().copied())?,
+        })
+    }
+
+    /// The chip `name` names, whatever its codes — the auto battle data
+    /// deck lists chips without one.
+    fn resolve_id(&self, name: &str) -> Option<usize> {
+        let entries = self.by_name.get(&name_key(name))?;
+        let entry = entries.iter().find(|e| e.legal).or_else(|| entries.first())?;
+        Some(entry.id)
+    }
+
+    /// Resolve a parsed line's name. A loose line's trailing letter may
+    /// be the end of the name rather than a code ("… X"), so that reading
+    /// is tried when the split one names nothing.
+    fn resolve_line(&self, name: &str, code: Option<ChipCode>) -> Option<Chip> {
+        self.resolve(name, code)
+            .or_else(|| self.resolve(&format!("{name} {}", code?), None))
+    }
+}
+
+/// Read `text` as a folder for `save`'s game: resolve it, check it on a
+/// scratch copy of the save, and report both. `None` when the save has
+/// no folder.
+pub fn folder_from_text(save: &SaveModel, text: &str) -> Option<FolderImport> {
+    let view = save.save.view_chips()?;
+    let folder_size = view.folder_size();
+    let folder_idx = view.equipped_folder_index();
+    let index = ChipIndex::new(save.assets.as_ref());
+
+    let mut import = FolderImport {
+        chips: vec![],
+        regular: None,
+        tags: None,
+        unknown: vec![],
+        dropped: 0,
+        violations: vec![],
+    };
+    let (mut tag1, mut tag2) = (None, None);
+    for (i, raw) in text.lines().enumerate() {
+        let Some(line) = parse_line(raw) else { continue };
+        // An empty slot in the listing is just the folder running short.
+        let Some(name) = line.name else { continue };
+        let Some(chip) = index.resolve_line(&name, line.code) else {
+            import.unknown.push((i + 1, raw.trim().to_string()));
+            continue;
+        };
+        for copy in 0..line.count {
+            let slot = import.chips.len();
+            if slot >= folder_size {
+                import.dropped += line.count - copy;
+                break;
+            }
+            import.chips.push(chip.clone());
+            // Markers on a grouped line belong to its first copies, the
+            // two tags to different copies where there are two.
+            if line.regular && import.regular.is_none() {
+                import.regular = Some(slot);
+            }
+            if line.tag1 && tag1.is_none() {
+                tag1 = Some(slot);
+            } else if line.tag2 && tag2.is_none() {
+                tag2 = Some(slot);
+            }
+        }
+    }
+    import.tags = tag1.zip(tag2).map(|(a, b)| [a, b]);
+
+    // Check the result the way the editor will once it's staged — on a
+    // scratch copy, so nothing is staged yet.
+    let mut scratch = save.save.clone_box();
+    if let Some(mut chips) = scratch.view_chips_mut() {
+        let folder = crate::model::edit::folder_from_top(import.chips.clone(), folder_size);
+        crate::model::edit::write_folder(&mut *chips, folder_idx, &folder, import.regular, import.tags);
+    }
+    import.violations = crate::dataview::build::violations(scratch.as_ref(), save.assets.as_ref())
+        .into_iter()
+        .filter(|v| {
+            matches!(
+                v,
+                crate::dataview::build::BuildViolation::FolderNotFull { .. }
+                    | crate::dataview::build::BuildViolation::Chip { .. }
+            )
+        })
+        .collect();
+    Some(import)
+}
+
+/// An auto battle data deck read back from text, resolved against one
+/// save. Each section's chips are in deck order, a chip listed across
+/// several runs once.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AutoBattleDataImport {
+    pub secondary_standard_chips: Vec<usize>,
+    pub standard_chips: Vec<usize>,
+    pub mega_chips: Vec<usize>,
+    pub giga_chip: Vec<usize>,
+    pub program_advance: Vec<usize>,
+    /// Lines naming no chip this game has, or listed where the deck has
+    /// no chips to rank (before any section, or under the combos), as
+    /// `(line number, text)`, numbered from 1.
+    pub unknown: Vec<(usize, String)>,
+}
+
+impl AutoBattleDataImport {
+    pub fn is_empty(&self) -> bool {
+        [
+            &self.secondary_standard_chips,
+            &self.standard_chips,
+            &self.mega_chips,
+            &self.giga_chip,
+            &self.program_advance,
+        ]
+        .iter()
+        .all(|chips| chips.is_empty())
+    }
+
+    /// The staged edit that installs this deck: every count cleared, then
+    /// each section's chips given counts falling from the top, so the
+    /// game's most-used-first ranking lists them in this order again.
+    /// Standard, Mega, Giga and Program Advance chips share the primary
+    /// count but are ranked apart by class, so each section counts down
+    /// on its own.
+    pub fn edit(&self) -> Edit {
+        let ranked = |chips: &[usize]| {
+            let len = chips.len();
+            chips
+                .iter()
+                .enumerate()
+                .map(move |(rank, &id)| (id, len - rank))
+                .collect::<Vec<_>>()
+        };
+        let mut edits = vec![Edit::AutoBattleData(AutoBattleDataEdit::ClearAll)];
+        edits.extend(
+            ranked(&self.secondary_standard_chips)
+                .into_iter()
+                .map(|(id, count)| Edit::AutoBattleData(AutoBattleDataEdit::SetSecondaryUseCount { id, count })),
+        );
+        for chips in [
+            &self.standard_chips,
+            &self.mega_chips,
+            &self.giga_chip,
+            &self.program_advance,
+        ] {
+            edits.extend(
+                ranked(chips)
+                    .into_iter()
+                    .map(|(id, count)| Edit::AutoBattleData(AutoBattleDataEdit::SetUseCount { id, count })),
+            );
+        }
+        Edit::Batch(edits)
+    }
+}
+
+/// Read `text` as an auto battle data deck for `save`'s game. `None` when
+/// the save has no auto battle data.
+pub fn auto_battle_data_from_text(save: &SaveModel, text: &str) -> Option<AutoBattleDataImport> {
+    save.save.view_auto_battle_data()?;
+    Some(read_auto_battle_data(text, save.assets.as_ref()))
+}
+
+/// The parse behind [`auto_battle_data_from_text`]. Sections go by the
+/// order the tab writes them — secondary standard, standard, mega, giga,
+/// combos, program advance — since their titles are in the UI's language
+/// and the two standard sections hold the same class. A chip the ROM has
+/// no name for is written as `#id`, and reads back the same way.
+pub(crate) fn read_auto_battle_data(
+    text: &str,
+    assets: &(dyn crate::dataview::rom::Assets + Send + Sync),
+) -> AutoBattleDataImport {
+    let index = ChipIndex::new(assets);
+    let mut import = AutoBattleDataImport::default();
+    let mut section = None;
+    for (i, raw) in text.lines().enumerate() {
+        let trimmed = raw.trim();
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            section = Some(section.map_or(0, |s| s + 1));
+            continue;
+        }
+        let Some(line) = parse_line(raw) else { continue };
+        // An unfilled run.
+        let Some(name) = line.name else { continue };
+        let chips = match section {
+            Some(0) => &mut import.secondary_standard_chips,
+            Some(1) => &mut import.standard_chips,
+            Some(2) => &mut import.mega_chips,
+            Some(3) => &mut import.giga_chip,
+            Some(5) => &mut import.program_advance,
+            _ => {
+                import.unknown.push((i + 1, trimmed.to_string()));
+                continue;
+            }
+        };
+        let id = match name.strip_prefix('#') {
+            Some(id) => id.parse().ok(),
+            None => index.resolve_id(&name),
+        };
+        match id {
+            Some(id) if !chips.contains(&id) => chips.push(id),
+            Some(_) => {}
+            None => import.unknown.push((i + 1, trimmed.to_string())),
+        }
+    }
+    import
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(count: usize, name: &str, code: Option<ChipCode>) -> Line {
+        Line {
+            count,
+            name: Some(name.to_string()),
+            code,
+            regular: false,
+            tag1: false,
+            tag2: false,
+        }
+    }
+
+    /// Both shapes "copy as text" writes read back as they were written.
+    #[test]
+    fn copied_tsv_parses_back() {
+        assert_eq!(
+            parse_line("3\tCannon\tA\t[REG][TAG1]"),
+            Some(Line {
+                regular: true,
+                tag1: true,
+                ..line(3, "Cannon", Some(ChipCode::A))
+            })
+        );
+        assert_eq!(
+            parse_line("Air Shot\t*"),
+            Some(line(1, "Air Shot", Some(ChipCode::Star)))
+        );
+        assert_eq!(
+            parse_line("2\t---"),
+            Some(Line {
+                name: None,
+                ..line(2, "", None)
+            })
+        );
+        assert_eq!(parse_line("[Standard]"), None);
+        assert_eq!(parse_line("   "), None);
+    }
+
+    #[test]
+    fn loose_lists_parse() {
+        assert_eq!(parse_line("Cannon A x3"), Some(line(3, "Cannon", Some(ChipCode::A))));
+        assert_eq!(
+            parse_line("4x Mini Bomb b"),
+            Some(line(4, "Mini Bomb", Some(ChipCode::B)))
+        );
+        assert_eq!(parse_line("Area Grab"), Some(line(1, "Area Grab", None)));
+        assert_eq!(
+            parse_line("Recov300 * ×2"),
+            Some(line(2, "Recov300", Some(ChipCode::Star)))
+        );
+    }
+
+    #[test]
+    fn names_resolve_loosely_and_prefer_a_legal_chip_with_the_code() {
+        use ChipCode::*;
+        let index = ChipIndex::from_entries([
+            ("AirShot".to_string(), 1, vec![A, B], true),
+            ("Cannon".to_string(), 2, vec![A], false),
+            ("Cannon".to_string(), 3, vec![A, C], true),
+            ("Bass X".to_string(), 4, vec![X], true),
+        ]);
+        assert_eq!(index.resolve_line("air shot", Some(B)), Some(Chip { id: 1, code: B }));
+        assert_eq!(index.resolve_line("Cannon", Some(C)), Some(Chip { id: 3, code: C }));
+        // A code the chip doesn't have stays, for the legality report.
+        assert_eq!(index.resolve_line("Cannon", Some(Z)), Some(Chip { id: 3, code: Z }));
+        assert_eq!(index.resolve_line("Air-Shot", None), Some(Chip { id: 1, code: A }));
+        // "Bass X" with no code: the letter was the name's.
+        assert_eq!(index.resolve_line("Bass", Some(X)), Some(Chip { id: 4, code: X }));
+        assert_eq!(index.resolve_line("Nonsense", None), None);
+    }
+}
@@ -4,7 +4,9 @@
 //! public API on `tango_gamesupport::model` — re-exported here so this
 //! crate and the app see one namespace; the logic stays private.
 
+pub mod build_code;
 pub mod edit;
+pub mod import;
 pub mod rom_overrides;
 pub mod rules;
 
@@ -19,19 +21,22 @@ pub use tango_gamesupport::AppliedPatch;
 /// pure capability probe — `view_*_mut().is_some()` — which needs `&mut save`,
 /// so it's computed once and cached on the immutable [`SaveModel`] (a frontend's
 /// per-frame render only holds `&SaveModel`, and the read-only `view_*()` probes
-/// answer a different question: BN3 has a viewable-but-not-writable navicust,
-/// BN1–4 a viewable-but-not-writable navi). Swapping the equipped navi flips
-/// some of these (a link navi has no navicust / patch cards), so re-probe via
+/// answer a different question: BN4 and BCC have a viewable-but-not-writable
+/// navi). Swapping the equipped navi flips some of these (a link navi has no
+/// navicust / patch cards), so re-probe via
 /// [`SaveModel::refresh_editability`] after any in-memory edit that can change
 /// capability.
 #[derive(Clone, Copy, Default)]
 pub struct Editability {
     /// `view_chips_mut().is_some()` — drives the Folder tab's Edit button.
     pub folder: bool,
-    /// `view_navicust_mut().is_some()` (BN4/5/6, and not a link navi).
+    /// `view_navicust_mut().is_some()` (BN3/4/5/6, and not a link navi).
     pub navicust: bool,
-    /// `view_navi_mut().is_some()` — the equipped navi (BN5/BN6/BN4.5).
+    /// `view_navi_mut()` with a roster — the equipped navi (BN5/BN6/BN4.5).
     pub navi: bool,
+    /// `view_navi_mut()` with a writable base max HP — the ROM gives its
+    /// range (BN1–3).
+    pub navi_stats: bool,
     /// `view_patch_card56s_mut().is_some()` — the BN5/BN6 list. BN4's
     /// slot-based Mod Cards are that game's own model; its UI crate
     /// answers for their editability itself (`SaveEditor::tab_editable`).
@@ -44,7 +49,7 @@ impl Editability {
     /// Whether *any* section is editable — drives the single save-level Edit
     /// button (once open, the user navigates tabs to edit each section).
     pub fn any(&self) -> bool {
-        self.folder || self.navicust || self.navi || self.patch_cards || self.auto_battle_data
+        self.folder || self.navicust || self.navi || self.navi_stats || self.patch_cards || self.auto_battle_data
     }
 }
 
@@ -67,23 +72,40 @@ impl SaveModel {
     pub fn chip_is_legal(&self, chip_id: usize) -> bool {
         self.assets.chip_is_legal(chip_id)
     }
+
+    /// The ROM this save is open against, patch included, as a build
+    /// code names it.
+    pub fn build_code_game(&self) -> crate::dataview::build_code::GameTag {
+        let patch = self.patch.as_ref().map(|p| crate::dataview::build_code::PatchTag {
+            name: p.name.clone(),
+            version: p.version.to_string(),
+        });
+        crate::dataview::build_code::GameTag::of(self.game, patch)
+    }
 }
 
 /// Probe every section's writable view once. Constructing a mutable view
-/// has no side effects, so this is a pure capability check.
-pub fn probe_editability(save: &mut (dyn crate::dataview::save::Save + Send + Sync)) -> Editability {
+/// has no side effects, so this is a pure capability check. `assets` is
+/// the effective ROM's: it carries the base HP range the navi stats are
+/// edited within.
+pub fn probe_editability(
+    save: &mut (dyn crate::dataview::save::Save + Send + Sync),
+    assets: &dyn crate::dataview::rom::Assets,
+) -> Editability {
     // Each `is_some()` gets its own statement so the borrowed view temporary
     // is dropped before the next probe — a single struct literal would keep
     // every mutable borrow of `save` alive at once.
     let folder = save.view_chips_mut().is_some();
     let navicust = save.view_navicust_mut().is_some();
-    let navi = save.view_navi_mut().is_some();
+    let navi = save.view_navi_mut().is_some_and(|nv| nv.has_roster());
+    let navi_stats = save.view_navi_mut().is_some() && assets.base_max_hp_range().is_some();
     let patch_cards = save.view_patch_card56s_mut().is_some();
     let auto_battle_data = save.view_auto_battle_data_mut().is_some();
     Editability {
         folder,
         navicust,
         navi,
+        navi_stats,
         patch_cards,
         auto_battle_data,
     }
@@ -94,7 +116,7 @@ pub fn probe_editability(save: &mut (dyn crate::dataview::save::Save + Send + Sy
 /// the edit path calls this after a navi change to keep the cached flags
 /// in sync.
 pub fn refresh_editability(save: &mut SaveModel) {
-    save.editability = probe_editability(&mut *save.save);
+    save.editability = probe_editability(&mut *save.save, save.assets.as_ref());
 }
 
 /// Prepare the save/ROM pair before validation and presentation. The ROM is
@@ -136,15 +158,18 @@ pub fn prepare(
 }
 
 /// Convert the concrete prepared envelope into the editor's mutable model.
-pub(crate) fn from_prepared(prepared: tango_gamesupport::PreparedSave) -> SaveModel {
+/// Public for hosts that edit a save without the editor shell around it
+/// (the headless save tool).
+pub fn from_prepared(prepared: tango_gamesupport::PreparedSave) -> SaveModel {
     let mut save = crate::dataview::unwrap_save(prepared.save);
-    let editability = probe_editability(&mut *save);
+    let assets = crate::dataview::unwrap_assets(prepared.assets);
+    let editability = probe_editability(&mut *save, assets.as_ref());
     SaveModel {
         game: prepared.game,
         save_path: prepared.save_path,
         save,
         editability,
         patch: prepared.patch,
-        assets: crate::dataview::unwrap_assets(prepared.assets),
+        assets,
     }
 }
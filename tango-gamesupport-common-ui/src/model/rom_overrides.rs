@@ -220,6 +220,9 @@ impl crate::dataview::rom::Assets for OverridenAssets {
     fn navicust_layout(&self) -> Option<crate::dataview::rom::NavicustLayout> {
         self.assets.navicust_layout()
     }
+    fn base_max_hp_range(&self) -> Option<crate::dataview::save::NaviStatRange> {
+        self.assets.base_max_hp_range()
+    }
     fn chips_have_mb(&self) -> bool {
         self.assets.chips_have_mb()
     }
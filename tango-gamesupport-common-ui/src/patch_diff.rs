@@ -0,0 +1,353 @@
+//! UI adaptation for comparing two versions of a patch: the headless
+//! [`rom_diff`](crate::dataview::rom_diff) of the two patched ROMs'
+//! tables, the save's folder to single out what touches it, and each
+//! side's build warnings to say whether the update breaks (or fixes) it.
+//! The host receives an opaque [`tango_gamesupport::PatchDiff`] and only
+//! asks it for localized rows.
+
+use crate::dataview::diff::Change;
+use crate::dataview::rom_diff::{AssetsDiff, ChipInfo, NavicustPartInfo, PatchCardInfo};
+use crate::i18n::t;
+use unic_langid::LanguageIdentifier;
+
+#[derive(Debug)]
+struct PatchDiff {
+    diff: AssetsDiff,
+    /// The chips in the save's equipped folder.
+    folder: std::collections::BTreeSet<usize>,
+    /// The save's build warnings under each version.
+    warnings: [Vec<tango_gamesupport::OpaqueBuildWarnings>; 2],
+}
+
+/// `save` is the one save both sides were prepared from; `assets` and
+/// `warnings` are the old version's, then the new one's.
+pub fn new(
+    save: &crate::editor::Save,
+    assets: [&crate::editor::Assets; 2],
+    warnings: [Vec<tango_gamesupport::OpaqueBuildWarnings>; 2],
+) -> tango_gamesupport::OpaquePatchDiff {
+    let folder = crate::build::equipped_folder_chips(save).into_iter().collect();
+    std::sync::Arc::new(PatchDiff {
+        diff: crate::dataview::rom_diff::diff(assets[0], assets[1]),
+        folder,
+        warnings,
+    })
+}
+
+fn chip_changes(lang: &LanguageIdentifier, from: &ChipInfo, to: &ChipInfo) -> Vec<String> {
+    let mut out = vec![];
+    if from.name != to.name {
+        out.push(t!(
+            lang,
+            "patch-diff-renamed",
+            name = to.name.clone().unwrap_or_default()
+        ));
+    }
+    if from.attack_power != to.attack_power {
+        out.push(t!(
+            lang,
+            "patch-diff-damage",
+            from = from.attack_power as i64,
+            to = to.attack_power as i64
+        ));
+    }
+    if from.mb != to.mb {
+        out.push(t!(lang, "patch-diff-mb", from = from.mb as i64, to = to.mb as i64));
+    }
+    if from.codes != to.codes {
+        out.push(t!(
+            lang,
+            "patch-diff-codes",
+            from = from.codes.iter().collect::<String>(),
+            to = to.codes.iter().collect::<String>()
+        ));
+    }
+    if from.element != to.element {
+        out.push(t!(lang, "patch-diff-element"));
+    }
+    if from.class != to.class || from.dark != to.dark {
+        out.push(t!(lang, "patch-diff-class"));
+    }
+    if from.legal != to.legal {
+        out.push(if to.legal {
+            t!(lang, "patch-diff-now-legal")
+        } else {
+            t!(lang, "patch-diff-now-illegal")
+        });
+    }
+    if from.description != to.description {
+        out.push(t!(lang, "patch-diff-description"));
+    }
+    out
+}
+
+fn navicust_part_changes(lang: &LanguageIdentifier, from: &NavicustPartInfo, to: &NavicustPartInfo) -> Vec<String> {
+    let mut out = vec![];
+    if from.name != to.name {
+        out.push(t!(
+            lang,
+            "patch-diff-renamed",
+            name = to.name.clone().unwrap_or_default()
+        ));
+    }
+    if from.color != to.color {
+        out.push(t!(lang, "patch-diff-color"));
+    }
+    if from.compressed != to.compressed || from.uncompressed != to.uncompressed || from.is_solid != to.is_solid {
+        out.push(t!(lang, "patch-diff-shape"));
+    }
+    if from.description != to.description {
+        out.push(t!(lang, "patch-diff-description"));
+    }
+    out
+}
+
+fn patch_card_changes(lang: &LanguageIdentifier, from: &PatchCardInfo, to: &PatchCardInfo) -> Vec<String> {
+    let mut out = vec![];
+    if from.name != to.name {
+        out.push(t!(
+            lang,
+            "patch-diff-renamed",
+            name = to.name.clone().unwrap_or_default()
+        ));
+    }
+    if from.mb != to.mb {
+        out.push(t!(lang, "patch-diff-mb", from = from.mb as i64, to = to.mb as i64));
+    }
+    if from.effects != to.effects {
+        out.push(t!(lang, "patch-diff-effects"));
+    }
+    out
+}
+
+/// One changed row as a line, named as the old version named it (the
+/// new one for a row only it has).
+fn row<T>(
+    lang: &LanguageIdentifier,
+    change: &Change<Option<T>>,
+    name: impl Fn(&T) -> Option<String>,
+    unknown: impl Fn() -> String,
+    changes: impl Fn(&LanguageIdentifier, &T, &T) -> Vec<String>,
+) -> String {
+    let name_of = |info: &T| name(info).unwrap_or_else(&unknown);
+    match (&change.from, &change.to) {
+        (Some(from), Some(to)) => t!(
+            lang,
+            "patch-diff-row",
+            name = name_of(from),
+            changes = changes(lang, from, to).join(", ")
+        ),
+        (None, Some(to)) => t!(lang, "patch-diff-added", name = name_of(to)),
+        (Some(from), None) => t!(lang, "patch-diff-removed", name = name_of(from)),
+        (None, None) => unreachable!("an unchanged row isn't in a diff"),
+    }
+}
+
+fn chip_row(lang: &LanguageIdentifier, id: usize, change: &Change<Option<ChipInfo>>) -> String {
+    row(
+        lang,
+        change,
+        |c| c.name.clone(),
+        || t!(lang, "build-chip-unknown", id = id as i64),
+        chip_changes,
+    )
+}
+
+/// Each side's warnings, as the lines they format to.
+fn warning_lines(lang: &LanguageIdentifier, warnings: &[tango_gamesupport::OpaqueBuildWarnings]) -> Vec<String> {
+    warnings.iter().flat_map(|w| w.format(lang)).collect()
+}
+
+impl tango_gamesupport::PatchDiff for PatchDiff {
+    fn is_empty(&self) -> bool {
+        self.diff.is_empty()
+    }
+
+    fn folder(&self, lang: &LanguageIdentifier) -> Vec<String> {
+        let mut out: Vec<String> = self
+            .diff
+            .chips
+            .iter()
+            .filter(|(id, _)| self.folder.contains(id))
+            .map(|(id, change)| chip_row(lang, *id, change))
+            .collect();
+        let [before, after] = self.warnings.each_ref().map(|w| warning_lines(lang, w));
+        for warning in after.iter().filter(|w| !before.contains(w)) {
+            out.push(t!(lang, "patch-diff-breaks", warning = warning.clone()));
+        }
+        for warning in before.iter().filter(|w| !after.contains(w)) {
+            out.push(t!(lang, "patch-diff-fixes", warning = warning.clone()));
+        }
+        out
+    }
+
+    fn sections(&self, lang: &LanguageIdentifier) -> Vec<(String, Vec<String>)> {
+        let chips = self
+            .diff
+            .chips
+            .iter()
+            .map(|(id, change)| chip_row(lang, *id, change))
+            .collect::<Vec<_>>();
+        let parts = self
+            .diff
+            .navicust_parts
+            .iter()
+            .map(|(id, change)| {
+                row(
+                    lang,
+                    change,
+                    |p| p.name.clone(),
+                    || t!(lang, "build-navicust-part-unknown", id = *id as i64),
+                    navicust_part_changes,
+                )
+            })
+            .collect::<Vec<_>>();
+        let cards = self
+            .diff
+            .patch_cards
+            .iter()
+            .map(|(id, change)| {
+                row(
+                    lang,
+                    change,
+                    |c| c.name.clone(),
+                    || t!(lang, "build-patch-card-unknown", id = *id as i64),
+                    patch_card_changes,
+                )
+            })
+            .collect::<Vec<_>>();
+        [
+            (t!(lang, "patch-diff-chips"), chips),
+            (t!(lang, "save-tab-navicust"), parts),
+            (t!(lang, "save-tab-patch-cards"), cards),
+        ]
+        .into_iter()
+        .filter(|(_, lines)| !lines.is_empty())
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dataview::rom::ChipClass::{Mega, Standard};
+    use crate::testing::ChipAssets;
+    use tango_gamesupport::PatchDiff as _;
+
+    /// Build warnings that are nothing but their lines.
+    #[derive(Debug)]
+    struct Lines(&'static [&'static str]);
+
+    impl tango_gamesupport::BuildWarnings for Lines {
+        fn format(&self, _lang: &LanguageIdentifier) -> Vec<String> {
+            self.0.iter().map(|line| line.to_string()).collect()
+        }
+    }
+
+    fn warnings(lines: &'static [&'static str]) -> Vec<tango_gamesupport::OpaqueBuildWarnings> {
+        vec![std::sync::Arc::new(Lines(lines))]
+    }
+
+    fn old() -> ChipAssets {
+        ChipAssets(vec![
+            ("Cannon", "ABC", Standard),
+            ("Sword", "LMN", Standard),
+            ("Recov10", "*", Standard),
+            ("AreaGrab", "*", Standard),
+        ])
+    }
+
+    fn new() -> ChipAssets {
+        ChipAssets(vec![
+            ("Cannon", "AB", Standard),
+            ("LongSwrd", "LMN", Standard),
+            ("Recov10", "*", Mega),
+        ])
+    }
+
+    fn patch_diff(
+        old: ChipAssets,
+        new: ChipAssets,
+        folder: &[usize],
+        warnings: [Vec<tango_gamesupport::OpaqueBuildWarnings>; 2],
+    ) -> PatchDiff {
+        PatchDiff {
+            diff: crate::dataview::rom_diff::diff(&old, &new),
+            folder: folder.iter().copied().collect(),
+            warnings,
+        }
+    }
+
+    fn strings(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn every_changed_row_is_listed_under_its_table() {
+        let english = "en-US".parse().unwrap();
+        let diff = patch_diff(old(), new(), &[], [vec![], vec![]]);
+        assert!(!diff.is_empty());
+        assert_eq!(
+            diff.sections(&english),
+            vec![(
+                "Chips".to_string(),
+                strings(&[
+                    "Cannon: codes ABC → AB",
+                    "Sword: renamed to LongSwrd",
+                    "Recov10: class changed",
+                    "AreaGrab (removed)",
+                ])
+            )]
+        );
+        assert_eq!(diff.folder(&english), Vec::<String>::new());
+
+        // A row the ROM names nothing goes by its id.
+        let diff = patch_diff(
+            ChipAssets(vec![]),
+            ChipAssets(vec![("", "A", Standard)]),
+            &[],
+            [vec![], vec![]],
+        );
+        assert_eq!(
+            diff.sections(&english),
+            vec![("Chips".to_string(), strings(&["Chip #0 (new)"]))]
+        );
+    }
+
+    #[test]
+    fn the_folder_gets_its_own_chips_and_the_problems_the_update_adds_or_clears() {
+        let english = "en-US".parse().unwrap();
+        let diff = patch_diff(
+            old(),
+            new(),
+            &[0, 3],
+            [
+                warnings(&["Folder not full", "Too many Cannons"]),
+                warnings(&["Too many Cannons", "Cannon C: code not in this version"]),
+            ],
+        );
+        assert_eq!(
+            diff.folder(&english),
+            strings(&[
+                "Cannon: codes ABC → AB",
+                "AreaGrab (removed)",
+                "New problem: Cannon C: code not in this version",
+                "Fixed: Folder not full",
+            ])
+        );
+    }
+
+    #[test]
+    fn identical_tables_diff_to_nothing() {
+        let english = "en-US".parse().unwrap();
+        let diff = patch_diff(
+            old(),
+            old(),
+            &[0, 1, 2],
+            [warnings(&["Folder not full"]), warnings(&["Folder not full"])],
+        );
+        assert!(diff.is_empty());
+        assert_eq!(diff.sections(&english), vec![]);
+        assert_eq!(diff.folder(&english), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,199 @@
+//! A stand-in game registration, save and chip table for this crate's
+//! tests: enough of a [`SaveModel`] to stage edits against, with no ROM
+//! or real save behind it.
+
+use crate::model::SaveModel;
+use std::borrow::Cow;
+
+/// A save that is nothing but its bytes. It has no views, so the shared
+/// appliers leave it alone; [`Poke`] is the edit that reaches it.
+#[derive(Clone)]
+pub struct BytesSave(pub Vec<u8>);
+
+impl crate::dataview::save::Save for BytesSave {
+    fn to_sram_dump(&self) -> Vec<u8> {
+        self.0.clone()
+    }
+
+    fn as_raw_wram(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(&self.0)
+    }
+
+    fn rebuild_checksum(&mut self) {}
+
+    fn edit_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    fn edit_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Write `bytes` at `offset` of a [`BytesSave`] — a game's own edit, the
+/// way BN4's Mod Cards reach their concrete save.
+#[derive(Debug)]
+pub struct Poke {
+    pub offset: usize,
+    pub bytes: Vec<u8>,
+    pub invalidation: crate::model::Invalidation,
+}
+
+impl crate::model::GameEdit for Poke {
+    fn apply(&self, save: &mut SaveModel) -> crate::model::Invalidation {
+        let save = save.save.as_mut().as_any_mut().downcast_mut::<BytesSave>().unwrap();
+        save.0[self.offset..][..self.bytes.len()].copy_from_slice(&self.bytes);
+        self.invalidation
+    }
+}
+
+impl Poke {
+    pub fn edit(offset: usize, bytes: &[u8]) -> crate::model::Edit {
+        crate::model::Edit::Game(std::sync::Arc::new(Poke {
+            offset,
+            bytes: bytes.to_vec(),
+            invalidation: Default::default(),
+        }))
+    }
+}
+
+/// A chip table and nothing else: `(name, codes, class)` by id, every
+/// chip legal. An empty name is a chip the ROM names nothing.
+pub struct ChipAssets(pub Vec<(&'static str, &'static str, crate::dataview::rom::ChipClass)>);
+
+struct TestChip {
+    name: &'static str,
+    codes: &'static str,
+    class: crate::dataview::rom::ChipClass,
+}
+
+impl crate::dataview::rom::Chip for TestChip {
+    fn name(&self) -> Option<String> {
+        (!self.name.is_empty()).then(|| self.name.to_string())
+    }
+
+    fn description(&self) -> Option<String> {
+        None
+    }
+
+    fn icon(&self) -> image::RgbaImage {
+        image::RgbaImage::new(0, 0)
+    }
+
+    fn image(&self) -> image::RgbaImage {
+        image::RgbaImage::new(0, 0)
+    }
+
+    fn codes(&self) -> Vec<char> {
+        self.codes.chars().collect()
+    }
+
+    fn element(&self) -> usize {
+        0
+    }
+
+    fn class(&self) -> crate::dataview::rom::ChipClass {
+        self.class
+    }
+
+    fn dark(&self) -> bool {
+        false
+    }
+
+    fn mb(&self) -> u8 {
+        0
+    }
+
+    fn attack_power(&self) -> u32 {
+        0
+    }
+
+    fn library_sort_order(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl crate::dataview::rom::Assets for ChipAssets {
+    fn chip_is_legal(&self, id: usize) -> bool {
+        id < self.0.len()
+    }
+
+    fn chip(&self, id: usize) -> Option<Box<dyn crate::dataview::rom::Chip + '_>> {
+        let &(name, codes, class) = self.0.get(id)?;
+        Some(Box::new(TestChip { name, codes, class }))
+    }
+
+    fn num_chips(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// `save` opened as the stand-in game's, with empty assets.
+pub fn save_model(save: impl crate::dataview::save::Save + Send + Sync + 'static) -> SaveModel {
+    let mut model = SaveModel {
+        game: &GAME,
+        save_path: Default::default(),
+        save: Box::new(save),
+        editability: Default::default(),
+        patch: None,
+        assets: Box::new(crate::dataview::rom::EmptyAssets),
+    };
+    crate::model::refresh_editability(&mut model);
+    model
+}
+
+struct NoEngine;
+
+impl tango_match::Backend for NoEngine {
+    fn sim_version(&self) -> u32 {
+        0
+    }
+
+    fn screen_layout(&self, _mode: tango_match::SessionMode) -> tango_match::ScreenLayout {
+        unreachable!("the test game never runs")
+    }
+
+    fn keys_mask(&self) -> u32 {
+        0
+    }
+
+    fn frame_timing(&self) -> tango_match::FrameTiming {
+        unreachable!("the test game never runs")
+    }
+
+    fn start(&self, _config: tango_match::StartConfig) -> Result<tango_match::Match, tango_match::Error> {
+        unreachable!("the test game never runs")
+    }
+}
+
+fn parse_save(sram: &[u8]) -> Result<tango_gamesupport::BoxedSave, tango_gamesupport::Error> {
+    Err(tango_gamesupport::Error::Save(
+        format!("the test game parses no saves ({} bytes given)", sram.len()).into(),
+    ))
+}
+
+pub static FAMILY: tango_gamesupport::Family = tango_gamesupport::Family {
+    id: "test",
+    games: &[&GAME],
+    match_types: &[1],
+    players_colored_by_seat: false,
+    save_editor: &crate::editor::EMPTY_SAVE_EDITOR,
+    translations: &[],
+};
+
+pub static GAME: tango_gamesupport::Game = tango_gamesupport::Game {
+    family: &FAMILY,
+    variant: 0,
+    rom_code: b"TEST",
+    revision: 0,
+    crc32: 0,
+    rom_size: 0,
+    region: tango_gamesupport::Region::US,
+    parse_save_fn: parse_save,
+    save_parser_version: 0,
+    load_rom_assets_fn: None,
+    pvp: &NoEngine,
+    save_templates: None,
+    logo_image: None,
+    background: None,
+};
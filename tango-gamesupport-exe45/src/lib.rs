@@ -46,6 +46,7 @@ pub static EXE45: Game = Game {
             dataview::save::Save::new(data)?,
         )))
     },
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::BR4J_00,
@@ -258,6 +258,14 @@ impl save::Save for Save {
         Some(Box::new(NaviView { save: self }))
     }
 
+    fn edit_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    fn edit_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.buf
+    }
+
     fn to_sram_dump(&self) -> Vec<u8> {
         self.buf.clone()
     }
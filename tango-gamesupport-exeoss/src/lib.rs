@@ -86,6 +86,7 @@ pub static EXEOSS: Game = Game {
     // The DS cart has no wram-derived assets — everything comes off the
     // cart image itself, and there is one charset, the cart being
     // Japan-only.
+    save_parser_version: 0,
     load_rom_assets_fn: Some(|rom, _wram, charset| {
         tango_gamesupport_common_dataview::wrap_assets(Box::new(dataview::rom::Assets::new(
             &dataview::rom::B6XJ_00,
@@ -114,6 +114,9 @@ pub type BoxedSave = Box<dyn SaveData>;
 /// Boxed opaque ROM assets the parsers hand back.
 pub type BoxedAssets = Box<dyn AssetsData>;
 
+pub mod rules;
+pub use rules::{BuildRules, RuleBreach};
+
 // The save-editor embedding API, feature-gated so the base crate stays
 // a pure detection/registry surface. Deliberately shape-oblivious: the
 // trait speaks opaque envelopes; the private gamesupport UI layer
@@ -122,8 +125,8 @@ pub type BoxedAssets = Box<dyn AssetsData>;
 pub mod save_editor;
 #[cfg(feature = "ui")]
 pub use save_editor::{
-    AppliedPatch, BuildWarnings, ChipDisplay, LoadedSave, LoadedSavePayload, OpaqueBuildWarnings,
-    PreparedSave, SaveEditor, SaveEditorEvent, SaveEditorMessage, SaveEditorState,
+    AppliedPatch, BuildWarnings, ChipDisplay, LoadedSave, LoadedSavePayload, OpaqueBuildWarnings, OpaquePatchDiff,
+    PatchDiff, PreparedSave, SaveEditor, SaveEditorEvent, SaveEditorMessage, SaveEditorState, SaveSummary,
 };
 
 /// One ROM revision Tango supports, with all of its per-game info.
@@ -160,6 +163,11 @@ pub struct Game {
     /// save *model* still validates the dump and hands back an opaque
     /// save that only round-trips its bytes.
     pub parse_save_fn: fn(&[u8]) -> Result<BoxedSave, Error>,
+    /// Bumped whenever `parse_save_fn` changes which dumps it accepts.
+    /// The library caches each save file's verdict across runs, keyed on
+    /// this, so a parser that starts (or stops) recognizing a dump gets
+    /// it re-checked instead of trusting what the old one said.
+    pub save_parser_version: u32,
     /// Build the ROM Assets for this game. `charset` overrides the
     /// per-game default character set; pass `None` for the default.
     /// `None` when this game has no save/ROM model.
@@ -0,0 +1,43 @@
+//! Tournament build rules, as a save is checked against them: the
+//! game-agnostic half the lobby (which only ever holds save bytes) and
+//! the private gamesupport layer (which can read a save) both speak.
+//! Ids are the game's own chip and NaviCust part ids.
+
+/// What a save's build must keep within, beyond the game's own rules.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BuildRules {
+    pub banned_chips: Vec<usize>,
+    pub banned_navicust_parts: Vec<usize>,
+    /// Most copies of any one chip the equipped folder may hold.
+    pub max_copies: Option<usize>,
+    /// Highest max HP the equipped navi may have.
+    pub max_hp: Option<u16>,
+}
+
+/// One way a save breaks its [`BuildRules`]. Names are the ROM's, for
+/// display; `None` where the ROM has none for the id.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RuleBreach {
+    BannedChip {
+        chip: usize,
+        name: Option<String>,
+    },
+    BannedNavicustPart {
+        part: usize,
+        name: Option<String>,
+    },
+    TooManyCopies {
+        chip: usize,
+        name: Option<String>,
+        count: usize,
+        limit: usize,
+    },
+    MaxHpOverCap {
+        hp: u16,
+        cap: u16,
+    },
+    /// The game has no save model to read a build out of, so rules that
+    /// constrain one can't be checked — and are refused rather than
+    /// passed unread.
+    Unreadable,
+}
@@ -22,6 +22,25 @@ pub trait BuildWarnings: std::fmt::Debug + Send + Sync {
 /// Type-erased warning report retained by the game-agnostic host.
 pub type OpaqueBuildWarnings = std::sync::Arc<dyn BuildWarnings>;
 
+/// What changed in a game's tables between two versions of a patch, as
+/// one save sees it. Computed once by [`SaveEditor::patch_diff`] and
+/// kept by the host, which asks for the localized rows when it renders.
+pub trait PatchDiff: std::fmt::Debug + Send + Sync {
+    /// Nothing in the tables changed.
+    fn is_empty(&self) -> bool;
+
+    /// What the change means for the save's own build: changed chips in
+    /// its folder, and build problems the new version introduces or
+    /// clears. Empty when the build is untouched.
+    fn folder(&self, lang: &LanguageIdentifier) -> Vec<String>;
+
+    /// Every changed row, under a heading per table.
+    fn sections(&self, lang: &LanguageIdentifier) -> Vec<(String, Vec<String>)>;
+}
+
+/// Type-erased patch diff retained by the game-agnostic host.
+pub type OpaquePatchDiff = std::sync::Arc<dyn PatchDiff>;
+
 /// A patch applied on top of a ROM, as the save UI needs to know it:
 /// its identity plus the exact ROM's object from `[rom_overrides]`
 /// (charset, display-name, and chip-legality overrides).
@@ -110,6 +129,18 @@ impl PreparedSave {
     }
 }
 
+/// The gist of a save's build, for telling several saves of one game
+/// apart at a glance (the save history lists one per version). Names are
+/// the ROM's; `None` where it has none for the id.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SaveSummary {
+    /// The equipped navi, for games that have a choice of one.
+    pub navi: Option<Option<String>>,
+    /// The equipped folder's chips with how many copies of each, in the
+    /// order they first appear.
+    pub folder: Vec<(Option<String>, usize)>,
+}
+
 /// What the app must act on after an [`SaveEditor::update`] — deliberately
 /// app-semantic only (clipboard, launches, disk writes); staged edits
 /// are applied to the data internally and never surface.
@@ -138,6 +169,19 @@ pub trait SaveEditor: Send + Sync {
     /// presentation assets.
     fn validate_save(&self, prepared: &PreparedSave) -> Option<crate::OpaqueBuildWarnings>;
 
+    /// Every way the prepared save breaks a tournament's `rules`. Empty
+    /// when it keeps them. A game with no save model to check gets a
+    /// lone [`crate::RuleBreach::Unreadable`] unless `rules` constrain
+    /// nothing.
+    fn check_rules(&self, prepared: &PreparedSave, rules: &crate::BuildRules) -> Vec<crate::RuleBreach>;
+
+    /// The prepared save's navi and folder, summarized.
+    fn summary(&self, prepared: &PreparedSave) -> SaveSummary;
+
+    /// What changed going from `left` to `right`: one save prepared
+    /// against a ROM patched with two versions of the same patch.
+    fn patch_diff(&self, left: &PreparedSave, right: &PreparedSave) -> OpaquePatchDiff;
+
     /// Decorate an already-prepared save model with renderable state and art.
     fn load(&'static self, prepared: PreparedSave) -> LoadedSave;
 
@@ -154,6 +198,22 @@ pub trait SaveEditor: Send + Sync {
         editable: bool,
     ) -> iced::Element<'a, std::sync::Arc<dyn SaveEditorMessage>>;
 
+    /// Render what changed from `left` to `right`, two saves of this
+    /// editor's game, side by side under `labels` (the embedder's names
+    /// for them). Read-only: nothing in it sends a message.
+    ///
+    /// Editors with nothing to compare need not implement it; the
+    /// default draws nothing.
+    fn diff_view<'a>(
+        &self,
+        _lang: &'a LanguageIdentifier,
+        _left: &'a LoadedSave,
+        _right: &'a LoadedSave,
+        _labels: [String; 2],
+    ) -> iced::Element<'a, std::sync::Arc<dyn SaveEditorMessage>> {
+        iced::widget::Space::new().into()
+    }
+
     /// Fold a message into the data: its view state always, and the
     /// save itself when the message is a staged edit (applied in place,
     /// including derived art — an `editable: false` embed can't mint
@@ -170,6 +230,19 @@ pub trait SaveEditor: Send + Sync {
         Option<SaveEditorEvent>,
     );
 
+    /// Write a pasted build code over the loaded save in place, the way
+    /// the editor's paste stages it, outside any edit session — for a
+    /// save the app is about to create rather than one on disk. `Ok`
+    /// carries a line per folder chip the save's pack (before the build
+    /// landed) holds too few copies of, empty when it holds them all;
+    /// `Err` is why the code was refused. Both are localized.
+    fn stage_build_code(
+        &self,
+        lang: &LanguageIdentifier,
+        data: &mut LoadedSave,
+        code: &str,
+    ) -> Result<Vec<String>, String>;
+
     /// Serialize the current in-memory save (staged edits included) —
     /// what a netplay commitment or session launch runs on.
     fn sram(&self, data: &LoadedSave) -> Vec<u8>;
@@ -0,0 +1,244 @@
+//! Reading ROMs out of the archives people keep their dumps in: `.zip`,
+//! `.7z` and `.gz`.
+//!
+//! Everything reads through a [`ReadSeek`](crate::storage::ReadSeek) from
+//! [`Storage::open`](crate::storage::Storage::open), so an archive in OPFS
+//! unpacks the same way one on disk does. Members are handed over one at
+//! a time and never all at once: a whole-set archive can hold hundreds of
+//! dumps, and only the few that turn out to be supported games are kept.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Members larger than this are skipped without being read. Comfortably
+/// above the largest supported ROM (a 512 Mbit DS cartridge), and low
+/// enough that a hostile size field can't have us allocate gigabytes.
+const MAX_MEMBER_LEN: u64 = 128 * 1024 * 1024;
+
+/// An archive format we can look inside.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Kind {
+    Zip,
+    SevenZ,
+    Gzip,
+}
+
+impl Kind {
+    /// The format `path`'s extension names, if it's one we read.
+    pub(crate) fn of(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "zip" => Some(Kind::Zip),
+            "7z" => Some(Kind::SevenZ),
+            "gz" => Some(Kind::Gzip),
+            _ => None,
+        }
+    }
+}
+
+/// Call `f` with each file member of the archive at `path` that `want`
+/// accepts — its path within the archive, and its contents. Directories
+/// and oversized members are skipped, and where the format allows it a
+/// member `want` refuses isn't decompressed. Errors are the archive's as
+/// a whole; a member that fails to read is logged and skipped.
+pub(crate) fn for_each_member(
+    kind: Kind,
+    path: &Path,
+    reader: Box<dyn crate::storage::ReadSeek>,
+    len: u64,
+    want: impl Fn(&Path) -> bool,
+    mut f: impl FnMut(&Path, Vec<u8>),
+) -> std::io::Result<()> {
+    match kind {
+        Kind::Zip => {
+            let mut za = zip::ZipArchive::new(reader).map_err(std::io::Error::other)?;
+            for i in 0..za.len() {
+                let entry = match za.by_index(i) {
+                    Ok(e) => e,
+                    Err(e) => {
+                        log::warn!("{}({i}): {e}", path.display());
+                        continue;
+                    }
+                };
+                if entry.is_dir() || entry.size() > MAX_MEMBER_LEN {
+                    continue;
+                }
+                let Some(name) = entry.enclosed_name().map(|p| p.to_owned()) else {
+                    continue;
+                };
+                if !want(&name) {
+                    continue;
+                }
+                match read_capped(entry) {
+                    Ok(data) => f(&name, data),
+                    Err(e) => log::warn!("{}/{}: {e}", path.display(), name.display()),
+                }
+            }
+            Ok(())
+        }
+        Kind::SevenZ => {
+            let mut sz = sevenz_rust::SevenZReader::new(reader, len, sevenz_rust::Password::empty())
+                .map_err(std::io::Error::other)?;
+            sz.for_each_entries(|entry, r| {
+                // A solid archive's members share one stream, so a
+                // skipped member still has to be read past.
+                let name = PathBuf::from(entry.name());
+                if entry.is_directory() || entry.size() > MAX_MEMBER_LEN || !want(&name) {
+                    std::io::copy(r, &mut std::io::sink())?;
+                    return Ok(true);
+                }
+                match read_capped(r) {
+                    Ok(data) => f(&name, data),
+                    Err(e) => log::warn!("{}/{}: {e}", path.display(), name.display()),
+                }
+                Ok(true)
+            })
+            .map_err(std::io::Error::other)
+        }
+        Kind::Gzip => {
+            let mut gz = flate2::read::MultiGzDecoder::new(reader);
+            let data = read_capped(&mut gz)?;
+            // One member, named by the header when the compressor
+            // recorded a name, and by the archive's own name (less its
+            // `.gz`) otherwise.
+            let name = gz
+                .header()
+                .and_then(|h| h.filename())
+                .map(|n| PathBuf::from(String::from_utf8_lossy(n).into_owned()))
+                .or_else(|| path.file_stem().map(PathBuf::from))
+                .unwrap_or_default();
+            if want(&name) {
+                f(&name, data);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Read `r` to the end, refusing anything past [`MAX_MEMBER_LEN`]: sizes
+/// in archive headers are claims, and a decompressor will happily keep
+/// going past one.
+fn read_capped(r: impl Read) -> std::io::Result<Vec<u8>> {
+    let mut data = vec![];
+    r.take(MAX_MEMBER_LEN + 1).read_to_end(&mut data)?;
+    if data.len() as u64 > MAX_MEMBER_LEN {
+        return Err(std::io::Error::other("member too large"));
+    }
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn members(kind: Kind, path: &str, archive: Vec<u8>) -> Vec<(PathBuf, Vec<u8>)> {
+        let len = archive.len() as u64;
+        let mut out = vec![];
+        for_each_member(
+            kind,
+            Path::new(path),
+            Box::new(std::io::Cursor::new(archive)),
+            len,
+            |_| true,
+            |name, data| out.push((name.to_path_buf(), data)),
+        )
+        .unwrap();
+        out
+    }
+
+    #[test]
+    fn kinds_go_by_extension_in_any_case() {
+        assert_eq!(Kind::of(Path::new("roms/bn6.ZIP")), Some(Kind::Zip));
+        assert_eq!(Kind::of(Path::new("roms/bn6.7z")), Some(Kind::SevenZ));
+        assert_eq!(Kind::of(Path::new("roms/bn6.gba.gz")), Some(Kind::Gzip));
+        assert_eq!(Kind::of(Path::new("roms/bn6.gba")), None);
+        assert_eq!(Kind::of(Path::new("roms/zip")), None);
+    }
+
+    #[test]
+    fn zip_members_come_out_with_their_paths() {
+        let mut za = zip::ZipWriter::new(std::io::Cursor::new(vec![]));
+        let options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        za.add_directory("gba/", options).unwrap();
+        za.start_file("gba/bn6.gba", options).unwrap();
+        za.write_all(b"falzar").unwrap();
+        za.start_file("readme.txt", options).unwrap();
+        za.write_all(b"hi").unwrap();
+        let archive = za.finish().unwrap().into_inner();
+
+        assert_eq!(
+            members(Kind::Zip, "set.zip", archive),
+            vec![
+                (PathBuf::from("gba/bn6.gba"), b"falzar".to_vec()),
+                (PathBuf::from("readme.txt"), b"hi".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn seven_z_members_come_out_with_their_paths() {
+        let mut sz = sevenz_rust::SevenZWriter::new(std::io::Cursor::new(vec![])).unwrap();
+        for (name, data) in [
+            ("gba/bn6.gba", &b"falzar"[..]),
+            ("readme.txt", &b"hi"[..]),
+            ("gba/bn6g.gba", &b"gregar"[..]),
+        ] {
+            let mut entry = sevenz_rust::SevenZArchiveEntry::new();
+            entry.name = name.to_string();
+            entry.has_stream = true;
+            sz.push_archive_entry(entry, Some(data)).unwrap();
+        }
+        let archive = sz.finish().unwrap().into_inner();
+
+        assert_eq!(
+            members(Kind::SevenZ, "set.7z", archive.clone()),
+            vec![
+                (PathBuf::from("gba/bn6.gba"), b"falzar".to_vec()),
+                (PathBuf::from("readme.txt"), b"hi".to_vec()),
+                (PathBuf::from("gba/bn6g.gba"), b"gregar".to_vec()),
+            ]
+        );
+
+        // A refused member is read past, not out: the one after it still
+        // comes out whole.
+        let mut out = vec![];
+        for_each_member(
+            Kind::SevenZ,
+            Path::new("set.7z"),
+            Box::new(std::io::Cursor::new(archive.clone())),
+            archive.len() as u64,
+            |name| name.extension().is_some_and(|ext| ext == "gba"),
+            |name, data| out.push((name.to_path_buf(), data)),
+        )
+        .unwrap();
+        assert_eq!(
+            out,
+            vec![
+                (PathBuf::from("gba/bn6.gba"), b"falzar".to_vec()),
+                (PathBuf::from("gba/bn6g.gba"), b"gregar".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn gzip_member_is_named_by_header_or_archive() {
+        let gz = |name: Option<&str>| {
+            let mut builder = flate2::GzBuilder::new();
+            if let Some(name) = name {
+                builder = builder.filename(name);
+            }
+            let mut enc = builder.write(vec![], flate2::Compression::fast());
+            enc.write_all(b"gregar").unwrap();
+            enc.finish().unwrap()
+        };
+        assert_eq!(
+            members(Kind::Gzip, "roms/x.gz", gz(Some("bn6g.gba"))),
+            vec![(PathBuf::from("bn6g.gba"), b"gregar".to_vec())]
+        );
+        assert_eq!(
+            members(Kind::Gzip, "roms/bn6g.gba.gz", gz(None)),
+            vec![(PathBuf::from("bn6g.gba"), b"gregar".to_vec())]
+        );
+    }
+}
@@ -8,6 +8,13 @@
 //! `exe.dat` zip open for the process lifetime, so repeated reads
 //! (backgrounds + future asset pulls) don't re-probe Steam or re-open
 //! the archive. Use [`get`] to access the per-volume cached instance.
+//!
+//! The collection's own save data is reachable too: [`scan_save_slots`]
+//! finds the GBA save image inside each of its save files, and
+//! [`read_save_slot`] turns a slot into the SRAM layout every game's
+//! `parse_save` takes. Nothing writes back into them: the collection's
+//! own header and trailer are undocumented, and no real save file has
+//! been checked against a write.
 
 use std::io::Read;
 use std::path::PathBuf;
@@ -26,6 +33,7 @@ pub use tango_gamesupport::Volume;
 /// reuses the same `ZipArchive`.
 pub struct Bnlc {
     volume: Volume,
+    steam_dir: PathBuf,
     app_dir: PathBuf,
     /// `exe.dat` archive kept open for the lifetime of this Bnlc.
     /// `ZipArchive::by_name` seeks the underlying file, so we need
@@ -39,7 +47,7 @@ impl Bnlc {
     /// archive can't be opened. Prefer [`get`] — it caches the
     /// result for the process lifetime.
     pub fn open(volume: Volume) -> Option<Self> {
-        let app_dir = locate_app_dir(volume)?;
+        let (steam_dir, app_dir) = locate_app_dir(volume)?;
         let archive_path = app_dir.join("exe").join("data").join("exe.dat");
         let file = std::fs::File::open(&archive_path)
             .inspect_err(|e| log::debug!("bnlc {volume:?}: open {}: {e}", archive_path.display()))
@@ -49,6 +57,7 @@ impl Bnlc {
             .ok()?;
         Some(Bnlc {
             volume,
+            steam_dir,
             app_dir,
             shared: Mutex::new(za),
         })
@@ -81,6 +90,20 @@ impl Bnlc {
         out
     }
 
+    /// Directories the volume's save files may be in: the install's own
+    /// `exe/data/save/`, and Steam Cloud's `remote/` folder for every
+    /// account on this machine. Not all of them exist.
+    pub fn save_dirs(&self) -> Vec<PathBuf> {
+        let mut dirs = vec![self.app_dir.join("exe").join("data").join("save")];
+        let app_id = self.volume.steam_app_id().to_string();
+        if let Ok(accounts) = std::fs::read_dir(self.steam_dir.join("userdata")) {
+            for account in accounts.flatten() {
+                dirs.push(account.path().join(&app_id).join("remote"));
+            }
+        }
+        dirs
+    }
+
     /// Read a single file out of the cached shared `exe.dat`.
     /// Returns `None` on missing entry / IO error.
     pub fn read_shared_file(&self, path_in_zip: &str) -> Option<Vec<u8>> {
@@ -105,8 +128,8 @@ impl Bnlc {
 /// storage — which is exactly why it stays native-only and outside
 /// [`crate::storage::Storage`]: there is no browser equivalent to
 /// abstract over.
-pub fn scan_steam_roms() -> std::collections::HashMap<crate::rom::GameRef, Vec<u8>> {
-    let mut roms = std::collections::HashMap::new();
+pub fn scan_steam_roms() -> crate::rom::Roms {
+    let mut roms = crate::rom::Roms::new();
     for volume in [Volume::Vol1, Volume::Vol2] {
         let Some(b) = get(volume) else {
             continue;
@@ -118,9 +141,9 @@ pub fn scan_steam_roms() -> std::collections::HashMap<crate::rom::GameRef, Vec<u
     roms
 }
 
-fn scan_rom_archive(path: &std::path::Path) -> std::collections::HashMap<crate::rom::GameRef, Vec<u8>> {
+fn scan_rom_archive(path: &std::path::Path) -> crate::rom::Roms {
     log::info!("scanning bnlc archive: {}", path.display());
-    let mut roms = std::collections::HashMap::new();
+    let mut roms = crate::rom::Roms::new();
     let f = match std::fs::File::open(path) {
         Ok(f) => f,
         Err(e) => {
@@ -164,11 +187,174 @@ fn scan_rom_archive(path: &std::path::Path) -> std::collections::HashMap<crate::
             entry_path.display(),
             game.family_and_variant()
         );
-        roms.insert(game, rom);
+        let source = crate::rom::Source {
+            path: path.to_path_buf(),
+            member: Some(entry_path),
+        };
+        roms.insert(game, crate::rom::Rom { source, bytes: rom });
     }
     roms
 }
 
+/// Save files larger than this aren't looked in: a volume's saves are a
+/// few dozen KiB, and anything bigger in those folders is something else.
+const MAX_SAVE_FILE_LEN: u64 = 1024 * 1024;
+
+/// How far into a save file its GBA image may start, and the alignment
+/// it's looked for at. The collection puts a header of its own in front
+/// of the image, and a trailer may follow it. Neither is documented, so
+/// neither is interpreted: a file is split into header, image and
+/// trailer by where its one image parses. See [`split_save_file`].
+const SAVE_IMAGE_SEARCH: usize = 0x1000;
+const SAVE_IMAGE_ALIGN: usize = 0x10;
+
+/// One game's save inside the collection's save data.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct SaveSlot {
+    pub volume: Volume,
+    /// The collection's file holding the slot.
+    pub path: PathBuf,
+    /// Where the GBA SRAM image starts within `path`. Everything before
+    /// it is the collection's.
+    pub offset: usize,
+    /// The image's length: its game's SRAM dump size. Everything after
+    /// it is the collection's too.
+    pub len: usize,
+    pub game: crate::rom::GameRef,
+}
+
+impl SaveSlot {
+    /// The slot's file name, for lists: the collection names its files
+    /// by game and slot number.
+    pub fn label(&self) -> String {
+        self.path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+}
+
+pub type SaveScanner = crate::scanner::Scanner<Vec<SaveSlot>>;
+
+/// Every installed volume's [`Bnlc::save_dirs`]: the roots to list for
+/// [`scan_save_slots`].
+pub fn save_roots() -> Vec<PathBuf> {
+    [Volume::Vol1, Volume::Vol2]
+        .into_iter()
+        .filter_map(get)
+        .flat_map(|b| b.save_dirs())
+        .collect()
+}
+
+/// Every save slot in `listing` (a listing of [`save_roots`]), in path
+/// order. A file holds at most one; see [`split_save_file`].
+pub fn scan_save_slots(storage: &dyn crate::Storage, listing: &crate::storage::Listing) -> Vec<SaveSlot> {
+    // `save_dirs` lists Steam's accounts from disk: once per scan, not
+    // once per file.
+    let dirs: Vec<(Volume, Vec<PathBuf>)> = [Volume::Vol1, Volume::Vol2]
+        .into_iter()
+        .filter_map(|volume| Some((volume, get(volume)?.save_dirs())))
+        .collect();
+    scan_save_slots_in(storage, listing, &dirs)
+}
+
+/// [`scan_save_slots`] against each volume's save directories as given.
+fn scan_save_slots_in(
+    storage: &dyn crate::Storage,
+    listing: &crate::storage::Listing,
+    dirs: &[(Volume, Vec<PathBuf>)],
+) -> Vec<SaveSlot> {
+    let mut slots = vec![];
+    for entry in listing.entries() {
+        if entry.len > MAX_SAVE_FILE_LEN {
+            continue;
+        }
+        let Some(volume) = dirs
+            .iter()
+            .find(|(_, dirs)| dirs.iter().any(|d| entry.path.starts_with(d)))
+            .map(|(volume, _)| *volume)
+        else {
+            continue;
+        };
+        let buf = match storage.read(&entry.path) {
+            Ok(buf) => buf,
+            Err(e) => {
+                log::warn!("bnlc: {}: {e}", entry.path.display());
+                continue;
+            }
+        };
+        match split_save_file(volume, &buf) {
+            Some((offset, len, game)) => {
+                log::info!(
+                    "bnlc: {}@{offset:#x}+{len:#x}: {:?}",
+                    entry.path.display(),
+                    game.family_and_variant()
+                );
+                slots.push(SaveSlot {
+                    volume,
+                    path: entry.path.clone(),
+                    offset,
+                    len,
+                    game,
+                });
+            }
+            None => log::debug!("bnlc: {}: no save image", entry.path.display()),
+        }
+    }
+    slots
+}
+
+/// Split a collection save file into header, GBA image and trailer: the
+/// image's offset and length, and its game. `None` unless exactly one
+/// of `volume`'s games parses a save at exactly one aligned offset, and
+/// the whole of that save's SRAM dump lies within the file — a file
+/// that could be read two ways, or whose image runs off its end, can't
+/// be read without guessing. A game's volume is the
+/// one its background art comes from, which is the one that ships it.
+fn split_save_file(volume: Volume, buf: &[u8]) -> Option<(usize, usize, crate::rom::GameRef)> {
+    let games: Vec<_> = crate::game::GAMES
+        .iter()
+        .copied()
+        .filter(|g| g.background.is_some_and(|bg| bg.volume == volume))
+        .collect();
+    let mut found = (0..=SAVE_IMAGE_SEARCH.min(buf.len()))
+        .step_by(SAVE_IMAGE_ALIGN)
+        .flat_map(|offset| {
+            let image = &buf[offset..];
+            games
+                .iter()
+                .filter_map(move |g| Some((offset, g.parse_save(image).ok()?.to_sram_dump().len(), *g)))
+        });
+    let (offset, len, game) = found.next()?;
+    if found.next().is_some() || offset + len > buf.len() {
+        return None;
+    }
+    Some((offset, len, game))
+}
+
+/// Failures reading a slot.
+#[derive(thiserror::Error, Debug)]
+pub enum SaveSlotError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// The file no longer holds the game's save where the slot says: the
+    /// collection rewrote it since the scan.
+    #[error("{0}: no longer holds a save for this game")]
+    Moved(PathBuf),
+}
+
+/// The slot's save, as its game parses it. Its `to_sram_dump` is an
+/// ordinary `.sav`.
+pub fn read_save_slot(
+    storage: &dyn crate::Storage,
+    slot: &SaveSlot,
+) -> Result<tango_gamesupport::BoxedSave, SaveSlotError> {
+    let buf = storage.read(&slot.path)?;
+    buf.get(slot.offset..slot.offset + slot.len)
+        .and_then(|image| slot.game.parse_save(image).ok())
+        .ok_or_else(|| SaveSlotError::Moved(slot.path.clone()))
+}
+
 /// Process-lifetime cached [`Bnlc`] for a volume. The first call
 /// probes Steam + opens the shared archive; subsequent calls return
 /// the same `&'static Bnlc`. `None` whenever the volume isn't
@@ -182,10 +368,84 @@ pub fn get(volume: Volume) -> Option<&'static Bnlc> {
     }
 }
 
-fn locate_app_dir(volume: Volume) -> Option<PathBuf> {
+/// The Steam install and the volume's app dir within it.
+fn locate_app_dir(volume: Volume) -> Option<(PathBuf, PathBuf)> {
     let steamdir = steamlocate::SteamDir::locate()
         .inspect_err(|err| log::debug!("steam not located: {err:?}"))
         .ok()?;
     let (app, lib) = steamdir.find_app(volume.steam_app_id()).ok().flatten()?;
-    Some(lib.resolve_app_dir(&app))
+    Some((steamdir.path().to_path_buf(), lib.resolve_app_dir(&app)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MemStorage;
+    use crate::Storage as _;
+
+    const HEADER: usize = 0x40;
+    const TRAILER: usize = 0x20;
+
+    fn bn6(code: &[u8; 4]) -> crate::rom::GameRef {
+        crate::game::find_by_rom_info(code, 0).expect("gamesupport-bn6 must be enabled for this test")
+    }
+
+    /// The game's bundled save, as a `.sav`.
+    fn sram(game: crate::rom::GameRef) -> Vec<u8> {
+        game.save_templates.expect("bn6 bundles templates")[0].1.to_sram_dump()
+    }
+
+    /// A stand-in collection save file: filler where the collection keeps
+    /// a header and a trailer of its own, around the GBA image. The
+    /// shape this module reads one as, not a copy of the real thing.
+    fn save_file(image: &[u8]) -> Vec<u8> {
+        [&[0xa5; HEADER][..], image, &[0x5a; TRAILER][..]].concat()
+    }
+
+    fn dirs() -> Vec<(Volume, Vec<PathBuf>)> {
+        vec![(Volume::Vol2, vec![PathBuf::from("/steam/remote")])]
+    }
+
+    async fn scan(fs: &MemStorage) -> Vec<SaveSlot> {
+        let listing = fs.list(&[PathBuf::from("/steam")]).await;
+        scan_save_slots_in(fs, &listing, &dirs())
+    }
+
+    #[tokio::test]
+    async fn slots_are_split_out_of_the_save_dirs() {
+        let gregar = bn6(b"BR5E");
+        let fs = MemStorage::new();
+        fs.insert("/steam/remote/exe6g_0.bin", save_file(&sram(gregar)));
+        // Not slots: a file that holds no save, and a save outside any
+        // save directory.
+        fs.insert("/steam/remote/config.bin", vec![0; 0x100]);
+        fs.insert("/steam/other/exe6g_1.bin", save_file(&sram(gregar)));
+
+        assert_eq!(
+            scan(&fs).await,
+            vec![SaveSlot {
+                volume: Volume::Vol2,
+                path: PathBuf::from("/steam/remote/exe6g_0.bin"),
+                offset: HEADER,
+                len: sram(gregar).len(),
+                game: gregar,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn a_slot_reads_as_its_sav() {
+        let gregar = bn6(b"BR5E");
+        let fs = MemStorage::new();
+        let path = PathBuf::from("/steam/remote/exe6g_0.bin");
+        fs.insert(&path, save_file(&sram(gregar)));
+        let [slot] = <[SaveSlot; 1]>::try_from(scan(&fs).await).unwrap();
+
+        assert_eq!(read_save_slot(&fs, &slot).unwrap().to_sram_dump(), sram(gregar));
+
+        // The collection rewrote the file with a longer header since.
+        fs.insert(&path, [&[0; 0x10][..], &save_file(&sram(gregar))].concat());
+        assert!(matches!(read_save_slot(&fs, &slot), Err(SaveSlotError::Moved(_))));
+        assert_eq!(fs.mutations(), vec![]);
+    }
 }
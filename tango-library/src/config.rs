@@ -188,6 +188,11 @@ pub struct Config {
     pub data_path: std::path::PathBuf,
     pub matchmaking_endpoint: String,
     pub patch_repo: String,
+    /// Further patch repos, after [`Self::patch_repo`] in priority order:
+    /// URLs, or directories holding an `index.json` and the packages it
+    /// names. See [`crate::patch`] for how their indexes merge.
+    #[serde(default)]
+    pub extra_patch_repos: Vec<String>,
     /// When `true`, the patch autoupdater (`patch::Autoupdater`)
     /// runs in the background and refreshes the local patch
     /// directory every 15 minutes. Defaults to true; off
@@ -407,6 +412,7 @@ impl Default for Config {
             data_path,
             matchmaking_endpoint: default_matchmaking_endpoint(),
             patch_repo: default_patch_repo(),
+            extra_patch_repos: Vec::new(),
             enable_patch_autoupdate: true,
             video_filter: String::new(),
             fractional_scaling: false,
@@ -448,6 +454,12 @@ impl Config {
     pub fn saves_path(&self) -> std::path::PathBuf {
         self.data_path.join("saves")
     }
+    /// Where overwritten saves are archived — see
+    /// [`crate::save_history`]. Beside the saves directory rather than
+    /// in it, so the save scan doesn't pick the versions up.
+    pub fn save_history_path(&self) -> std::path::PathBuf {
+        self.data_path.join("save-history")
+    }
     /// The configured patch repo, or the default when the setting is
     /// blank (which is how the settings field spells "use the default").
     pub fn patch_repo_url(&self) -> String {
@@ -457,6 +469,14 @@ impl Config {
             self.patch_repo.clone()
         }
     }
+    /// Every configured patch repo, highest priority first. Blank extra
+    /// entries — a row just added in the settings — are skipped.
+    pub fn patch_repos(&self) -> Vec<crate::patch::Repo> {
+        std::iter::once(self.patch_repo_url())
+            .chain(self.extra_patch_repos.iter().filter(|r| !r.trim().is_empty()).cloned())
+            .map(|r| crate::patch::Repo::parse(&r))
+            .collect()
+    }
     pub fn patches_path(&self) -> std::path::PathBuf {
         self.data_path.join("patches")
     }
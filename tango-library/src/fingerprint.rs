@@ -0,0 +1,297 @@
+//! The scan cache: what the last ROM and save scans made of each file,
+//! keyed on its path, size and modification time, so a rescan only reads
+//! and detects the files that changed.
+//!
+//! Without it every rescan CRC-checks every ROM in full and tries every
+//! save against every game's parser. With it, an unchanged file that
+//! wasn't a ROM (or a save) isn't read at all, an unchanged ROM is read
+//! but not checked, and an unchanged save is parsed only as the game it
+//! was last time.
+//!
+//! The cache is only as good as the [`Stamp`]s behind it: a backend that
+//! reports no modification time gets no caching, since a size alone
+//! can't tell one save from its next autosave. It is thrown away whole
+//! when the registry changes — a game added, removed or re-dumped, or a
+//! save parser bumping its version — so a verdict is never trusted
+//! across builds that would have reached a different one.
+
+use crate::rom::GameRef;
+use crate::storage::{self, Entry, Listing, Stamp, Storage};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Bumped whenever the records change shape or meaning; a cache from
+/// another format is dropped rather than migrated.
+const FORMAT: u32 = 2;
+
+const FILE_NAME: &str = "scan-fingerprints.json";
+
+/// A game as the cache stores it. `GameRef`s are addresses, meaningless
+/// across runs.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+struct GameKey {
+    family: String,
+    variant: u8,
+}
+
+impl GameKey {
+    fn of(game: GameRef) -> Self {
+        let (family, variant) = game.family_and_variant();
+        Self {
+            family: family.to_string(),
+            variant,
+        }
+    }
+
+    fn resolve(&self) -> Option<GameRef> {
+        crate::game::find_by_family_and_variant(&self.family, self.variant)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+struct FoundRom {
+    /// The member it was read from, for an archive.
+    member: Option<PathBuf>,
+    game: GameKey,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+struct RomRecord {
+    stamp: Stamp,
+    /// Empty for a file that held no supported ROM.
+    roms: Vec<FoundRom>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+struct SaveRecord {
+    stamp: Stamp,
+    /// Every game the save parsed as; empty for one that parsed as none.
+    games: Vec<GameKey>,
+}
+
+/// The loaded cache. Load one per rescan with [`Fingerprints::load`],
+/// hand it to [`crate::rom::scan_roms`] and [`crate::save::scan_saves`],
+/// then [`Fingerprints::store`] it.
+#[derive(Serialize, Deserialize, Default, Debug)]
+pub struct Fingerprints {
+    format: u32,
+    /// [`registry`] as of when this was written.
+    registry: Vec<String>,
+    roms: BTreeMap<PathBuf, RomRecord>,
+    saves: BTreeMap<PathBuf, SaveRecord>,
+
+    #[serde(skip)]
+    path: PathBuf,
+    #[serde(skip)]
+    dirty: bool,
+}
+
+/// What the verdicts were reached against: every registered game with
+/// the identity ROM detection checks and the version of the parser its
+/// saves were tried with. Any change to it empties the cache.
+fn registry() -> Vec<String> {
+    crate::game::GAMES
+        .iter()
+        .map(|game| {
+            let (family, variant) = game.family_and_variant();
+            format!(
+                "{family}-{variant}-{:08x}-{}-{}",
+                game.crc32(),
+                game.rom_size(),
+                game.save_parser_version
+            )
+        })
+        .collect()
+}
+
+impl Fingerprints {
+    /// The cache under `cache_path`, or an empty one if there is none or
+    /// it can't be used. Never fails: the worst a lost cache costs is
+    /// one full scan.
+    pub fn load(storage: &dyn Storage, cache_path: &Path) -> Self {
+        let path = cache_path.join(FILE_NAME);
+        let raw = match storage::read_opt(storage, &path) {
+            Ok(raw) => raw,
+            Err(e) => {
+                log::warn!("{}: {e}", path.display());
+                None
+            }
+        };
+        let mut fingerprints = Self::from_json(raw.as_deref());
+        fingerprints.path = path;
+        fingerprints
+    }
+
+    fn from_json(raw: Option<&[u8]>) -> Self {
+        let registry = registry();
+        let loaded = raw.and_then(|raw| match serde_json::from_slice::<Self>(raw) {
+            Ok(f) => Some(f),
+            Err(e) => {
+                log::warn!("scan cache: {e}");
+                None
+            }
+        });
+        match loaded {
+            Some(f) if f.format == FORMAT && f.registry == registry => f,
+            stale => Self {
+                format: FORMAT,
+                registry,
+                // Overwrite whatever was there, even if nothing gets
+                // recorded, so a stale cache isn't re-read every time.
+                dirty: stale.is_some(),
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Write the cache back if the scans changed it. Failures are logged
+    /// and otherwise ignored, like a failed load.
+    pub fn store(&mut self, storage: &dyn Storage) {
+        if !self.dirty {
+            return;
+        }
+        let result = serde_json::to_vec(self)
+            .map_err(std::io::Error::other)
+            .and_then(|json| storage::write_atomic(storage, &self.path, &json));
+        match result {
+            Ok(()) => self.dirty = false,
+            Err(e) => log::warn!("{}: {e}", self.path.display()),
+        }
+    }
+
+    /// Forget files that are no longer in `listing`.
+    pub(crate) fn retain_roms(&mut self, listing: &Listing) {
+        self.dirty |= retain(&mut self.roms, listing);
+    }
+
+    pub(crate) fn retain_saves(&mut self, listing: &Listing) {
+        self.dirty |= retain(&mut self.saves, listing);
+    }
+
+    /// What `entry` held when last scanned — each ROM's archive member
+    /// (if any) and game — if it hasn't changed since.
+    pub(crate) fn roms(&self, entry: &Entry) -> Option<Vec<(Option<PathBuf>, GameRef)>> {
+        let record = self.roms.get(&entry.path)?;
+        if Some(record.stamp) != entry.stamp() {
+            return None;
+        }
+        record
+            .roms
+            .iter()
+            .map(|found| Some((found.member.clone(), found.game.resolve()?)))
+            .collect()
+    }
+
+    pub(crate) fn set_roms(&mut self, entry: &Entry, found: impl IntoIterator<Item = (Option<PathBuf>, GameRef)>) {
+        let roms = found
+            .into_iter()
+            .map(|(member, game)| FoundRom {
+                member,
+                game: GameKey::of(game),
+            })
+            .collect();
+        self.dirty |= set(&mut self.roms, entry, |stamp| RomRecord { stamp, roms });
+    }
+
+    /// The games `entry` parsed as when last scanned, if it hasn't
+    /// changed since.
+    pub(crate) fn save_games(&self, entry: &Entry) -> Option<Vec<GameRef>> {
+        let record = self.saves.get(&entry.path)?;
+        if Some(record.stamp) != entry.stamp() {
+            return None;
+        }
+        record.games.iter().map(GameKey::resolve).collect()
+    }
+
+    pub(crate) fn set_save_games(&mut self, entry: &Entry, games: impl IntoIterator<Item = GameRef>) {
+        let games = games.into_iter().map(GameKey::of).collect();
+        self.dirty |= set(&mut self.saves, entry, |stamp| SaveRecord { stamp, games });
+    }
+}
+
+fn retain<R>(records: &mut BTreeMap<PathBuf, R>, listing: &Listing) -> bool {
+    let before = records.len();
+    let listed: std::collections::HashSet<&Path> = listing.entries().iter().map(|e| e.path.as_path()).collect();
+    records.retain(|path, _| listed.contains(path.as_path()));
+    records.len() != before
+}
+
+/// Record `entry`'s verdict, returning whether anything changed. An
+/// entry without a [`Stamp`] can't be cached, and drops any old record.
+fn set<R: PartialEq>(records: &mut BTreeMap<PathBuf, R>, entry: &Entry, record: impl FnOnce(Stamp) -> R) -> bool {
+    let Some(stamp) = entry.stamp() else {
+        return records.remove(&entry.path).is_some();
+    };
+    let record = record(stamp);
+    let unchanged = records.get(&entry.path) == Some(&record);
+    if !unchanged {
+        records.insert(entry.path.clone(), record);
+    }
+    !unchanged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(path: &str, len: u64, modified: Option<u64>) -> Entry {
+        Entry {
+            path: PathBuf::from(path),
+            len,
+            modified,
+        }
+    }
+
+    #[test]
+    fn verdicts_hold_only_while_the_stamp_does() {
+        let mut f = Fingerprints::from_json(None);
+        let save = entry("/saves/a.sav", 0x2000, Some(1));
+        f.set_save_games(&save, []);
+        assert_eq!(f.save_games(&save).map(|g| g.len()), Some(0));
+        assert!(f.save_games(&entry("/saves/a.sav", 0x2000, Some(2))).is_none());
+        assert!(f.save_games(&entry("/saves/a.sav", 0x4000, Some(1))).is_none());
+    }
+
+    #[test]
+    fn files_without_mtimes_are_never_cached() {
+        let mut f = Fingerprints::from_json(None);
+        let rom = entry("/roms/a.gba", 16, None);
+        f.set_roms(&rom, []);
+        assert!(f.roms(&rom).is_none());
+        assert!(!f.dirty);
+    }
+
+    #[test]
+    fn unlisted_files_are_forgotten() {
+        let mut f = Fingerprints::from_json(None);
+        let (a, b) = (entry("/roms/a.gba", 16, Some(1)), entry("/roms/b.gba", 16, Some(1)));
+        f.set_roms(&a, []);
+        f.set_roms(&b, []);
+        f.dirty = false;
+        f.retain_roms(&Listing::new(vec![b.clone()]));
+        assert!(f.dirty);
+        assert!(f.roms(&a).is_none());
+        assert!(f.roms(&b).is_some());
+    }
+
+    #[test]
+    fn another_registry_or_format_empties_the_cache() {
+        let mut f = Fingerprints::from_json(None);
+        let rom = entry("/roms/a.gba", 16, Some(1));
+        f.set_roms(&rom, []);
+        let json = serde_json::to_vec(&f).unwrap();
+        assert!(Fingerprints::from_json(Some(&json)).roms(&rom).is_some());
+
+        f.registry.push("bn7-0-00000000-0-0".into());
+        let stale = Fingerprints::from_json(Some(&serde_json::to_vec(&f).unwrap()));
+        assert!(stale.roms(&rom).is_none());
+        assert!(stale.dirty);
+
+        f.registry = registry();
+        f.format = FORMAT + 1;
+        assert!(Fingerprints::from_json(Some(&serde_json::to_vec(&f).unwrap()))
+            .roms(&rom)
+            .is_none());
+    }
+}
@@ -4,11 +4,17 @@
 //!   by, and the game-name localizer.
 //! * [`scanner`]: the shared fingerprint-gated rescan machinery the
 //!   content scans below build on.
+//! * [`fingerprint`]: the on-disk cache that lets the ROM and save scans
+//!   skip files unchanged since the last one.
 //! * [`rom`] / [`save`] / [`patch`] / [`replays`]: one module per kind of
 //!   content the library folders hold.
+//! * [`save_history`]: the earlier versions of each save, archived
+//!   before anything writes over it.
 //! * [`bnlc`]: Battle Network Legacy Collection (Steam) discovery, an
 //!   extra source of ROMs — native only, and absent from a wasm build.
 //! * [`config`]: the persisted settings model.
+//! * `testing`: in-memory stand-ins for the two seams below, for tests
+//!   here and, behind the `testing` feature, in other crates.
 //!
 //! Nothing here knows about a UI toolkit, and nothing here touches the
 //! filesystem or the network directly: [`storage::Storage`] and
@@ -17,7 +23,9 @@
 //! implementations; a browser build turns it off and hands in OPFS and
 //! `fetch` instead.
 
+mod archive;
 pub mod config;
+pub mod fingerprint;
 pub mod game;
 pub mod http;
 pub mod lang;
@@ -26,9 +34,13 @@ pub mod patch;
 pub mod replays;
 pub mod rom;
 pub mod save;
+pub mod save_history;
 pub mod scanner;
 pub mod storage;
 
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
 // Steam discovery: no meaning in a browser, and it pulls in steamlocate.
 #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
 pub mod bnlc;
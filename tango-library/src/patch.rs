@@ -1,8 +1,9 @@
-//! Installed patch packages and the cached repo index.
+//! Installed patch packages and the cached repo indexes.
 //!
 //! Patches live on disk as `.tangopatch` packages (see the `tango-patch`
-//! crate) in `<data>/patches/`, one file per patch version. Alongside
-//! them sits `index.json`, a cached copy of the repo's catalogue.
+//! crate) in `<data>/patches/`, one file per patch version. Beside them,
+//! under `repos/`, sits a cached copy of each configured repo's
+//! catalogue.
 //!
 //! # Nothing is mirrored
 //!
@@ -13,6 +14,17 @@
 //! peer turns up using it, or a replay needs it to re-simulate. The old
 //! format made the client sha256 every file in the repo and download all
 //! of them (hundreds of MiB) before it could tell you what a patch was.
+//!
+//! # Several repos
+//!
+//! A repo is either served over HTTP or is a plain directory laid out the
+//! same way — an `index.json` beside the packages it names — which is how
+//! an offline LAN event hands a tournament patch around on a USB stick
+//! or a network share. Every configured repo's index is merged into the
+//! one [`Catalog`], remembering which repos offer each version. When two
+//! repos list the same version with different contents, the one
+//! configured first decides what that version is; the others are
+//! recorded as conflicting and never downloaded from for it.
 
 use crate::http::{self, Fetch, Http};
 use crate::rom::GameRef;
@@ -38,6 +50,10 @@ pub enum Error {
     Package(Box<tango_patch::Error>),
     #[error("bad patch name: {0}")]
     BadName(String),
+    #[error("{name} {version} is not offered by any patch repo")]
+    NotOffered { name: String, version: semver::Version },
+    #[error("bad path in patch index: {0}")]
+    BadPath(String),
     #[error("{name} {version}: {source}")]
     NotWhatTheIndexPromised {
         name: String,
@@ -116,15 +132,33 @@ pub type PatchMap = BTreeMap<String, Arc<Patch>>;
 pub type Scanner = scanner::Scanner<Catalog>;
 
 /// What patches exist, from both directions: what's installed here and
-/// what the repo offers. The two overlap freely — a patch can be
+/// what the repos offer. The two overlap freely — a patch can be
 /// installed and indexed, installed only (sideloaded), or indexed only
 /// (not downloaded yet).
 #[derive(Default)]
 pub struct Catalog {
     pub installed: PatchMap,
-    /// Last index we fetched. Empty before the first successful fetch,
-    /// and kept across restarts so the app can browse offline.
+    /// Every configured repo's last index, merged. Empty before the
+    /// first successful fetch, and kept across restarts so the app can
+    /// browse offline.
     pub index: tango_patch::Index,
+    /// Which repos offer each version in `index`, in priority order.
+    /// Only repos whose package matches `index`'s entry are listed.
+    pub offers: BTreeMap<String, BTreeMap<semver::Version, Vec<Offer>>>,
+    /// Repos listing a version with contents other than `index`'s — see
+    /// the module docs.
+    pub conflicts: BTreeMap<String, BTreeMap<semver::Version, Vec<Repo>>>,
+}
+
+/// One repo's offer of a version. The path is per offer: two repos can
+/// ship the same package under different names.
+#[derive(Clone, Debug)]
+pub struct Offer {
+    pub repo: Repo,
+    /// Where the package sits, relative to the repo root.
+    pub path: String,
+    /// Where its README sidecar sits, if the repo publishes one.
+    pub readme: Option<String>,
 }
 
 /// One version as the UI sees it, whether or not it's on disk.
@@ -166,11 +200,28 @@ impl Catalog {
         self.installed.get(name)?.versions.get(version)
     }
 
-    /// The index entry for a version, if the repo offers it.
+    /// The index entry for a version, if any repo offers it.
     pub fn entry(&self, name: &str, version: &semver::Version) -> Option<&tango_patch::index::Entry> {
         self.index.get(name, version)
     }
 
+    /// The repos a version can be downloaded from, in the order to try
+    /// them.
+    pub fn offers(&self, name: &str, version: &semver::Version) -> &[Offer] {
+        self.offers
+            .get(name)
+            .and_then(|v| v.get(version))
+            .map_or(&[], |o| o.as_slice())
+    }
+
+    /// The repos listing a version with different contents.
+    pub fn conflicts(&self, name: &str, version: &semver::Version) -> &[Repo] {
+        self.conflicts
+            .get(name)
+            .and_then(|v| v.get(version))
+            .map_or(&[], |r| r.as_slice())
+    }
+
     /// Display metadata for a patch: the installed newest version's, or
     /// the index's if none is installed.
     pub fn title(&self, name: &str) -> Option<&str> {
@@ -256,33 +307,189 @@ pub fn package_path(patches_path: &Path, name: &str, version: &semver::Version)
     patches_path.join(format!("{name}-{version}.{}", tango_patch::EXTENSION))
 }
 
-/// `<patches>/index.json` — the cached repo catalogue.
-pub fn index_path(patches_path: &Path) -> PathBuf {
-    patches_path.join(tango_patch::index::FILE_NAME)
-}
+/// Where cached repo indexes live under the patches directory.
+const REPOS_DIR: &str = "repos";
 
 /// Everything a scan reads, for the change-detection fingerprint — the
-/// packages and the cached index all live in the one directory.
+/// packages and the cached indexes all live under the one directory.
 pub fn scan_roots(patches_path: &Path) -> Vec<PathBuf> {
     vec![patches_path.to_path_buf()]
 }
 
+/// Where a repo's index and packages come from.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Repo {
+    /// Served over HTTP(S), and polled with conditional GETs.
+    Http(String),
+    /// A directory laid out like a served repo, read through
+    /// [`Storage`]. Has no validator to poll with, so a fetch reads the
+    /// whole index and compares it with the cached copy.
+    Dir(PathBuf),
+}
+
+impl Repo {
+    /// A repo from its configured location: an `http(s)://` URL, a
+    /// `file://` URL, or a bare path.
+    pub fn parse(location: &str) -> Repo {
+        let location = location.trim();
+        if location.starts_with("http://") || location.starts_with("https://") {
+            return Repo::Http(location.to_owned());
+        }
+        let Some(path) = location.strip_prefix("file://") else {
+            return Repo::Dir(PathBuf::from(location));
+        };
+        // `file://host/path` has no meaning here beyond `localhost`.
+        let path = path.strip_prefix("localhost").unwrap_or(path);
+        let path = percent_decode(path);
+        // `file:///C:/patches` is a Windows drive path.
+        let path = match path.as_bytes() {
+            [b'/', drive, b':', ..] if drive.is_ascii_alphabetic() => path[1..].to_owned(),
+            _ => path,
+        };
+        Repo::Dir(PathBuf::from(path))
+    }
+
+    /// `<patches>/repos/<name>/`, where this repo's index is cached. The
+    /// name is the location with every byte but lowercase letters,
+    /// digits, `.` and `-` escaped as `_xx`, so no two locations share a
+    /// cache, even on a case-insensitive filesystem.
+    pub fn cache_path(&self, patches_path: &Path) -> PathBuf {
+        let (kind, location) = match self {
+            Repo::Http(url) => (
+                "http",
+                url.split_once("://").map_or(url.as_str(), |(_, rest)| rest).to_owned(),
+            ),
+            Repo::Dir(path) => ("dir", path.to_string_lossy().into_owned()),
+        };
+        let mut name = String::new();
+        for b in location.trim_end_matches(['/', '\\']).bytes() {
+            match b {
+                b'a'..=b'z' | b'0'..=b'9' | b'.' | b'-' => name.push(b as char),
+                _ => name.push_str(&format!("_{b:02x}")),
+            }
+        }
+        patches_path.join(REPOS_DIR).join(format!("{kind}-{name}"))
+    }
+
+    /// The cached copy of this repo's index.
+    pub fn index_path(&self, patches_path: &Path) -> PathBuf {
+        self.cache_path(patches_path).join(tango_patch::index::FILE_NAME)
+    }
+}
+
+impl std::fmt::Display for Repo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Repo::Http(url) => f.write_str(url),
+            Repo::Dir(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// `%XX` escapes in a `file://` URL, which is how a path with spaces
+/// arrives when it's copied out of a file manager or a browser.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(b)) => {
+                out.push(b);
+                i += 3;
+            }
+            (b, _) => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// An index path, made safe to join onto a directory repo's root: plain
+/// `/`-separated names only, so a hostile index can't reach outside it.
+fn repo_relative(path: &str) -> Result<PathBuf, Error> {
+    let mut out = PathBuf::new();
+    for part in path.split('/') {
+        if part.is_empty() || part == "." || part == ".." || part.contains(['\\', ':']) {
+            return Err(Error::BadPath(path.to_owned()));
+        }
+        out.push(part);
+    }
+    Ok(out)
+}
+
+/// GET `path` from `repo`. A directory repo reads through `storage` and
+/// never answers `NotModified`; it does honour `max_len`, and reports
+/// its one chunk of progress so a caller can still cancel.
+async fn get(
+    http: &dyn Http,
+    storage: &dyn Storage,
+    repo: &Repo,
+    path: &str,
+    if_none_match: Option<&str>,
+    max_len: Option<u64>,
+    on_progress: Option<&http::ProgressFn<'_>>,
+) -> Result<Fetch, Error> {
+    match repo {
+        Repo::Http(url) => {
+            let full_url = format!("{}/{path}", url.trim_end_matches('/'));
+            Ok(http
+                .get(http::Request {
+                    url: &full_url,
+                    if_none_match,
+                    max_len,
+                    on_progress,
+                })
+                .await?)
+        }
+        Repo::Dir(root) => {
+            let body = storage.read(&root.join(repo_relative(path)?))?;
+            let len = body.len() as u64;
+            if let Some(limit) = max_len.filter(|&limit| len > limit) {
+                return Err(http::Error::TooLarge { limit }.into());
+            }
+            if on_progress.is_some_and(|p| !p.call(len, len)) {
+                return Ok(Fetch::Cancelled);
+            }
+            Ok(Fetch::Body { etag: None, body })
+        }
+    }
+}
+
 fn game_for(target: tango_patch::RomTarget) -> Option<GameRef> {
     crate::game::find_by_rom_info(&target.code, target.revision)
 }
 
-/// Read the installed packages and the cached index.
-pub fn scan(storage: &dyn Storage, patches_path: &Path, listing: &Listing) -> Result<Catalog, Error> {
-    let index = match storage::read_opt(storage, &index_path(patches_path))? {
-        Some(raw) => match tango_patch::Index::parse(&String::from_utf8_lossy(&raw)) {
-            Ok(index) => index,
-            Err(e) => {
-                log::warn!("cached patch index is unusable, ignoring it: {e}");
-                tango_patch::Index::default()
+/// Read the installed packages and the cached index of each of `repos`,
+/// which are in priority order.
+pub fn scan(storage: &dyn Storage, patches_path: &Path, repos: &[Repo], listing: &Listing) -> Result<Catalog, Error> {
+    let indexes = repos
+        .iter()
+        .filter_map(|repo| {
+            let path = repo.index_path(patches_path);
+            let raw = match storage::read_opt(storage, &path) {
+                Ok(raw) => raw?,
+                Err(e) => {
+                    log::warn!("{}: {e}", path.display());
+                    return None;
+                }
+            };
+            match tango_patch::Index::parse(&String::from_utf8_lossy(&raw)) {
+                Ok(index) => Some((repo, index)),
+                Err(e) => {
+                    log::warn!("cached index of {repo} is unusable, ignoring it: {e}");
+                    None
+                }
             }
-        },
-        None => tango_patch::Index::default(),
-    };
+        })
+        .collect::<Vec<_>>();
+    let mut catalog = merge(indexes);
 
     // Newest version wins for the patch-level display metadata, so
     // collect per version first and fold afterwards.
@@ -320,7 +527,53 @@ pub fn scan(storage: &dyn Storage, patches_path: &Path, listing: &Listing) -> Re
         })
         .collect();
 
-    Ok(Catalog { installed, index })
+    catalog.installed = installed;
+    Ok(catalog)
+}
+
+/// Fold repo indexes, highest priority first, into one catalog (with
+/// nothing installed yet). A version's first listing decides what it is;
+/// a later repo agreeing on the hash becomes another place to download
+/// it from, and one disagreeing becomes a conflict.
+fn merge(indexes: Vec<(&Repo, tango_patch::Index)>) -> Catalog {
+    let mut catalog = Catalog::default();
+    for (repo, index) in indexes {
+        for (name, entries) in index.patches {
+            for (version, entry) in entries {
+                let offer = Offer {
+                    repo: repo.clone(),
+                    path: entry.path.clone(),
+                    readme: entry.readme.clone(),
+                };
+                let merged = catalog.index.patches.entry(name.clone()).or_default();
+                match merged.get(&version) {
+                    None => {
+                        merged.insert(version.clone(), entry);
+                    }
+                    Some(first) if first.sha256 == entry.sha256 && first.size == entry.size => {}
+                    Some(_) => {
+                        log::warn!("{repo} lists {name} {version} with different contents; ignoring its copy");
+                        catalog
+                            .conflicts
+                            .entry(name)
+                            .or_default()
+                            .entry(version)
+                            .or_default()
+                            .push(repo.clone());
+                        continue;
+                    }
+                }
+                catalog
+                    .offers
+                    .entry(name)
+                    .or_default()
+                    .entry(version)
+                    .or_default()
+                    .push(offer);
+            }
+        }
+    }
+    catalog
 }
 
 /// Read one package into a [`Version`] and its manifest.
@@ -389,6 +642,42 @@ pub fn display_authors(authors: &[String]) -> Vec<String> {
         .collect()
 }
 
+/// Headings a README's changelog goes under.
+const CHANGELOG_HEADINGS: &[&str] = &["changelog", "changes", "what's new"];
+
+/// The changelog section of a README: everything under its first
+/// heading named like one (any level, any case) up to the next heading
+/// at that level or above. The manifest has no changelog field of its
+/// own, so this is where patches keep one.
+pub fn changelog(readme: &str) -> Option<String> {
+    fn heading(line: &str) -> Option<(usize, &str)> {
+        let level = line.bytes().take_while(|&b| b == b'#').count();
+        let title = line[level..].strip_prefix(' ')?;
+        (1..=6)
+            .contains(&level)
+            .then(|| (level, title.trim().trim_end_matches('#').trim()))
+    }
+
+    let mut section: Option<(usize, Vec<&str>)> = None;
+    let mut fenced = false;
+    for line in readme.lines() {
+        if line.trim_start().starts_with("```") {
+            fenced = !fenced;
+        }
+        match (&mut section, heading(line).filter(|_| !fenced)) {
+            (None, Some((level, title))) if CHANGELOG_HEADINGS.contains(&title.to_lowercase().as_str()) => {
+                section = Some((level, vec![]));
+            }
+            (Some((level, _)), Some((next, _))) if next <= *level => break,
+            (Some((_, lines)), _) => lines.push(line),
+            (None, _) => {}
+        }
+    }
+    let (_, lines) = section?;
+    let text = lines.join("\n").trim().to_owned();
+    (!text.is_empty()).then_some(text)
+}
+
 /// Read the BPS for `game` out of the installed package and apply it to
 /// `rom`, returning the patched image.
 ///
@@ -414,6 +703,40 @@ pub fn apply_patch(
     Ok(bps::Patch::decode(&raw)?.apply(rom)?)
 }
 
+/// A package applied from outside the library: what [`apply_package`]
+/// hands back.
+pub struct AppliedPackage {
+    /// The patched ROM image.
+    pub rom: Vec<u8>,
+    pub name: String,
+    pub version: semver::Version,
+    /// The package's overrides for the ROM it was applied to.
+    pub rom_overrides: tango_patch::Overrides,
+    pub readme: Option<String>,
+}
+
+/// Apply a `.tangopatch` that isn't installed — read whole from `raw`,
+/// as the headless save tool takes one by path, or as
+/// [`fetch_package`] hands one back — to `rom` for `game`.
+/// Unlike [`apply_patch`] this also reads the manifest, so the caller
+/// gets the name, version and ROM overrides an editor layers its assets
+/// with.
+pub fn apply_package(raw: &[u8], rom: &[u8], game: GameRef) -> Result<AppliedPackage, Error> {
+    let mut package = Package::read(std::io::Cursor::new(raw))?;
+    let manifest = package.manifest().clone();
+    let (rom_code, revision) = game.rom_code_and_revision();
+    let target = tango_patch::RomTarget::new(*rom_code, revision);
+    let bps = package.bps(target)?;
+    let readme = package.readme()?;
+    Ok(AppliedPackage {
+        rom: bps::Patch::decode(&bps)?.apply(rom)?,
+        readme,
+        rom_overrides: manifest.rom_overrides.get(&target).cloned().unwrap_or_default(),
+        name: manifest.name,
+        version: manifest.version,
+    })
+}
+
 /// A patch version — the unit everything installs, removes and renders.
 pub type VersionKey = (String, semver::Version);
 
@@ -463,43 +786,50 @@ impl Download {
 
 pub type Downloads = std::collections::HashMap<VersionKey, Download>;
 
-/// Fetch the repo index, writing it to storage when it changed.
+/// Fetch one repo's index, writing it to its cache when it changed.
 ///
-/// Returns `true` if the stored copy was replaced (the caller should
+/// Returns `true` if the cached copy was replaced (the caller should
 /// rescan). Conditional on the stored ETag, so the common case — the
 /// repo hasn't published anything since we last looked — is a 304 with
 /// no body, which is what lets this poll on a timer without being rude.
 pub async fn fetch_index(
     http: &dyn Http,
     storage: &dyn Storage,
-    url: &str,
+    repo: &Repo,
     patches_path: &Path,
 ) -> Result<bool, Error> {
-    storage.create_dir_all(patches_path)?;
-    let path = index_path(patches_path);
-    let etag_path = patches_path.join("index.etag");
+    let cache_path = repo.cache_path(patches_path);
+    storage.create_dir_all(&cache_path)?;
+    let path = repo.index_path(patches_path);
+    let etag_path = cache_path.join("index.etag");
 
     // Only send the validator if we still have the body it describes.
-    let etag = if storage.is_file(&path) {
+    let cached = storage::read_opt(storage, &path)?;
+    let etag = if cached.is_some() {
         storage::read_opt(storage, &etag_path)?.map(|raw| String::from_utf8_lossy(&raw).trim().to_owned())
     } else {
         None
     };
 
-    let full_url = format!("{}/{}", url.trim_end_matches('/'), tango_patch::index::FILE_NAME);
-    let fetched = http
-        .get(http::Request {
-            url: &full_url,
-            if_none_match: etag.as_deref(),
-            max_len: None,
-            on_progress: None,
-        })
-        .await?;
+    let fetched = get(
+        http,
+        storage,
+        repo,
+        tango_patch::index::FILE_NAME,
+        etag.as_deref(),
+        None,
+        None,
+    )
+    .await?;
 
     let (etag, raw) = match fetched {
         Fetch::NotModified | Fetch::Cancelled => return Ok(false),
         Fetch::Body { etag, body } => (etag, body),
     };
+    // A directory repo has no validator, so it always answers in full.
+    if cached.as_deref() == Some(raw.as_slice()) {
+        return Ok(false);
+    }
 
     // Parse before writing: a corrupt or future-format index shouldn't
     // clobber the usable one we already have.
@@ -512,13 +842,60 @@ pub async fn fetch_index(
         }
     }
     log::info!(
-        "patch index: {} versions of {} patches",
+        "patch index from {repo}: {} versions of {} patches",
         index.len(),
         index.patches.len()
     );
     Ok(true)
 }
 
+/// How refreshing every repo went.
+#[derive(Debug, Default)]
+pub struct Refreshed {
+    /// Some cached index changed, or a stale one was dropped: rescan.
+    pub changed: bool,
+    /// The repos that couldn't be fetched. Their cached indexes stay.
+    pub failed: Vec<(Repo, Error)>,
+}
+
+/// Fetch every configured repo's index, and drop the caches of repos no
+/// longer configured so the next scan forgets what they offered.
+///
+/// One repo failing doesn't stop the others: an unreachable public repo
+/// is exactly the case a LAN event's directory repo is there for.
+pub async fn fetch_indexes(http: &dyn Http, storage: &dyn Storage, repos: &[Repo], patches_path: &Path) -> Refreshed {
+    let mut refreshed = Refreshed::default();
+    for repo in repos {
+        match fetch_index(http, storage, repo, patches_path).await {
+            Ok(changed) => refreshed.changed |= changed,
+            Err(e) => refreshed.failed.push((repo.clone(), e)),
+        }
+    }
+
+    let configured: HashSet<PathBuf> = repos.iter().map(|r| r.cache_path(patches_path)).collect();
+    let listing = storage.list(&[patches_path.join(REPOS_DIR)]).await;
+    for entry in listing.entries() {
+        if entry.path.parent().is_some_and(|dir| !configured.contains(dir)) {
+            match storage.remove_file(&entry.path) {
+                Ok(()) => refreshed.changed = true,
+                Err(e) => log::warn!("{}: {e}", entry.path.display()),
+            }
+        }
+    }
+
+    // Before there could be several repos, the one repo's index was
+    // cached loose beside the packages. Nothing reads it any more.
+    for name in [tango_patch::index::FILE_NAME, "index.etag"] {
+        let path = patches_path.join(name);
+        if storage.is_file(&path) {
+            if let Err(e) = storage.remove_file(&path) {
+                log::warn!("{}: {e}", path.display());
+            }
+        }
+    }
+    refreshed
+}
+
 /// How a download ended, short of an error. The installed path isn't
 /// carried back — [`package_path`] derives it, and nothing needs it.
 #[derive(Debug)]
@@ -528,19 +905,18 @@ pub enum Outcome {
     Cancelled,
 }
 
-/// Download one patch version into the patches directory.
+/// Download one patch version into the patches directory: the package
+/// [`fetch_package`] delivers, written in one piece.
 ///
 /// The package is verified against the index's hash *before* anything is
 /// written, so a failed, truncated, or cancelled download can't leave a
 /// half-written package that the next scan would treat as installed.
-/// The index fixes the exact byte count, which is passed down as the
-/// transfer's hard cap.
 ///
 /// `progress` returns false to cancel.
 pub async fn download(
     http: &dyn Http,
     storage: &dyn Storage,
-    url: &str,
+    offers: &[Offer],
     patches_path: &Path,
     name: &str,
     version: &semver::Version,
@@ -550,32 +926,87 @@ pub async fn download(
     validate_name(name)?;
     storage.create_dir_all(patches_path)?;
 
-    let full_url = format!("{}/{}", url.trim_end_matches('/'), entry.path);
-    let on_progress = move |downloaded, total| progress(Progress { downloaded, total });
-    let fetched = http
-        .get(http::Request {
-            url: &full_url,
-            if_none_match: None,
-            max_len: Some(entry.size),
-            on_progress: Some(&on_progress),
-        })
-        .await?;
-
-    let raw = match fetched {
-        Fetch::Cancelled => return Ok(Outcome::Cancelled),
-        // Nothing sends a validator here, so a 304 would be the server
-        // misbehaving; treat it as an empty body and let verify reject it.
-        Fetch::NotModified => Vec::new(),
-        Fetch::Body { body, .. } => body,
+    let Some(raw) = fetch_package(http, storage, offers, name, version, entry, progress).await? else {
+        return Ok(Outcome::Cancelled);
     };
-
-    verify(&raw, entry, name, version)?;
-
     storage::write_atomic(storage, &package_path(patches_path, name, version), &raw)?;
     log::info!("installed {name} {version} ({} bytes)", raw.len());
     Ok(Outcome::Installed)
 }
 
+/// Fetch one patch version's package into memory, trying each of
+/// `offers` in turn until one delivers a copy that matches `entry`.
+/// `None` if `progress` cancelled it. What [`download`] installs, and
+/// what a comparison applies when the version isn't installed.
+///
+/// The index fixes the exact byte count, which is passed down as the
+/// transfer's hard cap. Failing verification also moves on to the next
+/// offer, so one repo serving a bad copy doesn't block the rest.
+///
+/// `progress` returns false to cancel.
+pub async fn fetch_package(
+    http: &dyn Http,
+    storage: &dyn Storage,
+    offers: &[Offer],
+    name: &str,
+    version: &semver::Version,
+    entry: &tango_patch::index::Entry,
+    progress: impl Fn(Progress) -> bool + crate::marker::WasmNotSend + crate::marker::WasmNotSync,
+) -> Result<Option<Vec<u8>>, Error> {
+    let on_progress = move |downloaded, total| progress(Progress { downloaded, total });
+    let mut last_error = None;
+    for offer in offers {
+        let fetched = get(
+            http,
+            storage,
+            &offer.repo,
+            &offer.path,
+            None,
+            Some(entry.size),
+            Some(&on_progress),
+        )
+        .await;
+
+        let raw = match fetched {
+            Ok(Fetch::Cancelled) => return Ok(None),
+            // Nothing sends a validator here, so a 304 would be the server
+            // misbehaving; treat it as an empty body and let verify reject it.
+            Ok(Fetch::NotModified) => Vec::new(),
+            Ok(Fetch::Body { body, .. }) => body,
+            Err(e) => {
+                log::warn!("{name} {version} from {}: {e}", offer.repo);
+                last_error = Some(e);
+                continue;
+            }
+        };
+
+        if let Err(e) = verify(&raw, entry, name, version) {
+            log::warn!("{name} {version} from {}: {e}", offer.repo);
+            last_error = Some(e);
+            continue;
+        }
+
+        log::info!("fetched {name} {version} from {} ({} bytes)", offer.repo, raw.len());
+        return Ok(Some(raw));
+    }
+    Err(last_error.unwrap_or_else(|| Error::NotOffered {
+        name: name.to_owned(),
+        version: version.clone(),
+    }))
+}
+
+/// The README a repo publishes beside a version, for showing before the
+/// package is downloaded. `None` when the offer names none.
+pub async fn fetch_readme(http: &dyn Http, storage: &dyn Storage, offer: &Offer) -> Result<Option<String>, Error> {
+    let Some(path) = &offer.readme else {
+        return Ok(None);
+    };
+    match get(http, storage, &offer.repo, path, None, None, None).await? {
+        Fetch::Body { body, .. } => Ok(Some(String::from_utf8_lossy(&body).into_owned())),
+        Fetch::NotModified | Fetch::Cancelled => Ok(None),
+    }
+}
+
 /// A downloaded package must be what the index promised, and must be
 /// what it says it is. The hash check is also what makes serving
 /// packages from a mirror or a CDN cache safe.
@@ -597,7 +1028,7 @@ fn verify(raw: &[u8], entry: &tango_patch::index::Entry, name: &str, version: &s
 }
 
 /// Delete an installed package. The next scan drops it from the catalog;
-/// the index still lists it, so it can be reinstalled.
+/// the indexes still list it, so it can be reinstalled.
 pub fn uninstall(
     storage: &dyn Storage,
     patches_path: &Path,
@@ -614,6 +1045,7 @@ mod tests {
     use super::*;
     use crate::http::ReqwestHttp;
     use crate::storage::StdStorage;
+    use crate::testing::{MemStorage, Op, Response, ScriptedHttp};
     use tango_patch::bundle::Builder;
 
     /// The scans and fetches under test take the seam traits; natively
@@ -624,10 +1056,20 @@ mod tests {
         ReqwestHttp::new()
     }
 
+    /// The repo the tests that never touch the network pretend to have
+    /// fetched from.
+    fn offline() -> Repo {
+        Repo::Http("https://patches.example.invalid".into())
+    }
+
     /// Enumerate then scan, the way the frontend's rescan does.
     async fn scanned(root: &Path) -> Catalog {
+        scanned_from(root, &[offline()]).await
+    }
+
+    async fn scanned_from(root: &Path, repos: &[Repo]) -> Catalog {
         let listing = FS.list(&scan_roots(root)).await;
-        scan(FS, root, &listing).unwrap()
+        scan(FS, root, repos, &listing).unwrap()
     }
 
     /// A scratch data directory that cleans up on drop.
@@ -678,6 +1120,12 @@ legal_chip_ranges = [[1, 17], [19, 20]]
 
     /// An index offering `name` at `version` without it being installed.
     fn index_with(root: &Path, entries: &[(&str, &str, &str)]) {
+        index_from(root, &offline(), entries, "0");
+    }
+
+    /// [`index_with`] for a given repo, with every package hashing to
+    /// `hash` repeated.
+    fn index_from(root: &Path, repo: &Repo, entries: &[(&str, &str, &str)], hash: &str) {
         let mut index = tango_patch::Index::default();
         for (name, version, netplay) in entries {
             index.patches.entry((*name).to_owned()).or_default().insert(
@@ -691,12 +1139,13 @@ legal_chip_ranges = [[1, 17], [19, 20]]
                     games: vec!["BR6E_00".parse().unwrap()],
                     path: format!("{name}/{name}-{version}.tangopatch"),
                     size: 1234,
-                    sha256: "0".repeat(64),
+                    sha256: hash.repeat(64),
                     readme: None,
                 },
             );
         }
-        std::fs::write(index_path(root), index.to_json().unwrap()).unwrap();
+        std::fs::create_dir_all(repo.cache_path(root)).unwrap();
+        std::fs::write(repo.index_path(root), index.to_json().unwrap()).unwrap();
     }
 
     fn bn6_falzar() -> GameRef {
@@ -956,7 +1405,7 @@ legal_chip_ranges = [[1, 17], [19, 20]]
             &repo.0,
             &[("bn6_one", "1.0.0", "vanilla"), ("bn6_two", "2.0.0", "group:pair")],
         );
-        let url = serve(repo.0.clone()).await;
+        let url = Repo::Http(serve(repo.0.clone()).await);
         let data = TempDir::new();
 
         // First fetch pulls the index; the second is a 304.
@@ -970,7 +1419,7 @@ legal_chip_ranges = [[1, 17], [19, 20]]
         );
 
         // The whole repo is browsable, with nothing downloaded.
-        let catalog = scanned(&data.0).await;
+        let catalog = scanned_from(&data.0, &[url.clone()]).await;
         assert!(catalog.installed.is_empty());
         assert_eq!(
             catalog.names().into_iter().collect::<Vec<_>>(),
@@ -992,10 +1441,19 @@ legal_chip_ranges = [[1, 17], [19, 20]]
         let entry = catalog.entry("bn6_two", &version).unwrap().clone();
         let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
         let seen_w = seen.clone();
-        download(&net(), FS, &url, &data.0, "bn6_two", &version, &entry, move |p| {
-            seen_w.lock().unwrap().push(p.downloaded);
-            true
-        })
+        download(
+            &net(),
+            FS,
+            catalog.offers("bn6_two", &version),
+            &data.0,
+            "bn6_two",
+            &version,
+            &entry,
+            move |p| {
+                seen_w.lock().unwrap().push(p.downloaded);
+                true
+            },
+        )
         .await
         .unwrap();
         assert!(
@@ -1004,7 +1462,7 @@ legal_chip_ranges = [[1, 17], [19, 20]]
             seen.lock().unwrap()
         );
 
-        let catalog = scanned(&data.0).await;
+        let catalog = scanned_from(&data.0, &[url.clone()]).await;
         assert!(catalog.is_installed("bn6_two", &version));
         assert!(!catalog.is_installed("bn6_one", &v("1.0.0")), "only the asked-for one");
         assert_eq!(
@@ -1025,17 +1483,26 @@ legal_chip_ranges = [[1, 17], [19, 20]]
     async fn a_cancelled_download_leaves_nothing_behind() {
         let repo = TempDir::new();
         build_repo(&repo.0, &[("bn6_one", "1.0.0", "vanilla")]);
-        let url = serve(repo.0.clone()).await;
+        let url = Repo::Http(serve(repo.0.clone()).await);
         let data = TempDir::new();
         fetch_index(&net(), FS, &url, &data.0).await.unwrap();
 
-        let catalog = scanned(&data.0).await;
+        let catalog = scanned_from(&data.0, &[url.clone()]).await;
         let version = v("1.0.0");
         let entry = catalog.entry("bn6_one", &version).unwrap().clone();
 
-        let outcome = download(&net(), FS, &url, &data.0, "bn6_one", &version, &entry, |_| false)
-            .await
-            .unwrap();
+        let outcome = download(
+            &net(),
+            FS,
+            catalog.offers("bn6_one", &version),
+            &data.0,
+            "bn6_one",
+            &version,
+            &entry,
+            |_| false,
+        )
+        .await
+        .unwrap();
         assert!(matches!(outcome, Outcome::Cancelled), "{outcome:?}");
         assert!(!package_path(&data.0, "bn6_one", &version).exists());
         let leftovers: Vec<_> = std::fs::read_dir(&data.0)
@@ -1045,46 +1512,65 @@ legal_chip_ranges = [[1, 17], [19, 20]]
             .filter(|n| n.ends_with(".tmp") || n.ends_with(".part"))
             .collect();
         assert!(leftovers.is_empty(), "left a partial file: {leftovers:?}");
-        assert!(!scanned(&data.0).await.is_installed("bn6_one", &version));
+        assert!(!scanned_from(&data.0, &[url.clone()])
+            .await
+            .is_installed("bn6_one", &version));
     }
 
     #[tokio::test]
     async fn a_package_that_is_not_what_the_index_promised_is_rejected() {
         let repo = TempDir::new();
         build_repo(&repo.0, &[("bn6_one", "1.0.0", "vanilla")]);
-        let url = serve(repo.0.clone()).await;
+        let url = Repo::Http(serve(repo.0.clone()).await);
         let data = TempDir::new();
         fetch_index(&net(), FS, &url, &data.0).await.unwrap();
 
-        let catalog = scanned(&data.0).await;
+        let catalog = scanned_from(&data.0, &[url.clone()]).await;
         let version = v("1.0.0");
         let mut entry = catalog.entry("bn6_one", &version).unwrap().clone();
         entry.sha256 = "0".repeat(64);
 
-        let err = download(&net(), FS, &url, &data.0, "bn6_one", &version, &entry, |_| true)
-            .await
-            .unwrap_err()
-            .to_string();
+        let err = download(
+            &net(),
+            FS,
+            catalog.offers("bn6_one", &version),
+            &data.0,
+            "bn6_one",
+            &version,
+            &entry,
+            |_| true,
+        )
+        .await
+        .unwrap_err()
+        .to_string();
         assert!(err.contains("hash mismatch"), "{err}");
         // Nothing half-written left behind: not the package, and not
         // the temporary it streamed into.
         assert!(!package_path(&data.0, "bn6_one", &version).exists());
-        // Only the index and its validator; no package, and no
+        // Only the cached index and its validator; no package, and no
         // half-written temporary.
-        let mut left: Vec<String> = std::fs::read_dir(&data.0)
-            .unwrap()
-            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
-            .collect();
-        left.sort();
-        assert_eq!(left, vec!["index.etag", "index.json"], "no leftovers");
-        assert!(scanned(&data.0).await.installed.is_empty());
+        let listed = |dir: &Path| {
+            let mut left: Vec<String> = std::fs::read_dir(dir)
+                .unwrap()
+                .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+                .collect();
+            left.sort();
+            left
+        };
+        assert_eq!(listed(&data.0), vec![REPOS_DIR], "no leftovers");
+        assert_eq!(
+            listed(&url.cache_path(&data.0)),
+            vec!["index.etag", "index.json"],
+            "no leftovers"
+        );
+        assert!(scanned_from(&data.0, &[url.clone()]).await.installed.is_empty());
     }
 
     #[tokio::test]
     async fn a_failed_fetch_leaves_the_cached_index_usable() {
         let repo = TempDir::new();
         build_repo(&repo.0, &[("bn6_one", "1.0.0", "vanilla")]);
-        let url = serve(repo.0.clone()).await;
+        let url = Repo::Http(serve(repo.0.clone()).await);
         let data = TempDir::new();
         fetch_index(&net(), FS, &url, &data.0).await.unwrap();
 
@@ -1092,7 +1578,7 @@ legal_chip_ranges = [[1, 17], [19, 20]]
         // last saw, which is what makes the app work offline.
         std::fs::write(repo.0.join(tango_patch::index::FILE_NAME), "not json at all").unwrap();
         assert!(fetch_index(&net(), FS, &url, &data.0).await.is_err());
-        let catalog = scanned(&data.0).await;
+        let catalog = scanned_from(&data.0, &[url.clone()]).await;
         assert_eq!(catalog.names().len(), 1);
         assert!(catalog.entry("bn6_one", &v("1.0.0")).is_some());
     }
@@ -1101,9 +1587,617 @@ legal_chip_ranges = [[1, 17], [19, 20]]
     async fn a_corrupt_index_leaves_the_installed_patches_alone() {
         let dir = TempDir::new();
         install(&dir.0, "bn6_test", "1.0.0", "isolated");
-        std::fs::write(index_path(&dir.0), "{ this is not json").unwrap();
+        std::fs::create_dir_all(offline().cache_path(&dir.0)).unwrap();
+        std::fs::write(offline().index_path(&dir.0), "{ this is not json").unwrap();
         let catalog = scanned(&dir.0).await;
         assert!(catalog.index.is_empty());
         assert!(catalog.is_installed("bn6_test", &v("1.0.0")));
     }
+
+    #[tokio::test]
+    async fn repos_merge_with_the_first_listed_winning_a_conflict() {
+        let dir = TempDir::new();
+        let (public, lan, other) = (
+            offline(),
+            Repo::Dir(dir.0.join("usb")),
+            Repo::Http("https://mirror.example.invalid".into()),
+        );
+        index_from(
+            &dir.0,
+            &public,
+            &[("bn6_test", "1.0.0", "isolated"), ("bn6_shared", "1.0.0", "isolated")],
+            "0",
+        );
+        // Agrees about the shared patch and adds its own.
+        index_from(
+            &dir.0,
+            &lan,
+            &[("bn6_shared", "1.0.0", "isolated"), ("bn6_event", "1.0.0", "isolated")],
+            "0",
+        );
+        // Ships something else under a name and version already taken.
+        index_from(&dir.0, &other, &[("bn6_test", "1.0.0", "vanilla")], "1");
+
+        let catalog = scanned_from(&dir.0, &[public.clone(), lan.clone(), other.clone()]).await;
+        assert_eq!(
+            catalog.names().into_iter().collect::<Vec<_>>(),
+            vec!["bn6_event", "bn6_shared", "bn6_test"]
+        );
+        let from = |name: &str| {
+            catalog
+                .offers(name, &v("1.0.0"))
+                .iter()
+                .map(|o| o.repo.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(from("bn6_shared"), vec![public.clone(), lan.clone()]);
+        assert_eq!(from("bn6_event"), vec![lan]);
+        // The first listing decides; the disagreeing copy is never offered.
+        assert_eq!(from("bn6_test"), vec![public]);
+        assert_eq!(catalog.conflicts("bn6_test", &v("1.0.0")), [other]);
+        assert_eq!(
+            catalog.compatibility("bn6_test", &v("1.0.0")),
+            Some(&Compatibility::Isolated)
+        );
+        assert!(catalog.conflicts("bn6_shared", &v("1.0.0")).is_empty());
+    }
+
+    #[tokio::test]
+    async fn installs_from_a_plain_directory() {
+        let usb = TempDir::new();
+        build_repo(&usb.0, &[("bn6_event", "1.0.0", "group:event")]);
+        let repo = Repo::parse(&format!("file://{}", usb.0.display()));
+        assert_eq!(repo, Repo::Dir(usb.0.clone()));
+        let data = TempDir::new();
+
+        // No validator to send, so an unchanged index is caught by
+        // comparing it with the cached copy instead.
+        assert!(fetch_index(&net(), FS, &repo, &data.0).await.unwrap());
+        assert!(!fetch_index(&net(), FS, &repo, &data.0).await.unwrap());
+
+        let catalog = scanned_from(&data.0, &[repo.clone()]).await;
+        let version = v("1.0.0");
+        let entry = catalog.entry("bn6_event", &version).unwrap().clone();
+        let outcome = download(
+            &net(),
+            FS,
+            catalog.offers("bn6_event", &version),
+            &data.0,
+            "bn6_event",
+            &version,
+            &entry,
+            |_| true,
+        )
+        .await
+        .unwrap();
+        assert!(matches!(outcome, Outcome::Installed), "{outcome:?}");
+        assert!(scanned_from(&data.0, &[repo.clone()])
+            .await
+            .is_installed("bn6_event", &version));
+
+        // Unconfiguring the repo drops its cache, and with it the offer.
+        assert!(fetch_indexes(&net(), FS, &[], &data.0).await.changed);
+        assert!(!repo.index_path(&data.0).exists());
+        assert!(scanned_from(&data.0, &[]).await.index.is_empty());
+    }
+
+    #[tokio::test]
+    async fn a_download_falls_back_to_the_next_repo_offering_it() {
+        let usb = TempDir::new();
+        build_repo(&usb.0, &[("bn6_event", "1.0.0", "vanilla")]);
+        let data = TempDir::new();
+        // Nothing listens on port 1: the public repo is unreachable, as
+        // it is at an event with no internet.
+        let unreachable = Repo::Http("http://127.0.0.1:1".into());
+        let dir = Repo::Dir(usb.0.clone());
+        std::fs::create_dir_all(unreachable.cache_path(&data.0)).unwrap();
+        std::fs::copy(
+            usb.0.join(tango_patch::index::FILE_NAME),
+            unreachable.index_path(&data.0),
+        )
+        .unwrap();
+
+        let refreshed = fetch_indexes(&net(), FS, &[unreachable.clone(), dir.clone()], &data.0).await;
+        assert_eq!(
+            refreshed.failed.iter().map(|(r, _)| r.clone()).collect::<Vec<_>>(),
+            vec![unreachable.clone()]
+        );
+
+        let catalog = scanned_from(&data.0, &[unreachable, dir]).await;
+        let version = v("1.0.0");
+        assert_eq!(catalog.offers("bn6_event", &version).len(), 2);
+        let entry = catalog.entry("bn6_event", &version).unwrap().clone();
+        download(
+            &net(),
+            FS,
+            catalog.offers("bn6_event", &version),
+            &data.0,
+            "bn6_event",
+            &version,
+            &entry,
+            |_| true,
+        )
+        .await
+        .unwrap();
+        assert!(package_path(&data.0, "bn6_event", &version).exists());
+    }
+
+    /// Where the tests over [`ScriptedHttp`] keep their data. Nothing is
+    /// on disk: it is a path into a [`MemStorage`].
+    const MEM_ROOT: &str = "/data/patches";
+
+    /// One package's bytes, built the way [`install`] builds one.
+    fn package(name: &str, version: &str, netplay: &str) -> Vec<u8> {
+        let dir = TempDir::new();
+        install(&dir.0, name, version, netplay);
+        let path = std::fs::read_dir(&dir.0).unwrap().next().unwrap().unwrap().path();
+        std::fs::read(path).unwrap()
+    }
+
+    /// Script `http` to serve `packages` from the repo at `base`, with an
+    /// index listing them under `etag`. Returns the index.
+    fn publish(http: &ScriptedHttp, base: &str, packages: &[(&str, &str, &[u8])], etag: &str) -> String {
+        let mut index = tango_patch::Index::default();
+        for (name, version, raw) in packages {
+            let path = format!("{name}/{name}-{version}.tangopatch");
+            http.serve(format!("{base}/{path}"), Response::ok(*raw));
+            index.patches.entry((*name).to_owned()).or_default().insert(
+                version.parse().unwrap(),
+                tango_patch::index::Entry {
+                    title: format!("Test {name}"),
+                    authors: vec!["Someone <someone@example.com>".into()],
+                    license: None,
+                    source: None,
+                    netplay: Compatibility::Vanilla,
+                    games: vec!["BR6E_00".parse().unwrap()],
+                    path,
+                    size: raw.len() as u64,
+                    sha256: tango_patch::sha256_hex(raw),
+                    readme: None,
+                },
+            );
+        }
+        let index = index.to_json().unwrap();
+        http.serve(
+            format!("{base}/{}", tango_patch::index::FILE_NAME),
+            Response::ok(index.clone()).etag(etag),
+        );
+        index
+    }
+
+    async fn scanned_in(fs: &MemStorage, repos: &[Repo]) -> Catalog {
+        let root = Path::new(MEM_ROOT);
+        let listing = fs.list(&scan_roots(root)).await;
+        scan(fs, root, repos, &listing).unwrap()
+    }
+
+    /// One round of the frontend's autoupdater: refresh every index, and
+    /// rescan only when one changed.
+    async fn poll(http: &ScriptedHttp, fs: &MemStorage, repos: &[Repo], scanner: &Scanner) -> Refreshed {
+        let root = Path::new(MEM_ROOT);
+        let refreshed = fetch_indexes(http, fs, repos, root).await;
+        if refreshed.changed {
+            let listing = fs.list(&scan_roots(root)).await;
+            scanner.rescan(|| scan(fs, root, repos, &listing).ok());
+        }
+        refreshed
+    }
+
+    /// The files a test left in the [`MemStorage`], outside the index
+    /// caches.
+    fn installed_files(fs: &MemStorage) -> Vec<PathBuf> {
+        let repos = Path::new(MEM_ROOT).join(REPOS_DIR);
+        fs.files().into_iter().filter(|p| !p.starts_with(&repos)).collect()
+    }
+
+    #[tokio::test]
+    async fn the_autoupdater_polls_on_the_etag_and_rescans_only_on_a_change() {
+        let (http, fs, scanner) = (ScriptedHttp::new(), MemStorage::new(), Scanner::new());
+        let repo = Repo::Http("https://repo.example".into());
+        let repos = [repo.clone()];
+        let one = package("bn6_one", "1.0.0", "vanilla");
+        publish(
+            &http,
+            "https://repo.example",
+            &[("bn6_one", "1.0.0", &one[..])],
+            "\"1\"",
+        );
+
+        assert!(poll(&http, &fs, &repos, &scanner).await.changed);
+        assert_eq!(scanner.read().newest_version("bn6_one", None), Some(v("1.0.0")));
+
+        // Nothing new: a 304, and nothing written.
+        let written = fs.mutations().len();
+        assert!(!poll(&http, &fs, &repos, &scanner).await.changed);
+        assert_eq!(http.requests().last().unwrap().if_none_match.as_deref(), Some("\"1\""));
+        assert_eq!(fs.mutations().len(), written);
+
+        // A new version is published.
+        let newer = package("bn6_one", "1.1.0", "vanilla");
+        publish(
+            &http,
+            "https://repo.example",
+            &[("bn6_one", "1.0.0", &one[..]), ("bn6_one", "1.1.0", &newer[..])],
+            "\"2\"",
+        );
+        assert!(poll(&http, &fs, &repos, &scanner).await.changed);
+        assert_eq!(scanner.read().newest_version("bn6_one", None), Some(v("1.1.0")));
+        assert_eq!(
+            fs.get(repo.cache_path(Path::new(MEM_ROOT)).join("index.etag"))
+                .as_deref(),
+            Some(&b"\"2\""[..])
+        );
+
+        // The repo is dropped from the settings: its cache goes with it.
+        assert!(poll(&http, &fs, &[], &scanner).await.changed);
+        assert!(fs.files().is_empty(), "{:?}", fs.files());
+        assert!(scanner.read().index.is_empty());
+    }
+
+    #[tokio::test]
+    async fn the_validator_is_only_sent_with_the_index_it_describes() {
+        let (http, fs) = (ScriptedHttp::new(), MemStorage::new());
+        let repo = Repo::Http("https://repo.example".into());
+        let root = Path::new(MEM_ROOT);
+        publish(&http, "https://repo.example", &[], "\"1\"");
+        assert!(fetch_index(&http, &fs, &repo, root).await.unwrap());
+
+        // The cached body went missing: a 304 would leave us with none.
+        fs.remove_file(&repo.index_path(root)).unwrap();
+        assert!(fetch_index(&http, &fs, &repo, root).await.unwrap());
+        assert_eq!(http.requests().last().unwrap().if_none_match, None);
+
+        // A new index without an ETag: the old one mustn't outlive it.
+        let one = package("bn6_one", "1.0.0", "vanilla");
+        let index = publish(
+            &http,
+            "https://repo.example",
+            &[("bn6_one", "1.0.0", &one[..])],
+            "\"2\"",
+        );
+        http.serve(
+            format!("https://repo.example/{}", tango_patch::index::FILE_NAME),
+            Response::ok(index),
+        );
+        assert!(fetch_index(&http, &fs, &repo, root).await.unwrap());
+        assert!(!fs.is_file(&repo.cache_path(root).join("index.etag")));
+    }
+
+    #[tokio::test]
+    async fn an_index_that_cannot_be_stored_keeps_the_cached_one_and_its_etag() {
+        let (http, fs) = (ScriptedHttp::new(), MemStorage::new());
+        let repo = Repo::Http("https://repo.example".into());
+        let root = Path::new(MEM_ROOT);
+        publish(&http, "https://repo.example", &[], "\"1\"");
+        fetch_index(&http, &fs, &repo, root).await.unwrap();
+        let cached = fs.get(repo.index_path(root)).unwrap();
+
+        let one = package("bn6_one", "1.0.0", "vanilla");
+        publish(
+            &http,
+            "https://repo.example",
+            &[("bn6_one", "1.0.0", &one[..])],
+            "\"2\"",
+        );
+        fs.fail_once(Op::Rename, repo.index_path(root));
+        assert!(matches!(fetch_index(&http, &fs, &repo, root).await, Err(Error::Io(_))));
+        assert_eq!(fs.get(repo.index_path(root)), Some(cached));
+        assert_eq!(
+            fs.files(),
+            vec![repo.cache_path(root).join("index.etag"), repo.index_path(root)],
+            "no temporary left behind"
+        );
+
+        // Still validated against the old ETag, so the retry fetches the
+        // new index rather than being told nothing changed.
+        assert!(fetch_index(&http, &fs, &repo, root).await.unwrap());
+        assert_eq!(http.requests().last().unwrap().if_none_match.as_deref(), Some("\"1\""));
+        assert_eq!(scanned_in(&fs, &[repo]).await.names().len(), 1);
+
+        // A server error is just a failed repo.
+        http.serve(
+            format!("https://repo.example/{}", tango_patch::index::FILE_NAME),
+            Response::status(503),
+        );
+        let refreshed = fetch_indexes(&http, &fs, &[Repo::Http("https://repo.example".into())], root).await;
+        assert!(matches!(
+            refreshed.failed.as_slice(),
+            [(_, Error::Http(http::Error::Status(503)))]
+        ));
+    }
+
+    #[tokio::test]
+    async fn the_single_repo_index_cache_is_cleared_away() {
+        let fs = MemStorage::new();
+        let root = Path::new(MEM_ROOT);
+        for name in ["index.json", "index.etag", "bn6_one-1.0.0.tangopatch"] {
+            fs.insert(root.join(name), b"old".to_vec());
+        }
+        fetch_indexes(&ScriptedHttp::new(), &fs, &[], root).await;
+        assert_eq!(fs.files(), vec![root.join("bn6_one-1.0.0.tangopatch")]);
+    }
+
+    #[tokio::test]
+    async fn installs_then_uninstalls_leaving_the_offer() {
+        let (http, fs) = (ScriptedHttp::new(), MemStorage::new());
+        let repo = Repo::Http("https://repo.example".into());
+        let root = Path::new(MEM_ROOT);
+        let raw = package("bn6_one", "1.0.0", "vanilla");
+        publish(
+            &http,
+            "https://repo.example",
+            &[("bn6_one", "1.0.0", &raw[..])],
+            "\"1\"",
+        );
+        http.serve(
+            "https://repo.example/bn6_one/bn6_one-1.0.0.tangopatch",
+            Response::ok(raw.clone()).chunked(64),
+        );
+        fetch_index(&http, &fs, &repo, root).await.unwrap();
+
+        let catalog = scanned_in(&fs, &[repo.clone()]).await;
+        let version = v("1.0.0");
+        let entry = catalog.entry("bn6_one", &version).unwrap().clone();
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen_w = seen.clone();
+        let outcome = download(
+            &http,
+            &fs,
+            catalog.offers("bn6_one", &version),
+            root,
+            "bn6_one",
+            &version,
+            &entry,
+            move |p| {
+                seen_w.lock().unwrap().push((p.downloaded, p.total));
+                true
+            },
+        )
+        .await
+        .unwrap();
+        assert!(matches!(outcome, Outcome::Installed), "{outcome:?}");
+        let seen = seen.lock().unwrap().clone();
+        assert!(seen.len() > 2, "one report per chunk: {seen:?}");
+        assert!(seen.windows(2).all(|w| w[0].0 < w[1].0), "{seen:?}");
+        assert_eq!(seen.last(), Some(&(entry.size, entry.size)));
+        assert_eq!(http.requests().last().unwrap().max_len, Some(entry.size));
+
+        let installed = package_path(root, "bn6_one", &version);
+        assert_eq!(installed_files(&fs), vec![installed.clone()]);
+        assert_eq!(fs.get(&installed), Some(raw));
+        assert!(scanned_in(&fs, &[repo.clone()]).await.is_installed("bn6_one", &version));
+
+        uninstall(&fs, root, "bn6_one", &version).unwrap();
+        let catalog = scanned_in(&fs, &[repo]).await;
+        assert!(!catalog.is_installed("bn6_one", &version));
+        assert!(catalog.entry("bn6_one", &version).is_some(), "still offered");
+        assert!(matches!(
+            uninstall(&fs, root, "bn6_one", &version),
+            Err(Error::Io(e)) if e.kind() == std::io::ErrorKind::NotFound
+        ));
+    }
+
+    #[tokio::test]
+    async fn a_fetched_package_is_verified_but_not_installed() {
+        let (http, fs) = (ScriptedHttp::new(), MemStorage::new());
+        let repo = Repo::Http("https://repo.example".into());
+        let root = Path::new(MEM_ROOT);
+        let raw = package("bn6_one", "1.0.0", "vanilla");
+        publish(
+            &http,
+            "https://repo.example",
+            &[("bn6_one", "1.0.0", &raw[..])],
+            "\"1\"",
+        );
+        http.serve(
+            "https://repo.example/bn6_one/bn6_one-1.0.0.tangopatch",
+            Response::ok(raw.clone()),
+        );
+        fetch_index(&http, &fs, &repo, root).await.unwrap();
+
+        let catalog = scanned_in(&fs, &[repo]).await;
+        let version = v("1.0.0");
+        let entry = catalog.entry("bn6_one", &version).unwrap();
+        let fetched = fetch_package(
+            &http,
+            &fs,
+            catalog.offers("bn6_one", &version),
+            "bn6_one",
+            &version,
+            entry,
+            |_| true,
+        )
+        .await
+        .unwrap();
+        assert_eq!(fetched, Some(raw));
+        assert_eq!(installed_files(&fs), vec![]);
+    }
+
+    /// A transfer that breaks off, and one that overruns the size the
+    /// index promised, each move on to the next offer — and neither
+    /// writes anything.
+    #[tokio::test]
+    async fn a_broken_or_oversized_transfer_falls_back_and_writes_nothing() {
+        let (http, fs) = (ScriptedHttp::new(), MemStorage::new());
+        let (public, mirror) = (
+            Repo::Http("https://repo.example".into()),
+            Repo::Http("https://mirror.example".into()),
+        );
+        let root = Path::new(MEM_ROOT);
+        let raw = package("bn6_one", "1.0.0", "vanilla");
+        for base in ["https://repo.example", "https://mirror.example"] {
+            publish(&http, base, &[("bn6_one", "1.0.0", &raw[..])], "\"1\"");
+        }
+        let repos = [public, mirror];
+        assert!(fetch_indexes(&http, &fs, &repos, root).await.changed);
+
+        let path = "bn6_one/bn6_one-1.0.0.tangopatch";
+        http.serve(
+            format!("https://repo.example/{path}"),
+            Response::ok(raw.clone()).chunked(16).fail_after(32),
+        );
+        let mut oversized = raw.clone();
+        oversized.extend_from_slice(b"and then some");
+        http.serve(
+            format!("https://mirror.example/{path}"),
+            Response::ok(oversized).without_content_length(),
+        );
+
+        let catalog = scanned_in(&fs, &repos).await;
+        let version = v("1.0.0");
+        let entry = catalog.entry("bn6_one", &version).unwrap().clone();
+        let fetch = || {
+            download(
+                &http,
+                &fs,
+                catalog.offers("bn6_one", &version),
+                root,
+                "bn6_one",
+                &version,
+                &entry,
+                |_| true,
+            )
+        };
+        assert!(matches!(
+            fetch().await,
+            Err(Error::Http(http::Error::TooLarge { limit })) if limit == entry.size
+        ));
+        assert_eq!(
+            http.requests()
+                .iter()
+                .filter(|r| r.url.ends_with(path))
+                .map(|r| r.url.as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                format!("https://repo.example/{path}"),
+                format!("https://mirror.example/{path}")
+            ]
+        );
+        assert!(installed_files(&fs).is_empty(), "{:?}", installed_files(&fs));
+
+        // The mirror is fixed.
+        http.serve(format!("https://mirror.example/{path}"), Response::ok(raw));
+        assert!(matches!(fetch().await, Ok(Outcome::Installed)));
+        assert_eq!(installed_files(&fs), vec![package_path(root, "bn6_one", &version)]);
+    }
+
+    #[tokio::test]
+    async fn an_install_cut_short_leaves_nothing_behind() {
+        let (http, fs) = (ScriptedHttp::new(), MemStorage::new());
+        let repo = Repo::Http("https://repo.example".into());
+        let root = Path::new(MEM_ROOT);
+        let raw = package("bn6_one", "1.0.0", "vanilla");
+        publish(
+            &http,
+            "https://repo.example",
+            &[("bn6_one", "1.0.0", &raw[..])],
+            "\"1\"",
+        );
+        http.serve(
+            "https://repo.example/bn6_one/bn6_one-1.0.0.tangopatch",
+            Response::ok(raw).chunked(16),
+        );
+        fetch_index(&http, &fs, &repo, root).await.unwrap();
+        let catalog = scanned_in(&fs, &[repo]).await;
+        let version = v("1.0.0");
+        let entry = catalog.entry("bn6_one", &version).unwrap().clone();
+        let offers = catalog.offers("bn6_one", &version);
+
+        // Cancelled after the first chunk.
+        let outcome = download(&http, &fs, offers, root, "bn6_one", &version, &entry, |p| {
+            p.downloaded < 16
+        })
+        .await
+        .unwrap();
+        assert!(matches!(outcome, Outcome::Cancelled), "{outcome:?}");
+        assert!(installed_files(&fs).is_empty());
+
+        // Verified, but it can't be moved into place.
+        let installed = package_path(root, "bn6_one", &version);
+        fs.fail_once(Op::Rename, &installed);
+        assert!(matches!(
+            download(&http, &fs, offers, root, "bn6_one", &version, &entry, |_| true).await,
+            Err(Error::Io(_))
+        ));
+        assert!(installed_files(&fs).is_empty(), "{:?}", installed_files(&fs));
+
+        download(&http, &fs, offers, root, "bn6_one", &version, &entry, |_| true)
+            .await
+            .unwrap();
+        assert_eq!(installed_files(&fs), vec![installed]);
+    }
+
+    #[test]
+    fn repo_locations_parse() {
+        assert_eq!(
+            Repo::parse(" https://patches.tango.n1gp.net "),
+            Repo::Http("https://patches.tango.n1gp.net".into())
+        );
+        assert_eq!(
+            Repo::parse("/media/usb/patches"),
+            Repo::Dir("/media/usb/patches".into())
+        );
+        assert_eq!(
+            Repo::parse("file:///media/usb/lan%20event"),
+            Repo::Dir("/media/usb/lan event".into())
+        );
+        assert_eq!(
+            Repo::parse("file://localhost/srv/patches"),
+            Repo::Dir("/srv/patches".into())
+        );
+        assert_eq!(Repo::parse("file:///C:/patches"), Repo::Dir("C:/patches".into()));
+        // Distinct locations get distinct caches.
+        for (a, b) in [
+            ("https://a.example/x", "/a.example/x"),
+            ("https://a.example/x/y", "https://a.example/x_y"),
+            ("/srv/Patches", "/srv/patches"),
+        ] {
+            assert_ne!(
+                Repo::parse(a).cache_path(Path::new("p")),
+                Repo::parse(b).cache_path(Path::new("p"))
+            );
+        }
+        assert_eq!(
+            Repo::parse("https://patches.tango.n1gp.net/").cache_path(Path::new("p")),
+            Path::new("p/repos/http-patches.tango.n1gp.net")
+        );
+    }
+
+    #[test]
+    fn the_changelog_is_the_readme_section_under_its_heading() {
+        let readme = "# bn6_test\n\nIntro.\n\n## Changelog\n\n### 1.1.0\n- Cannon does 60\n\n```\n# not a heading\n```\n\n## Credits\nSomeone\n";
+        assert_eq!(
+            changelog(readme).as_deref(),
+            Some("### 1.1.0\n- Cannon does 60\n\n```\n# not a heading\n```")
+        );
+        assert_eq!(changelog("# What's New\nEverything").as_deref(), Some("Everything"));
+        assert_eq!(changelog("# bn6_test\n\nNo changes listed."), None);
+        assert_eq!(changelog("## Changes\n\n## Credits"), None);
+    }
+
+    #[test]
+    fn a_directory_repo_index_cannot_reach_outside_it() {
+        assert_eq!(
+            repo_relative("bn6/bn6-1.0.0.tangopatch").unwrap(),
+            Path::new("bn6").join("bn6-1.0.0.tangopatch")
+        );
+        for bad in ["../secret", "/etc/passwd", "a//b", "a\\..\\b", "C:/x", "a/./b"] {
+            assert!(matches!(repo_relative(bad), Err(Error::BadPath(_))), "{bad}");
+        }
+    }
+
+    #[test]
+    fn an_uninstalled_package_applies_for_the_games_it_targets() {
+        let raw = package("bn6_test", "1.0.0", "group:testing");
+        // The package's BPS is a stand-in, so getting as far as decoding
+        // it means the manifest read and the target matched.
+        assert!(matches!(
+            apply_package(&raw, b"rom", bn6_falzar()),
+            Err(Error::BpsDecode(_))
+        ));
+        assert!(matches!(
+            apply_package(&raw, b"rom", bn6_gregar()),
+            Err(Error::Package(_))
+        ));
+    }
 }
@@ -1,8 +1,18 @@
+use crate::fingerprint::Fingerprints;
 use crate::scanner;
-use crate::storage::{Listing, Storage};
+use crate::storage::{Entry, Listing, Storage};
 
 pub type GameRef = tango_gamesupport::GameRef;
-pub type Scanner = scanner::Scanner<std::collections::HashMap<GameRef, Vec<u8>>>;
+pub type Roms = std::collections::HashMap<GameRef, Rom>;
+pub type Scanner = scanner::Scanner<Roms>;
+
+/// A ROM the scan found: the image, and the file (or archive member) it
+/// was read from.
+#[derive(Clone)]
+pub struct Rom {
+    pub source: Source,
+    pub bytes: Vec<u8>,
+}
 
 /// Everything [`scan_roms`] reads: the configured roms dir plus any
 /// BNLC Steam per-game archives. Feeds the scanner's change-detection
@@ -22,35 +32,279 @@ pub fn scan_roots(roms_path: &std::path::Path) -> Vec<std::path::PathBuf> {
 }
 
 /// Discover ROMs from the library's roms directory, plus — natively —
-/// any Steam-installed BN Legacy Collection volumes.
+/// any Steam-installed BN Legacy Collection volumes. `.zip`, `.7z` and
+/// `.gz` archives in the roms directory are looked inside, and every
+/// supported ROM in one is picked up.
 ///
 /// `listing` is the snapshot of [`scan_roots`] the caller already
 /// gathered; see [`Listing`] for why the enumeration happens there and
-/// not here.
-pub fn scan_roms(storage: &dyn Storage, listing: &Listing) -> std::collections::HashMap<GameRef, Vec<u8>> {
-    let mut roms = std::collections::HashMap::new();
+/// not here. Files `fingerprints` vouches for skip detection, and what
+/// was found in the rest is recorded there.
+pub fn scan_roms(storage: &dyn Storage, listing: &Listing, fingerprints: &mut Fingerprints) -> Roms {
+    let mut roms = Roms::new();
     #[cfg(all(feature = "native", not(target_arch = "wasm32")))]
     roms.extend(crate::bnlc::scan_steam_roms());
-    roms.extend(scan_stored_roms(storage, listing));
+    roms.extend(scan_stored_roms(storage, listing, fingerprints));
     roms
 }
 
-fn scan_stored_roms(storage: &dyn Storage, listing: &Listing) -> std::collections::HashMap<GameRef, Vec<u8>> {
-    let mut roms = std::collections::HashMap::new();
+fn scan_stored_roms(storage: &dyn Storage, listing: &Listing, fingerprints: &mut Fingerprints) -> Roms {
+    fingerprints.retain_roms(listing);
+    let mut roms = Roms::new();
     for entry in listing.entries() {
-        let mut buf = match storage.read(&entry.path) {
+        let found = fingerprints
+            .roms(entry)
+            .and_then(|cached| read_cached_roms(storage, entry, &cached))
+            .unwrap_or_else(|| read_roms(storage, entry));
+        fingerprints.set_roms(
+            entry,
+            found.iter().map(|(source, game, _)| (source.member.clone(), *game)),
+        );
+        for (source, game, bytes) in found {
+            log::info!("rom scan: {source}: {:?}", game.family_and_variant());
+            roms.insert(game, Rom { source, bytes });
+        }
+    }
+    roms
+}
+
+/// Where a ROM in the library was read from: a file in the roms
+/// directory, or a member of an archive there.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Source {
+    pub path: std::path::PathBuf,
+    /// The ROM's path inside the archive at `path`, when it's one.
+    pub member: Option<std::path::PathBuf>,
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.member {
+            Some(member) => write!(f, "{}/{}", self.path.display(), member.display()),
+            None => write!(f, "{}", self.path.display()),
+        }
+    }
+}
+
+/// The ROMs `cached` says `entry` holds, read without detecting them:
+/// the fingerprint already vouches that the file is the one they were
+/// detected in. `None` if it didn't work out that way after all — a
+/// member gone missing, a ROM longer than its game's — and the caller
+/// should detect from scratch.
+fn read_cached_roms(
+    storage: &dyn Storage,
+    entry: &Entry,
+    cached: &[(Option<std::path::PathBuf>, GameRef)],
+) -> Option<Vec<(Source, GameRef, Vec<u8>)>> {
+    // Known to hold nothing: not even opened.
+    if cached.is_empty() {
+        return Some(vec![]);
+    }
+    // The same growth `detect` gives a trimmed dump.
+    let restore = |game: GameRef, mut buf: Vec<u8>| {
+        if buf.len() > game.rom_size() {
+            return None;
+        }
+        buf.resize(game.rom_size(), 0xff);
+        Some(buf)
+    };
+    let path = &entry.path;
+    let Some(kind) = crate::archive::Kind::of(path) else {
+        let [(None, game)] = cached else {
+            return None;
+        };
+        let buf = storage.read(path).ok()?;
+        let source = Source {
+            path: path.clone(),
+            member: None,
+        };
+        return Some(vec![(source, *game, restore(*game, buf)?)]);
+    };
+
+    let game_of = |member: &std::path::Path| {
+        cached
+            .iter()
+            .find(|(m, _)| m.as_deref() == Some(member))
+            .map(|(_, game)| *game)
+    };
+    let reader = storage.open(path).ok()?;
+    let mut found = vec![];
+    crate::archive::for_each_member(
+        kind,
+        path,
+        reader,
+        entry.len,
+        |member| game_of(member).is_some(),
+        |member, buf| {
+            let Some(game) = game_of(member) else {
+                return;
+            };
+            let source = Source {
+                path: path.clone(),
+                member: Some(member.to_path_buf()),
+            };
+            found.push(restore(game, buf).map(|buf| (source, game, buf)));
+        },
+    )
+    .ok()?;
+    let found = found.into_iter().collect::<Option<Vec<_>>>()?;
+    (found.len() == cached.len()).then_some(found)
+}
+
+/// Every supported ROM in `entry`: the file itself, or — for a `.zip`,
+/// `.7z` or `.gz` — each member of it that detects as one.
+fn read_roms(storage: &dyn Storage, entry: &Entry) -> Vec<(Source, GameRef, Vec<u8>)> {
+    let path = &entry.path;
+    let Some(kind) = crate::archive::Kind::of(path) else {
+        let mut buf = match storage.read(path) {
             Ok(b) => b,
             Err(e) => {
-                log::warn!("{}: {e}", entry.path.display());
-                continue;
+                log::warn!("{}: {e}", path.display());
+                return vec![];
             }
         };
         let Some(game) = crate::game::detect(&mut buf) else {
-            log::debug!("rom scan: {}: not a recognized rom", entry.path.display());
-            continue;
+            log::debug!("rom scan: {}: not a recognized rom", path.display());
+            return vec![];
+        };
+        let source = Source {
+            path: path.clone(),
+            member: None,
         };
-        log::info!("rom scan: {}: {:?}", entry.path.display(), game.family_and_variant());
-        roms.insert(game, buf);
+        return vec![(source, game, buf)];
+    };
+
+    let reader = match storage.open(path) {
+        Ok(r) => r,
+        Err(e) => {
+            log::warn!("{}: {e}", path.display());
+            return vec![];
+        }
+    };
+    let mut found = vec![];
+    let result = crate::archive::for_each_member(
+        kind,
+        path,
+        reader,
+        entry.len,
+        |_| true,
+        |member, mut buf| {
+            let source = Source {
+                path: path.clone(),
+                member: Some(member.to_path_buf()),
+            };
+            let Some(game) = crate::game::detect(&mut buf) else {
+                log::debug!("rom scan: {source}: not a recognized rom");
+                return;
+            };
+            found.push((source, game, buf));
+        },
+    );
+    if let Err(e) = result {
+        log::warn!("rom scan: {}: {e}", path.display());
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{rom_image, MemStorage, Op};
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    fn bn6_falzar() -> GameRef {
+        crate::game::find_by_rom_info(b"BR6E", 0).expect("gamesupport-bn6 must be enabled for this test")
+    }
+
+    fn bn6_gregar() -> GameRef {
+        crate::game::find_by_rom_info(b"BR5E", 0).expect("gamesupport-bn6 must be enabled for this test")
+    }
+
+    fn zip(members: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut za = zip::ZipWriter::new(std::io::Cursor::new(vec![]));
+        let options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+        for (name, data) in members {
+            za.start_file(*name, options).unwrap();
+            za.write_all(data).unwrap();
+        }
+        za.finish().unwrap().into_inner()
+    }
+
+    async fn scan(fs: &MemStorage, fingerprints: &mut Fingerprints) -> Roms {
+        let listing = fs.list(&[PathBuf::from("/roms")]).await;
+        scan_stored_roms(fs, &listing, fingerprints)
+    }
+
+    #[tokio::test]
+    async fn finds_roms_loose_and_inside_archives_through_storage() {
+        let (falzar, gregar) = (bn6_falzar(), bn6_gregar());
+        let fs = MemStorage::new();
+        fs.insert("/roms/falzar.gba", rom_image(falzar));
+        fs.insert(
+            "/roms/set.zip",
+            zip(&[("gba/gregar.gba", &rom_image(gregar)[..]), ("readme.txt", &b"hi"[..])]),
+        );
+        fs.insert("/roms/notes.txt", "not a rom");
+
+        let roms = scan(&fs, &mut Fingerprints::default()).await;
+        assert_eq!(roms.len(), 2);
+        assert_eq!(
+            roms[&falzar].source,
+            Source {
+                path: PathBuf::from("/roms/falzar.gba"),
+                member: None,
+            }
+        );
+        assert_eq!(roms[&falzar].bytes, rom_image(falzar));
+        assert_eq!(
+            roms[&gregar].source,
+            Source {
+                path: PathBuf::from("/roms/set.zip"),
+                member: Some(PathBuf::from("gba/gregar.gba")),
+            }
+        );
+        assert_eq!(roms[&gregar].bytes, rom_image(gregar));
+        assert_eq!(roms[&gregar].source.to_string(), "/roms/set.zip/gba/gregar.gba");
+    }
+
+    #[tokio::test]
+    async fn a_rescan_reads_roms_without_detecting_them_and_skips_the_rest() {
+        let (falzar, gregar) = (bn6_falzar(), bn6_gregar());
+        let fs = MemStorage::new();
+        fs.insert("/roms/falzar.gba", rom_image(falzar));
+        fs.insert("/roms/set.zip", zip(&[("gregar.gba", &rom_image(gregar)[..])]));
+        fs.insert("/roms/notes.txt", "not a rom");
+        let cache = std::path::Path::new("/cache");
+
+        let mut fingerprints = Fingerprints::load(&fs, cache);
+        let first = scan(&fs, &mut fingerprints).await;
+        fingerprints.store(&fs);
+
+        // As the next launch would, through the stored cache: each ROM
+        // is read for its bytes, and the file known to hold none isn't
+        // opened at all.
+        let before = fs.reads().len();
+        let mut fingerprints = Fingerprints::load(&fs, cache);
+        let again = scan(&fs, &mut fingerprints).await;
+        assert_eq!(
+            fs.reads()[before..],
+            [
+                (Op::Read, cache.join("scan-fingerprints.json")),
+                (Op::Read, PathBuf::from("/roms/falzar.gba")),
+                (Op::Open, PathBuf::from("/roms/set.zip")),
+            ]
+        );
+        assert_eq!(again.len(), 2);
+        for game in [falzar, gregar] {
+            assert_eq!(again[&game].source, first[&game].source);
+            assert_eq!(again[&game].bytes, first[&game].bytes);
+        }
+
+        // Until it changes.
+        fs.insert("/roms/notes.txt", "still not a rom");
+        let before = fs.reads().len();
+        scan(&fs, &mut fingerprints).await;
+        assert!(fs.reads()[before..].contains(&(Op::Read, PathBuf::from("/roms/notes.txt"))));
     }
-    roms
 }
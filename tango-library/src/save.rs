@@ -1,3 +1,4 @@
+use crate::fingerprint::Fingerprints;
 use crate::storage::{Listing, Storage};
 use crate::{rom::GameRef, scanner};
 
@@ -17,32 +18,53 @@ impl Clone for ScannedSave {
 
 pub type Scanner = scanner::Scanner<std::collections::HashMap<GameRef, Vec<ScannedSave>>>;
 
-pub fn scan_saves(storage: &dyn Storage, listing: &Listing) -> std::collections::HashMap<GameRef, Vec<ScannedSave>> {
+/// Every save in `listing`, by the games it parses as. A file
+/// `fingerprints` vouches for is parsed only as the games it was last
+/// time — or, if it was none, not read at all.
+pub fn scan_saves(
+    storage: &dyn Storage,
+    listing: &Listing,
+    fingerprints: &mut Fingerprints,
+) -> std::collections::HashMap<GameRef, Vec<ScannedSave>> {
     let mut by_game: std::collections::HashMap<GameRef, Vec<ScannedSave>> = std::collections::HashMap::new();
 
+    fingerprints.retain_saves(listing);
     for entry in listing.entries() {
-        let buf = match storage.read(&entry.path) {
-            Ok(b) => b,
+        let cached = fingerprints.save_games(entry);
+        if cached.as_ref().is_some_and(|games| games.is_empty()) {
+            continue;
+        }
+
+        let buf = match read_save(storage, &entry.path) {
+            Ok(b) => b.image,
             Err(e) => {
                 log::warn!("{}: {e}", entry.path.display());
                 continue;
             }
         };
 
-        let mut matched = false;
-        for game in crate::game::GAMES.iter().copied() {
-            if let Ok(save) = game.parse_save(&buf) {
-                by_game.entry(game).or_default().push(ScannedSave {
-                    path: entry.path.clone(),
-                    save,
-                });
-                matched = true;
-            }
-        }
+        let parse = |games: &[GameRef]| {
+            games
+                .iter()
+                .filter_map(|&game| Some((game, game.parse_save(&buf).ok()?)))
+                .collect::<Vec<_>>()
+        };
+        // A cached game that no longer parses means the cache was wrong
+        // about this file; ask everyone.
+        let parsed = cached
+            .and_then(|games| Some(parse(&games)).filter(|p| p.len() == games.len()))
+            .unwrap_or_else(|| parse(&crate::game::GAMES));
 
-        if !matched {
+        if parsed.is_empty() {
             log::warn!("save scan: {}: no matching game", entry.path.display());
         }
+        fingerprints.set_save_games(entry, parsed.iter().map(|(game, _)| *game));
+        for (game, save) in parsed {
+            by_game.entry(game).or_default().push(ScannedSave {
+                path: entry.path.clone(),
+                save,
+            });
+        }
     }
 
     for (_, saves) in by_game.iter_mut() {
@@ -60,3 +82,280 @@ pub fn scan_saves(storage: &dyn Storage, listing: &Listing) -> std::collections:
 
     by_game
 }
+
+/// What a byte of flash reads as before anything is written to it, and
+/// so what an image is grown with.
+const ERASED: u8 = 0xff;
+
+/// The footer DeSmuME appends to a `.dsv`: this text, six little-endian
+/// `u32`s (the image's actual and padded sizes, then the save type,
+/// address size, memory size and a version), and [`DESMUME_MAGIC`].
+const DESMUME_TEXT: &[u8] = b"|<--Snip above here to create a raw sav by excluding this DeSmuME savedata footer:";
+const DESMUME_MAGIC: &[u8] = b"|-DESMUME SAVE-|";
+const DESMUME_FOOTER_LEN: usize = DESMUME_TEXT.len() + 6 * 4 + DESMUME_MAGIC.len();
+
+/// The most a GBA emulator is taken to append past a chip-sized image —
+/// an RTC snapshot, typically, which is a few dozen bytes. Anything
+/// bigger is a dump of some other size, and left alone.
+const MAX_TRAILER_LEN: usize = 0x200;
+
+/// The smallest chip a trailer is looked for behind: the 8 KiB SRAM.
+const MIN_CHIP_LEN: usize = 0x2000;
+
+/// How an emulator wrapped the cart's image in the file it wrote.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Wrapper {
+    /// The file is the image, at whatever length the dumper chose.
+    Raw,
+    /// A chip-sized image followed by emulator state (VBA-M and mGBA
+    /// keep the cart clock there). Written back untouched.
+    Trailer(Vec<u8>),
+    /// A DeSmuME `.dsv`: the image followed by a footer recording its
+    /// size. Written back with the sizes brought up to date.
+    Desmume(Vec<u8>),
+}
+
+/// A save file split into the cart's image — what `parse_save` and the
+/// emulator want — and whatever the emulator that wrote it put around
+/// it, so an edited image can be written back in the same shape.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Unwrapped {
+    pub image: Vec<u8>,
+    pub wrapper: Wrapper,
+}
+
+impl Unwrapped {
+    pub fn from_file(file: &[u8]) -> Self {
+        if let Some(at) = file.len().checked_sub(DESMUME_FOOTER_LEN) {
+            let footer = &file[at..];
+            if footer.starts_with(DESMUME_TEXT) && footer.ends_with(DESMUME_MAGIC) {
+                return Self {
+                    image: file[..at].to_vec(),
+                    wrapper: Wrapper::Desmume(footer.to_vec()),
+                };
+            }
+        }
+
+        // A power of two is a whole chip; past one by a little, the rest
+        // is the emulator's.
+        let chip = 1 << file.len().max(1).ilog2();
+        if chip >= MIN_CHIP_LEN && file.len() > chip && file.len() - chip <= MAX_TRAILER_LEN {
+            return Self {
+                image: file[..chip].to_vec(),
+                wrapper: Wrapper::Trailer(file[chip..].to_vec()),
+            };
+        }
+
+        Self {
+            image: file.to_vec(),
+            wrapper: Wrapper::Raw,
+        }
+    }
+
+    /// The file to write for `sram`, a cart image as a save's
+    /// `to_sram_dump` or a running session hands it back.
+    ///
+    /// It is laid over the image this file held rather than replacing
+    /// it, since a game's dump can be shorter than the file it came from
+    /// (BN1's 32K of SRAM in a 64K `.sav`) and the rest is kept. Nor does
+    /// it grow the image with erased flash: a dump the dumper trimmed
+    /// stays trimmed unless the game actually wrote past its end.
+    pub fn rewrap(&self, sram: &[u8]) -> Vec<u8> {
+        let written = sram.iter().rposition(|&b| b != ERASED).map_or(0, |i| i + 1);
+        let mut image = self.image.clone();
+        image.resize(image.len().max(written), ERASED);
+        let n = sram.len().min(image.len());
+        image[..n].copy_from_slice(&sram[..n]);
+
+        match &self.wrapper {
+            Wrapper::Raw => {}
+            Wrapper::Trailer(trailer) => image.extend_from_slice(trailer),
+            Wrapper::Desmume(footer) => {
+                let at = image.len() + DESMUME_TEXT.len();
+                let len = (image.len() as u32).to_le_bytes();
+                let resized = image.len() != self.image.len();
+                image.extend_from_slice(footer);
+                if resized {
+                    image[at..][..4].copy_from_slice(&len);
+                    image[at + 4..][..4].copy_from_slice(&len);
+                }
+            }
+        }
+        image
+    }
+}
+
+/// Read the save at `path` and unwrap it.
+pub fn read_save(storage: &dyn Storage, path: &std::path::Path) -> std::io::Result<Unwrapped> {
+    Ok(Unwrapped::from_file(&storage.read(path)?))
+}
+
+/// Write `sram` to the save at `path`, in whatever wrapper the file
+/// there already has. A new file is written as the bare image.
+///
+/// With a `history`, the file being replaced is archived into it first
+/// (`now` being when, in milliseconds since the Unix epoch). Returns
+/// whether anything was written: a file that already holds `sram` is
+/// left alone, and nothing is archived for it.
+pub fn write_save(
+    storage: &dyn Storage,
+    path: &std::path::Path,
+    sram: &[u8],
+    history: Option<&crate::save_history::SaveHistory>,
+    now: u64,
+) -> std::io::Result<bool> {
+    let existing = crate::storage::read_opt(storage, path)?;
+    let file = match &existing {
+        Some(file) => Unwrapped::from_file(file).rewrap(sram),
+        None => sram.to_vec(),
+    };
+    if existing.as_ref() == Some(&file) {
+        return Ok(false);
+    }
+    if let Some(history) = history {
+        history.archive(storage, path, now)?;
+    }
+    crate::storage::write_atomic(storage, path, &file)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn dsv(image: &[u8]) -> Vec<u8> {
+        let len = (image.len() as u32).to_le_bytes();
+        [
+            image,
+            DESMUME_TEXT,
+            &len,
+            &len,
+            &[2, 0, 0, 0],
+            &[3, 0, 0, 0],
+            &[0, 0, 4, 0],
+            &[0; 4],
+            DESMUME_MAGIC,
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn raw_images_pass_through() {
+        let file = image(0x1_0000);
+        let unwrapped = Unwrapped::from_file(&file);
+        assert_eq!(unwrapped.wrapper, Wrapper::Raw);
+        assert_eq!(unwrapped.image, file);
+
+        let edited = image(0x1_0000).into_iter().rev().collect::<Vec<_>>();
+        assert_eq!(unwrapped.rewrap(&edited), edited);
+    }
+
+    #[test]
+    fn desmume_footers_are_stripped_and_restored() {
+        let file = dsv(&image(0x4_0000));
+        let unwrapped = Unwrapped::from_file(&file);
+        assert!(matches!(unwrapped.wrapper, Wrapper::Desmume(_)));
+        assert_eq!(unwrapped.image, image(0x4_0000));
+        assert_eq!(unwrapped.rewrap(&unwrapped.image), file);
+
+        let mut edited = image(0x4_0000);
+        edited[0x100] ^= 0xff;
+        assert_eq!(unwrapped.rewrap(&edited), dsv(&edited));
+    }
+
+    #[test]
+    fn desmume_footers_follow_a_grown_image() {
+        let file = dsv(&image(0x8000));
+        let unwrapped = Unwrapped::from_file(&file);
+        assert_eq!(unwrapped.rewrap(&image(0x1_0000)), dsv(&image(0x1_0000)));
+    }
+
+    #[test]
+    fn emulator_trailers_are_kept() {
+        let rtc = vec![0x5a; 16];
+        let file = [image(0x2_0000), rtc.clone()].concat();
+        let unwrapped = Unwrapped::from_file(&file);
+        assert_eq!(unwrapped.wrapper, Wrapper::Trailer(rtc.clone()));
+        assert_eq!(unwrapped.image, image(0x2_0000));
+
+        let edited = vec![0; 0x2_0000];
+        assert_eq!(unwrapped.rewrap(&edited), [edited, rtc].concat());
+    }
+
+    #[test]
+    fn trimmed_dumps_stay_trimmed() {
+        let file = image(0x7c14);
+        let unwrapped = Unwrapped::from_file(&file);
+        assert_eq!(unwrapped.wrapper, Wrapper::Raw);
+
+        // The game's dump is the whole chip, erased past what was saved.
+        let mut sram = vec![ERASED; 0x8000];
+        sram[..0x7c14].copy_from_slice(&file);
+        assert_eq!(unwrapped.rewrap(&sram), file);
+
+        sram[0x7f00] = 0;
+        assert_eq!(unwrapped.rewrap(&sram), sram[..0x7f01]);
+    }
+
+    #[test]
+    fn shorter_dumps_keep_the_rest_of_the_file() {
+        let file = image(0x1_0000);
+        let sram = vec![0; 0x8000];
+        let written = Unwrapped::from_file(&file).rewrap(&sram);
+        assert_eq!(written[..0x8000], sram[..]);
+        assert_eq!(written[0x8000..], file[0x8000..]);
+    }
+
+    #[tokio::test]
+    async fn a_rescan_reads_no_file_known_to_hold_no_save() {
+        use crate::testing::{MemStorage, Op};
+        use std::path::{Path, PathBuf};
+
+        let gregar = crate::game::find_by_rom_info(b"BR5E", 0).expect("gamesupport-bn6 must be enabled for this test");
+        let sram = gregar.save_templates.expect("bn6 bundles templates")[0]
+            .1
+            .to_sram_dump();
+        let fs = MemStorage::new();
+        // Behind an emulator's trailer, and bare.
+        fs.insert("/saves/a.sav", [&sram[..], &[0; 0x10]].concat());
+        fs.insert("/saves/b.sav", sram);
+        fs.insert("/saves/notes.txt", "not a save");
+        let listing = fs.list(&[PathBuf::from("/saves")]).await;
+        let cache = Path::new("/cache");
+        let paths = |saves: &std::collections::HashMap<GameRef, Vec<ScannedSave>>| {
+            saves
+                .iter()
+                .map(|(game, saves)| (*game, saves.iter().map(|s| s.path.clone()).collect::<Vec<_>>()))
+                .collect::<Vec<_>>()
+        };
+
+        let mut fingerprints = Fingerprints::load(&fs, cache);
+        let saves = scan_saves(&fs, &listing, &mut fingerprints);
+        fingerprints.store(&fs);
+        assert_eq!(
+            paths(&saves),
+            vec![(
+                gregar,
+                vec![PathBuf::from("/saves/a.sav"), PathBuf::from("/saves/b.sav")]
+            )]
+        );
+
+        // As the next launch would, through the stored cache.
+        let before = fs.reads().len();
+        let mut fingerprints = Fingerprints::load(&fs, cache);
+        let again = scan_saves(&fs, &listing, &mut fingerprints);
+        assert_eq!(
+            fs.reads()[before..],
+            [
+                (Op::Read, cache.join("scan-fingerprints.json")),
+                (Op::Read, PathBuf::from("/saves/a.sav")),
+                (Op::Read, PathBuf::from("/saves/b.sav")),
+            ]
+        );
+        assert_eq!(paths(&again), paths(&saves));
+    }
+}
@@ -0,0 +1,349 @@
+//! Earlier versions of each library save, kept so an overwrite can be
+//! undone.
+//!
+//! Everything that writes over a save — committing an edit, a
+//! single-player session's autosave, restoring an old version, deleting
+//! it — first hands the file it is about to replace to
+//! [`SaveHistory::archive`].
+//! Versions are whole files, emulator wrapper and all, so restoring one
+//! puts back exactly what was there.
+//!
+//! Each save's versions live in a directory of their own under
+//! [`Config::save_history_path`], mirroring the save's path under the
+//! saves directory, beside an index of when each was taken. The index
+//! is what makes the history readable without [`Storage::list`], which
+//! is async: archiving happens on the write path, and that is sync.
+//!
+//! The history sits outside the saves directory so the save scan never
+//! sees it, and under the data path rather than the cache because it
+//! is the one copy of what it holds.
+
+use crate::config::Config;
+use crate::storage::{self, Storage};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+const INDEX_FILE_NAME: &str = "index.json";
+
+/// However old, the last this many versions are always kept.
+const KEEP_LATEST: usize = 10;
+
+/// Past those, one version a day is kept — the last of that day — for
+/// this many days.
+const KEEP_DAYS: u64 = 30;
+
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+#[derive(Serialize, Deserialize, Default, Debug)]
+struct Index {
+    /// When each version was taken, in milliseconds since the Unix
+    /// epoch, oldest first. Also its file name.
+    versions: Vec<u64>,
+}
+
+/// One archived version of a save.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Version {
+    /// When it was replaced, in milliseconds since the Unix epoch.
+    pub taken: u64,
+    pub path: PathBuf,
+}
+
+/// Where the saves under a [`Config`]'s saves directory keep their
+/// history.
+#[derive(Clone, Debug)]
+pub struct SaveHistory {
+    root: PathBuf,
+    saves: PathBuf,
+}
+
+impl SaveHistory {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            root: config.save_history_path(),
+            saves: config.saves_path(),
+        }
+    }
+
+    /// The directory holding `save`'s versions: its path under the
+    /// saves directory, repeated under the history root. A save from
+    /// anywhere else goes by its file name alone.
+    fn dir(&self, save: &Path) -> PathBuf {
+        match save.strip_prefix(&self.saves) {
+            Ok(rel) => self.root.join(rel),
+            Err(_) => self.root.join(save.file_name().unwrap_or_default()),
+        }
+    }
+
+    fn load_index(&self, storage: &dyn Storage, dir: &Path) -> Index {
+        let path = dir.join(INDEX_FILE_NAME);
+        match storage::read_opt(storage, &path) {
+            Ok(Some(raw)) => serde_json::from_slice(&raw).unwrap_or_else(|e| {
+                log::warn!("{}: {e}", path.display());
+                Index::default()
+            }),
+            Ok(None) => Index::default(),
+            Err(e) => {
+                log::warn!("{}: {e}", path.display());
+                Index::default()
+            }
+        }
+    }
+
+    /// `save`'s archived versions, newest first.
+    pub fn versions(&self, storage: &dyn Storage, save: &Path) -> Vec<Version> {
+        let dir = self.dir(save);
+        self.load_index(storage, &dir)
+            .versions
+            .into_iter()
+            .rev()
+            .map(|taken| Version {
+                taken,
+                path: version_path(&dir, taken),
+            })
+            .collect()
+    }
+
+    /// Keep the file at `save` as a version before something writes
+    /// over it. A file that isn't there has nothing to keep, and one
+    /// identical to the newest version isn't kept twice. `now` is in
+    /// milliseconds since the Unix epoch; the caller supplies it
+    /// because there's no clock to read here on wasm.
+    pub fn archive(&self, storage: &dyn Storage, save: &Path, now: u64) -> std::io::Result<()> {
+        let Some(current) = storage::read_opt(storage, save)? else {
+            return Ok(());
+        };
+        let dir = self.dir(save);
+        let mut index = self.load_index(storage, &dir);
+        if let Some(&newest) = index.versions.last() {
+            if storage::read_opt(storage, &version_path(&dir, newest))?.as_deref() == Some(current.as_slice()) {
+                return Ok(());
+            }
+        }
+
+        // Versions are named for when they were taken, so two in the
+        // same millisecond — or after the clock stepped back — would
+        // collide; keep the names increasing instead.
+        let taken = index.versions.last().map_or(now, |&newest| now.max(newest + 1));
+        storage.write(&version_path(&dir, taken), &current)?;
+        index.versions.push(taken);
+
+        let keep = retained(&index.versions, now);
+        for &taken in index.versions.iter().filter(|t| !keep.contains(t)) {
+            let path = version_path(&dir, taken);
+            if let Err(e) = storage.remove_file(&path) {
+                log::warn!("{}: {e}", path.display());
+            }
+        }
+        index.versions = keep;
+        self.store_index(storage, &dir, &index)
+    }
+
+    /// Put `version` back in place of `save`, archiving what it replaces
+    /// first — so a restore can itself be undone.
+    pub fn restore(&self, storage: &dyn Storage, save: &Path, version: &Version, now: u64) -> std::io::Result<()> {
+        let old = storage.read(&version.path)?;
+        self.archive(storage, save, now)?;
+        storage::write_atomic(storage, save, &old)
+    }
+
+    /// Carry `from`'s history over to `to` after the save is renamed.
+    /// If `to` already has a history — a save of that name was deleted
+    /// earlier — the two are merged, oldest first, rather than one
+    /// replacing the other. A version whose name is taken moves to the
+    /// next free millisecond. If a move fails partway, each index is
+    /// left listing the versions that ended up beside it.
+    pub fn rename(&self, storage: &dyn Storage, from: &Path, to: &Path) -> std::io::Result<()> {
+        let (from, to) = (self.dir(from), self.dir(to));
+        let index = self.load_index(storage, &from);
+        if index.versions.is_empty() || from == to {
+            return Ok(());
+        }
+        let mut into = self.load_index(storage, &to);
+        let mut left = vec![];
+        let mut result = Ok(());
+        for &taken in &index.versions {
+            if result.is_err() {
+                left.push(taken);
+                continue;
+            }
+            let mut at = taken;
+            while into.versions.contains(&at) {
+                at += 1;
+            }
+            match storage.rename(&version_path(&from, taken), &version_path(&to, at)) {
+                Ok(()) => into.versions.push(at),
+                Err(e) => {
+                    left.push(taken);
+                    result = Err(e);
+                }
+            }
+        }
+        into.versions.sort_unstable();
+        self.store_index(storage, &to, &into)?;
+        if left.is_empty() {
+            storage.remove_file(&from.join(INDEX_FILE_NAME))?;
+        } else {
+            self.store_index(storage, &from, &Index { versions: left })?;
+        }
+        result
+    }
+
+    fn store_index(&self, storage: &dyn Storage, dir: &Path, index: &Index) -> std::io::Result<()> {
+        let json = serde_json::to_vec(index).map_err(std::io::Error::other)?;
+        storage::write_atomic(storage, &dir.join(INDEX_FILE_NAME), &json)
+    }
+}
+
+fn version_path(dir: &Path, taken: u64) -> PathBuf {
+    dir.join(format!("{taken}.sav"))
+}
+
+/// Which of `versions` (oldest first) the retention policy keeps as of
+/// `now`: the newest [`KEEP_LATEST`], and the newest of each day in the
+/// last [`KEEP_DAYS`].
+fn retained(versions: &[u64], now: u64) -> Vec<u64> {
+    let latest = versions.len().saturating_sub(KEEP_LATEST);
+    let cutoff = now.saturating_sub(KEEP_DAYS * DAY_MS);
+    versions
+        .iter()
+        .enumerate()
+        .filter(|&(i, &taken)| {
+            let last_of_its_day = !versions.get(i + 1).is_some_and(|&next| next / DAY_MS == taken / DAY_MS);
+            i >= latest || (taken >= cutoff && last_of_its_day)
+        })
+        .map(|(_, &taken)| taken)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{MemStorage, Op};
+
+    fn history() -> SaveHistory {
+        SaveHistory {
+            root: PathBuf::from("/data/history"),
+            saves: PathBuf::from("/data/saves"),
+        }
+    }
+
+    fn contents(fs: &MemStorage, versions: &[Version]) -> Vec<(u64, Vec<u8>)> {
+        versions.iter().map(|v| (v.taken, fs.get(&v.path).unwrap())).collect()
+    }
+
+    #[test]
+    fn archive_keeps_each_distinct_version_once() {
+        let (fs, history) = (MemStorage::new(), history());
+        let save = Path::new("/data/saves/bn6/falzar.sav");
+
+        // Nothing there yet, so nothing to keep.
+        history.archive(&fs, save, 100).unwrap();
+        assert_eq!(fs.mutations(), vec![]);
+
+        fs.insert(save, b"one".to_vec());
+        history.archive(&fs, save, 100).unwrap();
+        history.archive(&fs, save, 200).unwrap();
+        fs.insert(save, b"two".to_vec());
+        // The clock stepped back; the name still goes up.
+        history.archive(&fs, save, 50).unwrap();
+
+        let versions = history.versions(&fs, save);
+        assert_eq!(
+            versions.iter().map(|v| &v.path).collect::<Vec<_>>(),
+            [
+                Path::new("/data/history/bn6/falzar.sav/101.sav"),
+                Path::new("/data/history/bn6/falzar.sav/100.sav")
+            ]
+        );
+        assert_eq!(
+            contents(&fs, &versions),
+            vec![(101, b"two".to_vec()), (100, b"one".to_vec())]
+        );
+    }
+
+    #[test]
+    fn restore_puts_a_version_back_and_keeps_what_it_replaced() {
+        let (fs, history) = (MemStorage::new(), history());
+        let save = Path::new("/data/saves/falzar.sav");
+        fs.insert(save, b"old".to_vec());
+        history.archive(&fs, save, 100).unwrap();
+        fs.insert(save, b"bad edit".to_vec());
+
+        let [old] = <[Version; 1]>::try_from(history.versions(&fs, save)).unwrap();
+        history.restore(&fs, save, &old, 200).unwrap();
+        assert_eq!(fs.get(save).unwrap(), b"old");
+        assert_eq!(
+            contents(&fs, &history.versions(&fs, save)),
+            vec![(200, b"bad edit".to_vec()), (100, b"old".to_vec())]
+        );
+    }
+
+    #[test]
+    fn rename_merges_into_an_existing_history() {
+        let (fs, history) = (MemStorage::new(), history());
+        let (from, to) = (Path::new("/data/saves/a.sav"), Path::new("/data/saves/b.sav"));
+        for (save, taken, data) in [(to, 100, "b1"), (from, 100, "a1"), (from, 300, "a2"), (to, 200, "b2")] {
+            fs.insert(save, data.as_bytes().to_vec());
+            history.archive(&fs, save, taken).unwrap();
+        }
+
+        history.rename(&fs, from, to).unwrap();
+        assert_eq!(history.versions(&fs, from), vec![]);
+        assert_eq!(
+            contents(&fs, &history.versions(&fs, to)),
+            vec![
+                (300, b"a2".to_vec()),
+                (200, b"b2".to_vec()),
+                (101, b"a1".to_vec()),
+                (100, b"b1".to_vec()),
+            ]
+        );
+        assert!(!fs.files().iter().any(|f| f.starts_with("/data/history/a.sav")));
+    }
+
+    #[test]
+    fn a_rename_that_fails_partway_loses_no_versions() {
+        let (fs, history) = (MemStorage::new(), history());
+        let (from, to) = (Path::new("/data/saves/a.sav"), Path::new("/data/saves/b.sav"));
+        for (taken, data) in [(100, "a1"), (200, "a2")] {
+            fs.insert(from, data.as_bytes().to_vec());
+            history.archive(&fs, from, taken).unwrap();
+        }
+
+        fs.fail_once(Op::Rename, "/data/history/b.sav/200.sav");
+        assert!(history.rename(&fs, from, to).is_err());
+        assert_eq!(contents(&fs, &history.versions(&fs, from)), vec![(200, b"a2".to_vec())]);
+        assert_eq!(contents(&fs, &history.versions(&fs, to)), vec![(100, b"a1".to_vec())]);
+    }
+
+    #[test]
+    fn the_latest_versions_are_always_kept() {
+        let versions: Vec<u64> = (0..KEEP_LATEST as u64).collect();
+        assert_eq!(retained(&versions, 1000 * DAY_MS), versions);
+    }
+
+    #[test]
+    fn older_versions_thin_to_one_a_day() {
+        let now = 100 * DAY_MS;
+        // Three a day for five days, then the latest run of edits.
+        let mut versions: Vec<u64> = (95..100)
+            .flat_map(|day| (1..=3).map(move |hour| day * DAY_MS + hour * 60 * 60 * 1000))
+            .collect();
+        let recent: Vec<u64> = (0..KEEP_LATEST as u64).map(|i| now + i).collect();
+        versions.extend(&recent);
+
+        let kept = retained(&versions, now);
+        let days: Vec<u64> = (95..100).map(|day| day * DAY_MS + 3 * 60 * 60 * 1000).collect();
+        assert_eq!(kept, [days, recent].concat());
+    }
+
+    #[test]
+    fn versions_past_the_window_are_dropped() {
+        let now = 100 * DAY_MS;
+        let mut versions = vec![now - (KEEP_DAYS + 1) * DAY_MS, now - (KEEP_DAYS - 1) * DAY_MS];
+        let recent: Vec<u64> = (0..KEEP_LATEST as u64).map(|i| now + i).collect();
+        versions.extend(&recent);
+        assert_eq!(retained(&versions, now), [vec![versions[1]], recent].concat());
+    }
+}
@@ -75,6 +75,26 @@ pub struct Entry {
     pub modified: Option<u64>,
 }
 
+impl Entry {
+    /// What says this file is the one a cached verdict was reached
+    /// about, or `None` if the backend gives no way to tell — a size on
+    /// its own doesn't, once a file is rewritten in place.
+    pub fn stamp(&self) -> Option<Stamp> {
+        Some(Stamp {
+            len: self.len,
+            modified: self.modified?,
+        })
+    }
+}
+
+/// An [`Entry`]'s size and modification time, as
+/// [`crate::fingerprint`] keys its cache on them.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, serde::Serialize, serde::Deserialize)]
+pub struct Stamp {
+    pub len: u64,
+    pub modified: u64,
+}
+
 /// A store of files addressed by path. Paths are ordinary `Path`s — on a
 /// browser backend they are just `/`-separated keys into the OPFS
 /// directory tree, which is why nothing here uses `OsStr`-only APIs.
@@ -227,3 +247,39 @@ fn epoch_millis(t: std::time::SystemTime) -> Option<u64> {
         .ok()
         .map(|d| d.as_millis() as u64)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{MemStorage, Op};
+
+    #[test]
+    fn an_atomic_write_never_leaves_a_partial_file_behind() {
+        let fs = MemStorage::new();
+        let path = Path::new("/data/config.json");
+        let tmp = Path::new("/data/.config.json.tmp");
+        write_atomic(&fs, path, b"one").unwrap();
+        write_atomic(&fs, path, b"two").unwrap();
+        assert_eq!(fs.get(path).as_deref(), Some(&b"two"[..]));
+
+        // The temporary can't be written: the original is untouched.
+        fs.fail_once(Op::Write, tmp);
+        assert!(write_atomic(&fs, path, b"three").is_err());
+        assert_eq!(fs.get(path).as_deref(), Some(&b"two"[..]));
+
+        // It can't be moved into place: the temporary is cleaned up.
+        fs.fail_once(Op::Rename, path);
+        assert!(write_atomic(&fs, path, b"three").is_err());
+        assert_eq!(fs.get(path).as_deref(), Some(&b"two"[..]));
+        assert_eq!(fs.files(), vec![path.to_path_buf()]);
+    }
+
+    #[test]
+    fn only_a_missing_file_reads_as_absent() {
+        let fs = MemStorage::new();
+        assert_eq!(read_opt(&fs, Path::new("/data/absent")).unwrap(), None);
+        fs.insert("/data/present", "x");
+        fs.fail_once(Op::Read, "/data/present");
+        assert!(read_opt(&fs, Path::new("/data/present")).is_err());
+    }
+}
@@ -0,0 +1,479 @@
+//! Stand-ins for the two seams, for tests.
+//!
+//! [`MemStorage`] is a filesystem held in a map, with faults that can be
+//! armed per operation and path; [`ScriptedHttp`] answers each URL with
+//! a canned [`Response`] — ETags, status codes, missing content
+//! lengths, and bodies that break off partway. Between them the
+//! scanners and the patch flows can be driven through their failure
+//! paths without a disk or a socket.
+//!
+//! The patch tests still stand up a real HTTP server for the happy
+//! path, since URL joining and header handling are what a fake papers
+//! over; these are for what a real server can't be made to do on cue.
+//!
+//! Compiled for this crate's own tests, and for other crates' behind
+//! the `testing` feature.
+
+use crate::http::{Error, Fetch, GetFuture, Http, Request};
+use crate::storage::{Entry, ListFuture, Listing, ReadSeek, Storage};
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// A [`Storage`] operation, as faults are armed against and
+/// [`MemStorage::mutations`] and [`MemStorage::reads`] record them.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Op {
+    Read,
+    Open,
+    Write,
+    Remove,
+    CreateDir,
+    /// Matched and recorded by its destination.
+    Rename,
+}
+
+struct Fault {
+    op: Op,
+    path: PathBuf,
+    /// How many more times it fires; `None` until [`MemStorage::heal`].
+    remaining: Option<usize>,
+}
+
+#[derive(Default)]
+struct State {
+    files: BTreeMap<PathBuf, (Vec<u8>, u64)>,
+    /// Bumped on every write, standing in for a modification time so a
+    /// rewrite changes the listing even when the length doesn't.
+    clock: u64,
+    faults: Vec<Fault>,
+    mutations: Vec<(Op, PathBuf)>,
+    reads: Vec<(Op, PathBuf)>,
+}
+
+impl State {
+    /// Fire the first armed fault matching `op` on `path` — or on a
+    /// directory above it.
+    fn fault(&mut self, op: Op, path: &Path) -> std::io::Result<()> {
+        let Some(i) = self.faults.iter().position(|f| f.op == op && path.starts_with(&f.path)) else {
+            return Ok(());
+        };
+        if let Some(remaining) = &mut self.faults[i].remaining {
+            *remaining -= 1;
+            if *remaining == 0 {
+                self.faults.remove(i);
+            }
+        }
+        Err(std::io::Error::other(format!(
+            "injected {op:?} fault at {}",
+            path.display()
+        )))
+    }
+}
+
+fn not_found(path: &Path) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::NotFound, path.display().to_string())
+}
+
+/// An in-memory filesystem. Directories are implied by the files under
+/// them, so `create_dir_all` only checks for a fault.
+#[derive(Default)]
+pub struct MemStorage {
+    state: Mutex<State>,
+}
+
+impl MemStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Put a file in place without it counting as a mutation.
+    pub fn insert(&self, path: impl Into<PathBuf>, data: impl Into<Vec<u8>>) {
+        let mut state = self.state.lock().unwrap();
+        state.clock += 1;
+        let modified = state.clock;
+        state.files.insert(path.into(), (data.into(), modified));
+    }
+
+    pub fn get(&self, path: impl AsRef<Path>) -> Option<Vec<u8>> {
+        self.state
+            .lock()
+            .unwrap()
+            .files
+            .get(path.as_ref())
+            .map(|(data, _)| data.clone())
+    }
+
+    /// Every file, in path order.
+    pub fn files(&self) -> Vec<PathBuf> {
+        self.state.lock().unwrap().files.keys().cloned().collect()
+    }
+
+    /// Make `op` fail on `path`, and on anything under it, until
+    /// [`Self::heal`].
+    pub fn fail(&self, op: Op, path: impl Into<PathBuf>) {
+        self.arm(op, path.into(), None);
+    }
+
+    /// Make the next `op` on `path`, or on anything under it, fail.
+    pub fn fail_once(&self, op: Op, path: impl Into<PathBuf>) {
+        self.arm(op, path.into(), Some(1));
+    }
+
+    fn arm(&self, op: Op, path: PathBuf, remaining: Option<usize>) {
+        self.state.lock().unwrap().faults.push(Fault { op, path, remaining });
+    }
+
+    /// Disarm every fault.
+    pub fn heal(&self) {
+        self.state.lock().unwrap().faults.clear();
+    }
+
+    /// Every write, removal and rename that succeeded, in order.
+    pub fn mutations(&self) -> Vec<(Op, PathBuf)> {
+        self.state.lock().unwrap().mutations.clone()
+    }
+
+    /// Every read and open that succeeded, in order: what a cache is
+    /// meant to spare.
+    pub fn reads(&self) -> Vec<(Op, PathBuf)> {
+        self.state.lock().unwrap().reads.clone()
+    }
+}
+
+impl Storage for MemStorage {
+    fn read(&self, path: &Path) -> std::io::Result<Vec<u8>> {
+        let mut state = self.state.lock().unwrap();
+        state.fault(Op::Read, path)?;
+        let data = state
+            .files
+            .get(path)
+            .map(|(data, _)| data.clone())
+            .ok_or_else(|| not_found(path))?;
+        state.reads.push((Op::Read, path.to_path_buf()));
+        Ok(data)
+    }
+
+    fn open(&self, path: &Path) -> std::io::Result<Box<dyn ReadSeek>> {
+        let mut state = self.state.lock().unwrap();
+        state.fault(Op::Open, path)?;
+        let (data, _) = state.files.get(path).ok_or_else(|| not_found(path))?;
+        let reader = Box::new(std::io::Cursor::new(data.clone()));
+        state.reads.push((Op::Open, path.to_path_buf()));
+        Ok(reader)
+    }
+
+    fn write(&self, path: &Path, data: &[u8]) -> std::io::Result<()> {
+        let mut state = self.state.lock().unwrap();
+        state.fault(Op::Write, path)?;
+        state.clock += 1;
+        let modified = state.clock;
+        state.files.insert(path.to_path_buf(), (data.to_vec(), modified));
+        state.mutations.push((Op::Write, path.to_path_buf()));
+        Ok(())
+    }
+
+    fn remove_file(&self, path: &Path) -> std::io::Result<()> {
+        let mut state = self.state.lock().unwrap();
+        state.fault(Op::Remove, path)?;
+        state.files.remove(path).ok_or_else(|| not_found(path))?;
+        state.mutations.push((Op::Remove, path.to_path_buf()));
+        Ok(())
+    }
+
+    fn create_dir_all(&self, path: &Path) -> std::io::Result<()> {
+        self.state.lock().unwrap().fault(Op::CreateDir, path)
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> std::io::Result<()> {
+        let mut state = self.state.lock().unwrap();
+        state.fault(Op::Rename, to)?;
+        let file = state.files.remove(from).ok_or_else(|| not_found(from))?;
+        state.files.insert(to.to_path_buf(), file);
+        state.mutations.push((Op::Rename, to.to_path_buf()));
+        Ok(())
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.state.lock().unwrap().files.contains_key(path)
+    }
+
+    fn list<'a>(&'a self, roots: &'a [PathBuf]) -> ListFuture<'a> {
+        let state = self.state.lock().unwrap();
+        let entries = state
+            .files
+            .iter()
+            .filter(|(path, _)| roots.iter().any(|root| path.starts_with(root)))
+            .map(|(path, (data, modified))| Entry {
+                path: path.clone(),
+                len: data.len() as u64,
+                modified: Some(*modified),
+            })
+            .collect();
+        Box::pin(std::future::ready(Listing::new(entries)))
+    }
+}
+
+/// A stand-in dump of `game`'s ROM that [`crate::game::detect`] takes
+/// for the real thing: the header's game code and revision, blank
+/// cartridge everywhere else, and the last four bytes chosen so the
+/// whole image has the game's CRC32. No real ROM needed.
+#[cfg(test)]
+pub fn rom_image(game: crate::rom::GameRef) -> Vec<u8> {
+    let (code, revision) = game.rom_code_and_revision();
+    let mut rom = vec![0xff; game.rom_size()];
+    // The GBA header layout; every game the tests use is a GBA one.
+    rom[0xac..0xb0].copy_from_slice(code);
+    rom[0xbc] = revision;
+
+    let at = rom.len() - 4;
+    let mut prefix = crc32fast::Hasher::new();
+    prefix.update(&rom[..at]);
+    let crc_with = |tail: u32| {
+        let mut h = prefix.clone();
+        h.update(&tail.to_le_bytes());
+        h.finalize()
+    };
+    // Appending four bytes is an affine bijection on the CRC, so the
+    // tail is the solution of a 32x32 system over GF(2): each row pairs
+    // a tail bit's effect on the CRC with the tail bits behind it.
+    let base = crc_with(0);
+    let mut rows: Vec<(u32, u32)> = (0..32).map(|bit| (crc_with(1 << bit) ^ base, 1 << bit)).collect();
+    let mut want = (game.crc32() ^ base, 0);
+    for bit in 0..32 {
+        let pivot = (bit..32).find(|&i| rows[i].0 >> bit & 1 == 1).unwrap();
+        rows.swap(bit, pivot);
+        let (effect, tail) = rows[bit];
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i != bit && row.0 >> bit & 1 == 1 {
+                row.0 ^= effect;
+                row.1 ^= tail;
+            }
+        }
+        if want.0 >> bit & 1 == 1 {
+            want.0 ^= effect;
+            want.1 ^= tail;
+        }
+    }
+    rom[at..].copy_from_slice(&want.1.to_le_bytes());
+    rom
+}
+
+/// What [`ScriptedHttp`] answers one URL with.
+#[derive(Clone, Debug)]
+pub struct Response {
+    status: u16,
+    etag: Option<String>,
+    body: Vec<u8>,
+    content_length: Option<u64>,
+    chunk_len: usize,
+    fail_after: Option<usize>,
+}
+
+impl Response {
+    /// A 200 with `body`, its length declared, delivered in one chunk.
+    pub fn ok(body: impl Into<Vec<u8>>) -> Self {
+        let body = body.into();
+        Self {
+            status: 200,
+            etag: None,
+            content_length: Some(body.len() as u64),
+            body,
+            chunk_len: usize::MAX,
+            fail_after: None,
+        }
+    }
+
+    /// An empty response with `status`.
+    pub fn status(status: u16) -> Self {
+        Self {
+            status,
+            ..Self::ok(vec![])
+        }
+    }
+
+    /// Send an `ETag`, and answer a request carrying it as
+    /// `If-None-Match` with a 304.
+    pub fn etag(mut self, etag: impl Into<String>) -> Self {
+        self.etag = Some(etag.into());
+        self
+    }
+
+    /// Leave out `Content-Length`, as a chunked transfer does.
+    pub fn without_content_length(mut self) -> Self {
+        self.content_length = None;
+        self
+    }
+
+    /// Deliver the body `len` bytes at a time, with a progress report
+    /// after each.
+    pub fn chunked(mut self, len: usize) -> Self {
+        self.chunk_len = len.max(1);
+        self
+    }
+
+    /// Drop the connection once `bytes` of the body have arrived.
+    pub fn fail_after(mut self, bytes: usize) -> Self {
+        self.fail_after = Some(bytes);
+        self
+    }
+}
+
+#[derive(Clone)]
+enum Route {
+    Respond(Response),
+    Unreachable,
+}
+
+/// One request [`ScriptedHttp`] received.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Seen {
+    pub url: String,
+    pub if_none_match: Option<String>,
+    pub max_len: Option<u64>,
+}
+
+/// An [`Http`] that answers from a script, and notes what it was asked.
+/// A URL with nothing scripted is a 404. The body is streamed the way
+/// the reqwest implementation streams it: progress first at zero, the
+/// size cap checked before each chunk is taken.
+#[derive(Default)]
+pub struct ScriptedHttp {
+    routes: Mutex<HashMap<String, Route>>,
+    requests: Mutex<Vec<Seen>>,
+}
+
+impl ScriptedHttp {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer `url` with `response` from now on.
+    pub fn serve(&self, url: impl Into<String>, response: Response) {
+        self.routes.lock().unwrap().insert(url.into(), Route::Respond(response));
+    }
+
+    /// Fail every request for `url` before any response arrives.
+    pub fn unreachable(&self, url: impl Into<String>) {
+        self.routes.lock().unwrap().insert(url.into(), Route::Unreachable);
+    }
+
+    /// Every request so far, in order.
+    pub fn requests(&self) -> Vec<Seen> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Http for ScriptedHttp {
+    fn get<'a>(&'a self, request: Request<'a>) -> GetFuture<'a> {
+        self.requests.lock().unwrap().push(Seen {
+            url: request.url.to_owned(),
+            if_none_match: request.if_none_match.map(|s| s.to_owned()),
+            max_len: request.max_len,
+        });
+        let route = self.routes.lock().unwrap().get(request.url).cloned();
+
+        Box::pin(async move {
+            let response = match route {
+                None => return Err(Error::Status(404)),
+                Some(Route::Unreachable) => {
+                    return Err(Error::Transport(format!("{}: connection refused", request.url)))
+                }
+                Some(Route::Respond(response)) => response,
+            };
+            if response.etag.is_some() && request.if_none_match == response.etag.as_deref() {
+                return Ok(Fetch::NotModified);
+            }
+            if !(200..300).contains(&response.status) {
+                return Err(Error::Status(response.status));
+            }
+
+            let total = response.content_length.or(request.max_len).unwrap_or(0);
+            let mut body = Vec::new();
+            if let Some(progress) = request.on_progress {
+                if !progress.call(0, total) {
+                    return Ok(Fetch::Cancelled);
+                }
+            }
+
+            for chunk in response.body.chunks(response.chunk_len) {
+                if response.fail_after.is_some_and(|n| body.len() >= n) {
+                    return Err(Error::Transport("connection reset".into()));
+                }
+                if let Some(limit) = request.max_len {
+                    if body.len() as u64 + chunk.len() as u64 > limit {
+                        return Err(Error::TooLarge { limit });
+                    }
+                }
+                body.extend_from_slice(chunk);
+                if let Some(progress) = request.on_progress {
+                    if !progress.call(body.len() as u64, total) {
+                        return Ok(Fetch::Cancelled);
+                    }
+                }
+            }
+
+            Ok(Fetch::Body {
+                etag: response.etag,
+                body,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn a_rewrite_changes_the_listing_even_at_the_same_length() {
+        let fs = MemStorage::new();
+        fs.write(Path::new("/data/a.sav"), b"one").unwrap();
+        fs.write(Path::new("/elsewhere/b.sav"), b"two").unwrap();
+        let roots = [PathBuf::from("/data")];
+        let before = fs.list(&roots).await;
+        assert_eq!(before.entries().len(), 1, "only what is under the roots");
+
+        fs.write(Path::new("/data/a.sav"), b"uno").unwrap();
+        assert_ne!(fs.list(&roots).await, before);
+    }
+
+    #[test]
+    fn faults_fire_under_their_path_until_spent() {
+        let fs = MemStorage::new();
+        fs.fail_once(Op::Write, "/data");
+        assert!(fs.write(Path::new("/data/a.sav"), b"one").is_err());
+        fs.write(Path::new("/data/a.sav"), b"one").unwrap();
+
+        fs.fail(Op::Read, "/data/a.sav");
+        assert!(fs.read(Path::new("/data/a.sav")).is_err());
+        assert!(fs.read(Path::new("/data/a.sav")).is_err());
+        fs.heal();
+        assert_eq!(fs.read(Path::new("/data/a.sav")).unwrap(), b"one");
+        assert_eq!(
+            fs.read(Path::new("/data/b.sav")).unwrap_err().kind(),
+            std::io::ErrorKind::NotFound
+        );
+        assert_eq!(fs.mutations(), vec![(Op::Write, PathBuf::from("/data/a.sav"))]);
+    }
+
+    #[tokio::test]
+    async fn a_response_honours_its_etag_and_the_size_cap() {
+        let http = ScriptedHttp::new();
+        http.serve("http://repo/index.json", Response::ok("{}").etag("\"v1\""));
+
+        let mut request = Request::new("http://repo/index.json");
+        request.if_none_match = Some("\"v1\"");
+        assert!(matches!(http.get(request).await, Ok(Fetch::NotModified)));
+
+        let mut request = Request::new("http://repo/index.json");
+        request.max_len = Some(1);
+        assert!(matches!(http.get(request).await, Err(Error::TooLarge { limit: 1 })));
+
+        assert!(matches!(
+            http.get(Request::new("http://repo/missing")).await,
+            Err(Error::Status(404))
+        ));
+        assert_eq!(http.requests().len(), 3);
+    }
+}
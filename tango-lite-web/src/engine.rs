@@ -380,6 +380,11 @@ struct Engine {
     /// when we last did.
     save_path: Option<std::path::PathBuf>,
     last_save_ms: f64,
+    /// Whether the save file is still the one the session booted from,
+    /// and so goes into the save history before the first write
+    /// replaces it. The autosaves after that aren't versions of their
+    /// own.
+    archive_save: bool,
 }
 
 /// How often a single-player session's savedata is written back. The
@@ -460,6 +465,7 @@ fn install(
             fresh: false,
             prefetch_cost_ms: PREFETCH_COST_GUESS_MS,
             ctx: None,
+            archive_save: save_path.is_some(),
             save_path,
             last_save_ms: now,
         })
@@ -801,7 +807,9 @@ fn flush_save(engine: &mut Engine) {
         log::debug!("not persisting {}: the cart hasn't written a save yet", path.display());
         return;
     }
-    crate::library::write_save(&path, &bytes);
+    if crate::library::write_save(&path, &bytes, engine.archive_save) {
+        engine.archive_save = false;
+    }
 }
 
 /// Re-slice the session's canonical side-by-side composition into the
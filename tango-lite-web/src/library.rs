@@ -19,8 +19,9 @@ use std::path::{Path, PathBuf};
 use std::rc::Rc;
 
 use tango_library::config::Config;
+use tango_library::fingerprint::Fingerprints;
 use tango_library::rom::GameRef;
-use tango_library::{game, patch, rom, save, storage::Storage as _};
+use tango_library::{game, patch, rom, save, save_history, storage::Storage as _};
 
 use crate::http::BrowserHttp;
 use crate::storage::Files;
@@ -92,19 +93,28 @@ pub async fn rescan() {
     };
     let config = &library.config;
 
+    let mut fingerprints = Fingerprints::load(&library.files, &config.cache_path());
+
     let rom_listing = library.files.list(&rom::scan_roots(&config.roms_path())).await;
-    library
-        .roms
-        .rescan_if_changed(&rom_listing, || Some(rom::scan_roms(&library.files, &rom_listing)));
+    library.roms.rescan_if_changed(&rom_listing, || {
+        Some(rom::scan_roms(&library.files, &rom_listing, &mut fingerprints))
+    });
 
     let save_listing = library.files.list(&[config.saves_path()]).await;
-    library
-        .saves
-        .rescan_if_changed(&save_listing, || Some(save::scan_saves(&library.files, &save_listing)));
+    library.saves.rescan_if_changed(&save_listing, || {
+        Some(save::scan_saves(&library.files, &save_listing, &mut fingerprints))
+    });
+
+    fingerprints.store(&library.files);
 
     let patch_listing = library.files.list(&patch::scan_roots(&config.patches_path())).await;
     library.patches.rescan_if_changed(&patch_listing, || {
-        match patch::scan(&library.files, &config.patches_path(), &patch_listing) {
+        match patch::scan(
+            &library.files,
+            &config.patches_path(),
+            &config.patch_repos(),
+            &patch_listing,
+        ) {
             Ok(catalog) => Some(catalog),
             Err(e) => {
                 log::warn!("patch scan failed: {e}");
@@ -161,8 +171,11 @@ pub async fn import_rom(file_name: &str, bytes: &[u8]) -> Option<GameRef> {
 /// file, so several saves per game coexist the way they do on a desktop.
 pub async fn import_save(file_name: &str, bytes: &[u8]) -> bool {
     // Which game it belongs to is decided by which game can parse it —
-    // the same rule `save::scan_saves` applies on the way back out.
-    let Some(game) = game::GAMES.iter().copied().find(|g| g.parse_save(bytes).is_ok()) else {
+    // the same rule `save::scan_saves` applies on the way back out. The
+    // file is stored as it came, wrapper and all, so it exports back
+    // into the emulator it came from.
+    let image = save::Unwrapped::from_file(bytes).image;
+    let Some(game) = game::GAMES.iter().copied().find(|g| g.parse_save(&image).is_ok()) else {
         return false;
     };
     let (family, variant) = game.family_and_variant();
@@ -258,12 +271,34 @@ fn free_save_path(game: GameRef, name: &str) -> Option<PathBuf> {
 
 /// Persist a session's savedata back over the file it was loaded from.
 /// Single-player only — a PvP match runs entirely off the committed
-/// in-memory image and never writes anyone's save.
-pub fn write_save(path: &Path, bytes: &[u8]) {
-    if with(|library| library.files.write(path, bytes)).is_none() {
-        return;
+/// in-memory image and never writes anyone's save. Written in whatever
+/// emulator's wrapper the file was imported in, so exporting it back
+/// out still loads there. With `archive`, the file it replaces goes into
+/// the save history first. Returns whether the file changed.
+pub fn write_save(path: &Path, bytes: &[u8], archive: bool) -> bool {
+    let result = with(|library| {
+        let history = archive.then(|| save_history::SaveHistory::new(&library.config));
+        save::write_save(
+            &library.files,
+            path,
+            bytes,
+            history.as_ref(),
+            js_sys::Date::now() as u64,
+        )
+    });
+    match result {
+        Some(Ok(written)) => {
+            if written {
+                touch();
+            }
+            written
+        }
+        Some(Err(e)) => {
+            log::error!("writing {}: {e}", path.display());
+            false
+        }
+        None => false,
     }
-    touch();
 }
 
 pub async fn delete_file(path: PathBuf) {
@@ -411,25 +446,27 @@ pub fn write_replay(path: &Path, bytes: &[u8]) {
 // ---------------------------------------------------------------------
 // Patches
 
-/// Pull the repo index. Runs once at startup and on the Patches screen's
-/// refresh; see [`crate::http`] for why it isn't polled on a timer here
-/// the way the desktop polls it.
+/// Pull the repo indexes. Runs once at startup and on the Patches
+/// screen's refresh; see [`crate::http`] for why it isn't polled on a
+/// timer here the way the desktop polls it.
 pub async fn fetch_index() -> Result<(), String> {
     let Some(library) = LIBRARY.with(|l| l.borrow().clone()) else {
         return Err("library not open".into());
     };
-    let changed = patch::fetch_index(
+    let refreshed = patch::fetch_indexes(
         &library.http,
         &library.files,
-        &library.config.patch_repo_url(),
+        &library.config.patch_repos(),
         &library.config.patches_path(),
     )
-    .await
-    .map_err(|e| e.to_string())?;
-    if changed {
+    .await;
+    if refreshed.changed {
         rescan().await;
     }
-    Ok(())
+    match refreshed.failed.first() {
+        None => Ok(()),
+        Some((repo, e)) => Err(format!("{repo}: {e}")),
+    }
 }
 
 /// Download and install one patch version, hash-verified against the
@@ -438,17 +475,19 @@ pub async fn install_patch(name: String, version: semver::Version) -> Result<(),
     let Some(library) = LIBRARY.with(|l| l.borrow().clone()) else {
         return Err("library not open".into());
     };
-    let entry = library
-        .patches
-        .read()
-        .entry(&name, &version)
-        .cloned()
-        .ok_or_else(|| format!("{name} {version} is not in the index"))?;
+    let (entry, offers) = {
+        let patches = library.patches.read();
+        let entry = patches
+            .entry(&name, &version)
+            .cloned()
+            .ok_or_else(|| format!("{name} {version} is not in the index"))?;
+        (entry, patches.offers(&name, &version).to_vec())
+    };
 
     let outcome = patch::download(
         &library.http,
         &library.files,
-        &library.config.patch_repo_url(),
+        &offers,
         &library.config.patches_path(),
         &name,
         &version,
@@ -521,7 +560,7 @@ pub fn patched_rom(game: GameRef, patch_pick: Option<&(String, semver::Version)>
         .roms
         .read()
         .get(&game)
-        .cloned()
+        .map(|rom| rom.bytes.clone())
         .ok_or_else(|| format!("no rom for {}", game.family_and_variant().0))?;
     let Some((name, version)) = patch_pick else {
         return Ok(raw);
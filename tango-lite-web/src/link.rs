@@ -81,11 +81,16 @@ pub enum Verdict {
     Compatible,
     MissingGame,
     MissingRom,
-    Fetching { name: String },
+    Fetching {
+        name: String,
+    },
     DifferentVersions,
     SimVersionTooOld,
     SimVersionTooNew,
     DifferentMatchTypes,
+    /// The opponent plays under a tournament rule set, which this build
+    /// can't hold a save to (it has no save model to read one with).
+    TournamentRules,
 }
 
 pub fn snapshot() -> Snapshot {
@@ -192,6 +197,10 @@ fn verdict(link: &Link) -> Option<Verdict> {
             compat::Verdict::SimVersionTooOld => Verdict::SimVersionTooOld,
             compat::Verdict::SimVersionTooNew => Verdict::SimVersionTooNew,
             compat::Verdict::DifferentMatchTypes => Verdict::DifferentMatchTypes,
+            // We never name a rule set, so any of these means theirs.
+            compat::Verdict::DifferentRuleSets
+            | compat::Verdict::PatchNotAllowed { .. }
+            | compat::Verdict::RuleBroken { .. } => Verdict::TournamentRules,
         }
     })
 }
@@ -313,6 +322,8 @@ fn push_settings() {
             // No blind-setup toggle: this build has no save viewer to
             // blind, so there is nothing for the flag to hide.
             blind_setup: false,
+            // Nor a save model to check a rule set's limits with.
+            rule_set: None,
         }
     });
     LINK.with(|l| l.borrow_mut().net.send_local_settings(settings));
@@ -26,13 +26,12 @@ impl Loadout {
         self.game.is_some() && self.save_path.is_some()
     }
 
-    /// The savedata as it stands on "disk".
+    /// The savedata as it stands on "disk", out of any emulator's
+    /// wrapper it was imported in.
     pub fn save_bytes(&self) -> Option<Vec<u8>> {
         let path = self.save_path.as_ref()?;
-        crate::library::with(|library| {
-            use tango_library::storage::Storage as _;
-            library.files.read(path).ok()
-        })?
+        crate::library::with(|library| tango_library::save::read_save(&library.files, path).ok())?
+            .map(|save| save.image)
     }
 
     /// The ROM image to run: the stored dump with the patch applied.
@@ -39,18 +39,36 @@ use tango_library::storage::{Entry, ListFuture, Listing, ReadSeek, Storage};
 #[derive(Clone)]
 pub struct Files {
     inner: Rc<RefCell<HashMap<PathBuf, Vec<u8>>>>,
+    /// When each file was last written, in milliseconds since the epoch,
+    /// for [`Entry::modified`]. Mirrored beside the bytes (see
+    /// [`MODIFIED_PREFIX`]); a file written before times were kept has
+    /// none until its next write.
+    modified: Rc<RefCell<HashMap<PathBuf, u64>>>,
 }
 
+/// Key prefix for a file's modification time in the mirror: the path's
+/// own key behind it, the time as 8 little-endian bytes. File keys all
+/// start with `/`, so the two never collide.
+const MODIFIED_PREFIX: &str = "modified:";
+
 impl Files {
     /// Read the whole persisted store into memory. Everything after this
     /// is synchronous.
     pub async fn load() -> Self {
         let mut inner = HashMap::new();
+        let mut modified = HashMap::new();
         for key in idb::keys().await.unwrap_or_default() {
             match idb::get(&key).await {
-                Ok(Some(bytes)) => {
-                    inner.insert(PathBuf::from(&key), bytes);
-                }
+                Ok(Some(bytes)) => match key.strip_prefix(MODIFIED_PREFIX) {
+                    Some(path) => {
+                        if let Ok(time) = <[u8; 8]>::try_from(bytes.as_slice()) {
+                            modified.insert(PathBuf::from(path), u64::from_le_bytes(time));
+                        }
+                    }
+                    None => {
+                        inner.insert(PathBuf::from(&key), bytes);
+                    }
+                },
                 Ok(None) => {}
                 Err(e) => log::warn!("storage: load {key} failed: {e:?}"),
             }
@@ -58,6 +76,7 @@ impl Files {
         log::info!("storage: {} files loaded", inner.len());
         Self {
             inner: Rc::new(RefCell::new(inner)),
+            modified: Rc::new(RefCell::new(modified)),
         }
     }
 
@@ -90,16 +109,34 @@ impl Files {
     fn persist(&self, path: &Path) {
         let key = key(path);
         let value = self.inner.borrow().get(path).cloned();
+        let modified = self.modified.borrow().get(path).copied();
         wasm_bindgen_futures::spawn_local(async move {
-            let result = match value {
-                Some(bytes) => idb::put(&key, &bytes).await,
-                None => idb::delete(&key).await,
+            let modified_key = format!("{MODIFIED_PREFIX}{key}");
+            let result = match (value, modified) {
+                (Some(bytes), modified) => {
+                    let stamped = match modified {
+                        Some(time) => idb::put(&modified_key, &time.to_le_bytes()).await,
+                        None => Ok(()),
+                    };
+                    stamped.and(idb::put(&key, &bytes).await)
+                }
+                (None, _) => idb::delete(&key).await.and(idb::delete(&modified_key).await),
             };
             if let Err(e) = result {
                 log::warn!("storage: persisting {key} failed: {e:?}");
             }
         });
     }
+
+    /// Mark `path` as written just now. Strictly later than its last
+    /// write even within the same millisecond, or a quick rewrite of the
+    /// same length would look unchanged to the scan cache.
+    fn touch(&self, path: &Path) {
+        let now = js_sys::Date::now() as u64;
+        let mut modified = self.modified.borrow_mut();
+        let time = modified.get(path).map_or(now, |&last| now.max(last + 1));
+        modified.insert(path.to_path_buf(), time);
+    }
 }
 
 /// Paths are the keys. They are always the ones this app built (rooted
@@ -128,6 +165,7 @@ impl Storage for Files {
         // No directories to create: a flat path→bytes map has no empty
         // parents to miss, and `list` derives directories from the keys.
         self.inner.borrow_mut().insert(path.to_path_buf(), data.to_vec());
+        self.touch(path);
         self.persist(path);
         Ok(())
     }
@@ -136,6 +174,7 @@ impl Storage for Files {
         if self.inner.borrow_mut().remove(path).is_none() {
             return Err(not_found(path));
         }
+        self.modified.borrow_mut().remove(path);
         self.persist(path);
         Ok(())
     }
@@ -152,6 +191,12 @@ impl Storage for Files {
         // worst case is a stale duplicate that the next load ignores.
         let bytes = self.inner.borrow_mut().remove(from).ok_or_else(|| not_found(from))?;
         self.inner.borrow_mut().insert(to.to_path_buf(), bytes);
+        // Moved with the bytes, as a native rename keeps the mtime.
+        let modified = self.modified.borrow_mut().remove(from);
+        match modified {
+            Some(time) => self.modified.borrow_mut().insert(to.to_path_buf(), time),
+            None => self.modified.borrow_mut().remove(to),
+        };
         self.persist(from);
         self.persist(to);
         Ok(())
@@ -173,17 +218,15 @@ impl Storage for Files {
             .map(|(path, bytes)| Entry {
                 path: path.clone(),
                 len: bytes.len() as u64,
-                // No modification times: nothing here is edited outside
-                // this app, so the listing's other fields are enough of a
-                // rescan fingerprint on their own.
-                modified: None,
+                modified: self.modified.borrow().get(path).copied(),
             })
             .collect();
         Box::pin(std::future::ready(Listing::new(entries)))
     }
 }
 
-/// The mirror: a single IndexedDB object store of path → bytes.
+/// The mirror: a single IndexedDB object store of path → bytes, plus
+/// each file's [`MODIFIED_PREFIX`] entry.
 mod idb {
     use super::*;
 
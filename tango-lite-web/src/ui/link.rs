@@ -251,6 +251,10 @@ fn VerdictLine(verdict: Verdict) -> Element {
             "This game's netplay changed since your version of Tango — you need to update.".to_string(),
         ),
         Verdict::DifferentMatchTypes => ("error", "You've picked different match types.".to_string()),
+        Verdict::TournamentRules => (
+            "error",
+            "Your opponent is playing under tournament rules, which this version of Tango can't check.".to_string(),
+        ),
     };
     rsx! { div { class: "{class}", "{text}" } }
 }
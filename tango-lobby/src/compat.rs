@@ -7,7 +7,14 @@
 //! - resolve to the same [`tango_patch::Tag`],
 //! - have both sides' patch packages installed,
 //! - be builds that simulate the game the same way (`sim_version`),
-//! - agree on `match_type`.
+//! - agree on `match_type`,
+//! - play under the same tournament rule set, if any, on a patch it
+//!   allows.
+//!
+//! Whether each side's *save* keeps the rule set can't be told from
+//! Settings: that is checked on both peers once the saves are revealed
+//! (see [`RuleCheck`]), and a save that breaks it is a
+//! [`Verdict::RuleBroken`] the lobby holds instead of starting.
 //!
 //! Possession is checked from our side only — the legacy app exchanged
 //! `available_games` / `available_patches` lists over the wire, but the
@@ -66,6 +73,52 @@ pub enum Verdict {
     SimVersionTooNew,
     /// Tags agree but the picked match types diverge.
     DifferentMatchTypes,
+    /// The two sides name different tournament rule sets (or only one
+    /// names one).
+    DifferentRuleSets,
+    /// The rule set doesn't allow the patch one side is playing (`None`:
+    /// the unpatched game).
+    PatchNotAllowed {
+        side: Side,
+        patch: Option<protocol::PatchInfo>,
+    },
+    /// A revealed save breaks the rule set. Decided on both peers from
+    /// the same two saves, so neither sends StartMatch.
+    RuleBroken {
+        side: Side,
+        breach: tango_gamesupport::RuleBreach,
+    },
+}
+
+/// Which peer a [`Verdict`] is about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Local,
+    Remote,
+}
+
+/// Reads a revealed save and checks it against a rule set — the host's
+/// half of the rule check, since only it can parse a save (it holds the
+/// ROMs, the patches and the game's save model).
+pub trait RuleCheck: Send + Sync {
+    /// Every breach of `rules` in `save_data`, a save for `game_info`'s
+    /// game. `Err` when the save can't be read at all.
+    fn check(
+        &self,
+        game_info: &protocol::GameInfo,
+        save_data: &[u8],
+        rules: &tango_gamesupport::BuildRules,
+    ) -> Result<Vec<tango_gamesupport::RuleBreach>, String>;
+}
+
+/// The save-level half of a rule set, as [`RuleCheck`] takes it.
+pub fn build_rules(rule_set: &protocol::RuleSet) -> tango_gamesupport::BuildRules {
+    tango_gamesupport::BuildRules {
+        banned_chips: rule_set.banned_chips.clone(),
+        banned_navicust_parts: rule_set.banned_navicust_parts.clone(),
+        max_copies: rule_set.max_copies,
+        max_hp: rule_set.max_hp,
+    }
 }
 
 /// Are these two peers ready to play together? `roms` is the local ROM
@@ -73,7 +126,7 @@ pub enum Verdict {
 pub fn check(
     local: &protocol::Settings,
     remote: &protocol::Settings,
-    roms: &std::collections::HashMap<tango_library::rom::GameRef, Vec<u8>>,
+    roms: &tango_library::rom::Roms,
     catalog: &Catalog,
 ) -> Verdict {
     let (Some(local_gi), Some(remote_gi)) = (local.game_info.as_ref(), remote.game_info.as_ref()) else {
@@ -112,6 +165,22 @@ pub fn check(
         std::cmp::Ordering::Equal => {}
     }
 
+    // Rules before fetching, for the same reason as identity: a patch
+    // the rule set turns away isn't worth downloading.
+    if local.rule_set != remote.rule_set {
+        return Verdict::DifferentRuleSets;
+    }
+    if let Some(rule_set) = &local.rule_set {
+        for (side, gi) in [(Side::Local, local_gi), (Side::Remote, remote_gi)] {
+            if !rule_set.allows_patch(gi.patch.as_ref()) {
+                return Verdict::PatchNotAllowed {
+                    side,
+                    patch: gi.patch.clone(),
+                };
+            }
+        }
+    }
+
     if let Some(missing) = missing_patch(local, remote, catalog) {
         return missing;
     }
@@ -14,6 +14,7 @@ use subtle::ConstantTimeEq;
 
 use tango_net_protocol::control::make_commitment;
 
+use super::compat::{self, Side, Verdict};
 use super::{Command, Error, Event, Phase, State};
 
 #[derive(Clone)]
@@ -147,6 +148,10 @@ impl RemoteReady {
 pub(super) struct Handshake {
     pub(super) local: LocalReady,
     pub(super) remote: RemoteReady,
+    /// Why the last verified pair of reveals may not play — a save that
+    /// breaks the rule set in force. Held (so the lobby can say which
+    /// rule) until either side commits afresh.
+    pub(super) refusal: Option<Verdict>,
 }
 
 /// UI projection of the two ladders, derived per frame (and frozen
@@ -176,6 +181,12 @@ impl State {
         }
     }
 
+    /// Why the revealed saves may not play, if the rule check refused
+    /// them. See [`compat::RuleCheck`].
+    pub fn refusal(&self) -> Option<&Verdict> {
+        self.handshake.refusal.as_ref()
+    }
+
     /// Whether we've committed — the host's re-commit / uncommit
     /// triggers key off this.
     pub fn local_ready(&self) -> bool {
@@ -237,6 +248,7 @@ impl State {
         };
         let commitment = make_commitment(&compressed);
         self.handshake.local = LocalReady::Committed(LocalCommit { state, compressed });
+        self.handshake.refusal = None;
         // A fresh commitment supersedes whatever pairing the peer's
         // StartMatch belonged to; they'll send another once they've
         // verified the reveal that follows this Commit (see
@@ -298,9 +310,25 @@ impl State {
                 return None;
             }
         };
-        if let Err(e) = tango_net_protocol::control::NegotiatedState::deserialize(&peer_state_bytes) {
-            self.fail(Error::Other(format!("decode peer state: {e}")));
-            return None;
+        let peer_state = match tango_net_protocol::control::NegotiatedState::deserialize(&peer_state_bytes) {
+            Ok(state) => state,
+            Err(e) => {
+                self.fail(Error::Other(format!("decode peer state: {e}")));
+                return None;
+            }
+        };
+        // Both peers check both saves against the same rule set, so both
+        // reach the same refusal and neither sends StartMatch.
+        match self.rule_refusal(&peer_state.save_data) {
+            Ok(None) => {}
+            Ok(Some(refusal)) => {
+                self.handshake.refusal = Some(refusal);
+                return None;
+            }
+            Err(e) => {
+                self.fail(Error::Other(format!("check rules: {e}")));
+                return None;
+            }
         }
         let LocalReady::ChunksSent(commit) = std::mem::take(&mut self.handshake.local) else {
             unreachable!();
@@ -310,6 +338,35 @@ impl State {
         self.match_ready_event()
     }
 
+    /// The first breach of the rule set in force across our save and
+    /// `remote_save`, ours first. `Ok(None)` with no rule set, or when
+    /// both saves keep it.
+    fn rule_refusal(&self, remote_save: &[u8]) -> Result<Option<Verdict>, String> {
+        let Some(rule_set) = self.lobby.local.as_ref().and_then(|s| s.rule_set.as_ref()) else {
+            return Ok(None);
+        };
+        let Some(check) = &self.rule_check else {
+            return Err("a rule set is in force but this build can't read saves to check it".to_string());
+        };
+        let LocalReady::ChunksSent(commit) = &self.handshake.local else {
+            return Ok(None);
+        };
+        let rules = compat::build_rules(rule_set);
+        for (side, settings, save) in [
+            (Side::Local, &self.lobby.local, commit.state.save_data.as_slice()),
+            (Side::Remote, &self.lobby.remote, remote_save),
+        ] {
+            let game_info = settings
+                .as_ref()
+                .and_then(|s| s.game_info.as_ref())
+                .ok_or("a side has no game picked")?;
+            if let Some(breach) = check.check(game_info, save, &rules)?.into_iter().next() {
+                return Ok(Some(Verdict::RuleBroken { side, breach }));
+            }
+        }
+        Ok(None)
+    }
+
     /// Both sides have sent + received StartMatch — the host's cue to spin
     /// up the live match. `None` until both halves are present.
     pub(super) fn match_ready_event(&self) -> Option<Event> {
@@ -413,4 +470,83 @@ mod tests {
         assert!(state.apply(Incoming(Inbound::RemoteStartMatch)).is_none());
         assert!(matches!(reveal(&mut state, &second_reveal), Some(Event::MatchReady)));
     }
+
+    /// Flags any save whose first byte is 0xab — the peer's, in
+    /// [`peer_reveal`] — as carrying banned chip 1.
+    struct BanPeerSave;
+
+    impl compat::RuleCheck for BanPeerSave {
+        fn check(
+            &self,
+            _game_info: &tango_net_protocol::control::GameInfo,
+            save_data: &[u8],
+            _rules: &tango_gamesupport::BuildRules,
+        ) -> Result<Vec<tango_gamesupport::RuleBreach>, String> {
+            Ok(if save_data.first() == Some(&0xab) {
+                vec![tango_gamesupport::RuleBreach::BannedChip { chip: 1, name: None }]
+            } else {
+                vec![]
+            })
+        }
+    }
+
+    fn lobby_under_rules() -> State {
+        let settings = tango_net_protocol::control::Settings {
+            game_info: Some(tango_net_protocol::control::GameInfo {
+                family_and_variant: ("bn6".to_string(), 0),
+                patch: None,
+                sim_version: 0,
+            }),
+            rule_set: Some(tango_net_protocol::control::RuleSet {
+                name: "test".to_string(),
+                banned_chips: vec![1],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut state = lobby();
+        state.lobby.local = Some(settings.clone());
+        state.lobby.remote = Some(settings);
+        state.set_rule_check(std::sync::Arc::new(BanPeerSave));
+        state
+    }
+
+    /// A revealed save that breaks the rule set holds the match — no
+    /// StartMatch from us, so no handoff however theirs arrives — and
+    /// the refusal names the side and the rule.
+    #[test]
+    fn a_save_breaking_the_rules_holds_the_match() {
+        let (commitment, compressed) = peer_reveal(7);
+        let mut state = lobby_under_rules();
+
+        assert!(state.apply(Incoming(Inbound::RemoteCommit(commitment))).is_none());
+        assert!(state.commit(vec![1, 2, 3]).is_none());
+        assert!(reveal(&mut state, &compressed).is_none());
+        assert!(!state.ready_view().match_ready);
+        assert_eq!(
+            state.refusal(),
+            Some(&Verdict::RuleBroken {
+                side: Side::Remote,
+                breach: tango_gamesupport::RuleBreach::BannedChip { chip: 1, name: None },
+            })
+        );
+        assert!(state.apply(Incoming(Inbound::RemoteStartMatch)).is_none());
+
+        // Their fixed save re-commits: the refusal goes with the old one,
+        // and the new reveal verifies through to the match.
+        let mut fixed = tango_net_protocol::control::NegotiatedState {
+            nonce: [9; 16],
+            ts: 1,
+            save_data: vec![0xcd; 64],
+        }
+        .serialize()
+        .unwrap();
+        fixed = zstd::stream::encode_all(std::io::Cursor::new(fixed), 3).unwrap();
+        assert!(state
+            .apply(Incoming(Inbound::RemoteCommit(make_commitment(&fixed))))
+            .is_none());
+        assert!(state.refusal().is_none());
+        assert!(reveal(&mut state, &fixed).is_none());
+        assert!(state.ready_view().match_ready);
+    }
 }
@@ -264,6 +264,10 @@ pub struct State {
     /// `None` on the direct path (its recipe rides
     /// `ConnectionHandles::reconnect` instead).
     matchmaking_reconnect: Option<MatchmakingParams>,
+    /// The host's reader for revealed saves, used to hold both sides to
+    /// a tournament rule set. `None` in a host that can't read saves,
+    /// which then can't play under one. See [`State::set_rule_check`].
+    rule_check: Option<Arc<dyn compat::RuleCheck>>,
 }
 
 #[derive(Clone)]
@@ -298,6 +302,10 @@ pub struct LobbyState {
     /// TCP path) or relayed through a TURN server. `None` when it
     /// couldn't be determined.
     pub connection_kind: Option<ConnectionKind>,
+    /// The tournament rule set we play under, if any. Local-only UI
+    /// state like `match_type`; gets folded into Settings on send, and
+    /// the peer has to name the same one.
+    pub rule_set: Option<tango_net_protocol::control::RuleSet>,
 }
 
 /// See [`LobbyState::connection_kind`].
@@ -319,6 +327,7 @@ impl Default for LobbyState {
             blind_setup: false,
             default_mt_for_family: None,
             connection_kind: None,
+            rule_set: None,
         }
     }
 }
@@ -336,6 +345,7 @@ impl Default for State {
             lobby: LobbyState::default(),
             handshake: Handshake::default(),
             matchmaking_reconnect: None,
+            rule_check: None,
         }
     }
 }
@@ -487,7 +497,9 @@ impl State {
             }
             Inbound::RemoteCommit(c) => {
                 // A fresh commitment starts a fresh reveal — any prior
-                // chunks / StartMatch belonged to the pairing it replaces.
+                // chunks / StartMatch belonged to the pairing it replaces,
+                // and so did any rule refusal.
+                self.handshake.refusal = None;
                 self.handshake.remote = RemoteReady::Committed {
                     commitment: c,
                     expected: None,
@@ -682,6 +694,19 @@ impl State {
         self.lobby.match_type = match_type;
     }
 
+    /// The user picked (or cleared) a tournament rule set. As with
+    /// [`set_match_type`](Self::set_match_type), the host's settings
+    /// resend does the unready.
+    pub fn set_rule_set(&mut self, rule_set: Option<tango_net_protocol::control::RuleSet>) {
+        self.lobby.rule_set = rule_set;
+    }
+
+    /// Install the host's save reader for rule checks. Kept across
+    /// sessions; a host sets it once.
+    pub fn set_rule_check(&mut self, check: Arc<dyn compat::RuleCheck>) {
+        self.rule_check = Some(check);
+    }
+
     /// The user toggled the blind-setup checkbox.
     pub fn set_blind_setup(&mut self, v: bool) {
         let prev = self.lobby.blind_setup;
@@ -957,7 +982,7 @@ impl State {
 pub use tango_session::pvp::PreMatchData;
 
 /// Does this settings change warrant auto-unready? `true` for
-/// game-info or match-type changes (the user's effectively
+/// game-info, match-type or rule-set changes (the user's effectively
 /// changed what they're offering up), `false` for nickname /
 /// available-games churn (cosmetic / metadata-only). Lets
 /// `send_local_settings` drop stale commits without forcing
@@ -967,5 +992,5 @@ fn settings_materially_differ(
     a: &tango_net_protocol::control::Settings,
     b: &tango_net_protocol::control::Settings,
 ) -> bool {
-    a.game_info != b.game_info || a.match_type != b.match_type
+    a.game_info != b.game_info || a.match_type != b.match_type || a.rule_set != b.rule_set
 }
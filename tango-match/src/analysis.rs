@@ -51,7 +51,22 @@ pub enum BattleOutcome {
 // the battle; they now anchor it at the priming handoff like the
 // trap-anchored families, and v16 sidecars carrying the old late
 // starts would read as phantom setups.
-pub const FORMAT_VERSION: u32 = 17;
+// v18: the optional per-unit readings (custom gauge, status, buster
+// charge, form) ride along as change-point lanes per side, between the
+// chip uses and the rounds.
+// v19: HP drops are attributed to a source (see [`DamageSource`]) and
+// stored as per-side hit lanes after the reading lanes.
+// v20: both units' tiles are kept as change-point lanes after the hits
+// (see [`MatchStats::movement`]).
+// v21: custom-screen spans are kept per side — each core reports its
+// own player's screen — instead of the local side's alone.
+// v22: no more buster attribution: nothing polls the charge lane it
+// keyed on, so a v21 hit tagged buster can't have come from a real
+// replay, and the tag is now rejected.
+// v23: the reading lanes are gone again. No game polled the gauge,
+// status or charge, and the one form read sat at an unchecked offset,
+// so the hits now follow the chip uses directly.
+pub const FORMAT_VERSION: u32 = 23;
 
 /// Sidecar file magic.
 const MAGIC: &[u8; 4] = b"TGST";
@@ -62,6 +77,11 @@ const MAGIC: &[u8; 4] = b"TGST";
 /// the other series in [`MatchStats::read`].
 const MAX_HP_POINTS: usize = 65536;
 
+/// The same cap for each tile lane. A navi changes tiles a few times a
+/// second at most, so this is as far above a real match as
+/// [`MAX_HP_POINTS`] is.
+const MAX_TILE_POINTS: u32 = 65536;
+
 /// Per-match statistics, from the local player's perspective of the
 /// replay (or live session) they came from.
 ///
@@ -79,14 +99,28 @@ pub struct MatchStats {
     /// reconstructs exactly. Empty for a match that never got past a
     /// battle intro.
     pub hp: Vec<HpPoint>,
-    /// `[start, end)` tick spans during which the custom screen (chip
-    /// select) was open. Empty on games whose pollers don't report the
-    /// flag.
-    pub custom: Vec<(u32, u32)>,
+    /// `[start, end)` tick spans during which each side's custom screen
+    /// (chip select) was open, `[local, remote]`. Empty on games whose
+    /// pollers don't report the flag. See
+    /// [`custom_timing`](Self::custom_timing) for what's derived from
+    /// them.
+    pub custom: [Vec<(u32, u32)>; 2],
     /// Chip-use events per side (`[local, remote]`): `(tick, chip id)`
     /// as the game's poller reported them. Empty on games that report
     /// no chips.
     pub chip_uses: [Vec<(u32, u16)>; 2],
+    /// Every HP drop, attributed, per DEALING side (`[local, remote]`):
+    /// `hits[0]` is the damage the local player did to the remote one.
+    /// See [`DamageSource`] for the attribution rule and
+    /// [`damage_totals`](Self::damage_totals) for the per-chip sums.
+    pub hits: [Vec<Hit>; 2],
+    /// Both units' tiles per side (`[local, remote]`), `(tick, (x, y))`
+    /// in [`UnitObs::tile`](crate::telemetry::UnitObs::tile)'s
+    /// coordinates, change-point encoded: each entry holds until the
+    /// next, and every round's first tile is recorded whether or not it
+    /// changed, so a round's slice never starts on the previous round's
+    /// tail. Empty on games that don't report tiles (BCC).
+    pub tiles: [Vec<(u32, (u8, u8))>; 2],
     /// Where the rounds fall, in play order — the whole of what anything
     /// knows about round structure. Grows as an analysis reaches each
     /// boundary, so a partial fold is a truthful prefix rather than a
@@ -112,6 +146,127 @@ pub struct Round {
 
 pub use crate::battle::RoundSample;
 
+/// How far back (in ticks) an HP drop looks for the dealer's chip use
+/// that caused it: three seconds, long enough for the slow chips
+/// (bombs' arcs, towers crawling down a row, multi-hit sweeps) to land,
+/// short enough that a chip whiffed a while ago doesn't claim the
+/// unrelated damage that follows it, which falls to
+/// [`DamageSource::Other`] instead.
+pub const ATTRIBUTION_WINDOW: u32 = 180;
+
+/// What an HP drop is attributed to. The rule, applied to each drop
+/// the change-point fold sees on one side (the dealer being the OTHER
+/// side — a game's own HP only goes down by the opponent's doing or the
+/// field's):
+///
+/// 1. the dealer's most recent chip use at or before the drop, if it
+///    is within [`ATTRIBUTION_WINDOW`] ticks and the same round:
+///    [`Chip`](Self::Chip);
+/// 2. otherwise [`Other`](Self::Other) — the buster, panels, poison,
+///    anything the telemetry has no lane for. The buster gets no source
+///    of its own until some game polls its charge state.
+///
+/// Most-recent is a heuristic: two chips in flight at once credit the
+/// later one. It is a pure function of the series already in
+/// [`MatchStats`], so the same replay attributes identically every time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DamageSource {
+    Chip(u16),
+    Other,
+}
+
+/// One attributed HP drop.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Hit {
+    pub tick: u32,
+    pub source: DamageSource,
+    pub amount: u16,
+}
+
+/// One source's damage summed over a span — see
+/// [`MatchStats::damage_totals`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DamageTotal {
+    pub source: DamageSource,
+    pub damage: u32,
+    pub hits: u32,
+}
+
+/// One side's custom-screen timing over a span — see
+/// [`MatchStats::custom_timing`]. Ticks throughout; the per-window lists
+/// are parallel.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CustomTiming {
+    /// How long each custom window opened in the span stayed open.
+    pub windows: Vec<u32>,
+    /// Chips used after each window closed and before the next one
+    /// opened (or the span ended) — what that window's picks became.
+    pub chips: Vec<u32>,
+    /// Round start to the side's first chip use, one entry per round
+    /// covered that had one.
+    pub first_chip: Vec<u32>,
+}
+
+impl CustomTiming {
+    /// Mean time in a custom window.
+    pub fn mean_window(&self) -> Option<f32> {
+        mean(&self.windows)
+    }
+
+    /// Longest time in a custom window.
+    pub fn max_window(&self) -> Option<u32> {
+        self.windows.iter().copied().max()
+    }
+
+    /// Mean chips used per custom window.
+    pub fn mean_chips(&self) -> Option<f32> {
+        mean(&self.chips)
+    }
+
+    /// Mean round start to first chip use.
+    pub fn mean_first_chip(&self) -> Option<f32> {
+        mean(&self.first_chip)
+    }
+
+    /// Fold another span's timing into this one — how the match-wide
+    /// figures are built from the rounds'.
+    pub fn extend(&mut self, other: CustomTiming) {
+        self.windows.extend(other.windows);
+        self.chips.extend(other.chips);
+        self.first_chip.extend(other.first_chip);
+    }
+}
+
+fn mean(values: &[u32]) -> Option<f32> {
+    (!values.is_empty()).then(|| values.iter().map(|&v| v as f32).sum::<f32>() / values.len() as f32)
+}
+
+/// The battle field's size in tiles: six columns, three rows.
+pub const FIELD_COLUMNS: usize = 6;
+pub const FIELD_ROWS: usize = 3;
+
+/// One unit's movement over a span, derived from its tile lane — see
+/// [`MatchStats::movement`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Movement {
+    /// Ticks spent on each tile, `occupancy[y - 1][x - 1]`.
+    pub occupancy: [[u32; FIELD_COLUMNS]; FIELD_ROWS],
+    /// Tile changes within the span — a step, a teleport and a knockback
+    /// each count one.
+    pub moves: u32,
+    /// Ticks spent on the unit's own back column: column 1 for a unit
+    /// that started the span on the left half, column 6 for one that
+    /// started on the right.
+    pub back_row_ticks: u32,
+}
+
+impl Movement {
+    /// Every tick the heatmap covers.
+    pub fn total_ticks(&self) -> u32 {
+        self.occupancy.iter().flatten().sum()
+    }
+}
+
 /// One HP reading.
 #[derive(Clone, Copy, Debug)]
 pub struct HpPoint {
@@ -168,13 +323,144 @@ impl MatchStats {
         Some((round.start, end.max(round.start)))
     }
 
+    /// `side`'s damage dealt (0 local, 1 remote), summed per source
+    /// over the ticks in `[start, end)` — the whole match for `None` —
+    /// and sorted by damage, highest first (ties by hit count, then
+    /// source, so the order is stable).
+    pub fn damage_totals(&self, side: usize, span: Option<(u32, u32)>) -> Vec<DamageTotal> {
+        let mut totals: Vec<DamageTotal> = vec![];
+        for hit in &self.hits[side] {
+            if span.is_some_and(|(start, end)| hit.tick < start || hit.tick >= end) {
+                continue;
+            }
+            match totals.iter_mut().find(|t| t.source == hit.source) {
+                Some(t) => {
+                    t.damage += hit.amount as u32;
+                    t.hits += 1;
+                }
+                None => totals.push(DamageTotal {
+                    source: hit.source,
+                    damage: hit.amount as u32,
+                    hits: 1,
+                }),
+            }
+        }
+        totals.sort_by(|a, b| {
+            b.damage
+                .cmp(&a.damage)
+                .then(b.hits.cmp(&a.hits))
+                .then(a.source.cmp(&b.source))
+        });
+        totals
+    }
+
+    /// `side`'s movement (0 local, 1 remote) over `[start, end)`: the
+    /// tile lane read with step semantics — each tile holds from its
+    /// entry to the next, the last to `end` — so the occupancy counts
+    /// sum to the span's covered ticks. `None` when the lane has no
+    /// reading inside the span (a game without tiles, a round torn down
+    /// mid-intro). Ticks before the span's first reading (the intro)
+    /// aren't counted. Per round, pass [`round_span`](Self::round_span).
+    pub fn movement(&self, side: usize, (start, end): (u32, u32)) -> Option<Movement> {
+        let lane = &self.tiles[side];
+        let from = lane.partition_point(|&(t, _)| t < start);
+        let to = lane.partition_point(|&(t, _)| t < end);
+        let points = &lane[from..to];
+        let &(_, first) = points.first()?;
+        let back = if first.0 as usize <= FIELD_COLUMNS / 2 {
+            1
+        } else {
+            FIELD_COLUMNS as u8
+        };
+        let mut m = Movement::default();
+        for (i, &(tick, (x, y))) in points.iter().enumerate() {
+            let until = points.get(i + 1).map_or(end, |&(t, _)| t);
+            let ticks = until.saturating_sub(tick);
+            if let Some(cell) = (y as usize)
+                .checked_sub(1)
+                .and_then(|y| m.occupancy.get_mut(y))
+                .and_then(|row| row.get_mut((x as usize).checked_sub(1)?))
+            {
+                *cell += ticks;
+            }
+            if x == back {
+                m.back_row_ticks += ticks;
+            }
+            if i > 0 && points[i - 1].1 != (x, y) {
+                m.moves += 1;
+            }
+        }
+        Some(m)
+    }
+
+    /// `side`'s custom-screen timing (0 local, 1 remote) over round
+    /// `i`, spanned as [`round_span`](Self::round_span) spans it: every
+    /// custom window opening inside the round, the chips used after
+    /// each, and the round start to the side's first chip use. `None`
+    /// for a round that doesn't exist.
+    pub fn custom_timing(&self, side: usize, i: usize, match_end: Option<u32>) -> Option<CustomTiming> {
+        let (start, end) = self.round_span(i, match_end)?;
+        let windows: Vec<_> = self.custom[side]
+            .iter()
+            .filter(|&&(a, _)| a >= start && a < end)
+            .copied()
+            .collect();
+        let uses = &self.chip_uses[side];
+        let used = |from: u32, to: u32| uses.iter().filter(|&&(t, _)| t >= from && t < to).count() as u32;
+        Some(CustomTiming {
+            chips: windows
+                .iter()
+                .enumerate()
+                .map(|(w, &(_, close))| used(close, windows.get(w + 1).map_or(end, |&(open, _)| open)))
+                .collect(),
+            windows: windows.iter().map(|&(a, b)| b - a).collect(),
+            first_chip: uses
+                .iter()
+                .find(|&&(t, _)| t >= start && t < end)
+                .map(|&(t, _)| t - start)
+                .into_iter()
+                .collect(),
+        })
+    }
+
+    /// [`custom_timing`](Self::custom_timing) over every round at once.
+    pub fn match_custom_timing(&self, side: usize, match_end: Option<u32>) -> CustomTiming {
+        let mut all = CustomTiming::default();
+        for i in 0..self.rounds.len() {
+            all.extend(self.custom_timing(side, i, match_end).unwrap_or_default());
+        }
+        all
+    }
+
+    /// What `side`'s HP drop at `tick` is attributed to — see
+    /// [`DamageSource`]. Reads only what's been folded so far, which is
+    /// everything at or before `tick`.
+    fn attribute(&self, side: usize, tick: u32) -> DamageSource {
+        let dealer = 1 - side;
+        // Never reaching back past the round's own start: last round's
+        // chips don't carry over.
+        let round_start = self.rounds.last().map_or(0, |r| r.start);
+        let recent = |t: u32| t >= round_start && t <= tick && tick - t <= ATTRIBUTION_WINDOW;
+        if let Some(&(t, chip)) = self.chip_uses[dealer].last() {
+            if recent(t) {
+                return DamageSource::Chip(chip);
+            }
+        }
+        DamageSource::Other
+    }
+
     /// The last tick any series reached.
     fn last_tick(&self) -> Option<u32> {
         [
             self.hp.last().map(|p| p.tick),
-            self.custom.last().map(|&(_, end)| end),
+            self.custom[0].last().map(|&(_, end)| end),
+            self.custom[1].last().map(|&(_, end)| end),
             self.chip_uses[0].last().map(|&(t, _)| t),
             self.chip_uses[1].last().map(|&(t, _)| t),
+            self.hits[0].last().map(|h| h.tick),
+            self.hits[1].last().map(|h| h.tick),
+            self.tiles[0].last().map(|&(t, _)| t),
+            self.tiles[1].last().map(|&(t, _)| t),
         ]
         .into_iter()
         .flatten()
@@ -216,11 +502,14 @@ pub struct StatsBuilder {
     /// trace reaches its own right edge without every repeat costing an
     /// entry.
     pending: Option<HpPoint>,
-    /// A custom span opened and not yet closed.
-    custom_open: Option<u32>,
+    /// Each side's custom span opened and not yet closed.
+    custom_open: [Option<u32>; 2],
     /// While the round in progress is still repeating the previous
     /// round's final HP — see [`Self::start_round`].
     trimming: bool,
+    /// No sample of the round in progress has been recorded yet: the
+    /// next one anchors both tile lanes — see [`MatchStats::tiles`].
+    round_fresh: bool,
 }
 
 impl StatsBuilder {
@@ -249,6 +538,7 @@ impl StatsBuilder {
             outcome: None,
         });
         self.trimming = true;
+        self.round_fresh = true;
     }
 
     /// Append one simulated tick's sample.
@@ -274,6 +564,32 @@ impl StatsBuilder {
             local: sample.local,
             remote: sample.remote,
         };
+        let fresh = std::mem::take(&mut self.round_fresh);
+        for (lane, tile) in self.stats.tiles.iter_mut().zip(sample.tiles) {
+            // (0, 0) is a game with no tile to report.
+            if tile != (0, 0) && (fresh || lane.last().map(|&(_, t)| t) != Some(tile)) {
+                lane.push((sample.tick, tile));
+            }
+        }
+
+        // Drops against the last recorded point are hits — but not on a
+        // round's first point, whose "drop" is the reset between rounds.
+        if let (Some(prev), false) = (self.stats.hp.last().copied(), fresh) {
+            for (side, (before, after)) in [(prev.local, sample.local), (prev.remote, sample.remote)]
+                .into_iter()
+                .enumerate()
+            {
+                if after < before {
+                    let source = self.stats.attribute(side, sample.tick);
+                    self.stats.hits[1 - side].push(Hit {
+                        tick: sample.tick,
+                        source,
+                        amount: before - after,
+                    });
+                }
+            }
+        }
+
         match self.stats.hp.last() {
             // A repeat: remember it as the candidate final point.
             Some(prev) if (prev.local, prev.remote) == pair => self.pending = Some(point),
@@ -283,13 +599,20 @@ impl StatsBuilder {
             }
         }
 
-        match (sample.custom, self.custom_open) {
-            (true, None) => self.custom_open = Some(sample.tick),
-            (false, Some(start)) => {
-                self.stats.custom.push((start, sample.tick));
-                self.custom_open = None;
+        for ((open, spans), custom) in self
+            .custom_open
+            .iter_mut()
+            .zip(&mut self.stats.custom)
+            .zip(sample.custom)
+        {
+            match (custom, *open) {
+                (true, None) => *open = Some(sample.tick),
+                (false, Some(start)) => {
+                    spans.push((start, sample.tick));
+                    *open = None;
+                }
+                _ => {}
             }
-            _ => {}
         }
     }
 
@@ -318,8 +641,10 @@ impl StatsBuilder {
         if let Some(point) = self.pending.take() {
             self.stats.hp.push(point);
         }
-        if let (Some(start), Some(last)) = (self.custom_open.take(), self.last_tick) {
-            self.stats.custom.push((start, last + 1));
+        for (open, spans) in self.custom_open.iter_mut().zip(&mut self.stats.custom) {
+            if let (Some(start), Some(last)) = (open.take(), self.last_tick) {
+                spans.push((start, last + 1));
+            }
         }
     }
 
@@ -339,8 +664,10 @@ impl StatsBuilder {
         if let Some(point) = self.pending {
             out.hp.push(point);
         }
-        if let (Some(start), Some(last)) = (self.custom_open, self.last_tick) {
-            out.custom.push((start, last + 1));
+        for (open, spans) in self.custom_open.iter().zip(&mut out.custom) {
+            if let (Some(start), Some(last)) = (open, self.last_tick) {
+                spans.push((*start, last + 1));
+            }
         }
         out
     }
@@ -365,6 +692,8 @@ pub enum ReadError {
     UnsupportedVersion(u32),
     #[error("bad outcome tag {0}")]
     BadOutcomeTag(i8),
+    #[error("bad damage source tag {0}")]
+    BadSourceTag(u8),
     /// A count field beyond the reader's sanity cap — corrupt data,
     /// rejected before allocating for it.
     #[error("implausible {what} count {n}")]
@@ -415,16 +744,15 @@ impl MatchStats {
                 remote: u16_of(&mut r)?,
             });
         }
-        let n_custom = u32_of(&mut r)?;
-        if n_custom > 8192 {
-            return Err(ReadError::ImplausibleCount {
-                what: "custom span",
-                n: n_custom,
-            });
-        }
-        let mut custom = Vec::with_capacity(n_custom as usize);
-        for _ in 0..n_custom {
-            custom.push((u32_of(&mut r)?, u32_of(&mut r)?));
+        let mut custom: [Vec<(u32, u32)>; 2] = [vec![], vec![]];
+        for side in &mut custom {
+            let n = u32_of(&mut r)?;
+            if n > 8192 {
+                return Err(ReadError::ImplausibleCount { what: "custom span", n });
+            }
+            for _ in 0..n {
+                side.push((u32_of(&mut r)?, u32_of(&mut r)?));
+            }
         }
         let mut chip_uses: [Vec<(u32, u16)>; 2] = [vec![], vec![]];
         for side in &mut chip_uses {
@@ -436,6 +764,54 @@ impl MatchStats {
                 side.push((u32_of(&mut r)?, u16_of(&mut r)?));
             }
         }
+        fn lane<T>(
+            r: &mut impl std::io::Read,
+            what: &'static str,
+            value: impl Fn(&mut dyn std::io::Read) -> Result<T, ReadError>,
+        ) -> Result<Vec<(u32, T)>, ReadError> {
+            let n = u32_of(r)?;
+            if n > MAX_TILE_POINTS {
+                return Err(ReadError::ImplausibleCount { what, n });
+            }
+            let mut out = Vec::with_capacity(n as usize);
+            for _ in 0..n {
+                let tick = u32_of(r)?;
+                out.push((tick, value(r)?));
+            }
+            Ok(out)
+        }
+        fn u8_of(r: &mut dyn std::io::Read) -> std::io::Result<u8> {
+            let mut b = [0u8; 1];
+            r.read_exact(&mut b)?;
+            Ok(b[0])
+        }
+        let mut hits: [Vec<Hit>; 2] = [vec![], vec![]];
+        for side in &mut hits {
+            let n = u32_of(&mut r)?;
+            // Every hit is an HP change point, so the HP cap bounds it.
+            if n as usize > MAX_HP_POINTS {
+                return Err(ReadError::ImplausibleCount { what: "hit", n });
+            }
+            for _ in 0..n {
+                let tick = u32_of(&mut r)?;
+                let tag = u8_of(&mut r)?;
+                let chip = u16_of(&mut r)?;
+                let source = match tag {
+                    0 => DamageSource::Chip(chip),
+                    2 => DamageSource::Other,
+                    other => return Err(ReadError::BadSourceTag(other)),
+                };
+                side.push(Hit {
+                    tick,
+                    source,
+                    amount: u16_of(&mut r)?,
+                });
+            }
+        }
+        let mut tiles: [Vec<(u32, (u8, u8))>; 2] = [vec![], vec![]];
+        for side in &mut tiles {
+            *side = lane(&mut r, "tile point", |r| Ok((u8_of(r)?, u8_of(r)?)))?;
+        }
         let n_rounds = u32_of(&mut r)?;
         // A best-of-3 match writes 2-3 rounds; anything huge is a
         // corrupt count, better rejected than allocated.
@@ -468,6 +844,8 @@ impl MatchStats {
             hp,
             custom,
             chip_uses,
+            hits,
+            tiles,
             rounds,
         })
     }
@@ -481,10 +859,12 @@ impl MatchStats {
             w.write_all(&p.local.to_le_bytes())?;
             w.write_all(&p.remote.to_le_bytes())?;
         }
-        w.write_all(&(self.custom.len() as u32).to_le_bytes())?;
-        for &(a, b) in &self.custom {
-            w.write_all(&a.to_le_bytes())?;
-            w.write_all(&b.to_le_bytes())?;
+        for side in &self.custom {
+            w.write_all(&(side.len() as u32).to_le_bytes())?;
+            for &(a, b) in side {
+                w.write_all(&a.to_le_bytes())?;
+                w.write_all(&b.to_le_bytes())?;
+            }
         }
         for side in &self.chip_uses {
             w.write_all(&(side.len() as u32).to_le_bytes())?;
@@ -493,6 +873,26 @@ impl MatchStats {
                 w.write_all(&id.to_le_bytes())?;
             }
         }
+        for side in &self.hits {
+            w.write_all(&(side.len() as u32).to_le_bytes())?;
+            for hit in side {
+                let (tag, chip) = match hit.source {
+                    DamageSource::Chip(id) => (0u8, id),
+                    DamageSource::Other => (2, 0),
+                };
+                w.write_all(&hit.tick.to_le_bytes())?;
+                w.write_all(&[tag])?;
+                w.write_all(&chip.to_le_bytes())?;
+                w.write_all(&hit.amount.to_le_bytes())?;
+            }
+        }
+        for side in &self.tiles {
+            w.write_all(&(side.len() as u32).to_le_bytes())?;
+            for &(t, (x, y)) in side {
+                w.write_all(&t.to_le_bytes())?;
+                w.write_all(&[x, y])?;
+            }
+        }
         w.write_all(&(self.rounds.len() as u32).to_le_bytes())?;
         for round in &self.rounds {
             w.write_all(&round.start.to_le_bytes())?;
@@ -532,7 +932,8 @@ pub fn fold_confirmed(
             tick,
             local: obs.units[local_player].hp,
             remote: obs.units[1 - local_player].hp,
-            custom: obs.custom[local_player],
+            custom: [obs.custom[local_player], obs.custom[1 - local_player]],
+            tiles: [obs.units[local_player].tile, obs.units[1 - local_player].tile],
         });
     };
 
@@ -589,10 +990,7 @@ mod tests {
 
     fn obs(p0: u16, p1: u16, custom: bool) -> crate::telemetry::BattleObs {
         crate::telemetry::BattleObs {
-            units: [
-                UnitObs { hp: p0, tile: (1, 2) },
-                UnitObs { hp: p1, tile: (4, 2) },
-            ],
+            units: [UnitObs { hp: p0, tile: (1, 2) }, UnitObs { hp: p1, tile: (4, 2) }],
             custom: [custom, custom],
         }
     }
@@ -662,11 +1060,66 @@ mod tests {
             ]
         );
         // Custom span and chip use land on the same timebase, unsegmented.
-        assert_eq!(stats.custom, vec![(5, 7)]);
+        assert_eq!(stats.custom, [vec![(5, 7)], vec![(5, 7)]]);
         assert_eq!(stats.chip_uses[0], vec![(6, 42)]);
         assert!(stats.chip_uses[1].is_empty());
     }
 
+    /// Each drop credits the dealer's most recent chip within the window
+    /// and the round; with none, it lands on `Other`.
+    #[test]
+    fn hp_drops_are_attributed_to_the_dealer() {
+        let stats = two_round_match();
+        // Local dealt 10 before any chip (tick 5), then chip 42's 90 at
+        // tick 7 — and round 2's drop at 13 doesn't reach back into
+        // round 1 for it.
+        assert_eq!(
+            stats.hits[0],
+            vec![
+                Hit {
+                    tick: 5,
+                    source: DamageSource::Other,
+                    amount: 10,
+                },
+                Hit {
+                    tick: 7,
+                    source: DamageSource::Chip(42),
+                    amount: 90,
+                },
+                Hit {
+                    tick: 13,
+                    source: DamageSource::Other,
+                    amount: 60,
+                },
+            ]
+        );
+        // The remote side's 20 at tick 6; no hit for round 2's reset.
+        assert_eq!(
+            stats.hits[1],
+            vec![Hit {
+                tick: 6,
+                source: DamageSource::Other,
+                amount: 20,
+            }]
+        );
+        assert_eq!(
+            stats.damage_totals(0, None),
+            vec![
+                DamageTotal {
+                    source: DamageSource::Chip(42),
+                    damage: 90,
+                    hits: 1,
+                },
+                DamageTotal {
+                    source: DamageSource::Other,
+                    damage: 70,
+                    hits: 2,
+                },
+            ]
+        );
+        assert_eq!(stats.damage_totals(0, stats.round_span(1, None)).len(), 1);
+    }
+
     /// A recording that opens on a setup section (bn6 random battle: the
     /// interactive rank/folder phase before round 1) marks the setup →
     /// round 1 boundary too, so sectioned consumers split there; a
@@ -757,9 +1210,109 @@ mod tests {
         assert_eq!(snap.rounds.len(), done.rounds.len());
     }
 
+    /// The tile lanes give each round its heatmap, move count and
+    /// back-column time, the step semantics making the counts add up to
+    /// the span.
     #[test]
-    fn the_sidecar_roundtrips() {
+    fn tiles_derive_per_round_movement() {
         let stats = two_round_match();
+        // Constant tiles: one anchoring point per side per round.
+        assert_eq!(stats.tiles[0], vec![(3, (1, 2)), (12, (1, 2))]);
+        let round1 = stats.movement(0, stats.round_span(0, None).unwrap()).unwrap();
+        assert_eq!(round1.moves, 0);
+        assert_eq!(round1.occupancy[1][0], 7);
+        assert_eq!(round1.back_row_ticks, 7);
+        assert_eq!(round1.total_ticks(), 7);
+
+        let mut b = StatsBuilder::new();
+        fold_confirmed(&mut b, 0, vec![], vec![(0, Event::RoundStarted)]);
+        let at = |x: u8, y: u8| {
+            let mut o = obs(100, 100, false);
+            o.units[1].tile = (x, y);
+            o
+        };
+        fold_confirmed(
+            &mut b,
+            0,
+            vec![(1, at(6, 2)), (2, at(6, 2)), (4, at(5, 2)), (5, at(5, 1)), (9, at(6, 1))],
+            vec![],
+        );
+        let stats = b.finish();
+        let m = stats.movement(1, (0, 10)).unwrap();
+        assert_eq!(m.moves, 3);
+        assert_eq!(m.occupancy[1][5], 3);
+        assert_eq!(m.occupancy[1][4], 1);
+        assert_eq!(m.occupancy[0][4], 4);
+        assert_eq!(m.occupancy[0][5], 1);
+        // The right-half unit's back column is 6.
+        assert_eq!(m.back_row_ticks, 4);
+        assert_eq!(m.total_ticks(), 9);
+        assert_eq!(stats.movement(1, (20, 30)), None);
+    }
+
+    /// Each core's custom flag lands on its own side's spans, oriented
+    /// like everything else.
+    #[test]
+    fn custom_spans_are_kept_per_side() {
+        let mut b = StatsBuilder::new();
+        fold_confirmed(&mut b, 1, vec![], vec![(0, Event::RoundStarted)]);
+        let mut samples = vec![];
+        for (tick, custom) in [(1, [true, true]), (2, [true, false]), (3, [false, false])] {
+            let mut o = obs(100, 100, false);
+            o.custom = custom;
+            samples.push((tick, o));
+        }
+        fold_confirmed(&mut b, 1, samples, vec![]);
+        let stats = b.finish();
+        // Player 1 is local: it left custom at 2, player 0 at 3.
+        assert_eq!(stats.custom, [vec![(1, 2)], vec![(1, 3)]]);
+    }
+
+    #[test]
+    fn custom_timing_splits_windows_and_their_chips_by_round() {
+        let stats = MatchStats {
+            custom: [vec![(0, 300), (600, 700), (1000, 1240)], vec![]],
+            chip_uses: [
+                vec![(320, 1), (350, 2), (650, 3), (710, 4), (720, 5), (1300, 6)],
+                vec![],
+            ],
+            rounds: vec![
+                Round {
+                    start: 0,
+                    outcome: Some((1000, BattleOutcome::Win)),
+                },
+                Round {
+                    start: 1000,
+                    outcome: None,
+                },
+            ],
+            ..Default::default()
+        };
+        let round1 = stats.custom_timing(0, 0, Some(2000)).unwrap();
+        assert_eq!(round1.windows, vec![300, 100]);
+        // A chip fired while a window is open (650) belongs to neither
+        // window's stretch.
+        assert_eq!(round1.chips, vec![2, 2]);
+        assert_eq!(round1.first_chip, vec![320]);
+        let round2 = stats.custom_timing(0, 1, Some(2000)).unwrap();
+        assert_eq!(round2.windows, vec![240]);
+        assert_eq!(round2.chips, vec![1]);
+        assert_eq!(round2.first_chip, vec![300]);
+        assert_eq!(stats.custom_timing(0, 2, Some(2000)), None);
+
+        let all = stats.match_custom_timing(0, Some(2000));
+        assert_eq!(all.max_window(), Some(300));
+        assert_eq!(all.mean_window(), Some(640.0 / 3.0));
+        assert_eq!(all.mean_chips(), Some(5.0 / 3.0));
+        assert_eq!(all.mean_first_chip(), Some(310.0));
+        // The remote side reported nothing.
+        assert_eq!(stats.match_custom_timing(1, Some(2000)).mean_window(), None);
+    }
+
+    #[test]
+    fn the_sidecar_roundtrips() {
+        let mut stats = two_round_match();
+        stats.custom[1] = vec![(5, 6)];
         let mut buf = Vec::new();
         stats.write(&mut buf).unwrap();
         let back = MatchStats::read(&buf[..]).unwrap();
@@ -769,6 +1322,8 @@ mod tests {
         );
         assert_eq!(back.custom, stats.custom);
         assert_eq!(back.chip_uses, stats.chip_uses);
+        assert_eq!(back.hits, stats.hits);
+        assert_eq!(back.tiles, stats.tiles);
         assert_eq!(
             back.rounds.iter().map(|r| (r.start, r.outcome)).collect::<Vec<_>>(),
             stats.rounds.iter().map(|r| (r.start, r.outcome)).collect::<Vec<_>>()
@@ -37,7 +37,9 @@ struct World {
     /// Shared with the [`Match`], which hands it out for video, audio
     /// and RAM readout while the session simulates.
     link: Arc<Mutex<dyn Link>>,
-    live_tick: u32,
+    /// The tick the pair is parked at. Shared with the [`Match`] so a
+    /// host can stamp its own readings on the simulation's timebase.
+    live_tick: Arc<AtomicU32>,
     local_player: usize,
     /// Snapshots the engine has retired, kept for their allocations —
     /// a rollback session retires one nearly every tick and they run to
@@ -77,7 +79,8 @@ impl getgud::World for World {
         inputs[1 - self.local_player] = remotes[0];
         inputs[self.local_player] = *local;
         let mut link = self.link.lock().unwrap();
-        let render = self.live_tick + 1 >= self.render_from.load(Ordering::Relaxed);
+        let live_tick = self.live_tick.load(Ordering::Relaxed);
+        let render = live_tick + 1 >= self.render_from.load(Ordering::Relaxed);
         let screens = self.displayed_screens.load(Ordering::Relaxed);
         for player in 0..2 {
             let mut side = link.side(player);
@@ -92,7 +95,7 @@ impl getgud::World for World {
         if let Some(audio) = self.audio.as_mut() {
             audio.pump(&mut *link);
         }
-        self.live_tick += 1;
+        self.live_tick.store(live_tick + 1, Ordering::Relaxed);
         Ok(())
     }
 
@@ -101,7 +104,7 @@ impl getgud::World for World {
         Ok(SnapshotAt {
             audio_mark: self.audio.as_ref().map_or(0, |a| a.produced()),
             snapshot: link.snapshot(self.pool.pop())?,
-            tick: self.live_tick,
+            tick: self.live_tick.load(Ordering::Relaxed),
         })
     }
 
@@ -109,7 +112,7 @@ impl getgud::World for World {
         // The engine loads the settled state before every re-simulation;
         // when nothing speculated past it the pair is already parked
         // there and — by determinism — holds exactly this state.
-        if self.live_tick == state.tick {
+        if self.live_tick.load(Ordering::Relaxed) == state.tick {
             return Ok(());
         }
         let mut link = self.link.lock().unwrap();
@@ -121,7 +124,7 @@ impl getgud::World for World {
         if let Some(audio) = self.audio.as_mut() {
             audio.revoke_to(state.audio_mark);
         }
-        self.live_tick = state.tick;
+        self.live_tick.store(state.tick, Ordering::Relaxed);
         Ok(())
     }
 
@@ -158,6 +161,8 @@ pub struct Match {
     /// live so an arrangement setting takes effect mid-session.
     displayed_screens: Arc<AtomicU8>,
     render_from: Arc<AtomicU32>,
+    /// See the world's copy: the tick the pair is parked at.
+    live_tick: Arc<AtomicU32>,
     /// Confirmed rows the world's `log` callback has recorded, shared
     /// with it (the engine owns its world outright).
     confirmed: Arc<Mutex<Vec<[HostInput; 2]>>>,
@@ -195,13 +200,14 @@ impl Match {
         let link: Arc<Mutex<dyn Link>> = Arc::new(Mutex::new(link));
         let visible = Arc::new([AtomicBool::new(local_player == 0), AtomicBool::new(local_player == 1)]);
         let render_from = Arc::new(AtomicU32::new(0));
+        let live_tick = Arc::new(AtomicU32::new(0));
         let displayed_screens = Arc::new(AtomicU8::new(u8::MAX));
         let confirmed = Arc::new(Mutex::new(Vec::new()));
         let audio_seat = Arc::new(std::sync::atomic::AtomicUsize::new(local_player));
         let audio = audio.map(|into| crate::audio::Pump::new(into, audio_seat.clone()));
         let mut world = World {
             link: link.clone(),
-            live_tick: 0,
+            live_tick: live_tick.clone(),
             local_player,
             pool: Vec::new(),
             visible: visible.clone(),
@@ -233,6 +239,7 @@ impl Match {
             visible,
             displayed_screens,
             render_from,
+            live_tick,
             confirmed,
             drained: 0,
             last_rollback_depth: 0,
@@ -344,6 +351,14 @@ impl Match {
         self.telemetry.as_ref()
     }
 
+    /// The newest tick the pair has simulated, speculation included —
+    /// the frame the host presents, and the timebase telemetry stamps
+    /// its samples on. Behind [`advance`](Self::advance)'s returned
+    /// tick by the present delay: that one indexes inputs.
+    pub fn simulated(&self) -> u32 {
+        self.live_tick.load(Ordering::Relaxed)
+    }
+
     /// Confirmed `(tick, [p0, p1])` input rows in order, for the
     /// replay sink. Ticks are 1-based: the row that produced simulated
     /// tick `t` is stamped `t`, so a tick's confirmed inputs and its
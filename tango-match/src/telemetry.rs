@@ -74,7 +74,8 @@ pub struct UnitObs {
     /// The tile the unit stands on, `(x, y)`, 1-based over the whole
     /// field: x 1..=6 left to right (columns 1-3 are the left player's
     /// side), y 1..=3 top to bottom. Where the unit IS — a move in
-    /// flight reads as its origin until it lands.
+    /// flight reads as its origin until it lands. `(0, 0)` on a game
+    /// with no field position to report (BCC).
     pub tile: (u8, u8),
 }
 
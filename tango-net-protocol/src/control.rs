@@ -153,6 +153,56 @@ pub struct Settings {
     pub match_type: (u8, u8),
     pub game_info: Option<GameInfo>,
     pub blind_setup: bool,
+    /// The tournament rules the sender plays under, if any. Both sides
+    /// must name the same set before either can ready up, and both
+    /// check both revealed saves against it before sending StartMatch.
+    pub rule_set: Option<RuleSet>,
+}
+
+/// A tournament's build rules, as organisers hand them out (a JSON file
+/// of this shape) and as the lobby carries them. Chip and NaviCust part
+/// ids are the game's own; a rule set is written against one game.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(default)]
+pub struct RuleSet {
+    /// What the lobby shows players, e.g. "Weekly #12".
+    pub name: String,
+    pub banned_chips: Vec<usize>,
+    pub banned_navicust_parts: Vec<usize>,
+    /// Most copies of any one chip a folder may hold, on top of the
+    /// game's own limits.
+    pub max_copies: Option<usize>,
+    /// Highest max HP a navi may bring.
+    pub max_hp: Option<u16>,
+    /// The patches a side may play on. `None` allows any patch or none;
+    /// `Some` pins play to the listed ones, and to the unpatched game only
+    /// if `allow_unpatched` says so.
+    pub allowed_patches: Option<Vec<PatchRule>>,
+    /// Whether the unpatched game is allowed alongside `allowed_patches`.
+    /// Ignored when that's `None`.
+    pub allow_unpatched: bool,
+}
+
+/// One patch a [`RuleSet`] allows, by name and version range.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct PatchRule {
+    pub name: String,
+    pub versions: semver::VersionReq,
+}
+
+impl RuleSet {
+    /// Whether a side playing `patch` (`None`: unpatched) may play.
+    pub fn allows_patch(&self, patch: Option<&PatchInfo>) -> bool {
+        let Some(allowed) = &self.allowed_patches else {
+            return true;
+        };
+        match patch {
+            Some(p) => allowed
+                .iter()
+                .any(|r| r.name == p.name && r.versions.matches(&p.version)),
+            None => self.allow_unpatched,
+        }
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug)]
@@ -207,3 +257,41 @@ pub fn make_commitment(buf: &[u8]) -> [u8; 16] {
     h.finalize_xof().read(&mut out);
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patch(name: &str, version: &str) -> PatchInfo {
+        PatchInfo {
+            name: name.to_string(),
+            version: version.parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn unpatched_play_is_allowed_only_when_named() {
+        let pinned = RuleSet {
+            allowed_patches: Some(vec![PatchRule {
+                name: "bn6_balance".to_string(),
+                versions: "^1.2".parse().unwrap(),
+            }]),
+            ..Default::default()
+        };
+        assert!(pinned.allows_patch(Some(&patch("bn6_balance", "1.2.3"))));
+        assert!(!pinned.allows_patch(Some(&patch("bn6_balance", "2.0.0"))));
+        assert!(!pinned.allows_patch(Some(&patch("bn6_other", "1.2.3"))));
+        assert!(!pinned.allows_patch(None));
+
+        let with_unpatched = RuleSet {
+            allow_unpatched: true,
+            ..pinned
+        };
+        assert!(with_unpatched.allows_patch(None));
+        assert!(with_unpatched.allows_patch(Some(&patch("bn6_balance", "1.2.0"))));
+
+        let anything = RuleSet::default();
+        assert!(anything.allows_patch(None));
+        assert!(anything.allows_patch(Some(&patch("bn6_other", "0.1.0"))));
+    }
+}
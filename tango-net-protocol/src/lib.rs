@@ -108,4 +108,8 @@ pub mod derive;
 // bytes — the file the game itself calls most recently saved — so the
 // choice travels inside `save_data` and priming reads it there on both
 // peers.
-pub const PROTOCOL_VERSION: u32 = 0x56;
+// 0x57: lobby settings carry a tournament rule set (`Settings` grew
+// `rule_set`), which both peers check the two revealed saves against
+// before StartMatch. A 0x56 peer's Settings can't decode against ours,
+// and it would never refuse a save that breaks the rules.
+pub const PROTOCOL_VERSION: u32 = 0x57;
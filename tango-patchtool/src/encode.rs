@@ -0,0 +1,137 @@
+//! A BPS encoder. The `bps` crate only reads patches, which is all the
+//! app needs; writing one lives here with the one thing that makes them.
+//!
+//! Linear, not delta-searching: bytes that still match the clean ROM at
+//! the same offset are copied from it, runs of one byte (a ROM expanded
+//! with 0xff padding) are copied from the patched output itself, and
+//! everything else is carried literally. ROM hacks mostly edit in place,
+//! so this comes out close to what a searching encoder would — and the
+//! package is compressed anyway.
+
+/// Matching bytes shorter than this go out as literals: a source copy
+/// costs a command of its own, so breaking a literal run for a couple of
+/// bytes makes the patch bigger, not smaller.
+const MIN_SOURCE_RUN: usize = 4;
+
+/// Shortest run of one byte worth a self-referencing target copy.
+const MIN_BYTE_RUN: usize = 32;
+
+const SOURCE_READ: u64 = 0;
+const TARGET_READ: u64 = 1;
+const TARGET_COPY: u64 = 3;
+
+fn write_number(out: &mut Vec<u8>, mut n: u64) {
+    loop {
+        let x = (n & 0x7f) as u8;
+        n >>= 7;
+        if n == 0 {
+            out.push(0x80 | x);
+            return;
+        }
+        out.push(x);
+        n -= 1;
+    }
+}
+
+fn write_command(out: &mut Vec<u8>, kind: u64, len: usize) {
+    write_number(out, ((len as u64 - 1) << 2) | kind);
+}
+
+fn flush_literal(out: &mut Vec<u8>, literal: &[u8]) {
+    if literal.is_empty() {
+        return;
+    }
+    write_command(out, TARGET_READ, literal.len());
+    out.extend_from_slice(literal);
+}
+
+fn run_len(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b).take_while(|(a, b)| a == b).count()
+}
+
+/// A patch turning `source` into `target`.
+pub fn encode(source: &[u8], target: &[u8]) -> Vec<u8> {
+    let mut out = b"BPS1".to_vec();
+    write_number(&mut out, source.len() as u64);
+    write_number(&mut out, target.len() as u64);
+    // No metadata.
+    write_number(&mut out, 0);
+
+    // Where the next target copy reads from, as the decoder tracks it:
+    // each copy's offset is relative to where the last one ended.
+    let mut target_relative = 0usize;
+    let mut literal_start = 0;
+    let mut i = 0;
+    while i < target.len() {
+        let same = run_len(source.get(i..).unwrap_or_default(), &target[i..]);
+        if same >= MIN_SOURCE_RUN {
+            flush_literal(&mut out, &target[literal_start..i]);
+            write_command(&mut out, SOURCE_READ, same);
+            i += same;
+            literal_start = i;
+            continue;
+        }
+
+        let byte_run = target[i..].iter().take_while(|&&b| b == target[i]).count();
+        if byte_run >= MIN_BYTE_RUN {
+            // Write the byte once, then copy the rest from it: the
+            // decoder copies a byte at a time, so the copy reads what it
+            // has just written.
+            flush_literal(&mut out, &target[literal_start..=i]);
+            write_command(&mut out, TARGET_COPY, byte_run - 1);
+            let offset = i as i64 - target_relative as i64;
+            write_number(&mut out, (offset.unsigned_abs() << 1) | (offset < 0) as u64);
+            target_relative = i + byte_run - 1;
+            i += byte_run;
+            literal_start = i;
+            continue;
+        }
+
+        i += 1;
+    }
+    flush_literal(&mut out, &target[literal_start..]);
+
+    out.extend_from_slice(&crc32fast::hash(source).to_le_bytes());
+    out.extend_from_slice(&crc32fast::hash(target).to_le_bytes());
+    let checksum = crc32fast::hash(&out);
+    out.extend_from_slice(&checksum.to_le_bytes());
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(source: &[u8], target: &[u8]) -> Vec<u8> {
+        let patch = encode(source, target);
+        assert_eq!(bps::Patch::decode(&patch).unwrap().apply(source).unwrap(), target);
+        patch
+    }
+
+    #[test]
+    fn edits_in_place_stay_small() {
+        let source: Vec<u8> = (0..0x10000u32).map(|i| (i * 7 % 251) as u8).collect();
+        let mut target = source.clone();
+        target[0x100..0x110].copy_from_slice(b"CHANGED CHIPNAME");
+        target[0x8000] ^= 0xff;
+        assert!(round_trip(&source, &target).len() < 64);
+    }
+
+    #[test]
+    fn growing_with_padding_copies_the_padding() {
+        let source: Vec<u8> = (0..0x1000u32).map(|i| i as u8).collect();
+        let mut target = source.clone();
+        target.extend(std::iter::repeat(0xff).take(0x10000));
+        target.extend_from_slice(b"new code");
+        target.extend(std::iter::repeat(0x00).take(0x100));
+        assert!(round_trip(&source, &target).len() < 64);
+    }
+
+    #[test]
+    fn shrinking_and_unrelated() {
+        round_trip(b"a long enough source to copy from", b"a long");
+        round_trip(b"abc", b"xyz xyz xyz");
+        round_trip(b"", b"");
+        round_trip(b"something", b"");
+    }
+}
@@ -0,0 +1,242 @@
+//! `tango-patchtool`: builds a patch from ROMs. Given a clean ROM (one
+//! Tango recognizes) and its modified counterpart for each revision a
+//! patch supports, writes a BPS payload per revision and a starting
+//! `manifest.toml` whose `[rom_overrides]` already rename every chip,
+//! navicust part and patch card the modification renamed — the app
+//! reads names through the overrides, so a patch that renames without
+//! them shows the old names in the save editor.
+//!
+//! The first release starts from that skeleton; later ones pass the
+//! maintained manifest back in with `--manifest`, and `--pack` turns the
+//! lot into the `.tangopatch` the library installs.
+
+mod encode;
+mod report;
+mod skeleton;
+
+use anyhow::Context as _;
+use std::path::{Path, PathBuf};
+use tango_gamesupport_common_dataview::{self as dataview, rom_diff};
+use tango_library::rom::GameRef;
+
+/// Build Tango patch packages from modified ROMs.
+#[derive(clap::Parser)]
+#[command(version, about)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Write a BPS payload per ROM pair and a manifest, and optionally
+    /// package them.
+    New {
+        /// A clean ROM. Repeat with `--modified` once per revision the
+        /// patch supports; they pair up in order.
+        #[arg(long, required = true)]
+        clean: Vec<PathBuf>,
+        /// The modified ROM for the `--clean` in the same position.
+        #[arg(long, required = true)]
+        modified: Vec<PathBuf>,
+        /// Where to write the payloads, the manifest and the package.
+        #[arg(long)]
+        out: PathBuf,
+        /// A maintained manifest to package with instead of writing a
+        /// fresh one.
+        #[arg(long, conflicts_with_all = ["name", "version", "title", "author", "netplay"])]
+        manifest: Option<PathBuf>,
+        /// The patch's name: lowercase, as it appears in the index.
+        #[arg(long, required_unless_present = "manifest")]
+        name: Option<String>,
+        #[arg(long, required_unless_present = "manifest")]
+        version: Option<semver::Version>,
+        /// Defaults to the name.
+        #[arg(long)]
+        title: Option<String>,
+        /// `Name <addr@example.com>`. Repeatable.
+        #[arg(long)]
+        author: Vec<String>,
+        /// Who the patched game can play: `vanilla`, `isolated` or
+        /// `group:<name>`.
+        #[arg(long)]
+        netplay: Option<String>,
+        /// A README to package.
+        #[arg(long, requires = "pack")]
+        readme: Option<PathBuf>,
+        /// Also write the `.tangopatch`.
+        #[arg(long)]
+        pack: bool,
+    },
+    /// Print what a modified ROM changed in the chip, navicust part and
+    /// patch card tables, one line per row.
+    Diff {
+        #[arg(long)]
+        clean: PathBuf,
+        #[arg(long)]
+        modified: PathBuf,
+    },
+}
+
+fn read(path: &Path) -> anyhow::Result<Vec<u8>> {
+    std::fs::read(path).with_context(|| format!("reading {}", path.display()))
+}
+
+/// Read a clean ROM, which must be one Tango recognizes, and the
+/// modified ROM made from it.
+fn open_pair(clean_path: &Path, modified_path: &Path) -> anyhow::Result<(GameRef, Vec<u8>, Vec<u8>)> {
+    let mut clean = read(clean_path)?;
+    let game = tango_library::game::detect(&mut clean).ok_or_else(|| {
+        anyhow::anyhow!(
+            "{}: not a clean ROM Tango recognizes (a patch is built against an unmodified dump)",
+            clean_path.display()
+        )
+    })?;
+    let modified = read(modified_path)?;
+    if modified == clean {
+        anyhow::bail!("{}: identical to {}", modified_path.display(), clean_path.display());
+    }
+    Ok((game, clean, modified))
+}
+
+/// Both ROMs' asset tables, diffed. `None` for a game with no ROM model
+/// to read tables through.
+fn diff_assets(game: GameRef, clean: &[u8], modified: &[u8]) -> Option<rom_diff::AssetsDiff> {
+    // The tables are read out of ROM, but loading them wants a WRAM to
+    // resolve a few pointers through; any save of the game serves, so
+    // take the default template's.
+    let templates = game.save_templates?;
+    let (_, save) = templates
+        .iter()
+        .find(|(name, _)| name.is_empty())
+        .or(templates.first())?;
+    let wram = dataview::save_ref(&**save).as_raw_wram().into_owned();
+    let clean = dataview::unwrap_assets(game.load_rom_assets(clean, &wram, None)?);
+    let modified = dataview::unwrap_assets(game.load_rom_assets(modified, &wram, None)?);
+    Some(rom_diff::diff(&*clean, &*modified))
+}
+
+fn target_of(game: GameRef) -> tango_patch::RomTarget {
+    let (rom_code, revision) = game.rom_code_and_revision();
+    tango_patch::RomTarget::new(*rom_code, revision)
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+    match args.command {
+        Command::Diff { clean, modified } => {
+            let (game, clean, modified) = open_pair(&clean, &modified)?;
+            let diff = diff_assets(game, &clean, &modified)
+                .ok_or_else(|| anyhow::anyhow!("Tango doesn't read ROM tables for {}", target_of(game)))?;
+            for line in report::lines(&diff) {
+                println!("{line}");
+            }
+            Ok(())
+        }
+        Command::New {
+            clean,
+            modified,
+            out,
+            manifest,
+            name,
+            version,
+            title,
+            author,
+            netplay,
+            readme,
+            pack,
+        } => {
+            if clean.len() != modified.len() {
+                anyhow::bail!(
+                    "{} --clean but {} --modified: pass one of each per revision",
+                    clean.len(),
+                    modified.len()
+                );
+            }
+            std::fs::create_dir_all(&out).with_context(|| format!("creating {}", out.display()))?;
+
+            let mut payloads: Vec<(tango_patch::RomTarget, Vec<u8>)> = vec![];
+            let mut targets = vec![];
+            for (clean_path, modified_path) in clean.iter().zip(&modified) {
+                let (game, clean, modified) = open_pair(clean_path, modified_path)?;
+                let target = target_of(game);
+                if payloads.iter().any(|(t, _)| *t == target) {
+                    anyhow::bail!("{}: a second clean ROM for {target}", clean_path.display());
+                }
+
+                let bps = encode::encode(&clean, &modified);
+                let applied = bps::Patch::decode(&bps)
+                    .ok()
+                    .and_then(|p| p.apply(&clean).ok())
+                    .filter(|applied| *applied == modified);
+                if applied.is_none() {
+                    anyhow::bail!(
+                        "{target}: the encoded patch doesn't reproduce {}",
+                        modified_path.display()
+                    );
+                }
+                let bps_path = out.join(format!("{target}.bps"));
+                std::fs::write(&bps_path, &bps).with_context(|| format!("writing {}", bps_path.display()))?;
+                eprintln!("{target}: {} ({} bytes)", bps_path.display(), bps.len());
+
+                let diff = diff_assets(game, &clean, &modified);
+                match &diff {
+                    Some(diff) => {
+                        for line in report::lines(diff) {
+                            eprintln!("{target}: {line}");
+                        }
+                    }
+                    None => eprintln!("{target}: Tango doesn't read ROM tables for this game; no overrides"),
+                }
+                payloads.push((target, bps));
+                targets.push((target.to_string(), diff));
+            }
+
+            let manifest = match manifest {
+                Some(path) => {
+                    let text = std::fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+                    let manifest =
+                        tango_patch::Manifest::parse(&text).with_context(|| format!("parsing {}", path.display()))?;
+                    for (target, _) in &payloads {
+                        if !manifest.rom_overrides.contains_key(target) {
+                            eprintln!("warning: {} has no [rom_overrides.{target}]", path.display());
+                        }
+                    }
+                    manifest
+                }
+                None => {
+                    let name = name.expect("clap requires --name without --manifest");
+                    let metadata = skeleton::Metadata {
+                        title: title.unwrap_or_else(|| name.clone()),
+                        name,
+                        version: version.expect("clap requires --version without --manifest"),
+                        authors: author,
+                        netplay: netplay.unwrap_or_else(|| "isolated".to_owned()),
+                    };
+                    let text = skeleton::manifest(&metadata, &targets);
+                    let manifest = tango_patch::Manifest::parse(&text).context("the generated manifest")?;
+                    let path = out.join("manifest.toml");
+                    std::fs::write(&path, text).with_context(|| format!("writing {}", path.display()))?;
+                    eprintln!("manifest: {}", path.display());
+                    manifest
+                }
+            };
+
+            if pack {
+                let readme = readme.as_deref().map(std::fs::read_to_string).transpose()?;
+                let mut builder = tango_patch::bundle::Builder::new(manifest);
+                if let Some(readme) = readme {
+                    builder.set_readme(readme);
+                }
+                for (target, bps) in payloads {
+                    builder.add_rom(target, bps);
+                }
+                builder
+                    .write_file(&out)
+                    .with_context(|| format!("packaging into {}", out.display()))?;
+                eprintln!("package: written to {}", out.display());
+            }
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! A changed-tables summary, one line per row, for a release's notes.
+
+use tango_gamesupport_common_dataview::diff::Change;
+use tango_gamesupport_common_dataview::rom_diff::{AssetsDiff, ChipInfo, NavicustPartInfo, PatchCardInfo};
+
+fn name(name: &Option<String>) -> String {
+    name.as_deref()
+        .map_or_else(|| "(unnamed)".to_owned(), |n| format!("{n:?}"))
+}
+
+/// `label from → to`, if the field changed.
+fn field<T: PartialEq, U: std::fmt::Display>(
+    out: &mut Vec<String>,
+    label: &str,
+    from: &T,
+    to: &T,
+    show: impl Fn(&T) -> U,
+) {
+    if from != to {
+        out.push(format!("{label} {} → {}", show(from), show(to)));
+    }
+}
+
+fn chip(from: &ChipInfo, to: &ChipInfo) -> Vec<String> {
+    let mut out = vec![];
+    field(&mut out, "name", &from.name, &to.name, name);
+    field(&mut out, "damage", &from.attack_power, &to.attack_power, |v| *v);
+    field(&mut out, "MB", &from.mb, &to.mb, |v| *v);
+    field(&mut out, "codes", &from.codes, &to.codes, |v| {
+        v.iter().collect::<String>()
+    });
+    field(&mut out, "element", &from.element, &to.element, |v| *v);
+    field(&mut out, "class", &from.class, &to.class, |v| format!("{v:?}"));
+    field(&mut out, "dark", &from.dark, &to.dark, |v| *v);
+    field(&mut out, "legal", &from.legal, &to.legal, |v| *v);
+    if from.description != to.description {
+        out.push("description changed".to_owned());
+    }
+    out
+}
+
+fn navicust_part(from: &NavicustPartInfo, to: &NavicustPartInfo) -> Vec<String> {
+    let mut out = vec![];
+    field(&mut out, "name", &from.name, &to.name, name);
+    field(&mut out, "color", &from.color, &to.color, |v| format!("{v:?}"));
+    field(&mut out, "solid", &from.is_solid, &to.is_solid, |v| *v);
+    if from.compressed != to.compressed || from.uncompressed != to.uncompressed {
+        out.push("shape changed".to_owned());
+    }
+    if from.description != to.description {
+        out.push("description changed".to_owned());
+    }
+    out
+}
+
+fn patch_card(from: &PatchCardInfo, to: &PatchCardInfo) -> Vec<String> {
+    let mut out = vec![];
+    field(&mut out, "name", &from.name, &to.name, name);
+    field(&mut out, "MB", &from.mb, &to.mb, |v| *v);
+    if from.effects != to.effects {
+        out.push("effects changed".to_owned());
+    }
+    out
+}
+
+fn row<T>(
+    kind: &str,
+    id: usize,
+    change: &Change<Option<T>>,
+    name_of: impl Fn(&T) -> &Option<String>,
+    fields: impl Fn(&T, &T) -> Vec<String>,
+) -> String {
+    match (&change.from, &change.to) {
+        (Some(from), Some(to)) => format!("{kind} {id} {}: {}", name(name_of(from)), fields(from, to).join("; ")),
+        (None, Some(to)) => format!("{kind} {id} {}: added", name(name_of(to))),
+        (Some(from), None) => format!("{kind} {id} {}: removed", name(name_of(from))),
+        (None, None) => unreachable!("an unchanged row isn't in a diff"),
+    }
+}
+
+pub fn lines(diff: &AssetsDiff) -> Vec<String> {
+    let chips = diff.chips.iter().map(|(id, c)| row("chip", *id, c, |c| &c.name, chip));
+    let parts = diff
+        .navicust_parts
+        .iter()
+        .map(|(id, c)| row("navicust part", *id, c, |p| &p.name, navicust_part));
+    let cards = diff
+        .patch_cards
+        .iter()
+        .map(|(id, c)| row("patch card", *id, c, |c| &c.name, patch_card));
+    chips.chain(parts).chain(cards).collect()
+}
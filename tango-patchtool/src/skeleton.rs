@@ -0,0 +1,196 @@
+//! The starting `manifest.toml` for a new patch: its metadata, plus a
+//! `[rom_overrides]` table per target pre-filled with every chip, part
+//! and patch card name the patch changed.
+//!
+//! Written by hand rather than through `toml`'s serializer, which puts
+//! each override row on a `[[table]]` header of its own — unreadable
+//! when the one renamed chip is number 300. Here each table is one
+//! inline array, a row per id, with the ROM's old name alongside.
+
+use std::fmt::Write as _;
+use tango_gamesupport_common_dataview::rom_diff::{AssetsDiff, RowChange};
+
+pub struct Metadata {
+    pub name: String,
+    pub version: semver::Version,
+    pub title: String,
+    pub authors: Vec<String>,
+    pub netplay: String,
+}
+
+/// One row of an override table: what the patched ROM says, for each
+/// field that differs from the clean one.
+#[derive(Default)]
+struct Row {
+    name: Option<String>,
+    description: Option<String>,
+    /// The clean ROM's name, for the comment.
+    was: Option<String>,
+}
+
+/// Rows indexed by id. Ids before the last changed one are filled in
+/// empty: the table is positional.
+fn rows<T>(changes: &[RowChange<T>], fields: impl Fn(&T) -> (Option<String>, Option<String>)) -> Vec<Row> {
+    let mut rows = vec![];
+    for (id, change) in changes {
+        // A row only one side has isn't a rename: there is no clean
+        // name to override.
+        let (Some(from), Some(to)) = (&change.from, &change.to) else {
+            continue;
+        };
+        let (from_name, from_description) = fields(from);
+        let (to_name, to_description) = fields(to);
+        if from_name == to_name && from_description == to_description {
+            continue;
+        }
+        if rows.len() <= *id {
+            rows.resize_with(id + 1, Row::default);
+        }
+        rows[*id] = Row {
+            name: to_name.filter(|_| from_name != to_name),
+            description: to_description.filter(|_| from_description != to_description),
+            was: from_name,
+        };
+    }
+    rows
+}
+
+fn quote(s: &str) -> String {
+    toml::Value::String(s.to_owned()).to_string()
+}
+
+fn write_table(out: &mut String, key: &str, rows: &[Row]) {
+    if rows.is_empty() {
+        return;
+    }
+    let _ = writeln!(out, "{key} = [");
+    for (id, row) in rows.iter().enumerate() {
+        let fields = [("name", &row.name), ("description", &row.description)]
+            .into_iter()
+            .filter_map(|(k, v)| v.as_deref().map(|v| format!("{k} = {}", quote(v))))
+            .collect::<Vec<_>>();
+        if fields.is_empty() {
+            let _ = writeln!(out, "    {{}}, # {id}");
+            continue;
+        }
+        let _ = write!(out, "    {{ {} }}, # {id}", fields.join(", "));
+        if let Some(was) = &row.was {
+            let _ = write!(out, ", was {was:?}");
+        }
+        out.push('\n');
+    }
+    out.push_str("]\n");
+}
+
+/// The manifest text. `targets` pairs each ROM target the patch covers
+/// (as `BR6E_00`) with what changed in it; a target with no renames still
+/// gets its (empty) table, so every ROM the package patches is listed.
+pub fn manifest(metadata: &Metadata, targets: &[(String, Option<AssetsDiff>)]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "format = 2");
+    let _ = writeln!(out, "name = {}", quote(&metadata.name));
+    let _ = writeln!(out, "version = {}", quote(&metadata.version.to_string()));
+    let _ = writeln!(out, "title = {}", quote(&metadata.title));
+    let authors = metadata.authors.iter().map(|a| quote(a)).collect::<Vec<_>>();
+    let _ = writeln!(out, "authors = [{}]", authors.join(", "));
+    let _ = writeln!(out, "netplay = {}", quote(&metadata.netplay));
+
+    for (target, diff) in targets {
+        let _ = write!(out, "\n[rom_overrides.{target}]\n");
+        let Some(diff) = diff else {
+            continue;
+        };
+        write_table(
+            &mut out,
+            "chips",
+            &rows(&diff.chips, |c| (c.name.clone(), c.description.clone())),
+        );
+        write_table(
+            &mut out,
+            "navicust_parts",
+            &rows(&diff.navicust_parts, |p| (p.name.clone(), p.description.clone())),
+        );
+        write_table(
+            &mut out,
+            "patch_card56s",
+            &rows(&diff.patch_cards, |c| (c.name.clone(), None)),
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tango_gamesupport_common_dataview::diff::Change;
+    use tango_gamesupport_common_dataview::rom;
+    use tango_gamesupport_common_dataview::rom_diff::ChipInfo;
+
+    fn chip(name: &str, attack_power: u32) -> ChipInfo {
+        ChipInfo {
+            name: Some(name.to_owned()),
+            description: Some("A chip".to_owned()),
+            codes: vec!['A'],
+            element: 0,
+            class: rom::ChipClass::Standard,
+            dark: false,
+            mb: 10,
+            attack_power,
+            legal: true,
+        }
+    }
+
+    #[test]
+    fn renames_land_at_their_ids() {
+        let metadata = Metadata {
+            name: "bn6_test".to_owned(),
+            version: "1.0.0".parse().unwrap(),
+            title: "Test \"patch\"".to_owned(),
+            authors: vec!["Someone <someone@example.com>".to_owned()],
+            netplay: "isolated".to_owned(),
+        };
+        let diff = AssetsDiff {
+            chips: vec![
+                (
+                    1,
+                    Change {
+                        from: Some(chip("Cannon", 40)),
+                        to: Some(chip("Cannon", 60)),
+                    },
+                ),
+                (
+                    2,
+                    Change {
+                        from: Some(chip("Sword", 80)),
+                        to: Some(chip("Blade", 80)),
+                    },
+                ),
+                (
+                    3,
+                    Change {
+                        from: None,
+                        to: Some(chip("NewChip", 100)),
+                    },
+                ),
+            ],
+            ..Default::default()
+        };
+        let text = manifest(
+            &metadata,
+            &[("BR6E_00".to_owned(), Some(diff)), ("BR5E_00".to_owned(), None)],
+        );
+
+        let parsed: toml::Table = toml::from_str(&text).unwrap();
+        assert_eq!(parsed["title"].as_str(), Some("Test \"patch\""));
+        let overrides = parsed["rom_overrides"].as_table().unwrap();
+        assert!(overrides["BR5E_00"].as_table().unwrap().is_empty());
+        let chips = overrides["BR6E_00"]["chips"].as_array().unwrap();
+        // A damage change isn't a rename, and a new row has nothing to
+        // override.
+        assert_eq!(chips.len(), 3);
+        assert!(chips[0].as_table().unwrap().is_empty());
+        assert!(chips[1].as_table().unwrap().is_empty());
+        assert_eq!(chips[2].as_table().unwrap().len(), 1);
+        assert_eq!(chips[2]["name"].as_str(), Some("Blade"));
+    }
+}
@@ -0,0 +1,188 @@
+//! `tango-savetool`: the save editor without the editor. Loads a ROM +
+//! save pair the way the app does, prints its build as JSON, and applies
+//! an edit script or a build code through the same staged edits, legality
+//! report and checksum/write path the editor commits with.
+//!
+//! For a patched game, pass the unpatched ROM and the `.tangopatch` with
+//! `--patch`: the tool applies it as the app does, and the package's
+//! `[rom_overrides]` (charset, chip legality) feed the legality report.
+//!
+//! An op whose edits leave the save unchanged was refused by the game's
+//! model (a chip past a full folder, a Regular over its memory); one the
+//! save already satisfies has no edits and isn't a refusal. `edit` fails
+//! on a refusal rather than writing a save that isn't what the script
+//! asked for, unless `--allow-refused` says that's expected.
+
+mod script;
+mod show;
+
+use anyhow::Context as _;
+use std::path::{Path, PathBuf};
+use tango_gamesupport_common_ui::model::{self, SaveModel};
+
+/// Inspect and edit Battle Network saves from the command line.
+#[derive(clap::Parser)]
+#[command(version, about)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Print the save's navi, folder, navicust, patch cards and build
+    /// violations as JSON.
+    Show {
+        #[arg(long)]
+        rom: PathBuf,
+        #[arg(long)]
+        save: PathBuf,
+        /// A `.tangopatch` to apply to the ROM first.
+        #[arg(long)]
+        patch: Option<PathBuf>,
+    },
+    /// Apply an edit script (or a build code) and write the result as a
+    /// new save. Refuses to write a build with violations unless told to.
+    Edit {
+        #[arg(long)]
+        rom: PathBuf,
+        #[arg(long)]
+        save: PathBuf,
+        /// A `.tangopatch` to apply to the ROM first.
+        #[arg(long)]
+        patch: Option<PathBuf>,
+        /// Where to write the edited save. May be the input save.
+        #[arg(long)]
+        out: PathBuf,
+        /// A JSON array of edit ops, or `-` for stdin.
+        #[arg(long, required_unless_present = "build_code", conflicts_with = "build_code")]
+        script: Option<PathBuf>,
+        /// A build code to paste, as the editor's Paste build does.
+        #[arg(long)]
+        build_code: Option<String>,
+        /// Write the save even if its build breaks the game's rules.
+        #[arg(long)]
+        allow_violations: bool,
+        /// Write the save even if the save refused some ops.
+        #[arg(long)]
+        allow_refused: bool,
+    },
+}
+
+/// Open a ROM + save pair, patching the ROM with `patch_path` if given,
+/// into the editor's model, along with the save file's emulator wrapper
+/// to write an edit back in.
+fn open(
+    rom_path: &Path,
+    save_path: &Path,
+    patch_path: Option<&Path>,
+) -> anyhow::Result<(SaveModel, tango_library::save::Unwrapped)> {
+    let mut rom = std::fs::read(rom_path).with_context(|| format!("reading {}", rom_path.display()))?;
+    let game = tango_library::game::detect(&mut rom)
+        .ok_or_else(|| anyhow::anyhow!("{}: not a ROM Tango recognizes", rom_path.display()))?;
+    if !game.has_save_model() {
+        anyhow::bail!("{}: Tango doesn't edit saves for this game", rom_path.display());
+    }
+    let (rom, applied_patch) = match patch_path {
+        Some(path) => {
+            let raw = std::fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+            let applied = tango_library::patch::apply_package(&raw, &rom, game)
+                .with_context(|| format!("{}: applying to {}", path.display(), rom_path.display()))?;
+            let patch = model::AppliedPatch {
+                name: applied.name,
+                version: applied.version,
+                rom_overrides: applied.rom_overrides,
+            };
+            (applied.rom, Some(patch))
+        }
+        None => (rom, None),
+    };
+    let file = std::fs::read(save_path).with_context(|| format!("reading {}", save_path.display()))?;
+    let unwrapped = tango_library::save::Unwrapped::from_file(&file);
+    let save = game
+        .parse_save(&unwrapped.image)
+        .with_context(|| format!("{}: not a save for this ROM", save_path.display()))?;
+    let model = model::from_prepared(model::prepare(game, rom, save_path.to_path_buf(), save, applied_patch));
+    Ok((model, unwrapped))
+}
+
+fn read_script(path: &Path) -> anyhow::Result<Vec<script::Op>> {
+    let json = if path == Path::new("-") {
+        std::io::read_to_string(std::io::stdin()).context("reading script from stdin")?
+    } else {
+        std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?
+    };
+    script::parse(&json).with_context(|| format!("parsing {}", path.display()))
+}
+
+fn print_report(save: &SaveModel) -> anyhow::Result<()> {
+    println!("{}", serde_json::to_string_pretty(&show::report(save))?);
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = <Args as clap::Parser>::parse();
+    match args.command {
+        Command::Show { rom, save, patch } => print_report(&open(&rom, &save, patch.as_deref())?.0),
+        Command::Edit {
+            rom,
+            save,
+            patch,
+            out,
+            script,
+            build_code,
+            allow_violations,
+            allow_refused,
+        } => {
+            let (mut model, unwrapped) = open(&rom, &save, patch.as_deref())?;
+            let ops = match (script, build_code) {
+                (Some(path), _) => read_script(&path)?,
+                (None, Some(code)) => vec![script::Op::BuildCode { code }],
+                (None, None) => unreachable!("clap requires one of --script / --build-code"),
+            };
+
+            let mut refused = 0;
+            for (i, op) in ops.iter().enumerate() {
+                let edits = op.edits(&model).with_context(|| format!("op {i}"))?;
+                if edits.is_empty() {
+                    // The save already holds what the op asks for.
+                    continue;
+                }
+                let before = model.save.to_sram_dump();
+                for edit in edits {
+                    // Nothing here draws, so there's no derived art to
+                    // invalidate.
+                    let _ = model::apply_edit(&mut model, edit);
+                }
+                if model.save.to_sram_dump() == before {
+                    eprintln!("warning: op {i} was refused: {op:?}");
+                    refused += 1;
+                }
+            }
+            if refused > 0 && !allow_refused {
+                anyhow::bail!(
+                    "not writing {}: the save refused {} op(s) (pass --allow-refused to write it anyway)",
+                    out.display(),
+                    refused
+                );
+            }
+
+            let violations = show::violations(&model);
+            for v in &violations {
+                eprintln!("violation: {v}");
+            }
+            if !violations.is_empty() && !allow_violations {
+                anyhow::bail!(
+                    "not writing {}: the build has {} violation(s) (pass --allow-violations to write it anyway)",
+                    out.display(),
+                    violations.len()
+                );
+            }
+
+            model.save.rebuild_checksum();
+            let file = unwrapped.rewrap(&model.save.to_sram_dump());
+            std::fs::write(&out, file).with_context(|| format!("writing {}", out.display()))?;
+            print_report(&model)
+        }
+    }
+}
@@ -0,0 +1,371 @@
+//! The edit script: a JSON array of operations, each one lowered to the
+//! same staged [`Edit`]s the editor's controls produce and applied in
+//! order. For example:
+//!
+//! ```json
+//! [
+//!     { "op": "build_code", "code": "..." },
+//!     { "op": "set_regular", "slot": 4 },
+//!     { "op": "add_patch_card", "card": 12 }
+//! ]
+//! ```
+//!
+//! Ids are the ROM's own (chip, part, card and navi indexes as `show`
+//! prints them). An op the save already satisfies lowers to no edits. One
+//! the save refuses — a chip past a full folder, a Regular over its
+//! memory — is skipped by the appliers exactly as the editor would; the
+//! caller finds out by its edits not changing the save, and `edit`
+//! refuses to write unless told to expect it.
+
+use tango_gamesupport_common_ui::dataview::build_code;
+use tango_gamesupport_common_ui::dataview::save::{Chip, ChipCode, NavicustPart};
+use tango_gamesupport_common_ui::model::{ChipEdit, Edit, NaviEdit, NavicustEdit, PatchCard56Edit, SaveModel};
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Op {
+    /// Paste a whole build code: navi, folder, navicust and patch cards,
+    /// whichever the code carries. BN4's Mod Cards are that game's own
+    /// edit and aren't applied.
+    BuildCode {
+        code: String,
+    },
+    SetNavi {
+        navi: usize,
+    },
+    SetBaseMaxHp {
+        hp: u16,
+    },
+    ClearFolder,
+    /// Replace the equipped folder outright. REG/TAG are cleared unless
+    /// given.
+    SetFolder {
+        chips: Vec<ScriptChip>,
+        #[serde(default)]
+        regular: Option<usize>,
+        #[serde(default)]
+        tags: Option<[usize; 2]>,
+    },
+    AddChip {
+        chip: usize,
+        code: char,
+    },
+    RemoveChip {
+        slot: usize,
+    },
+    /// Make `slot` the Regular chip, or clear it with `null`.
+    SetRegular {
+        slot: Option<usize>,
+    },
+    SetTags {
+        slots: Option<[usize; 2]>,
+    },
+    SetPackCount {
+        chip: usize,
+        variant: usize,
+        count: usize,
+    },
+    ClearPack,
+    ClearNavicust,
+    AddPart {
+        part: usize,
+        col: u8,
+        row: u8,
+        #[serde(default)]
+        rot: u8,
+        #[serde(default)]
+        compressed: bool,
+    },
+    RemovePart {
+        slot: usize,
+    },
+    ClearPatchCards,
+    AddPatchCard {
+        card: usize,
+    },
+    RemovePatchCard {
+        slot: usize,
+    },
+}
+
+/// A folder chip as a script spells it.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+pub struct ScriptChip {
+    pub chip: usize,
+    pub code: char,
+}
+
+pub fn parse(json: &str) -> serde_json::Result<Vec<Op>> {
+    serde_json::from_str(json)
+}
+
+fn chip_code(code: char) -> anyhow::Result<ChipCode> {
+    ChipCode::from_char(code.to_ascii_uppercase()).ok_or_else(|| anyhow::anyhow!("{code:?} is not a chip code"))
+}
+
+fn equipped_regular(save: &SaveModel) -> Option<usize> {
+    let chips = save.save.view_chips()?;
+    chips.regular_chip_index(chips.equipped_folder_index()).flatten()
+}
+
+/// Whether every section `code` carries already matches `save`'s. Mod
+/// Cards aren't applied, so they aren't compared.
+fn build_holds(code: &build_code::BuildCode, save: &SaveModel) -> bool {
+    let current = build_code::BuildCode::from_save(save.build_code_game(), save.save.as_ref());
+    (code.navi.is_none() || code.navi == current.navi)
+        && (code.folder.is_none() || code.folder == current.folder)
+        && (code.navicust.is_none() || code.navicust == current.navicust)
+        && (code.patch_cards.is_none() || code.patch_cards == current.patch_cards)
+}
+
+fn pack_is_empty(save: &SaveModel) -> bool {
+    let Some(chips) = save.save.view_chips() else {
+        return true;
+    };
+    (0..save.assets.num_chips()).all(|id| {
+        (0..save.assets.chip(id).map_or(0, |c| c.codes().len())).all(|v| chips.pack_count(id, v).unwrap_or(0) == 0)
+    })
+}
+
+impl Op {
+    /// The staged edits this op stands for against `save` as it is now:
+    /// none when the save already holds what the op sets. Adding or
+    /// removing always has edits, as does any op the save can't hold.
+    pub fn edits(&self, save: &SaveModel) -> anyhow::Result<Vec<Edit>> {
+        let folder = || build_code::Folder::of(save.save.as_ref());
+        Ok(match self {
+            Op::BuildCode { code } => {
+                let code = build_code::decode_for(code.trim(), &save.build_code_game())?;
+                if build_holds(&code, save) {
+                    return Ok(vec![]);
+                }
+                tango_gamesupport_common_ui::model::build_code::edits(&code, save)
+            }
+            Op::SetNavi { navi } if save.save.view_navi().is_some_and(|nv| nv.navi() == *navi) => vec![],
+            Op::SetNavi { navi } => vec![Edit::Navi(NaviEdit::SetNavi(*navi))],
+            Op::SetBaseMaxHp { hp }
+                if save
+                    .save
+                    .view_navi()
+                    .is_some_and(|nv| nv.base_max_hp(save.assets.as_ref()) == Some(*hp)) =>
+            {
+                vec![]
+            }
+            Op::SetBaseMaxHp { hp } => vec![Edit::Navi(NaviEdit::SetBaseMaxHp(*hp))],
+            Op::ClearFolder
+                if folder().is_some_and(|f| f.chips.is_empty() && f.regular.is_none() && f.tags.is_none()) =>
+            {
+                vec![]
+            }
+            Op::ClearFolder => vec![Edit::Chips(ChipEdit::ClearFolder)],
+            Op::SetFolder { chips, regular, tags } => {
+                let chips = chips
+                    .iter()
+                    .map(|c| {
+                        Ok(Chip {
+                            id: c.chip,
+                            code: chip_code(c.code)?,
+                        })
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                // The folder keeps what fits, so compare against that.
+                let size = save.save.view_chips().map_or(0, |c| c.folder_size());
+                let target = build_code::Folder {
+                    chips: chips.iter().take(size).cloned().map(Some).collect(),
+                    regular: *regular,
+                    tags: *tags,
+                };
+                if folder() == Some(target) {
+                    return Ok(vec![]);
+                }
+                vec![Edit::Chips(ChipEdit::SetFolder {
+                    chips,
+                    regular: *regular,
+                    tags: *tags,
+                })]
+            }
+            Op::AddChip { chip, code } => vec![Edit::Chips(ChipEdit::AddChip {
+                chip_id: *chip,
+                code: chip_code(*code)?,
+            })],
+            Op::RemoveChip { slot } => vec![Edit::Chips(ChipEdit::RemoveChip { slot: *slot })],
+            // The editor only toggles, so aim the toggle at whichever slot
+            // gets us to `slot`: the new one, or the current one to clear.
+            Op::SetRegular { slot } => {
+                let current = equipped_regular(save);
+                match slot.or(current) {
+                    Some(target) if current != *slot => vec![Edit::Chips(ChipEdit::ToggleRegular { slot: target })],
+                    _ => vec![],
+                }
+            }
+            Op::SetTags { slots } if folder().is_some_and(|f| f.tags == *slots) => vec![],
+            Op::SetTags { slots } => vec![Edit::Chips(ChipEdit::SetTags(*slots))],
+            Op::SetPackCount { chip, variant, count }
+                if save
+                    .save
+                    .view_chips()
+                    .is_some_and(|c| c.pack_count(*chip, *variant) == Some(*count)) =>
+            {
+                vec![]
+            }
+            Op::SetPackCount { chip, variant, count } => vec![Edit::Chips(ChipEdit::SetPackCount {
+                id: *chip,
+                variant: *variant,
+                count: *count,
+            })],
+            Op::ClearPack if pack_is_empty(save) => vec![],
+            Op::ClearPack => vec![Edit::Chips(ChipEdit::ClearPack)],
+            Op::ClearNavicust
+                if save
+                    .save
+                    .view_navicust()
+                    .is_some_and(|nc| (0..nc.count()).all(|i| nc.navicust_part(i).is_none())) =>
+            {
+                vec![]
+            }
+            Op::ClearNavicust => vec![Edit::Navicust(NavicustEdit::ClearAll)],
+            Op::AddPart {
+                part,
+                col,
+                row,
+                rot,
+                compressed,
+            } => vec![Edit::Navicust(NavicustEdit::AddPart(NavicustPart {
+                id: *part,
+                col: *col,
+                row: *row,
+                rot: *rot,
+                compressed: *compressed,
+            }))],
+            Op::RemovePart { slot } => vec![Edit::Navicust(NavicustEdit::RemovePart { slot: *slot })],
+            Op::ClearPatchCards if save.save.view_patch_card56s().is_some_and(|pc| pc.count() == 0) => vec![],
+            Op::ClearPatchCards => vec![Edit::PatchCard56s(PatchCard56Edit::ClearAll)],
+            Op::AddPatchCard { card } => vec![Edit::PatchCard56s(PatchCard56Edit::AddCard { id: *card })],
+            Op::RemovePatchCard { slot } => vec![Edit::PatchCard56s(PatchCard56Edit::RemoveCard { slot: *slot })],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_op_shape() {
+        let ops = parse(
+            r#"[
+                { "op": "build_code", "code": "abc" },
+                { "op": "clear_folder" },
+                { "op": "add_chip", "chip": 1, "code": "*" },
+                { "op": "set_regular", "slot": null },
+                { "op": "set_tags", "slots": [0, 1] },
+                { "op": "add_part", "part": 3, "col": 2, "row": 1 },
+                { "op": "set_folder", "chips": [{ "chip": 7, "code": "A" }], "regular": 0 }
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            ops,
+            vec![
+                Op::BuildCode { code: "abc".into() },
+                Op::ClearFolder,
+                Op::AddChip { chip: 1, code: '*' },
+                Op::SetRegular { slot: None },
+                Op::SetTags { slots: Some([0, 1]) },
+                Op::AddPart {
+                    part: 3,
+                    col: 2,
+                    row: 1,
+                    rot: 0,
+                    compressed: false,
+                },
+                Op::SetFolder {
+                    chips: vec![ScriptChip { chip: 7, code: 'A' }],
+                    regular: Some(0),
+                    tags: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_ops() {
+        assert!(parse(r#"[{ "op": "delete_everything" }]"#).is_err());
+    }
+
+    #[test]
+    fn chip_codes_read_either_case() {
+        assert_eq!(chip_code('a').unwrap(), ChipCode::A);
+        assert_eq!(chip_code('*').unwrap(), ChipCode::Star);
+        assert!(chip_code('?').is_err());
+    }
+
+    /// BN6 Gregar's bundled save, with no ROM behind it: enough for the
+    /// ops to read the save they lower against.
+    #[cfg(feature = "gamesupport-bn6")]
+    fn bn6_model() -> SaveModel {
+        let game = &tango_gamesupport_bn6::BN6G;
+        let (_, template) = &game.save_templates.unwrap()[0];
+        let mut model = SaveModel {
+            game,
+            save_path: Default::default(),
+            save: tango_gamesupport_common_ui::dataview::unwrap_save(template.clone_box()),
+            editability: Default::default(),
+            patch: None,
+            assets: Box::new(tango_gamesupport_common_ui::dataview::rom::EmptyAssets),
+        };
+        tango_gamesupport_common_ui::model::refresh_editability(&mut model);
+        model
+    }
+
+    #[cfg(feature = "gamesupport-bn6")]
+    #[test]
+    fn set_regular_toggles_toward_the_target() {
+        let model = bn6_model();
+        let current = equipped_regular(&model);
+        let target = if current == Some(0) { 1 } else { 0 };
+
+        let toggles = |slot: Option<usize>| {
+            Op::SetRegular { slot }
+                .edits(&model)
+                .unwrap()
+                .into_iter()
+                .map(|edit| match edit {
+                    Edit::Chips(ChipEdit::ToggleRegular { slot }) => slot,
+                    other => panic!("expected a Regular toggle, got {other:?}"),
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(toggles(current), vec![]);
+        assert_eq!(toggles(Some(target)), vec![target]);
+        assert_eq!(toggles(None), current.into_iter().collect::<Vec<_>>());
+    }
+
+    #[cfg(feature = "gamesupport-bn6")]
+    #[test]
+    fn ops_the_save_already_holds_have_no_edits() {
+        let model = bn6_model();
+        let navi = model.save.view_navi().unwrap().navi();
+        assert!(Op::SetNavi { navi }.edits(&model).unwrap().is_empty());
+        assert!(!Op::SetNavi { navi: navi + 1 }.edits(&model).unwrap().is_empty());
+
+        let code = build_code::BuildCode::from_save(model.build_code_game(), model.save.as_ref())
+            .encode()
+            .unwrap();
+        assert!(Op::BuildCode { code }.edits(&model).unwrap().is_empty());
+    }
+
+    #[cfg(feature = "gamesupport-bn6")]
+    #[test]
+    fn bad_chip_codes_fail_the_op() {
+        let model = bn6_model();
+        assert!(Op::AddChip { chip: 1, code: '?' }.edits(&model).is_err());
+        assert!(Op::SetFolder {
+            chips: vec![ScriptChip { chip: 1, code: 'A' }, ScriptChip { chip: 2, code: '#' }],
+            regular: None,
+            tags: None,
+        }
+        .edits(&model)
+        .is_err());
+    }
+}
@@ -0,0 +1,153 @@
+//! The save's build as JSON: what `show` prints, and what `edit` prints
+//! of the save it wrote. Names come from the ROM so a human can check a
+//! script's ids against them; everything else is the shared dataview's
+//! read side, as the editor's tabs show it.
+
+use tango_gamesupport::BuildWarnings as _;
+use tango_gamesupport_common_ui::dataview::build_code::BuildCode;
+use tango_gamesupport_common_ui::model::SaveModel;
+
+#[derive(serde::Serialize)]
+pub struct Report {
+    game: String,
+    navi: Option<Navi>,
+    folder: Option<Folder>,
+    navicust: Option<Vec<Part>>,
+    patch_cards: Option<Vec<Card>>,
+    /// The build as a code another save can paste, or `None` when a
+    /// section is too large to encode.
+    build_code: Option<String>,
+    violations: Vec<String>,
+}
+
+#[derive(serde::Serialize)]
+struct Navi {
+    id: usize,
+    name: Option<String>,
+    max_hp: u16,
+    base_max_hp: Option<u16>,
+    buster: Option<Buster>,
+}
+
+#[derive(serde::Serialize)]
+struct Buster {
+    attack: u8,
+    speed: u8,
+    charge: u8,
+}
+
+#[derive(serde::Serialize)]
+struct Folder {
+    index: usize,
+    /// Every slot, `null` where empty.
+    chips: Vec<Option<Chip>>,
+    regular: Option<usize>,
+    tags: Option<[usize; 2]>,
+}
+
+#[derive(serde::Serialize)]
+struct Chip {
+    chip: usize,
+    code: String,
+    name: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+struct Part {
+    slot: usize,
+    part: usize,
+    name: Option<String>,
+    col: u8,
+    row: u8,
+    rot: u8,
+    compressed: bool,
+}
+
+#[derive(serde::Serialize)]
+struct Card {
+    card: usize,
+    name: Option<String>,
+    enabled: bool,
+}
+
+/// The legality report in en-US, one line per problem — the same lines
+/// the editor's build warnings show.
+pub fn violations(save: &SaveModel) -> Vec<String> {
+    let lang = "en-US".parse().unwrap();
+    tango_gamesupport_common_ui::build::warnings(&*save.save, &*save.assets)
+        .iter()
+        .flat_map(|w| w.format(&lang))
+        .collect()
+}
+
+pub fn report(save: &SaveModel) -> Report {
+    let assets = &*save.assets;
+
+    let navi = save.save.view_navi().map(|nv| Navi {
+        id: nv.navi(),
+        name: assets.navi(nv.navi()).and_then(|n| n.name()),
+        max_hp: nv.max_hp(assets),
+        base_max_hp: nv.base_max_hp(assets),
+        buster: nv.buster_stats(assets).map(|b| Buster {
+            attack: b.attack,
+            speed: b.speed,
+            charge: b.charge,
+        }),
+    });
+
+    let folder = save.save.view_chips().map(|chips| {
+        let index = chips.equipped_folder_index();
+        Folder {
+            index,
+            chips: (0..chips.folder_size())
+                .map(|slot| {
+                    chips.chip(index, slot).map(|c| Chip {
+                        chip: c.id,
+                        code: c.code.to_string(),
+                        name: assets.chip(c.id).and_then(|info| info.name()),
+                    })
+                })
+                .collect(),
+            regular: chips.regular_chip_index(index).flatten(),
+            tags: chips.tag_chip_indexes(index).flatten(),
+        }
+    });
+
+    let navicust = save.save.view_navicust().map(|nc| {
+        (0..nc.count())
+            .filter_map(|slot| {
+                let p = nc.navicust_part(slot)?;
+                Some(Part {
+                    slot,
+                    part: p.id,
+                    name: assets.navicust_part(p.id).and_then(|info| info.name()),
+                    col: p.col,
+                    row: p.row,
+                    rot: p.rot,
+                    compressed: p.compressed,
+                })
+            })
+            .collect()
+    });
+
+    let patch_cards = save.save.view_patch_card56s().map(|pc| {
+        (0..pc.count())
+            .filter_map(|slot| pc.patch_card(slot))
+            .map(|c| Card {
+                card: c.id,
+                name: assets.patch_card56(c.id).and_then(|info| info.name()),
+                enabled: c.enabled,
+            })
+            .collect()
+    });
+
+    Report {
+        game: save.build_code_game().to_string(),
+        navi,
+        folder,
+        navicust,
+        patch_cards,
+        build_code: BuildCode::from_save(save.build_code_game(), &*save.save).encode().ok(),
+        violations: violations(save),
+    }
+}
@@ -0,0 +1,184 @@
+//! The netcode health timeline: what the live telemetry deck's
+//! sparklines show (TPS, skew, lead, rollback depth, ping), sampled on
+//! the match's own tick timebase for the whole match instead of a few
+//! seconds of UI frames, so it can be laid against the rounds after the
+//! match is over. The PvP drive loop records it; [`crate::stats`] keeps
+//! it in a file beside the replay.
+//!
+//! Unlike the match stats this can't be recomputed from the replay —
+//! the recording holds inputs, not the network they crossed — so a
+//! missing file is simply a match with no timeline.
+
+// Format history — bump on any layout change; readers reject every
+// other version.
+// v1: initial layout.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Sidecar file magic.
+const MAGIC: &[u8; 4] = b"TGNH";
+
+/// How many match ticks apart the drive loop takes a sample — half a
+/// second, fine enough to place a lag spike within a round and small
+/// enough that an hour-long set stays a few hundred kilobytes.
+pub const SAMPLE_INTERVAL: u32 = 30;
+
+/// Reader-side sanity cap on stored samples — days of play at
+/// [`SAMPLE_INTERVAL`]; it only rejects corrupt counts before
+/// allocating for them.
+const MAX_SAMPLES: u32 = 1 << 22;
+
+/// Stored in place of a ping the link had no reading for yet.
+const NO_PING: u16 = u16::MAX;
+
+/// One reading of the netcode, taken at a match tick.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NetSample {
+    /// The match tick the sample was taken on — the same timebase as
+    /// the stats' rounds, so a sample places itself within one.
+    pub tick: u32,
+    /// Measured simulation ticks per second.
+    pub tps: f32,
+    /// What the pacing loop was targeting at the time.
+    pub fps_target: f32,
+    /// Clock-sync skew (positive = we lead).
+    pub skew: i32,
+    /// Local inputs not yet matched by a remote input.
+    pub lead: i32,
+    /// Speculative ticks the last advance rolled back.
+    pub depth: u32,
+    /// Latest raw round-trip time, `None` before the first pong.
+    pub ping_ms: Option<u16>,
+}
+
+/// A match's netcode health, in tick order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NetHealth {
+    pub samples: Vec<NetSample>,
+}
+
+/// Why a timeline failed to parse. Every variant means "no timeline"
+/// to the caller — the distinctions only serve logs.
+#[derive(Debug, thiserror::Error)]
+pub enum ReadError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("not a net health sidecar (bad magic)")]
+    BadMagic,
+    #[error("unsupported net health version {0} (want {FORMAT_VERSION})")]
+    UnsupportedVersion(u32),
+    #[error("implausible sample count {0}")]
+    ImplausibleCount(u32),
+}
+
+impl NetHealth {
+    /// The samples taken within `[start, end)`.
+    pub fn span(&self, start: u32, end: u32) -> &[NetSample] {
+        let lo = self.samples.partition_point(|s| s.tick < start);
+        let hi = self.samples.partition_point(|s| s.tick < end);
+        &self.samples[lo..hi.max(lo)]
+    }
+
+    /// Parse a sidecar written by [`Self::write`].
+    pub fn read(mut r: impl std::io::Read) -> Result<Self, ReadError> {
+        fn bytes<const N: usize>(r: &mut impl std::io::Read) -> std::io::Result<[u8; N]> {
+            let mut b = [0u8; N];
+            r.read_exact(&mut b)?;
+            Ok(b)
+        }
+        if &bytes::<4>(&mut r)? != MAGIC {
+            return Err(ReadError::BadMagic);
+        }
+        let version = u32::from_le_bytes(bytes(&mut r)?);
+        if version != FORMAT_VERSION {
+            return Err(ReadError::UnsupportedVersion(version));
+        }
+        let n = u32::from_le_bytes(bytes(&mut r)?);
+        if n > MAX_SAMPLES {
+            return Err(ReadError::ImplausibleCount(n));
+        }
+        let mut samples = Vec::with_capacity(n as usize);
+        for _ in 0..n {
+            let tick = u32::from_le_bytes(bytes(&mut r)?);
+            let tps = f32::from_le_bytes(bytes(&mut r)?);
+            let fps_target = f32::from_le_bytes(bytes(&mut r)?);
+            let skew = i32::from_le_bytes(bytes(&mut r)?);
+            let lead = i32::from_le_bytes(bytes(&mut r)?);
+            let depth = u32::from_le_bytes(bytes(&mut r)?);
+            let ping = u16::from_le_bytes(bytes(&mut r)?);
+            samples.push(NetSample {
+                tick,
+                tps,
+                fps_target,
+                skew,
+                lead,
+                depth,
+                ping_ms: (ping != NO_PING).then_some(ping),
+            });
+        }
+        Ok(Self { samples })
+    }
+
+    /// Serialize: magic, version, then a count and the samples, every
+    /// field little-endian.
+    pub fn write(&self, mut w: impl std::io::Write) -> std::io::Result<()> {
+        w.write_all(MAGIC)?;
+        w.write_all(&FORMAT_VERSION.to_le_bytes())?;
+        w.write_all(&(self.samples.len() as u32).to_le_bytes())?;
+        for s in &self.samples {
+            w.write_all(&s.tick.to_le_bytes())?;
+            w.write_all(&s.tps.to_le_bytes())?;
+            w.write_all(&s.fps_target.to_le_bytes())?;
+            w.write_all(&s.skew.to_le_bytes())?;
+            w.write_all(&s.lead.to_le_bytes())?;
+            w.write_all(&s.depth.to_le_bytes())?;
+            w.write_all(&s.ping_ms.map_or(NO_PING, |p| p.min(NO_PING - 1)).to_le_bytes())?;
+        }
+        w.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(tick: u32, ping_ms: Option<u16>) -> NetSample {
+        NetSample {
+            tick,
+            tps: 59.7,
+            fps_target: 59.73,
+            skew: -2,
+            lead: 5,
+            depth: 3,
+            ping_ms,
+        }
+    }
+
+    /// A timeline survives the sidecar byte-for-byte, a missing ping
+    /// included, and a file from another version is refused.
+    #[test]
+    fn sidecar_roundtrips() {
+        let health = NetHealth {
+            samples: vec![sample(30, None), sample(60, Some(48)), sample(90, Some(212))],
+        };
+        let mut buf = Vec::new();
+        health.write(&mut buf).unwrap();
+        assert_eq!(NetHealth::read(buf.as_slice()).unwrap(), health);
+
+        buf[4..8].copy_from_slice(&(FORMAT_VERSION + 1).to_le_bytes());
+        assert!(matches!(
+            NetHealth::read(buf.as_slice()),
+            Err(ReadError::UnsupportedVersion(_))
+        ));
+    }
+
+    #[test]
+    fn span_cuts_on_ticks() {
+        let health = NetHealth {
+            samples: (1..=6).map(|i| sample(i * 30, None)).collect(),
+        };
+        let ticks = |s: &[NetSample]| s.iter().map(|s| s.tick).collect::<Vec<_>>();
+        assert_eq!(ticks(health.span(60, 120)), [60, 90]);
+        assert_eq!(ticks(health.span(170, 1000)), [180]);
+        assert!(health.span(200, 100).is_empty());
+    }
+}
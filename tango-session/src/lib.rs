@@ -51,6 +51,8 @@ pub mod platform;
 /// so the Replays tab never re-simulates one it has already seen.
 #[cfg(not(target_arch = "wasm32"))]
 pub mod stats;
+/// The netcode health timeline a live match records for its replay.
+pub mod health;
 
 /// The joypad bit vocabulary [`Session::set_input`] speaks —
 /// re-exported so hosts get the bit names without their own emulator
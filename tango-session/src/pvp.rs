@@ -193,6 +193,10 @@ pub struct PvpSession {
     /// sidecar write can read it during teardown regardless of how far the
     /// background tasks have already wound down.
     stats: Arc<Mutex<tango_match::analysis::StatsBuilder>>,
+    /// The netcode health timeline, sampled by the drive thread every
+    /// [`SAMPLE_INTERVAL`](crate::health::SAMPLE_INTERVAL) ticks. Shared
+    /// like `stats`, for the results screen and the sidecar write.
+    net_health: Arc<Mutex<crate::health::NetHealth>>,
     /// Where this match's replay is being recorded, or `None` if the writer
     /// failed to open. The post-match results screen offers to play it back.
     pub replay_path: Option<std::path::PathBuf>,
@@ -469,6 +473,7 @@ impl PvpSession {
         // A game whose engine reports no chip events folds the rest of
         // the stats without them — the aggregator is the same for all.
         let stats = Arc::new(Mutex::new(tango_match::analysis::StatsBuilder::new()));
+        let net_health = Arc::new(Mutex::new(crate::health::NetHealth::default()));
 
         // Remote input events flow receive-task → drive thread over this
         // queue; the rennet reassembly in PvpReceiver already ordered and
@@ -529,6 +534,7 @@ impl PvpSession {
             in_match: in_match.clone(),
             replay_writer,
             stats: stats.clone(),
+            net_health: net_health.clone(),
             // Keyed against the store's own directory, so a store
             // that isn't one (a browser's) simply has no sidecar —
             // which is also the only kind of host that has nowhere
@@ -538,6 +544,13 @@ impl PvpSession {
                 .as_ref()
                 .zip(replays.and_then(|store| store.root()))
                 .map(|(path, root)| crate::stats::stats_path(cache_path, root, path)),
+            // Beside the replay, where the recording is a file at all.
+            #[cfg(not(target_arch = "wasm32"))]
+            health_path: replay_path
+                .as_ref()
+                .filter(|_| replays.and_then(|store| store.root()).is_some())
+                .map(|path| crate::stats::health_path(path)),
+            link: link.clone(),
             tps_counter: tps_counter.clone(),
             screen: screen.clone(),
             wake: wake.clone(),
@@ -621,6 +634,7 @@ impl PvpSession {
             remote_nickname: pre_match.remote_settings.nickname,
             frame_delay,
             stats,
+            net_health,
             replay_path,
             layout,
             screen,
@@ -744,12 +758,7 @@ impl PvpSession {
     /// per-frame marks. Independent of UI refresh rate. ZERO until the
     /// second sample lands.
     pub fn tps(&self) -> f32 {
-        let mean = self.tps_counter.lock().unwrap().mean_duration();
-        if mean.is_zero() {
-            0.0
-        } else {
-            1.0 / mean.as_secs_f32()
-        }
+        self.tps_counter.lock().unwrap().rate()
     }
 
     /// What the pacing loop is currently targeting. Pairs with `tps()` —
@@ -767,6 +776,12 @@ impl PvpSession {
         self.stats.lock().unwrap().snapshot()
     }
 
+    /// The netcode health timeline recorded so far. Read at teardown for
+    /// the results screen, beside [`stats_snapshot`](Self::stats_snapshot).
+    pub fn net_health_snapshot(&self) -> crate::health::NetHealth {
+        self.net_health.lock().unwrap().clone()
+    }
+
     /// How long the match ran, start of session to local completion — or to
     /// now, if completion hasn't been observed yet (it is stamped a frame
     /// after the completion flag flips). For the results screen.
@@ -967,10 +982,17 @@ struct DriveContext {
     in_match: crate::net::InMatchTx,
     replay_writer: Option<tango_replay::Writer>,
     stats: Arc<Mutex<tango_match::analysis::StatsBuilder>>,
+    /// See the session's copy.
+    net_health: Arc<Mutex<crate::health::NetHealth>>,
     /// Where this match's stats sidecar goes. Native-only: the cache is
     /// a file next to the replay, and a browser has no such place.
     #[cfg(not(target_arch = "wasm32"))]
     stats_path: Option<std::path::PathBuf>,
+    /// Where the health timeline goes, beside the replay.
+    #[cfg(not(target_arch = "wasm32"))]
+    health_path: Option<std::path::PathBuf>,
+    /// The peer link, for the ping each health sample carries.
+    link: Arc<crate::net::link::Link>,
     tps_counter: Arc<Mutex<TpsCounter>>,
     screen: Arc<crate::Framebuffer>,
     wake: Arc<tokio::sync::Notify>,
@@ -1813,6 +1835,12 @@ impl PvpDriver {
         }
         self.ctx.tps_counter.lock().unwrap().mark();
         self.ctx.wake.notify_one();
+        // Sampled on the simulated tick, the one the stats' samples and
+        // rounds carry, not `advance`'s input tick a present delay ahead.
+        let simulated = self.match_.simulated();
+        if simulated % crate::health::SAMPLE_INTERVAL == 0 {
+            self.sample_health(simulated);
+        }
 
         // Whatever the host is arranging to show, handed over each tick:
         // a console composes nothing for a screen nobody is looking at,
@@ -1830,10 +1858,36 @@ impl PvpDriver {
         true
     }
 
-    /// Flush the replay tail and cache the match's stats. Called once,
-    /// after [`PvpDriver::tick`] reports the match over — reached through
-    /// [`crate::Drive::finish`], which is why a host must wind a driver
-    /// down rather than drop it.
+    /// Take one netcode health sample at simulated tick `tick`: the
+    /// same readouts the live telemetry deck shows, as they stand after
+    /// this advance. A tick already sampled (the pair stood still this
+    /// advance) isn't sampled twice.
+    fn sample_health(&self, tick: u32) {
+        let mut health = self.ctx.net_health.lock().unwrap();
+        if health.samples.last().is_some_and(|s| s.tick >= tick) {
+            return;
+        }
+        let metrics = &self.ctx.metrics;
+        let sample = crate::health::NetSample {
+            tick,
+            tps: self.ctx.tps_counter.lock().unwrap().rate(),
+            fps_target: f32::from_bits(metrics.fps_target.load(Ordering::Relaxed)),
+            skew: metrics.skew.load(Ordering::Relaxed),
+            lead: metrics.queue_len.load(Ordering::Relaxed) as i32,
+            depth: metrics.depth.load(Ordering::Relaxed),
+            ping_ms: self
+                .ctx
+                .link
+                .latency_raw()
+                .map(|d| d.as_millis().min(u16::MAX as u128) as u16),
+        };
+        health.samples.push(sample);
+    }
+
+    /// Flush the replay tail and cache the match's stats and netcode
+    /// health. Called once, after [`PvpDriver::tick`] reports the match
+    /// over — reached through [`crate::Drive::finish`], which is why a
+    /// host must wind a driver down rather than drop it.
     pub fn finish(mut self) {
         // Teardown: flush the replay tail. Finalize (write the EOR
         // sentinel) only if the match completed — same policy as the trap
@@ -1873,6 +1927,13 @@ impl PvpDriver {
                     log::warn!("failed to write replay stats cache entry: {e}");
                 }
             }
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(health_path) = self.ctx.health_path.as_ref() {
+                let health = self.ctx.net_health.lock().unwrap();
+                if let Err(e) = crate::stats::write_net_health(health_path, &health) {
+                    log::warn!("failed to write replay net health entry: {e}");
+                }
+            }
         }
     }
 }
@@ -1934,4 +1995,16 @@ impl TpsCounter {
         }
         total / count
     }
+
+    /// Ticks per second over the window — the inverse of
+    /// [`mean_duration`](Self::mean_duration), ZERO under the same
+    /// condition.
+    pub fn rate(&self) -> f32 {
+        let mean = self.mean_duration();
+        if mean.is_zero() {
+            0.0
+        } else {
+            1.0 / mean.as_secs_f32()
+        }
+    }
 }
@@ -2,7 +2,9 @@
 //! [`tango_match::analysis::MatchStats`] live on disk. Written at match
 //! teardown by the live PvP session and by the replay prefetcher's
 //! analysis pass; read back by anything that wants a replay's stats
-//! without re-simulating it.
+//! without re-simulating it. The live session's netcode health
+//! timeline ([`crate::health`]) is NOT a cache entry and lives beside
+//! the replay instead.
 
 /// Where a replay's cached match stats live: the replay's path relative
 /// to the replays root, mirrored under `<data>/cache/replay-stats/` with
@@ -13,6 +15,26 @@ pub fn stats_path(
     cache_path: &std::path::Path,
     replays_path: &std::path::Path,
     replay_path: &std::path::Path,
+) -> std::path::PathBuf {
+    cache_slot(cache_path, replays_path, replay_path, ".stats")
+}
+
+/// Where a replay's netcode health timeline lives: next to the replay,
+/// with `.net` appended to its file name. Only a live match writes one
+/// and there is nothing to recompute it from, so unlike [`stats_path`]
+/// it stays out of the cache — clearing the cache must not lose it, and
+/// moving a replay with its `.net` file keeps them paired.
+pub fn health_path(replay_path: &std::path::Path) -> std::path::PathBuf {
+    let mut s = replay_path.as_os_str().to_owned();
+    s.push(".net");
+    std::path::PathBuf::from(s)
+}
+
+fn cache_slot(
+    cache_path: &std::path::Path,
+    replays_path: &std::path::Path,
+    replay_path: &std::path::Path,
+    suffix: &str,
 ) -> std::path::PathBuf {
     // A replay outside the replays root shouldn't happen (the scanner is
     // the only source of replay paths) — keyed degraded by file name.
@@ -26,7 +48,7 @@ pub fn stats_path(
                 .unwrap_or_default()
         });
     let mut s = cache_path.join("replay-stats").join(rel).into_os_string();
-    s.push(".stats");
+    s.push(suffix);
     std::path::PathBuf::from(s)
 }
 
@@ -54,3 +76,17 @@ pub fn write_match_stats(
     let f = std::fs::File::create(stats_file)?;
     stats.write(std::io::BufWriter::new(f))
 }
+
+/// A replay's netcode health timeline, if one was recorded. Same
+/// failure policy as [`load_match_stats`], except that "missing" is
+/// final: a replay without one was never played live here.
+pub fn load_net_health(replay_path: &std::path::Path) -> Option<crate::health::NetHealth> {
+    let f = std::fs::File::open(health_path(replay_path)).ok()?;
+    crate::health::NetHealth::read(std::io::BufReader::new(f)).ok()
+}
+
+/// Write `health` to a replay's timeline file, beside the replay.
+pub fn write_net_health(health_file: &std::path::Path, health: &crate::health::NetHealth) -> std::io::Result<()> {
+    let f = std::fs::File::create(health_file)?;
+    health.write(std::io::BufWriter::new(f))
+}
@@ -42,11 +42,32 @@ save-rename-confirm = Rename
 save-delete-confirm = Delete
 save-action-cancel = Cancel
 save-delete-prompt = Delete { $name }?
+save-compare = Compare with…
+save-compare-pick = Pick a save to compare with…
+save-compare-none = No other saves of this game
+save-compare-title = Comparing saves
+save-compare-close = Close comparison
+save-bnlc-slot = Legacy Collection: { $slot } (import)
+save-history = History…
+save-history-title = Earlier versions
+save-history-close = Close history
+save-history-empty = No earlier versions yet. One is kept every time this save is overwritten.
+save-history-restore = Restore
+save-history-unreadable = No longer reads as a save of this game
+save-history-unnamed = (unnamed)
 save-name-placeholder = New name
 save-new = New save
 save-new-confirm = Create
 save-template-default = (default)
 save-template-pick = Pick a template…
+save-gallery = Template gallery
+save-gallery-title = Save templates
+save-gallery-close = Close gallery
+save-gallery-loading = Loading template…
+save-gallery-build-placeholder = Paste a build code to start from it…
+save-gallery-apply-build = Apply build
+save-gallery-build-staged = The build is applied; every chip in it is in the template's pack.
+save-gallery-pack-short = This build needs chips the template's pack doesn't have:
 
 # Empty-state hints
 empty-scanning-title = Scanning your library…
@@ -117,10 +138,25 @@ lobby-compat-version-mismatch = Game versions don't match (different patch / ROM
 lobby-compat-sim-too-old = This game's netplay changed since your opponent's version of Tango — they need to update.
 lobby-compat-sim-too-new = This game's netplay changed since your version of Tango — you need to update.
 lobby-compat-match-mismatch = Match type doesn't match.
+lobby-compat-rules-mismatch = You aren't playing under the same rule set.
+lobby-compat-unpatched = the unpatched game
+lobby-compat-patch-not-allowed-you = The rule set doesn't allow your patch ({ $patch }).
+lobby-compat-patch-not-allowed-opponent = The rule set doesn't allow your opponent's patch ({ $patch }).
+lobby-compat-rule-broken-you = Your save breaks the rule set: { $breach }.
+lobby-compat-rule-broken-opponent = Your opponent's save breaks the rule set: { $breach }.
+lobby-rule-banned-chip = { $name } is a banned chip
+lobby-rule-banned-part = { $name } is a banned NaviCust part
+lobby-rule-too-many-copies = { $count } copies of { $name } (limit { $limit })
+lobby-rule-max-hp = { $hp } max HP (cap { $cap })
+lobby-rule-unreadable = Tango can't read this game's saves to check the rules
 lobby-ready = Ready
 lobby-unready = Unready
 lobby-match-starting = Starting…
 lobby-blind-mine = Blind setup
+lobby-rules = Rule set
+lobby-rules-none = None
+lobby-rules-clear = Clear rule set
+lobby-rules-load-failed = Couldn't load that rule set: { $error }
 lobby-blind-peer-on = Opponent is hiding their setup.
 lobby-blind-self-on = You are hiding your setup.
 session-opponent = Opponent setup
@@ -153,6 +189,41 @@ session-results-draws = { $count ->
 session-results-watch-replay = Watch replay
 session-results-done = Done
 
+# Damage attribution (results card, replay detail)
+
+damage-top = Top damage
+damage-total = { $damage } ({ $hits ->
+    [one] 1 hit
+   *[other] { $hits } hits
+})
+damage-source-other = Other
+
+# Movement analysis (replay detail)
+
+movement-title = Positioning
+movement-round = Round { $number }
+movement-summary = { $moves ->
+    [one] 1 move
+   *[other] { $moves } moves
+} · { $back }% back column
+
+# Custom-screen timing (replay detail)
+
+custom-timing-title = Custom screen
+custom-timing-mean = Avg. time
+custom-timing-max = Max. time
+custom-timing-first-chip = First chip
+custom-timing-chips = Chips / window
+custom-timing-match = Match
+custom-timing-seconds = { $seconds }s
+
+# Netcode health lanes under the HP graph
+net-health-tps = TPS
+net-health-skew = Skew
+net-health-lead = Lead
+net-health-depth = Rollback
+net-health-ping = Ping
+
 # Save view sub-tabs
 
 # Navi pane
@@ -293,10 +364,24 @@ patches-details-authors = Authors:
 patches-details-license = License:
 patches-details-source = Source:
 patches-details-games = Supported games:
+patches-details-repos = From:
+patches-details-conflicts = Different copy in:
 patches-netplay-compatibility = Netplay:
 patches-netplay-isolated = This version only
 patches-netplay-vanilla = Plays with the unpatched game
 patches-netplay-group = Plays with: { $group }
+patches-compare-with = Compare with…
+patches-compare-title = { $from } → { $to } on { $game }
+patches-compare-close = Close comparison
+patches-comparing = Comparing…
+patches-compare-unavailable = One of these versions is no longer installed or offered by any patch repo.
+patches-compare-no-rom = None of the games these versions patch is in your library.
+patches-compare-failed = Couldn't compare these versions: { $error }
+patches-compare-template = No save of this game is selected, so this checks the game's default folder.
+patches-compare-folder = Your folder
+patches-compare-folder-unaffected = Nothing in this update touches your folder.
+patches-compare-identical = The chip, NaviCust and patch card tables didn't change.
+patches-compare-changelog = Changelog
 
 # Settings panel
 settings-section-general = General
@@ -385,6 +470,12 @@ settings-matchmaking-endpoint = Matchmaking endpoint
 settings-data-folder = Data folder
 settings-data-folder-change = Change…
 settings-patch-repo = Patches repository
+settings-extra-patch-repo = Additional repository
+settings-extra-patch-repo-placeholder = URL or folder
+settings-extra-patch-repo-remove = Remove repository
+settings-extra-patch-repos = Additional repositories
+settings-extra-patch-repo-add = Add repository
+settings-extra-patch-repo-add-folder = Add folder…
 settings-enable-patch-autoupdate = Check for new patches in the background
 settings-enable-updater = Automatically check for app updates
 settings-allow-prerelease-upgrades = Include prereleases when checking for app updates
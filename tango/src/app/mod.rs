@@ -390,11 +390,8 @@ impl App {
             }
         };
 
-        let mut patch_autoupdater = autoupdate::Autoupdater::new(
-            config.patches_path(),
-            config.patch_repo.clone(),
-            scanners.patches.clone(),
-        );
+        let mut patch_autoupdater =
+            autoupdate::Autoupdater::new(config.patches_path(), config.patch_repos(), scanners.patches.clone());
         if config.enable_patch_autoupdate {
             patch_autoupdater.start();
         }
@@ -457,6 +454,7 @@ impl App {
             lobby_swap: anim::Transition::swap(false),
             lobby_exit_snapshot: None,
         };
+        app.install_rule_check();
         app.refresh_loaded();
         let scan = app.boot_scan();
         (app, scan)
@@ -553,6 +551,7 @@ impl App {
             self.scanners.replays.read().iter().map(|r| r.path.clone()).collect();
         self.replays.stats.retain(|p, _| live.contains(p));
         self.replays.hp_charts.retain(|p, _| live.contains(p));
+        self.replays.net_health.retain(|p, _| live.contains(p));
         self.kick_replay_stats_loader()
     }
 
@@ -712,6 +711,14 @@ impl App {
         )
     }
 
+    /// Hand the lobby a [`netplay::RuleCheck`] over the current library,
+    /// for tournament rule sets. Re-run when the data folder moves, since
+    /// the checker reads patches from a fixed path.
+    fn install_rule_check(&mut self) {
+        let check = netplay::RuleCheck::new(self.scanners.clone(), self.config.patches_path());
+        self.netplay.set_rule_check(std::sync::Arc::new(check));
+    }
+
     /// Build the current Settings packet and push it to the peer — only
     /// meaningful while netplay is in Lobby phase; outside that this
     /// returns `Task::none()`. Wrapped in a helper because it has three
@@ -858,7 +865,7 @@ impl App {
         let roms = self.scanners.roms.read();
         let saves = self.scanners.saves.read();
         let patches = self.scanners.patches.read();
-        let Some(rom) = roms.get(&game).cloned() else {
+        let Some(rom) = roms.get(&game).map(|rom| rom.bytes.clone()) else {
             self.loaded = None;
             return;
         };
@@ -119,6 +119,27 @@ impl App {
                 }
                 self.resend_settings_if_lobby()
             }
+            E::PickRuleSet => iced::Task::perform(
+                async {
+                    let file = rfd::AsyncFileDialog::new()
+                        .add_filter("JSON", &["json"])
+                        .pick_file()
+                        .await?;
+                    let read = tokio::fs::read_to_string(file.path())
+                        .await
+                        .map_err(|e| e.to_string())
+                        .and_then(|json| serde_json::from_str(&json).map_err(|e| e.to_string()));
+                    Some(read)
+                },
+                |loaded| match loaded {
+                    Some(loaded) => Message::Play(tabs::play::Message::RuleSetLoaded(loaded)),
+                    None => Message::NoOp,
+                },
+            ),
+            E::SetRuleSet(rule_set) => {
+                self.netplay.set_rule_set(rule_set);
+                self.resend_settings_if_lobby()
+            }
             E::SetBlindSetup(v) => {
                 self.netplay.set_blind_setup(v);
                 // Remember the choice so the next lobby (this session or
@@ -161,7 +182,8 @@ impl App {
                     Ok((s, audio, save, drive)) => {
                         self.session.active = Some(Box::new(s));
                         self.session.audio_binding = audio;
-                        self.session.attach_save_backup(save_path, save);
+                        let history = crate::library::save_history::SaveHistory::new(&self.config);
+                        self.session.attach_save_backup(save_path, save, history);
                         self.session.attach_drive_threads([drive]);
                         self.session.session_installed();
                     }
@@ -217,6 +239,10 @@ impl App {
                     match rename_save(&src, &new_stem) {
                         Ok(dst) => {
                             log::info!("renamed save: {} → {}", src.display(), dst.display());
+                            let history = crate::library::save_history::SaveHistory::new(&self.config);
+                            if let Err(e) = history.rename(crate::library::storage(), &src, &dst) {
+                                log::error!("moving the history of {}: {e}", src.display());
+                            }
                             self.loadout.save = Some(dst);
                             self.persist_selection();
                             return self.rescan_off_thread(RescanFollowup::Refresh);
@@ -228,7 +254,13 @@ impl App {
             }
             E::SaveDelete => {
                 if let Some(src) = self.loadout.save.clone() {
-                    if let Err(e) = std::fs::remove_file(&src) {
+                    // Deleting is the last overwrite: the file stays
+                    // recoverable from the history like any other.
+                    let history = crate::library::save_history::SaveHistory::new(&self.config);
+                    let result = history
+                        .archive(crate::library::storage(), &src, crate::library::now_ms())
+                        .and_then(|()| std::fs::remove_file(&src));
+                    if let Err(e) = result {
                         log::error!("delete save: {e}");
                     } else {
                         log::info!("deleted save: {}", src.display());
@@ -243,6 +275,101 @@ impl App {
                 }
                 iced::Task::none()
             }
+            E::SaveCompare { other } => {
+                // Same ROM and patch as the selected save, so both sides
+                // name every id alike.
+                let Some(loaded) = self.loaded.as_ref() else {
+                    return iced::Task::none();
+                };
+                let game = loaded.game;
+                let left = loaded.save_path.clone();
+                let patch = loaded.patch.as_ref().map(|p| (p.name.clone(), p.version.clone()));
+                let roms = self.scanners.roms.read();
+                let saves = self.scanners.saves.read();
+                let patches = self.scanners.patches.read();
+                let Some(rom) = roms.get(&game).map(|rom| rom.bytes.clone()) else {
+                    return iced::Task::none();
+                };
+                let Some(scanned) = saves.get(&game).and_then(|v| v.iter().find(|s| s.path == other)) else {
+                    log::warn!("compare: {} is no longer a save of this game", other.display());
+                    return iced::Task::none();
+                };
+                let save = scanned.save.clone_box();
+                let patch_meta = patch.and_then(|(name, version)| {
+                    patches
+                        .version(&name, &version)
+                        .map(|v| (name.clone(), version.clone(), v.clone()))
+                });
+                drop(patches);
+                drop(saves);
+                drop(roms);
+                let right = selection::build(game, rom, other, save, &self.config.patches_path(), patch_meta);
+                self.play.open_compare(left, right);
+                iced::Task::none()
+            }
+            E::SaveHistoryOpen => {
+                // Each version summarized under the selected save's ROM
+                // and patch, like the compare view's partner.
+                let Some(loaded) = self.loaded.as_ref() else {
+                    return iced::Task::none();
+                };
+                let game = loaded.game;
+                let Some(raw) = self.scanners.roms.read().get(&game).map(|rom| rom.bytes.clone()) else {
+                    return iced::Task::none();
+                };
+                let rom = match loaded.patch.as_ref() {
+                    Some(p) => crate::library::patch::apply_patch(
+                        crate::library::storage(),
+                        &raw,
+                        game,
+                        &self.config.patches_path(),
+                        &p.name,
+                        &p.version,
+                    )
+                    .unwrap_or_else(|e| {
+                        log::error!("history: applying {} v{}: {e}", p.name, p.version);
+                        raw
+                    }),
+                    None => raw,
+                };
+                let history = crate::library::save_history::SaveHistory::new(&self.config);
+                let versions = history
+                    .versions(crate::library::storage(), &loaded.save_path)
+                    .into_iter()
+                    .map(|version| {
+                        let summary = crate::library::save::read_save(crate::library::storage(), &version.path)
+                            .ok()
+                            .and_then(|file| game.parse_save(&file.image).ok())
+                            .map(|save| {
+                                let prepared = selection::prepare_from_patched_rom(
+                                    game,
+                                    rom.clone(),
+                                    version.path.clone(),
+                                    save,
+                                    loaded.patch.clone(),
+                                );
+                                game.family.save_editor.summary(&prepared)
+                            });
+                        tabs::play::HistoryEntry { version, summary }
+                    })
+                    .collect();
+                self.play.open_history(loaded.save_path.clone(), versions);
+                iced::Task::none()
+            }
+            E::SaveHistoryRestore(version) => {
+                let Some(save) = self.loadout.save.clone() else {
+                    return iced::Task::none();
+                };
+                let history = crate::library::save_history::SaveHistory::new(&self.config);
+                match history.restore(crate::library::storage(), &save, &version, crate::library::now_ms()) {
+                    Ok(()) => {
+                        log::info!("restored {} from {}", save.display(), version.path.display());
+                        return self.rescan_off_thread(RescanFollowup::Refresh);
+                    }
+                    Err(e) => log::error!("restore save: {e}"),
+                }
+                iced::Task::none()
+            }
             E::SaveNew { name, template, game } => {
                 // The new save is created for `game` (the variant the
                 // user picked), which may differ from the currently
@@ -257,44 +384,111 @@ impl App {
                                 dst.display()
                             );
                             // Templates are only offered for patch-supported
-                            // variants, so the patch normally still applies;
-                            // drop it only if it somehow doesn't support the
-                            // created variant.
-                            if !loadout::patch_supports(&self.loadout, &self.scanners, game) {
-                                self.loadout.patch = None;
-                                self.loadout.patch_version = None;
-                            }
-                            self.loadout.game = Some(game);
-                            self.loadout.family = Some(game.family_and_variant().0);
-                            self.loadout.save = Some(dst);
-                            // Records the save→patch association too — a
-                            // template-created save is born remembering the
-                            // patch it was created under.
-                            self.persist_selection();
-                            return self.rescan_off_thread(RescanFollowup::Refresh);
+                            // variants, so the patch normally still applies.
+                            return self.select_new_save(game, dst);
                         }
                         Err(e) => log::error!("create save: {e}"),
                     }
                 }
                 iced::Task::none()
             }
+            E::SaveGalleryPreview { game, template, build } => {
+                // Under the loadout's patch, as the save made from it will
+                // be played: the template's names and legality are the
+                // patched ROM's.
+                let Some(save) = tabs::play::creation_template(game, &template, &self.loadout, &self.scanners) else {
+                    return iced::Task::none();
+                };
+                let Some(rom) = self.scanners.roms.read().get(&game).map(|rom| rom.bytes.clone()) else {
+                    return iced::Task::none();
+                };
+                let patch_meta = self
+                    .loadout
+                    .patch
+                    .clone()
+                    .zip(self.loadout.patch_version.clone())
+                    .and_then(|(name, version)| {
+                        let meta = self.scanners.patches.read().version(&name, &version).cloned()?;
+                        Some((name, version, meta))
+                    });
+                let mut preview = selection::build(
+                    game,
+                    rom,
+                    std::path::PathBuf::new(),
+                    save,
+                    &self.config.patches_path(),
+                    patch_meta,
+                );
+                let editor = preview.editor;
+                let staged = build.map(|code| editor.stage_build_code(&self.config.language, &mut preview, &code));
+                self.play.open_gallery_preview(game, &template, preview, staged);
+                iced::Task::none()
+            }
+            E::SaveNewFromSram { name, game, sram } => {
+                let save = match game.parse_save(&sram) {
+                    Ok(save) => save,
+                    Err(e) => {
+                        log::error!("create save from gallery: {e:?}");
+                        return iced::Task::none();
+                    }
+                };
+                match create_new_save(&self.config.saves_path(), &name, save.as_ref()) {
+                    Ok(dst) => {
+                        log::info!(
+                            "created new save for {:?} from the gallery: {}",
+                            game.family_and_variant(),
+                            dst.display()
+                        );
+                        return self.select_new_save(game, dst);
+                    }
+                    Err(e) => log::error!("create save from gallery: {e}"),
+                }
+                iced::Task::none()
+            }
+            E::SaveImportBnlc { slot, name } => {
+                let save = match crate::library::bnlc::read_save_slot(crate::library::storage(), &slot) {
+                    Ok(save) => save,
+                    Err(e) => {
+                        log::error!("import bnlc save: {e}");
+                        return iced::Task::none();
+                    }
+                };
+                match create_new_save(&self.config.saves_path(), &name, save.as_ref()) {
+                    Ok(dst) => {
+                        log::info!("imported bnlc save: {} → {}", slot.path.display(), dst.display());
+                        return self.select_new_save(slot.game, dst);
+                    }
+                    Err(e) => log::error!("import bnlc save: {e}"),
+                }
+                iced::Task::none()
+            }
             E::SaveEditCommit { sram } => {
                 // The edit session already staged everything into the
                 // in-memory save, recomputed the checksum, and serialized
-                // it — all that's left app-side is the disk write.
+                // it — all that's left app-side is the disk write, in
+                // whatever emulator's wrapper the file arrived in.
                 // `Some(sram)` once written; the SRAM is reused below to
                 // refresh a live netplay commitment.
                 let saved_sram = match self.loaded.as_ref().map(|l| l.save_path.as_path()) {
-                    Some(path) if !path.as_os_str().is_empty() => match std::fs::write(path, &sram) {
-                        Ok(()) => {
-                            log::info!("saved edited save: {}", path.display());
-                            Some(sram)
-                        }
-                        Err(e) => {
-                            log::error!("save edited save: {e}");
-                            None
+                    Some(path) if !path.as_os_str().is_empty() => {
+                        let history = crate::library::save_history::SaveHistory::new(&self.config);
+                        match crate::library::save::write_save(
+                            crate::library::storage(),
+                            path,
+                            &sram,
+                            Some(&history),
+                            crate::library::now_ms(),
+                        ) {
+                            Ok(_) => {
+                                log::info!("saved edited save: {}", path.display());
+                                Some(sram)
+                            }
+                            Err(e) => {
+                                log::error!("save edited save: {e}");
+                                None
+                            }
                         }
-                    },
+                    }
                     _ => None,
                 };
                 let Some(sram) = saved_sram else {
@@ -386,14 +580,22 @@ impl App {
                 self.rescan_off_thread(followup)
             }
             E::RefreshIndex => {
-                let url = self.config.patch_repo_url();
+                let repos = self.config.patch_repos();
                 let root = self.config.patches_path();
                 iced::Task::perform(
                     async move {
-                        patch::fetch_index(crate::library::http(), crate::library::storage(), &url, &root)
-                            .await
-                            .map(|_changed| ())
-                            .map_err(|e| e.to_string())
+                        let refreshed =
+                            patch::fetch_indexes(crate::library::http(), crate::library::storage(), &repos, &root)
+                                .await;
+                        if refreshed.failed.is_empty() {
+                            return Ok(());
+                        }
+                        Err(refreshed
+                            .failed
+                            .iter()
+                            .map(|(repo, e)| format!("{repo}: {e}"))
+                            .collect::<Vec<_>>()
+                            .join("; "))
                     },
                     tabs::patches::Message::RefreshFinished,
                 )
@@ -411,35 +613,31 @@ impl App {
             }
             E::FetchReadme(key) => {
                 let (name, version) = key.clone();
-                let url = self.config.patch_repo_url();
-                let Some(path) = self
+                let Some(offer) = self
                     .scanners
                     .patches
                     .read()
-                    .entry(&name, &version)
-                    .and_then(|e| e.readme.clone())
+                    .offers(&name, &version)
+                    .iter()
+                    .find(|o| o.readme.is_some())
+                    .cloned()
                 else {
                     return iced::Task::none();
                 };
                 iced::Task::perform(
                     async move {
-                        reqwest::Client::new()
-                            .get(format!("{}/{path}", url.trim_end_matches('/')))
-                            .header("User-Agent", "tango")
-                            .timeout(std::time::Duration::from_secs(30))
-                            .send()
-                            .await
-                            .ok()?
-                            .error_for_status()
-                            .ok()?
-                            .text()
+                        patch::fetch_readme(crate::library::http(), crate::library::storage(), &offer)
                             .await
-                            .ok()
+                            .unwrap_or_else(|e| {
+                                log::warn!("README for {name} {version}: {e}");
+                                None
+                            })
                     },
                     move |readme| tabs::patches::Message::ReadmeFetched(key.clone(), readme),
                 )
                 .map(Message::Patches)
             }
+            E::Compare(key) => self.compare_patch_versions(key),
             E::InstallFailed => {
                 // Don't leave a replay queued behind a download that
                 // isn't coming.
@@ -456,6 +654,156 @@ impl App {
         }
     }
 
+    /// Select a save just created for `game`, which may be another
+    /// variant than the selected one: adopt it as the loadout's game,
+    /// keep the loadout's patch only if it supports `game`, and rescan
+    /// so the save shows up. Persisting records the save→patch
+    /// association too, so a new save is born remembering the patch it
+    /// was created under.
+    fn select_new_save(&mut self, game: rom::GameRef, path: std::path::PathBuf) -> iced::Task<Message> {
+        if !loadout::patch_supports(&self.loadout, &self.scanners, game) {
+            self.loadout.patch = None;
+            self.loadout.patch_version = None;
+        }
+        self.loadout.game = Some(game);
+        self.loadout.family = Some(game.family_and_variant().0);
+        self.loadout.save = Some(path);
+        self.persist_selection();
+        self.rescan_off_thread(RescanFollowup::Refresh)
+    }
+
+    /// Apply two versions of a patch to the player's ROM and diff what
+    /// they do to the game's tables, off the UI thread. A version that
+    /// isn't installed is fetched from the repos offering it and applied
+    /// from memory: comparing installs nothing.
+    ///
+    /// The folder checked is the selected save's when the patch covers
+    /// its game; otherwise the first covered game with a ROM in the
+    /// library, with a default template standing in for a save.
+    fn compare_patch_versions(&mut self, key: tabs::patches::ComparisonKey) -> iced::Task<Message> {
+        use tabs::patches::{CompareError, Compared};
+
+        let finished = {
+            let key = key.clone();
+            move |result| Message::Patches(tabs::patches::Message::CompareFinished(key.clone(), result))
+        };
+        let (name, from, to) = key;
+        let patches = self.scanners.patches.read();
+        // `None` for an installed version, read off disk; otherwise what
+        // to fetch it with.
+        let source = |version: &semver::Version| {
+            if patches.is_installed(&name, version) {
+                return Some(None);
+            }
+            let entry = patches.entry(&name, version)?.clone();
+            Some(Some((entry, patches.offers(&name, version).to_vec())))
+        };
+        let (Some(old_source), Some(new_source)) = (source(&from), source(&to)) else {
+            return iced::Task::done(finished(Err(CompareError::Unavailable)));
+        };
+        let [old_games, new_games] = [&from, &to].map(|version| patches.supported_games(&name, version));
+        let new_installed = patches.version(&name, &to).cloned();
+        drop(patches);
+
+        let roms = self.scanners.roms.read();
+        let usable = |game: &rom::GameRef| {
+            game.has_save_model() && old_games.contains(game) && new_games.contains(game) && roms.contains_key(game)
+        };
+        let own = self
+            .loaded
+            .as_ref()
+            .filter(|loaded| usable(&loaded.game))
+            .map(|loaded| (loaded.game, loaded.editor.sram(loaded)));
+        let own_save = own.is_some();
+        let picked = own.or_else(|| {
+            let mut games: Vec<rom::GameRef> = new_games.iter().copied().filter(usable).collect();
+            games.sort_by_key(|game| game.family_and_variant());
+            games.into_iter().find_map(|game| {
+                // The patch's own default template first: it's the save
+                // the patch expects to be played from.
+                if let Some(templates) = new_installed.as_ref().and_then(|new| new.save_templates.get(&game)) {
+                    if let Some(template) = templates.get("").or(templates.values().next()) {
+                        return Some((game, template.to_sram_dump()));
+                    }
+                }
+                let templates = game.save_templates?;
+                let (_, template) = templates
+                    .iter()
+                    .find(|(name, _)| name.is_empty())
+                    .or(templates.first())?;
+                Some((game, template.to_sram_dump()))
+            })
+        });
+        let Some((game, sram)) = picked else {
+            return iced::Task::done(finished(Err(CompareError::NoRom)));
+        };
+        let rom = roms[&game].bytes.clone();
+        drop(roms);
+
+        let patches_path = self.config.patches_path();
+        iced::Task::perform(
+            async move {
+                let compared = async move {
+                    let mut raws = [None, None];
+                    for (raw, (version, source)) in raws.iter_mut().zip([(&from, old_source), (&to, new_source)]) {
+                        let Some((entry, offers)) = source else {
+                            continue;
+                        };
+                        let fetched = patch::fetch_package(
+                            crate::library::http(),
+                            crate::library::storage(),
+                            &offers,
+                            &name,
+                            version,
+                            &entry,
+                            |_| true,
+                        )
+                        .await?;
+                        *raw = Some(fetched.ok_or_else(|| anyhow::anyhow!("cancelled"))?);
+                    }
+                    let [old_raw, new_raw] = raws;
+                    let compared = tokio::task::spawn_blocking(move || -> anyhow::Result<Compared> {
+                        let prepare = |version: &semver::Version, raw: Option<Vec<u8>>| -> anyhow::Result<_> {
+                            let path = patch::package_path(&patches_path, &name, version);
+                            let raw = match raw {
+                                Some(raw) => raw,
+                                None => crate::library::storage().read(&path)?,
+                            };
+                            let applied = patch::apply_package(&raw, &rom, game)?;
+                            let save = game
+                                .parse_save(&sram)
+                                .map_err(|e| anyhow::anyhow!("parse save: {e:?}"))?;
+                            let applied_patch = selection::AppliedPatch {
+                                name: name.clone(),
+                                version: version.clone(),
+                                rom_overrides: applied.rom_overrides,
+                            };
+                            let prepared = selection::prepare_from_patched_rom(
+                                game,
+                                applied.rom,
+                                std::path::PathBuf::new(),
+                                save,
+                                Some(applied_patch),
+                            );
+                            Ok((prepared, applied.readme))
+                        };
+                        let ((left, _), (right, readme)) = (prepare(&from, old_raw)?, prepare(&to, new_raw)?);
+                        Ok(Compared {
+                            game,
+                            own_save,
+                            diff: game.family.save_editor.patch_diff(&left, &right),
+                            changelog: readme.as_deref().and_then(patch::changelog),
+                        })
+                    })
+                    .await??;
+                    anyhow::Ok(compared)
+                };
+                compared.await.map_err(|e| CompareError::Failed(format!("{e:#}")))
+            },
+            finished,
+        )
+    }
+
     /// Download one patch version, reporting byte progress as it goes.
     ///
     /// Progress and the terminal result travel down one channel, so the
@@ -468,13 +816,19 @@ impl App {
             return iced::Task::none();
         }
         let (name, version) = key.clone();
-        let Some(entry) = self.scanners.patches.read().entry(&name, &version).cloned() else {
+        let (entry, offers) = {
+            let patches = self.scanners.patches.read();
+            (
+                patches.entry(&name, &version).cloned(),
+                patches.offers(&name, &version).to_vec(),
+            )
+        };
+        let Some(entry) = entry else {
             return iced::Task::done(Message::Patches(tabs::patches::Message::InstallFinished(
                 key,
-                Err("not offered by this patch repo".to_string()),
+                Err("not offered by any patch repo".to_string()),
             )));
         };
-        let url = self.config.patch_repo_url();
         let root = self.config.patches_path();
         self.downloads.insert(
             key.clone(),
@@ -494,7 +848,7 @@ impl App {
             let result = patch::download(
                 crate::library::http(),
                 crate::library::storage(),
-                &url,
+                &offers,
                 &root,
                 &name,
                 &version,
@@ -867,7 +1221,7 @@ impl App {
                     .roms
                     .read()
                     .get(&entry)
-                    .cloned()
+                    .map(|rom| rom.bytes.clone())
                     .ok_or_else(|| anyhow::anyhow!("rom for {:?} not scanned", entry.family_and_variant()))?;
                 let rom = if let Some(patch_info) = gi.patch.as_ref() {
                     let v = semver::Version::parse(&patch_info.version)?;
@@ -1112,6 +1466,18 @@ impl App {
                 tabs::settings::Message::DataFolderPicked,
             );
         }
+        // Same for the folder picker that adds a directory patch repo.
+        if matches!(msg, tabs::settings::Message::OpenPatchRepoFolderPicker) {
+            return iced::Task::perform(
+                async move {
+                    rfd::AsyncFileDialog::new()
+                        .pick_folder()
+                        .await
+                        .map(|h| h.path().to_path_buf())
+                },
+                tabs::settings::Message::PatchRepoFolderPicked,
+            );
+        }
         use tabs::settings::ConfigChange as C;
         let Some(change) = self.settings.update(msg) else {
             return iced::Task::none();
@@ -1122,7 +1488,26 @@ impl App {
             C::StreamerMode(b) => self.config.streamer_mode = b,
             C::MatchmakingEndpoint(s) => self.config.matchmaking_endpoint = s,
             C::RelayMode(m) => self.config.relay_mode = m,
-            C::PatchRepo(s) => self.config.patch_repo = s,
+            C::PatchRepo(s) => {
+                self.config.patch_repo = s;
+                self.patch_autoupdater.set_repos(self.config.patch_repos());
+            }
+            C::ExtraPatchRepo(i, s) => {
+                if let Some(repo) = self.config.extra_patch_repos.get_mut(i) {
+                    *repo = s;
+                }
+                self.patch_autoupdater.set_repos(self.config.patch_repos());
+            }
+            C::AddExtraPatchRepo(s) => {
+                self.config.extra_patch_repos.push(s);
+                self.patch_autoupdater.set_repos(self.config.patch_repos());
+            }
+            C::RemoveExtraPatchRepo(i) => {
+                if i < self.config.extra_patch_repos.len() {
+                    self.config.extra_patch_repos.remove(i);
+                }
+                self.patch_autoupdater.set_repos(self.config.patch_repos());
+            }
             C::DataPath(path) => {
                 self.config.data_path = path;
                 // Make sure the standard subfolders exist in the new location
@@ -1144,12 +1529,13 @@ impl App {
                 self.scanners.rescan(&self.config, &listings);
                 self.patch_autoupdater = crate::library::autoupdate::Autoupdater::new(
                     self.config.patches_path(),
-                    self.config.patch_repo.clone(),
+                    self.config.patch_repos(),
                     self.scanners.patches.clone(),
                 );
                 if self.config.enable_patch_autoupdate {
                     self.patch_autoupdater.start();
                 }
+                self.install_rule_check();
             }
             C::PatchAutoupdate(b) => {
                 self.config.enable_patch_autoupdate = b;
@@ -260,6 +260,7 @@ impl App {
                             phase: &self.netplay.phase,
                             lobby: &self.netplay.lobby,
                             ready: self.netplay.ready_view(),
+                            refusal: self.netplay.refusal(),
                             handoff_pending: self.netplay.handoff_pending(),
                             swap: &self.lobby_swap,
                             exit_snapshot: self.lobby_exit_snapshot.as_ref(),
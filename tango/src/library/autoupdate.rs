@@ -8,14 +8,18 @@
 //!
 //! Under the old patch format this re-hashed every file in the patch
 //! directory and downloaded whatever differed; now it re-fetches one
-//! small conditional GET, so it costs a 304 in the steady state.
+//! small conditional GET per repo, so it costs a 304 each in the steady
+//! state.
 
-use crate::library::patch::{fetch_index, scan, scan_roots, Scanner};
+use crate::library::patch::{fetch_indexes, scan, scan_roots, Repo, Scanner};
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 
 pub struct Autoupdater {
     patches_path: PathBuf,
-    patch_repo: String,
+    /// Shared with the running loop, which reads it afresh each round, so
+    /// editing the repo list in settings doesn't need a restart.
+    patch_repos: Arc<Mutex<Vec<Repo>>>,
     patches_scanner: Scanner,
     cancellation_token: Option<tokio_util::sync::CancellationToken>,
 }
@@ -25,15 +29,20 @@ impl Autoupdater {
     /// not to hammer the repo.
     const INTERVAL: std::time::Duration = std::time::Duration::from_secs(15 * 60);
 
-    pub fn new(patches_path: PathBuf, patch_repo: String, patches_scanner: Scanner) -> Self {
+    pub fn new(patches_path: PathBuf, patch_repos: Vec<Repo>, patches_scanner: Scanner) -> Self {
         Self {
             patches_path,
-            patch_repo,
+            patch_repos: Arc::new(Mutex::new(patch_repos)),
             patches_scanner,
             cancellation_token: None,
         }
     }
 
+    /// Poll these repos from the next round on.
+    pub fn set_repos(&self, patch_repos: Vec<Repo>) {
+        *self.patch_repos.lock().unwrap() = patch_repos;
+    }
+
     /// Start the background loop. Idempotent.
     pub fn start(&mut self) {
         if self.cancellation_token.is_some() {
@@ -43,25 +52,22 @@ impl Autoupdater {
         let token = tokio_util::sync::CancellationToken::new();
         let scanner = self.patches_scanner.clone();
         let patches_path = self.patches_path.clone();
-        let patch_repo = if self.patch_repo.is_empty() {
-            tango_library::config::DEFAULT_PATCH_REPO.to_string()
-        } else {
-            self.patch_repo.clone()
-        };
+        let patch_repos = self.patch_repos.clone();
         tokio::task::spawn({
             let token = token.clone();
             async move {
                 let storage = crate::library::storage();
                 let http = crate::library::http();
                 loop {
-                    match fetch_index(http, storage, &patch_repo, &patches_path).await {
-                        // Only a changed index is worth a rescan.
-                        Ok(true) => {
-                            let listing = storage.list(&scan_roots(&patches_path)).await;
-                            scanner.rescan(|| scan(storage, &patches_path, &listing).ok());
-                        }
-                        Ok(false) => {}
-                        Err(e) => log::error!("patch index autoupdate failed: {e:?}"),
+                    let repos = patch_repos.lock().unwrap().clone();
+                    let refreshed = fetch_indexes(http, storage, &repos, &patches_path).await;
+                    for (repo, e) in &refreshed.failed {
+                        log::error!("patch index autoupdate from {repo} failed: {e:?}");
+                    }
+                    // Only a changed index is worth a rescan.
+                    if refreshed.changed {
+                        let listing = storage.list(&scan_roots(&patches_path)).await;
+                        scanner.rescan(|| scan(storage, &patches_path, &repos, &listing).ok());
                     }
                     tokio::select! {
                         _ = tokio::time::sleep(Self::INTERVAL) => {}
@@ -20,7 +20,7 @@ pub mod autoupdate;
 pub mod replays;
 mod scanning;
 
-pub use tango_library::{bnlc, game, patch, rom, save, storage};
+pub use tango_library::{bnlc, game, patch, rom, save, save_history, storage};
 pub(crate) use scanning::Scanners;
 
 use tango_library::http::Http;
@@ -32,6 +32,15 @@ pub fn storage() -> &'static dyn Storage {
     &tango_library::storage::StdStorage
 }
 
+/// The time as [`save_history`] takes it: milliseconds since the Unix
+/// epoch.
+pub fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
 /// The app's HTTP client. One instance for the process so the index
 /// poll and any package downloads share a connection pool.
 pub fn http() -> &'static dyn Http {
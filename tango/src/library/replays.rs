@@ -14,7 +14,7 @@ pub use tango_library::replays::*;
 // re-exported so app callers keep one replays-module surface. Written
 // at match teardown for live matches and by
 // [`compute_and_cache_match_stats`] for everything else.
-pub use tango_session::stats::{load_match_stats, stats_path, write_match_stats};
+pub use tango_session::stats::{load_match_stats, load_net_health, stats_path, write_match_stats};
 
 use crate::library::rom::GameRef;
 
@@ -50,7 +50,7 @@ pub fn compute_and_cache_match_stats(
             .roms
             .read()
             .get(&entry)
-            .cloned()
+            .map(|rom| rom.bytes.clone())
             .ok_or_else(|| anyhow::anyhow!("rom for {}/{} not scanned", gi.rom_family, gi.rom_variant))?;
         let rom = if let Some(patch_info) = gi.patch.as_ref() {
             let v = semver::Version::parse(&patch_info.version)?;
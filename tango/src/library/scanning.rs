@@ -7,9 +7,10 @@
 //! shell merely to read indexed content.
 
 use crate::config;
+use tango_library::fingerprint::Fingerprints;
 use tango_library::storage::Listing;
 
-use super::{patch, replays, rom, save};
+use super::{bnlc, patch, replays, rom, save};
 
 #[derive(Clone)]
 pub(crate) struct Scanners {
@@ -17,6 +18,8 @@ pub(crate) struct Scanners {
     pub(crate) saves: save::Scanner,
     pub(crate) patches: patch::Scanner,
     pub(crate) replays: replays::Scanner,
+    /// The Legacy Collection's own save slots, offered for import.
+    pub(crate) bnlc_saves: bnlc::SaveScanner,
 }
 
 impl Scanners {
@@ -26,10 +29,11 @@ impl Scanners {
             saves: save::Scanner::new(),
             patches: patch::Scanner::new(),
             replays: replays::Scanner::new(),
+            bnlc_saves: bnlc::SaveScanner::new(),
         }
     }
 
-    /// What the four scans read, enumerated but not yet read: the cheap
+    /// What the scans read, enumerated but not yet read: the cheap
     /// half of a rescan, and the only asynchronous one. See
     /// [`Scanners::rescan`].
     pub(crate) async fn list(config: &config::Config) -> Listings {
@@ -39,10 +43,11 @@ impl Scanners {
             saves: storage.list(&[config.saves_path()]).await,
             patches: storage.list(&patch::scan_roots(&config.patches_path())).await,
             replays: storage.list(&[config.replays_path()]).await,
+            bnlc_saves: storage.list(&bnlc::save_roots()).await,
         }
     }
 
-    /// Rescan all four collections from an already-gathered [`Listings`].
+    /// Rescan every collection from an already-gathered [`Listings`].
     /// Each scanner is gated on its listing, so automatic triggers skip the
     /// full read-and-parse unless files actually changed.
     pub(crate) fn rescan(&self, config: &config::Config, listings: &Listings) {
@@ -56,15 +61,30 @@ impl Scanners {
     pub(crate) fn rescan_library(&self, config: &config::Config, listings: &Listings) {
         let storage = super::storage();
         let patches_path = config.patches_path();
+        let patch_repos = config.patch_repos();
+        // Only read when a scan actually runs: an unchanged listing skips
+        // both.
+        let cache_path = config.cache_path();
+        let mut fingerprints: Option<Fingerprints> = None;
         let start = std::time::Instant::now();
-        self.roms
-            .rescan_if_changed(&listings.roms, || Some(rom::scan_roms(storage, &listings.roms)));
+        self.roms.rescan_if_changed(&listings.roms, || {
+            let fingerprints = fingerprints.get_or_insert_with(|| Fingerprints::load(storage, &cache_path));
+            Some(rom::scan_roms(storage, &listings.roms, fingerprints))
+        });
         let roms = start.elapsed();
-        self.saves
-            .rescan_if_changed(&listings.saves, || Some(save::scan_saves(storage, &listings.saves)));
+        self.saves.rescan_if_changed(&listings.saves, || {
+            let fingerprints = fingerprints.get_or_insert_with(|| Fingerprints::load(storage, &cache_path));
+            Some(save::scan_saves(storage, &listings.saves, fingerprints))
+        });
+        if let Some(mut fingerprints) = fingerprints {
+            fingerprints.store(storage);
+        }
+        self.bnlc_saves.rescan_if_changed(&listings.bnlc_saves, || {
+            Some(bnlc::scan_save_slots(storage, &listings.bnlc_saves))
+        });
         let saves = start.elapsed() - roms;
         self.patches.rescan_if_changed(&listings.patches, || {
-            patch::scan(storage, &patches_path, &listings.patches).ok()
+            patch::scan(storage, &patches_path, &patch_repos, &listings.patches).ok()
         });
         let patches = start.elapsed() - roms - saves;
         log::debug!("rescan: roms {roms:.1?}, saves {saves:.1?}, patches {patches:.1?}");
@@ -86,4 +106,5 @@ pub(crate) struct Listings {
     pub(crate) saves: Listing,
     pub(crate) patches: Listing,
     pub(crate) replays: Listing,
+    pub(crate) bnlc_saves: Listing,
 }
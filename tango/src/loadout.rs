@@ -248,6 +248,7 @@ impl Loadout {
                 }
             }),
             blind_setup: lobby.blind_setup,
+            rule_set: lobby.rule_set.clone(),
         }
     }
 }
@@ -304,6 +305,10 @@ pub struct SaveOption {
     /// `false` when `game`'s ROM isn't owned — the row greys out and
     /// can't be selected.
     pub available: bool,
+    /// Set for a Legacy Collection save slot rather than a file in the
+    /// saves dir (`path` is then the collection's file). Picking one
+    /// imports it instead of selecting it.
+    pub bnlc: Option<crate::library::bnlc::SaveSlot>,
 }
 
 // Identity is the path: a save is the same option regardless of which
@@ -355,6 +360,7 @@ impl SaveOption {
             display,
             game,
             available,
+            bnlc: None,
         }
     }
 }
@@ -481,6 +487,28 @@ pub fn save_options(
             av.len().cmp(&bv.len())
         })
     });
+    // The Legacy Collection's slots close the list, after every save
+    // already in the library: picking one makes a copy there.
+    if let Some(family) = loadout.family {
+        let multi_variant = game::games_in_family(family).count() > 1;
+        for slot in scanners.bnlc_saves.read().iter() {
+            if slot.game.family_and_variant().0 != family {
+                continue;
+            }
+            let label = t!(lang, "save-bnlc-slot", slot = slot.label());
+            let display = match multi_variant.then(|| game::variant_short_name(lang, slot.game)) {
+                Some(variant) => format!("{variant} \u{2013} {label}"),
+                None => label,
+            };
+            save_options.push(SaveOption {
+                path: slot.path.clone(),
+                display,
+                game: slot.game,
+                available: true,
+                bnlc: Some(slot.clone()),
+            });
+        }
+    }
     save_options
 }
 
@@ -3,20 +3,26 @@
 //! The state machine — connection choreography, settings exchange, ready
 //! handshake, match handoff — lives in the [`tango_lobby`] crate, which
 //! knows nothing about iced. This module re-exports its surface (so
-//! `crate::netplay::*` keeps resolving) and supplies the three pieces
-//! that are genuinely iced-shaped:
+//! `crate::netplay::*` keeps resolving) and supplies the pieces that
+//! are genuinely iced- or host-shaped:
 //!
 //! * [`connect`] / [`connect_direct`]: run a bring-up as an `iced::Task`.
 //! * [`subscription`]: bridge the connection's progress channel into the
 //!   update loop.
 //! * [`Delivery`]: iced routes messages by value and demands `Clone`;
 //!   what comes down that channel owns a live data channel and can't be.
+//! * [`RuleCheck`]: reads a revealed save against a tournament rule set,
+//!   which takes the ROMs and patches only the host has.
 
 pub use tango_lobby::{
     compat, randomcode, ConnectionKind, DirectRole, Error, Event, Incoming, LinkIdent, LobbyState, MatchmakingParams,
     Phase, PreMatchData, ReadyView, State,
 };
 
+mod rules;
+
+pub(crate) use rules::RuleCheck;
+
 use std::sync::Arc;
 
 /// Start a matchmaking attempt and run it to wherever it lands. The task
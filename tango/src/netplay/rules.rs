@@ -0,0 +1,67 @@
+//! The lobby's save reader for tournament rule checks: turns a revealed
+//! save back into the build it fights with, the same way the match
+//! itself will load it — the scanned ROM, the side's patch applied —
+//! and hands it to the game's save editor to check.
+
+use crate::library::{patch, Scanners};
+use tango_gamesupport::{BuildRules, RuleBreach};
+use tango_net_protocol::control::GameInfo;
+
+pub(crate) struct RuleCheck {
+    scanners: Scanners,
+    patches_path: std::path::PathBuf,
+}
+
+impl RuleCheck {
+    /// Patches are read from `patches_path`; a data-folder move
+    /// installs a fresh checker.
+    pub(crate) fn new(scanners: Scanners, patches_path: std::path::PathBuf) -> Self {
+        Self { scanners, patches_path }
+    }
+
+    fn check(&self, game_info: &GameInfo, save_data: &[u8], rules: &BuildRules) -> anyhow::Result<Vec<RuleBreach>> {
+        let (family, variant) = &game_info.family_and_variant;
+        let game = crate::library::game::find_by_family_and_variant(family, *variant)
+            .ok_or_else(|| anyhow::anyhow!("unknown rom {family}/{variant}"))?;
+        let rom = self
+            .scanners
+            .roms
+            .read()
+            .get(&game)
+            .map(|rom| rom.bytes.clone())
+            .ok_or_else(|| anyhow::anyhow!("rom not scanned"))?;
+        let (rom, applied_patch) = match game_info.patch.as_ref() {
+            Some(p) => {
+                let rom = patch::apply_patch(
+                    crate::library::storage(),
+                    &rom,
+                    game,
+                    &self.patches_path,
+                    &p.name,
+                    &p.version,
+                )?;
+                let applied_patch = self.scanners.patches.read().version(&p.name, &p.version).map(|meta| {
+                    crate::selection::AppliedPatch {
+                        name: p.name.clone(),
+                        version: p.version.clone(),
+                        rom_overrides: meta.rom_overrides_for(game),
+                    }
+                });
+                (rom, applied_patch)
+            }
+            None => (rom, None),
+        };
+        let save = game
+            .parse_save(save_data)
+            .map_err(|e| anyhow::anyhow!("parse save: {e:?}"))?;
+        let prepared =
+            crate::selection::prepare_from_patched_rom(game, rom, std::path::PathBuf::new(), save, applied_patch);
+        Ok(game.family.save_editor.check_rules(&prepared, rules))
+    }
+}
+
+impl tango_lobby::compat::RuleCheck for RuleCheck {
+    fn check(&self, game_info: &GameInfo, save_data: &[u8], rules: &BuildRules) -> Result<Vec<RuleBreach>, String> {
+        RuleCheck::check(self, game_info, save_data, rules).map_err(|e| format!("{e:#}"))
+    }
+}
@@ -102,7 +102,7 @@ pub fn for_replay_local(
         .roms
         .read()
         .get(&game)
-        .cloned()
+        .map(|rom| rom.bytes.clone())
         .ok_or_else(|| anyhow::anyhow!("rom for {}/{} not scanned", gi.rom_family, gi.rom_variant))?;
 
     let save = game.parse_save(&replay.srams[replay.local_player_index as usize])?;
@@ -312,6 +312,12 @@ pub struct MatchResults {
     /// The match-wide HP scale the round traces were normalized against —
     /// the chart's hover readout multiplies back through it.
     pub max_hp: f32,
+    /// The sources that dealt the most damage per side (`[you,
+    /// opponent]`), names resolved at capture time like the rounds'
+    /// chip marks.
+    pub top_damage: [Vec<crate::ui::widgets::DamageLine>; 2],
+    /// The match's netcode health, cut at the same rounds as `rounds`.
+    pub net: Vec<crate::ui::widgets::CookedNetRound>,
     /// When the results screen was put up — the zero point of its reveal
     /// choreography (per-round HP sweeps, then the verdict stamp). One-shot:
     /// returning from a replay watch finds it long elapsed, so the card sits
@@ -319,6 +325,9 @@ pub struct MatchResults {
     pub revealed_at: iced::time::Instant,
 }
 
+/// How many sources each side's "top damage" list shows.
+pub const TOP_DAMAGE_LINES: usize = 3;
+
 /// One round on the results card: the outcome plus the cooked series for
 /// the round graph. `trace` points are `(x, you, opponent)`, all normalized —
 /// x over the round's sampled ticks, HP against the match-wide maximum so
@@ -359,6 +368,8 @@ impl MatchResults {
         // ended and its replay is still flushing — so the cards run to
         // the last reading.
         let (cooked, max_hp) = crate::ui::widgets::cook_hp_rounds(&stats, loadeds, None);
+        let top_damage = crate::ui::widgets::cook_top_damage(&stats, loadeds, TOP_DAMAGE_LINES);
+        let net = crate::ui::widgets::cook_net_rounds(&stats, &pvp.net_health_snapshot(), None);
         let rounds = cooked
             .into_iter()
             // Every round the match simulated is on the card, decided or
@@ -379,6 +390,8 @@ impl MatchResults {
             duration: pvp.match_duration(),
             replay_path: pvp.replay_path.clone(),
             max_hp,
+            top_damage,
+            net,
             revealed_at: iced::time::Instant::now(),
         };
         anim::kick(view::results::reveal_duration(&results));
@@ -421,11 +434,15 @@ struct SaveBackup {
     /// savedata can be shorter than its file — BN1's SRAM is 32K inside
     /// a 64K `.sav` — and the memory-mapped path this replaced only
     /// ever wrote the leading bytes, so the tail is preserved rather
-    /// than truncated away.
-    original: Vec<u8>,
+    /// than truncated away, as is any emulator's wrapper around it.
+    original: crate::library::save::Unwrapped,
     /// The file's contents as last written, so an unchanged save costs
     /// nothing.
     written: Vec<u8>,
+    /// Where the file the session booted from is archived before the
+    /// first write replaces it. Taken by that write: the autosaves after
+    /// it are one play session, not versions of their own.
+    history: Option<crate::library::save_history::SaveHistory>,
     next_check: std::time::Instant,
 }
 
@@ -435,11 +452,16 @@ impl SaveBackup {
     /// crash costs a few seconds of play rather than the session.
     const INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
 
-    fn new(path: std::path::PathBuf, initial: Vec<u8>) -> Self {
+    fn new(
+        path: std::path::PathBuf,
+        initial: crate::library::save::Unwrapped,
+        history: crate::library::save_history::SaveHistory,
+    ) -> Self {
         Self {
             path,
-            written: initial.clone(),
+            written: initial.rewrap(&initial.image),
             original: initial,
+            history: Some(history),
             next_check: std::time::Instant::now() + Self::INTERVAL,
         }
     }
@@ -449,15 +471,17 @@ impl SaveBackup {
     /// down mid-battle, and the next check tries again.
     fn store(&mut self, image: Option<Vec<u8>>) {
         let Some(image) = image else { return };
-        let mut file = self.original.clone();
-        if image.len() >= file.len() {
-            file = image;
-        } else {
-            file[..image.len()].copy_from_slice(&image);
-        }
+        let file = self.original.rewrap(&image);
         if file == self.written {
             return;
         }
+        if let Some(history) = self.history.take() {
+            // Play on regardless: losing the session's progress would be
+            // worse than losing the old version.
+            if let Err(e) = history.archive(crate::library::storage(), &self.path, crate::library::now_ms()) {
+                log::error!("archiving {}: {e}", self.path.display());
+            }
+        }
         if let Err(e) = std::fs::write(&self.path, &file) {
             log::error!("writing {}: {e}", self.path.display());
             return;
@@ -931,8 +955,13 @@ impl State {
     /// single-player session's savedata. Called by the host right after
     /// it installs one; other session kinds own their own persistence
     /// (PvP writes replays, replay playback writes nothing).
-    pub fn attach_save_backup(&mut self, path: std::path::PathBuf, initial: Vec<u8>) {
-        self.singleplayer_save = Some(SaveBackup::new(path, initial));
+    pub fn attach_save_backup(
+        &mut self,
+        path: std::path::PathBuf,
+        initial: crate::library::save::Unwrapped,
+        history: crate::library::save_history::SaveHistory,
+    ) {
+        self.singleplayer_save = Some(SaveBackup::new(path, initial, history));
     }
 
     /// Take ownership of the threads driving the session just installed.
@@ -1384,7 +1413,7 @@ pub fn build_playback(
             .roms
             .read()
             .get(&entry)
-            .cloned()
+            .map(|rom| rom.bytes.clone())
             .ok_or_else(|| anyhow::anyhow!("rom for {}/{} not scanned", gi.rom_family, gi.rom_variant))?;
         let rom = if let Some(patch_info) = gi.patch.as_ref() {
             let v = semver::Version::parse(&patch_info.version)?;
@@ -1484,7 +1513,7 @@ pub async fn spawn_pvp(
         .roms
         .read()
         .get(&local_game)
-        .cloned()
+        .map(|rom| rom.bytes.clone())
         .ok_or_else(|| anyhow::anyhow!("local rom not scanned"))?;
     let local_rom_bytes = if let Some((name, version)) = local_patch.as_ref() {
         patch::apply_patch(
@@ -1518,7 +1547,7 @@ pub async fn spawn_pvp(
         .roms
         .read()
         .get(&remote_game)
-        .cloned()
+        .map(|rom| rom.bytes.clone())
         .ok_or_else(|| anyhow::anyhow!("remote rom not scanned"))?;
     let remote_rom_bytes = if let Some(p) = remote_gi.patch.as_ref() {
         patch::apply_patch(
@@ -1753,7 +1782,7 @@ pub fn spawn_singleplayer(
 ) -> anyhow::Result<(
     singleplayer::SinglePlayerSession,
     Option<audio::Binding>,
-    Vec<u8>,
+    crate::library::save::Unwrapped,
     std::thread::JoinHandle<()>,
 )> {
     let game = game::from_gamedb_entry(loaded.game)
@@ -1765,7 +1794,7 @@ pub fn spawn_singleplayer(
         .roms
         .read()
         .get(&loaded.game)
-        .cloned()
+        .map(|rom| rom.bytes.clone())
         .ok_or_else(|| anyhow::anyhow!("rom not in scanner cache"))?;
     let rom_bytes = if let Some(p) = loaded.patch.as_ref() {
         patch::apply_patch(
@@ -1784,11 +1813,13 @@ pub fn spawn_singleplayer(
     // through —
     // so the bytes go in here and come back out through
     // [`SaveBackup`], which is what actually keeps the file current.
-    let save = std::fs::read(&loaded.save_path)?;
+    // The core gets the bare image; an emulator's footer or trailer is
+    // put back on the way out.
+    let save = crate::library::save::read_save(crate::library::storage(), &loaded.save_path)?;
     let (session, driver, audio) = singleplayer::SinglePlayerSession::new(
         game,
         std::sync::Arc::new(rom_bytes),
-        Some(save.clone()),
+        Some(save.image.clone()),
         // Leave the cart clock on the real one, as it has always been.
         None,
         game.pvp.frame_timing().fps() as f32,
@@ -1822,7 +1853,7 @@ pub fn spawn_training(
         .roms
         .read()
         .get(&loaded.game)
-        .cloned()
+        .map(|rom| rom.bytes.clone())
         .ok_or_else(|| anyhow::anyhow!("rom not in scanner cache"))?;
     let rom_bytes = if let Some(p) = loaded.patch.as_ref() {
         patch::apply_patch(
@@ -8,10 +8,15 @@
 //! popover, which is gated on a live latency reading and retires
 //! itself the moment the remote drops. Split out of the session view
 //! so the emulator/drawer/overlay layout in `mod.rs` isn't sharing a
-//! file with ~500 lines of charting + tone math.
+//! file with the charting. The tones, spans and value formats live in
+//! `widgets`, which the match's recorded health timeline draws with too.
 
 use super::super::*;
 use super::{Message, PvpSession};
+use crate::ui::widgets::{
+    depth_point, fmt_depth, fmt_lead, fmt_ping, fmt_skew, lead_point, ping_point, skew_point, stat_tone_color,
+    tone_for_depth, tone_for_lead, tone_for_ping, tone_for_skew, tone_for_tps, tps_point, StatTone,
+};
 // Explicit so these win over iced's prelude `column!`/`row!` macros (see mod.rs).
 use sweeten::widget::{column, row};
 
@@ -77,13 +82,6 @@ fn frame_delay_control<'a>(lang: &'a LanguageIdentifier, pvp: &'a PvpSession) ->
 // same width: a turtle-icon heading over a lobby-style slider row.
 const PANEL_W: f32 = 228.0;
 const SPARK_H: f32 = 24.0;
-// Each metric's full-height value span (sample saturates into it). Chosen to
-// line up with the tone thresholds so a point's height roughly tracks its color.
-const TPS_SPAN: f32 = 8.0; // fps below target = floor of the chart
-const SKEW_SPAN: i32 = 8; // ± about parity; 0 sits mid-height
-const LEAD_SPAN: i32 = 24; // ± about zero; saturates well before the overflow bail
-const DEPTH_SPAN: u32 = 8;
-const PING_SPAN: u128 = 200;
 
 /// A compact per-metric history chart for the match-settings panel. Each
 /// retained sample is `(height fraction in 0..=1, tone)`, plotted left→right
@@ -324,12 +322,7 @@ fn match_settings_content<'a>(
         true,
         None,
         history,
-        |s| {
-            (s.fps_target > 0.0).then(|| {
-                let yf = (s.tps - (s.fps_target - TPS_SPAN)) / TPS_SPAN;
-                (yf.clamp(0.0, 1.0), tone_for_tps(s.tps, s.fps_target))
-            })
-        },
+        |s| tps_point(s.tps, s.fps_target),
         |s| (s.fps_target > 0.0).then(|| tps_value(s.tps, s.fps_target, tone_for_tps(s.tps, s.fps_target))),
     );
 
@@ -339,12 +332,7 @@ fn match_settings_content<'a>(
         false,
         Some(0.5),
         history,
-        |s| {
-            s.round.map(|(skew, _, _)| {
-                let yf = (skew.clamp(-SKEW_SPAN, SKEW_SPAN) as f32 + SKEW_SPAN as f32) / (2.0 * SKEW_SPAN as f32);
-                (yf, tone_for_skew(skew))
-            })
-        },
+        |s| s.round.map(|(skew, _, _)| skew_point(skew)),
         |s| {
             s.round
                 .map(|(skew, _, _)| value_text(fmt_skew(skew), Some(tone_for_skew(skew))))
@@ -357,12 +345,7 @@ fn match_settings_content<'a>(
         false,
         Some(0.5),
         history,
-        |s| {
-            s.round.map(|(_, _, lead)| {
-                let yf = (lead.clamp(-LEAD_SPAN, LEAD_SPAN) as f32 + LEAD_SPAN as f32) / (2.0 * LEAD_SPAN as f32);
-                (yf, tone_for_lead(lead))
-            })
-        },
+        |s| s.round.map(|(_, _, lead)| lead_point(lead)),
         |s| {
             s.round
                 .map(|(_, _, lead)| value_text(fmt_lead(lead), Some(tone_for_lead(lead))))
@@ -375,10 +358,7 @@ fn match_settings_content<'a>(
         true,
         Some(0.0),
         history,
-        |s| {
-            s.round
-                .map(|(_, depth, _)| (depth.min(DEPTH_SPAN) as f32 / DEPTH_SPAN as f32, tone_for_depth(depth)))
-        },
+        |s| s.round.map(|(_, depth, _)| depth_point(depth)),
         |s| {
             s.round
                 .map(|(_, depth, _)| value_text(fmt_depth(depth), Some(tone_for_depth(depth))))
@@ -391,12 +371,7 @@ fn match_settings_content<'a>(
         true,
         Some(0.0),
         history,
-        |s| {
-            Some((
-                s.ping_ms.min(PING_SPAN) as f32 / PING_SPAN as f32,
-                tone_for_ping(s.ping_ms),
-            ))
-        },
+        |s| Some(ping_point(s.ping_ms)),
         |s| Some(value_text(fmt_ping(s.ping_ms), Some(tone_for_ping(s.ping_ms)))),
     );
 
@@ -427,115 +402,6 @@ fn match_settings_content<'a>(
     .into()
 }
 
-/// Semantic tone for a PvP telemetry value. The icon always rides
-/// muted; only the value picks up `Good`/`Warn`/`Bad` so color reads
-/// as "this number means something is healthy / borderline / wrong"
-/// rather than mere decoration.
-#[derive(Clone, Copy)]
-enum StatTone {
-    Muted,
-    Good,
-    Warn,
-    Bad,
-}
-
-fn stat_tone_color(theme: &iced::Theme, tone: StatTone) -> iced::Color {
-    match tone {
-        StatTone::Muted => widgets::muted_color(theme),
-        StatTone::Good => theme.extended_palette().success.strong.color,
-        // Amber lives outside iced's default palette, so hardcode a
-        // tone that reads on both the dark navy and light parchment
-        // HUD plates.
-        StatTone::Warn => iced::Color::from_rgb(0.92, 0.67, 0.18),
-        StatTone::Bad => theme.extended_palette().danger.strong.color,
-    }
-}
-
-// Health tone per metric. Shared by the instrument-panel cells and the
-// popover sparklines so the value readout and the chart points always agree
-// on green/amber/red.
-
-/// TPS vs the live fps target: green at/near rate, amber as it dips, red when
-/// it falls well behind (visible netplay stutter). Muted before a target exists.
-fn tone_for_tps(tps: f32, fps_target: f32) -> StatTone {
-    if fps_target <= 0.0 {
-        StatTone::Muted
-    } else if tps >= fps_target - 1.0 {
-        StatTone::Good
-    } else if tps >= fps_target - 5.0 {
-        StatTone::Warn
-    } else {
-        StatTone::Bad
-    }
-}
-
-/// Clock skew: green near parity, amber drifting, red far out, by `|skew|`.
-fn tone_for_skew(skew: i32) -> StatTone {
-    match skew.unsigned_abs() {
-        0..=3 => StatTone::Good,
-        4..=7 => StatTone::Warn,
-        _ => StatTone::Bad,
-    }
-}
-
-/// Local lead by `|lead|`: green at a healthy steady lead, amber as it climbs,
-/// red when it runs far from zero in either direction (the remote is lagging and
-/// we're heading toward the bail, or we've fallen behind it).
-fn tone_for_lead(lead: i32) -> StatTone {
-    match lead.unsigned_abs() {
-        0..=8 => StatTone::Good,
-        9..=16 => StatTone::Warn,
-        _ => StatTone::Bad,
-    }
-}
-
-/// Rollback depth: green shallow, amber climbing, red when speculation runs deep.
-fn tone_for_depth(depth: u32) -> StatTone {
-    match depth {
-        0..=2 => StatTone::Good,
-        3..=5 => StatTone::Warn,
-        _ => StatTone::Bad,
-    }
-}
-
-/// Latency band: green under 80 ms, amber under 140 ms, red beyond.
-fn tone_for_ping(ping_ms: u128) -> StatTone {
-    if ping_ms < 80 {
-        StatTone::Good
-    } else if ping_ms < 140 {
-        StatTone::Warn
-    } else {
-        StatTone::Bad
-    }
-}
-
-// Value formatting for the telemetry readouts.
-
-/// Signed skew in a 3-wide field; bare `0` at parity reads calmer than `+0`.
-fn fmt_skew(skew: i32) -> String {
-    if skew == 0 {
-        "0".to_string()
-    } else {
-        format!("{skew:+}")
-    }
-}
-/// Signed local lead in ticks; bare `0` at zero reads calmer than `+0`.
-fn fmt_lead(lead: i32) -> String {
-    if lead == 0 {
-        "0".to_string()
-    } else {
-        format!("{lead:+}")
-    }
-}
-/// Rollback depth.
-fn fmt_depth(depth: u32) -> String {
-    format!("{depth}")
-}
-/// Latency in ms.
-fn fmt_ping(ping_ms: u128) -> String {
-    format!("{ping_ms} ms")
-}
-
 /// Sync-skew band → signal-bars icon. Full bars at parity,
 /// dropping as the two sides drift apart — same bands as
 /// [`tone_for_skew`], so the bars and the tint always agree.
@@ -55,6 +55,8 @@ const DRAWS_SLOT_H: f32 = 16.0;
 /// Trace field plus the widget's two per-side chip-event lanes (always
 /// present), matching the replays tab's proportions.
 const GRAPH_H: f32 = 72.0;
+/// The netcode health lanes under it — the replays tab's height too.
+const NET_GRAPH_H: f32 = 72.0;
 
 /// How long [`MatchResults::capture`] must keep redraws flowing to play the
 /// whole reveal. Zero on a disconnect — that card comes up at rest.
@@ -248,6 +250,23 @@ pub fn results_view<'a>(lang: &'a LanguageIdentifier, results: &'a MatchResults)
                 GRAPH_H,
                 None,
             ));
+        // The netcode lanes, flush under the graph on the same rounds and
+        // revealed by the same sweep.
+        if results.net.iter().any(|r| !r.points.is_empty()) {
+            body = body.push(widgets::net_health_graph(
+                &results.net,
+                widgets::net_health_labels(lang),
+                sweep,
+                NET_GRAPH_H,
+            ));
+        }
+        if let Some(damage) = widgets::top_damage(
+            lang,
+            &results.top_damage,
+            [t!(lang, "session-results-you"), results.remote_nickname.clone()],
+        ) {
+            body = body.push(iced::widget::Space::new().height(8)).push(damage);
+        }
     } else {
         // Static fallback: the pre-trace layout — full score up front, plus a
         // marks row when there was more than one round to sequence.
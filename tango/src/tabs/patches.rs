@@ -46,6 +46,38 @@ pub enum Message {
     SearchChanged(String),
     /// Which slice of the catalog the list shows.
     FilterChanged(Filter),
+    /// Compare the selected version with another one.
+    CompareWith(semver::Version),
+    CloseComparison,
+    /// A comparison of the selected patch from one version to another
+    /// came back.
+    CompareFinished(ComparisonKey, Result<Compared, CompareError>),
+}
+
+/// A patch and the two versions compared, older first.
+pub type ComparisonKey = (String, semver::Version, semver::Version);
+
+/// Two versions of a patch applied to the player's ROM, and what changed
+/// in the game's tables between them.
+#[derive(Debug, Clone)]
+pub struct Compared {
+    /// The game the versions were applied to.
+    pub game: crate::library::rom::GameRef,
+    /// Whether the folder checked is the selected save's, rather than
+    /// the game's default template.
+    pub own_save: bool,
+    pub diff: tango_gamesupport::OpaquePatchDiff,
+    /// The newer version's changelog (see [`crate::library::patch::changelog`]).
+    pub changelog: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum CompareError {
+    /// A version is neither installed nor offered by any repo any more.
+    Unavailable,
+    /// No game both versions patch has a ROM in the library.
+    NoRom,
+    Failed(String),
 }
 
 /// Which patches the list shows. The catalog holds both what's on disk
@@ -101,6 +133,12 @@ pub struct PatchesState {
     /// Entrance restarted when a different patch is selected —
     /// the detail panel slides in from the right.
     pub detail_enter: crate::ui::anim::Enter,
+    /// The version the selected one is being compared with. While set,
+    /// the comparison takes the README's place.
+    pub compare: Option<semver::Version>,
+    /// What the comparison found, once it's back.
+    comparison: Option<Result<Compared, CompareError>>,
+    changelog_items: Vec<iced::widget::markdown::Item>,
 }
 
 /// Side-effects bubble-up. See [`crate::tabs::replays::Effect`]
@@ -123,6 +161,8 @@ pub enum Effect {
     Uninstall(VersionKey),
     /// Fetch the README the repo published for a version we don't have.
     FetchReadme(VersionKey),
+    /// Apply both versions to the player's ROM and diff the tables.
+    Compare(ComparisonKey),
     /// Something changed on disk — App should re-scan + refresh loaded.
     Rescan,
     /// A download failed — anything queued behind it should give up.
@@ -145,10 +185,12 @@ impl PatchesState {
                 }
                 self.selected = Some(name);
                 self.version = newest;
+                self.close_comparison();
                 self.refresh_readme(patches)
             }
             Message::VersionSelected(v) => {
                 self.version = Some(v);
+                self.close_comparison();
                 self.refresh_readme(patches)
             }
             Message::RevealPackage(p) => Some(Effect::RevealPath(p)),
@@ -220,9 +262,54 @@ impl PatchesState {
                 self.filter = f;
                 None
             }
+            Message::CompareWith(other) => {
+                let key = self.comparison_key_with(&other)?;
+                self.compare = Some(other);
+                self.comparison = None;
+                self.changelog_items.clear();
+                Some(Effect::Compare(key))
+            }
+            Message::CloseComparison => {
+                self.close_comparison();
+                None
+            }
+            Message::CompareFinished(key, result) => {
+                // The user may have moved on while it ran.
+                if self.compare.as_ref().and_then(|other| self.comparison_key_with(other)) != Some(key) {
+                    return None;
+                }
+                self.changelog_items = result
+                    .as_ref()
+                    .ok()
+                    .and_then(|c| c.changelog.as_deref())
+                    .map(|md| iced::widget::markdown::parse(md).collect())
+                    .unwrap_or_default();
+                self.comparison = Some(result);
+                None
+            }
         }
     }
 
+    /// The selected version compared with `other`, older first.
+    fn comparison_key_with(&self, other: &semver::Version) -> Option<ComparisonKey> {
+        let (name, version) = (self.selected.clone()?, self.version.clone()?);
+        if version == *other {
+            return None;
+        }
+        let (from, to) = if *other < version {
+            (other.clone(), version)
+        } else {
+            (version, other.clone())
+        };
+        Some((name, from, to))
+    }
+
+    fn close_comparison(&mut self) {
+        self.compare = None;
+        self.comparison = None;
+        self.changelog_items.clear();
+    }
+
     /// Rebuild the parsed-markdown cache for the currently selected
     /// patch+version. No-op if the cache already matches. Returns a
     /// fetch effect when the version isn't installed and we haven't
@@ -247,8 +334,8 @@ impl PatchesState {
             None => match self.remote_readmes.get(&key) {
                 Some(cached) => cached.clone(),
                 None => {
-                    // Only worth asking if the repo says it published one.
-                    if patches.entry(&key.0, &key.1).and_then(|e| e.readme.as_ref()).is_some() {
+                    // Only worth asking if a repo says it published one.
+                    if patches.offers(&key.0, &key.1).iter().any(|o| o.readme.is_some()) {
                         effect = Some(Effect::FetchReadme(key.clone()));
                     }
                     None
@@ -557,6 +644,20 @@ impl PatchesState {
             details = details.push(detail_row(t!(lang, "patches-details-source"), source));
         }
         details = details.push(detail_row(t!(lang, "patches-details-games"), supported_games_str));
+        // Which repos offer the version, and which ship something else
+        // under the same name and version — the one listed first wins.
+        if let Some(version) = &selected_version {
+            let offers = patches.offers(name, version);
+            if !offers.is_empty() {
+                let repos = offers.iter().map(|o| o.repo.to_string()).collect::<Vec<_>>();
+                details = details.push(detail_row(t!(lang, "patches-details-repos"), repos.join(", ")));
+            }
+            let conflicts = patches.conflicts(name, version);
+            if !conflicts.is_empty() {
+                let repos = conflicts.iter().map(|r| r.to_string()).collect::<Vec<_>>();
+                details = details.push(detail_row(t!(lang, "patches-details-conflicts"), repos.join(", ")));
+            }
+        }
         if let Some(netplay) = info.and_then(|i| i.netplay()) {
             details = details.push(detail_row(
                 t!(lang, "patches-netplay-compatibility"),
@@ -564,12 +665,22 @@ impl PatchesState {
             ));
         }
 
-        let actions = self.action_row(lang, info, key, downloads);
+        // Any version compares, installed or not: one that isn't is
+        // fetched for the comparison and not kept.
+        let others: Vec<semver::Version> = patches
+            .versions(name)
+            .into_keys()
+            .rev()
+            .filter(|v| Some(v) != selected_version.as_ref())
+            .collect();
+        let actions = self.action_row(lang, info, key, others, downloads);
 
         // README is flush with the pane edges (no outer padding) so the
         // scrollbar hugs the pane; the markdown body has its own
         // PANE_PADDING inset so the prose doesn't slam the pane wall.
-        let readme_body: Element<'_, Message> = if self.readme_items.is_empty() {
+        let readme_body: Element<'_, Message> = if let Some(other) = &self.compare {
+            self.comparison_body(lang, config, selected_version.as_ref(), other)
+        } else if self.readme_items.is_empty() {
             text(t!(lang, "patches-readme-placeholder")).size(TEXT_CAPTION).into()
         } else {
             let theme = crate::ui::theme::theme_for(config);
@@ -606,6 +717,8 @@ impl PatchesState {
         lang: &'a LanguageIdentifier,
         info: Option<&crate::library::patch::VersionInfo<'_>>,
         key: Option<VersionKey>,
+        // Versions the selected one can be compared with.
+        others: Vec<semver::Version>,
         downloads: &Downloads,
     ) -> Element<'a, Message> {
         let Some((info, key)) = info.zip(key) else {
@@ -675,6 +788,12 @@ impl PatchesState {
                 STANDARD_PADDING,
             ));
         }
+        if !others.is_empty() {
+            controls = controls.push(
+                widgets::picker(others, self.compare.clone(), Message::CompareWith)
+                    .placeholder(t!(lang, "patches-compare-with")),
+            );
+        }
 
         if let Some(size) = info.size() {
             controls = controls.push(
@@ -689,6 +808,105 @@ impl PatchesState {
             .align_y(Alignment::Center)
             .into()
     }
+
+    /// What replaces the README while two versions are compared: what
+    /// changed for the player's own folder first, then every changed
+    /// row, then the newer version's changelog.
+    fn comparison_body<'a>(
+        &'a self,
+        lang: &'a LanguageIdentifier,
+        config: &crate::config::Config,
+        version: Option<&semver::Version>,
+        other: &semver::Version,
+    ) -> Element<'a, Message> {
+        let heading = |s: String| text(s).size(TEXT_BODY).style(widgets::muted_text_style);
+        let lines = |lines: Vec<String>| {
+            column(lines.into_iter().map(|l| Element::from(text(l).size(TEXT_CAPTION)))).spacing(2)
+        };
+
+        let (from, to) = match version {
+            Some(v) if v < other => (v, other),
+            Some(v) => (other, v),
+            None => (other, other),
+        };
+        let title = match &self.comparison {
+            Some(Ok(compared)) => t!(
+                lang,
+                "patches-compare-title",
+                from = from.to_string(),
+                to = to.to_string(),
+                game = game::display_name(lang, compared.game)
+            ),
+            _ => format!("{from} → {to}"),
+        };
+        let header = row![
+            container(text(title).size(TEXT_TITLE)).width(Fill),
+            widgets::icon_button(
+                Icon::X,
+                t!(lang, "patches-compare-close"),
+                Message::CloseComparison,
+                STANDARD_PADDING
+            ),
+        ]
+        .spacing(8)
+        .align_y(Alignment::Center);
+        let mut body = column![header].spacing(12);
+
+        let compared = match &self.comparison {
+            None => return body.push(text(t!(lang, "patches-comparing")).size(TEXT_CAPTION)).into(),
+            Some(Err(e)) => {
+                let message = match e {
+                    CompareError::Unavailable => t!(lang, "patches-compare-unavailable"),
+                    CompareError::NoRom => t!(lang, "patches-compare-no-rom"),
+                    CompareError::Failed(error) => t!(lang, "patches-compare-failed", error = error.clone()),
+                };
+                return body
+                    .push(text(message).size(TEXT_CAPTION).style(widgets::danger_text_style))
+                    .into();
+            }
+            Some(Ok(compared)) => compared,
+        };
+
+        let mut folder = column![heading(t!(lang, "patches-compare-folder"))].spacing(4);
+        if !compared.own_save {
+            folder = folder.push(
+                text(t!(lang, "patches-compare-template"))
+                    .size(TEXT_CAPTION)
+                    .style(widgets::muted_text_style),
+            );
+        }
+        let changed = compared.diff.folder(lang);
+        folder = if changed.is_empty() {
+            folder.push(text(t!(lang, "patches-compare-folder-unaffected")).size(TEXT_CAPTION))
+        } else {
+            folder.push(lines(changed))
+        };
+        body = body.push(folder);
+
+        if compared.diff.is_empty() {
+            body = body.push(text(t!(lang, "patches-compare-identical")).size(TEXT_CAPTION));
+        }
+        for (section, rows) in compared.diff.sections(lang) {
+            body = body.push(column![heading(section), lines(rows)].spacing(4));
+        }
+
+        if !self.changelog_items.is_empty() {
+            let theme = crate::ui::theme::theme_for(config);
+            let style = crate::ui::theme::markdown_style(&theme);
+            body = body.push(
+                column![
+                    heading(t!(lang, "patches-compare-changelog")),
+                    iced::widget::markdown::view(
+                        &self.changelog_items,
+                        iced::widget::markdown::Settings::with_text_size(TEXT_BODY, style),
+                    )
+                    .map(Message::ReadmeLinkClicked),
+                ]
+                .spacing(4),
+            );
+        }
+        body.into()
+    }
 }
 
 /// How a version's netplay declaration reads in the UI. The typed
@@ -757,9 +975,15 @@ mod tests {
                 readme: Some("bn6_test/bn6_test-1.0.0.README.md".into()),
             },
         );
+        let offer = crate::library::patch::Offer {
+            repo: crate::library::patch::Repo::Http("https://patches.example.invalid".into()),
+            path: "bn6_test/bn6_test-1.0.0.tangopatch".into(),
+            readme: Some("bn6_test/bn6_test-1.0.0.README.md".into()),
+        };
         Catalog {
-            installed: Default::default(),
             index,
+            offers: [("bn6_test".to_owned(), [(v("1.0.0"), vec![offer])].into())].into(),
+            ..Default::default()
         }
     }
 
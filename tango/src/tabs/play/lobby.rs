@@ -70,6 +70,12 @@ pub(super) struct Lobby<'a> {
     /// In-flight patch downloads, so a `MissingPatch` verdict can say
     /// how the fetch it triggered is actually going.
     pub(super) downloads: &'a crate::library::patch::Downloads,
+    /// Why the revealed saves may not play under the rule set in force,
+    /// once the handshake has refused them. Outranks a `Compatible`
+    /// verdict: Settings agreeing is exactly how the saves got revealed.
+    pub(super) refusal: Option<&'a netplay::compat::Verdict>,
+    /// The last rule-set file that failed to load, as the error to show.
+    pub(super) rule_set_error: Option<&'a str>,
 }
 
 impl<'a> Lobby<'a> {
@@ -129,7 +135,10 @@ impl<'a> Lobby<'a> {
                 (Some(l), Some(r)) => {
                     let roms = self.scanners.roms.read();
                     let patches = self.scanners.patches.read();
-                    Status::Verdict(netplay::compat::check(l, r, &roms, &patches))
+                    Status::Verdict(match (netplay::compat::check(l, r, &roms, &patches), self.refusal) {
+                        (netplay::compat::Verdict::Compatible, Some(refusal)) => refusal.clone(),
+                        (verdict, _) => verdict,
+                    })
                 }
                 _ => Status::Handshake,
             },
@@ -153,6 +162,13 @@ impl<'a> Lobby<'a> {
         if let Some(line) = self.connection_line() {
             status_col = status_col.push(line);
         }
+        if let Some(error) = self.rule_set_error {
+            status_col = status_col.push(
+                text(t!(self.lang, "lobby-rules-load-failed", error = error.to_string()))
+                    .size(TEXT_CAPTION)
+                    .style(widgets::danger_text_style),
+            );
+        }
         // Leave's wrapper carries the bar's fixed content height (see
         // COMMAND_BAR_CONTENT) — a strut, not a clip: if the failure
         // text ever wraps taller, the row still grows around it. The
@@ -378,6 +394,30 @@ impl<'a> Lobby<'a> {
                     Verdict::SimVersionTooOld => t!(lang, "lobby-compat-sim-too-old"),
                     Verdict::SimVersionTooNew => t!(lang, "lobby-compat-sim-too-new"),
                     Verdict::DifferentMatchTypes => t!(lang, "lobby-compat-match-mismatch"),
+                    Verdict::DifferentRuleSets => t!(lang, "lobby-compat-rules-mismatch"),
+                    Verdict::PatchNotAllowed { side, patch } => {
+                        let patch = match patch {
+                            Some(p) => format!("{} {}", p.name, p.version),
+                            None => t!(lang, "lobby-compat-unpatched"),
+                        };
+                        match side {
+                            netplay::compat::Side::Local => {
+                                t!(lang, "lobby-compat-patch-not-allowed-you", patch = patch)
+                            }
+                            netplay::compat::Side::Remote => {
+                                t!(lang, "lobby-compat-patch-not-allowed-opponent", patch = patch)
+                            }
+                        }
+                    }
+                    Verdict::RuleBroken { side, breach } => {
+                        let breach = breach_label(lang, breach);
+                        match side {
+                            netplay::compat::Side::Local => t!(lang, "lobby-compat-rule-broken-you", breach = breach),
+                            netplay::compat::Side::Remote => {
+                                t!(lang, "lobby-compat-rule-broken-opponent", breach = breach)
+                            }
+                        }
+                    }
                 };
                 let style: fn(&iced::Theme) -> iced::widget::text::Style = if status.compat_ok() {
                     widgets::success_text_style
@@ -547,9 +587,37 @@ impl<'a> Lobby<'a> {
                 .into(),
         );
 
+        // Tournament rule set — loaded from a file both players were
+        // handed, and part of the committed terms like the match type:
+        // the peer has to name the same one, and changing it voids
+        // commits. Locks with the rest while `inert`.
+        let pick = (!inert).then_some(Message::PickRuleSet);
+        let name = match &self.state.rule_set {
+            Some(rule_set) => rule_set.name.clone(),
+            None => t!(lang, "lobby-rules-none"),
+        };
+        let mut rules = row![widgets::labeled_icon_button_maybe(
+            Icon::FolderOpen,
+            name,
+            pick,
+            STANDARD_PADDING,
+            widgets::neutral,
+        )]
+        .spacing(6)
+        .align_y(Alignment::Center);
+        if self.state.rule_set.is_some() {
+            rules = rules.push(widgets::icon_button_maybe(
+                Icon::X,
+                t!(lang, "lobby-rules-clear"),
+                (!inert).then_some(Message::ClearRuleSet),
+                STANDARD_PADDING,
+            ));
+        }
+        let rules_col = labeled(t!(lang, "lobby-rules"), rules.into());
+
         // Top-align so the captions sit on one line like a table
         // header row, whatever each control's height is.
-        row![match_col, delay_col, blind_col]
+        row![match_col, rules_col, delay_col, blind_col]
             .spacing(20)
             .align_y(Alignment::Start)
             .into()
@@ -750,6 +818,35 @@ impl Status<'_> {
     }
 }
 
+/// One rule-set breach, for the refusal line. Chips and parts go by the
+/// ROM's name where it has one.
+fn breach_label(lang: &LanguageIdentifier, breach: &tango_gamesupport::RuleBreach) -> String {
+    use tango_gamesupport::RuleBreach;
+    let named = |name: &Option<String>, id: usize| name.clone().unwrap_or_else(|| format!("#{id}"));
+    match breach {
+        RuleBreach::BannedChip { chip, name } => t!(lang, "lobby-rule-banned-chip", name = named(name, *chip)),
+        RuleBreach::BannedNavicustPart { part, name } => {
+            t!(lang, "lobby-rule-banned-part", name = named(name, *part))
+        }
+        RuleBreach::TooManyCopies {
+            chip,
+            name,
+            count,
+            limit,
+        } => t!(
+            lang,
+            "lobby-rule-too-many-copies",
+            name = named(name, *chip),
+            count = *count as i64,
+            limit = *limit as i64
+        ),
+        RuleBreach::MaxHpOverCap { hp, cap } => {
+            t!(lang, "lobby-rule-max-hp", hp = *hp as i64, cap = *cap as i64)
+        }
+        RuleBreach::Unreadable => t!(lang, "lobby-rule-unreadable"),
+    }
+}
+
 /// Soft-disable helper: when the lobby is inert, reroute a control's
 /// message constructor to [`Message::Noop`] so the control renders
 /// unchanged but drops input — pick_list doesn't accept a `None`
@@ -54,6 +54,13 @@ pub enum Message {
     SetFrameDelay(u32),
     /// Lobby UI: user toggled the blind-setup checkbox.
     SetBlindSetup(bool),
+    /// Lobby UI: user pressed the rule-set button. App opens a file
+    /// picker and answers with [`Message::RuleSetLoaded`].
+    PickRuleSet,
+    /// A picked rule-set file, read and parsed (or why it couldn't be).
+    RuleSetLoaded(Result<tango_net_protocol::control::RuleSet, String>),
+    /// Lobby UI: user cleared the rule set.
+    ClearRuleSet,
     /// Lobby UI: user pressed Ready. App loads the local
     /// save's raw SRAM, builds a NegotiatedState, and
     /// commits the local save.
@@ -90,6 +97,33 @@ pub enum Message {
     /// since the family's variants each ship their own templates.
     SaveNewTemplateSelected(rom::GameRef, String),
     SaveNewConfirm,
+    SaveCompareStart,
+    /// The save to diff the selected one against, picked from the
+    /// compare form.
+    SaveCompareSelected(std::path::PathBuf),
+    SaveCompareClose,
+    /// A Legacy Collection slot picked from the save picker: copy it
+    /// into the saves dir and select the copy.
+    SaveImportBnlc(crate::library::bnlc::SaveSlot),
+    SaveHistoryOpen,
+    SaveHistoryClose,
+    /// Put an earlier version back in place of the selected save.
+    SaveHistoryRestore(crate::library::save_history::Version),
+    /// Open the template gallery in place of the save view.
+    SaveGalleryOpen,
+    SaveGalleryClose,
+    /// A template picked in the gallery: (variant, template name), as
+    /// [`Message::SaveNewTemplateSelected`] carries it.
+    SaveGallerySelected(rom::GameRef, String),
+    /// A message from the previewed template's read-only save view.
+    SaveGalleryEditor(std::sync::Arc<dyn tango_gamesupport::SaveEditorMessage>),
+    SaveGalleryBuildChanged(String),
+    /// Stage the pasted build code over the previewed template.
+    SaveGalleryApplyBuild,
+    SaveGalleryNameChanged(String),
+    /// Write the previewed template, pasted build and all, as a new
+    /// save.
+    SaveGalleryCreate,
 }
 
 // ---------- Play tab state ----------
@@ -123,6 +157,59 @@ pub struct State {
     /// render (the live form — including the rename draft — is
     /// already gone).
     save_action_exit: SaveAction,
+    /// The open side-by-side diff, replacing the save view until closed.
+    compare: Option<Compare>,
+    /// The open version history, replacing the save view until closed.
+    history: Option<History>,
+    /// The open template gallery, replacing the save view until closed.
+    gallery: Option<Gallery>,
+    /// Why the last rule-set file didn't load, shown under the lobby
+    /// status until the next pick, clear or leave.
+    rule_set_error: Option<String>,
+}
+
+/// The selected save diffed against another of the same game. `left`
+/// pins which selection it was opened for: switching saves leaves it
+/// behind rather than diffing the new pick against a stale partner.
+struct Compare {
+    left: std::path::PathBuf,
+    right: selection::LoadedSave,
+}
+
+/// The selected save's earlier versions, newest first. `save` pins the
+/// selection it was opened for, as [`Compare::left`] does.
+struct History {
+    save: std::path::PathBuf,
+    versions: Vec<HistoryEntry>,
+}
+
+/// The template gallery: every template the new-save form offers, the
+/// picked one previewed through the read-only save view, and a pasted
+/// build staged over it before it becomes a save.
+struct Gallery {
+    /// (variant, template name).
+    selected: Option<(rom::GameRef, String)>,
+    /// The picked template as the App loaded it, with the build staged
+    /// over it if there is one. `None` until it arrives.
+    preview: Option<selection::LoadedSave>,
+    /// The build code draft.
+    build: String,
+    /// What staging the build said: a line per folder chip the
+    /// template's pack is short of, or why the code was refused. `None`
+    /// for the bare template.
+    staged: Option<Result<Vec<String>, String>>,
+    /// The new save's name.
+    name: String,
+    /// The suggestion last written into `name`, as
+    /// [`SaveAction::NewSave`]'s `auto_default`.
+    auto_name: Option<String>,
+}
+
+/// One earlier version of a save, with what the App could make of it.
+pub struct HistoryEntry {
+    pub version: crate::library::save_history::Version,
+    /// `None` for a version that no longer parses as the save's game.
+    pub summary: Option<tango_gamesupport::SaveSummary>,
 }
 
 impl Default for State {
@@ -134,6 +221,10 @@ impl Default for State {
             save_body_enter: crate::ui::anim::Enter::default(),
             save_form: crate::ui::anim::Transition::swap(false),
             save_action_exit: SaveAction::None,
+            compare: None,
+            history: None,
+            gallery: None,
+            rule_set_error: None,
         }
     }
 }
@@ -162,6 +253,11 @@ pub enum Effect {
     /// Lobby "blind my setup" toggled. App records it, persists the
     /// choice, and resends Settings.
     SetBlindSetup(bool),
+    /// Lobby rule-set button — App opens a file picker for one.
+    PickRuleSet,
+    /// Lobby rule set loaded or cleared. App records it and resends
+    /// Settings.
+    SetRuleSet(Option<tango_net_protocol::control::RuleSet>),
     /// Lobby Un-ready — drop our commitment and tell the peer.
     Unready,
     /// Lobby frame-delay slider moved. App persists `config.frame_delay`; it's
@@ -192,6 +288,9 @@ pub enum Effect {
     SaveRename { new_stem: String },
     /// Delete the currently-selected save file.
     SaveDelete,
+    /// Load `other` (a save of the selected save's game) and diff the
+    /// selected save against it.
+    SaveCompare { other: std::path::PathBuf },
     /// Create a fresh save in the saves dir from a bundled
     /// template.
     SaveNew {
@@ -202,6 +301,30 @@ pub enum Effect {
         /// game's template and adopts it as the loadout's game.
         game: rom::GameRef,
     },
+    /// Copy a Legacy Collection slot into the saves dir as `name`
+    /// (no extension) and select it.
+    SaveImportBnlc {
+        slot: crate::library::bnlc::SaveSlot,
+        name: String,
+    },
+    /// Load the selected save's history and open it.
+    SaveHistoryOpen,
+    /// Put the version back in place of the currently-selected save.
+    SaveHistoryRestore(crate::library::save_history::Version),
+    /// Load `game`'s `template` for the gallery to preview, with
+    /// `build` (a pasted build code) staged over it.
+    SaveGalleryPreview {
+        game: rom::GameRef,
+        template: String,
+        build: Option<String>,
+    },
+    /// Create a save named `name` (no extension) for `game` from `sram`
+    /// and select it — the gallery's template with its build staged.
+    SaveNewFromSram {
+        name: String,
+        game: rom::GameRef,
+        sram: Vec<u8>,
+    },
     /// Task returned from the save view's `ui.update`. Generic pipe
     /// so save_editor-internal side effects (e.g. the scroll-to-top
     /// snap on tab change) flow through without per-feature
@@ -258,6 +381,41 @@ impl State {
         self.link_code = code;
     }
 
+    /// Open the compare view: `left` is the selected save's path,
+    /// `right` the save it's diffed against, loaded by the App under the
+    /// same ROM and patch.
+    pub fn open_compare(&mut self, left: std::path::PathBuf, right: selection::LoadedSave) {
+        self.history = None;
+        self.compare = Some(Compare { left, right });
+    }
+
+    /// Open the history view for `save`, the selected save, over the
+    /// versions the App loaded and summarized.
+    pub fn open_history(&mut self, save: std::path::PathBuf, versions: Vec<HistoryEntry>) {
+        self.compare = None;
+        self.history = Some(History { save, versions });
+    }
+
+    /// Show the App's load of a gallery template, `staged` being what
+    /// staging the pasted build over it said. Dropped if the gallery
+    /// has since closed or moved to another template.
+    pub fn open_gallery_preview(
+        &mut self,
+        game: rom::GameRef,
+        template: &str,
+        preview: selection::LoadedSave,
+        staged: Option<Result<Vec<String>, String>>,
+    ) {
+        let Some(gallery) = self.gallery.as_mut() else {
+            return;
+        };
+        if gallery.selected.as_ref().map(|(g, t)| (*g, t.as_str())) != Some((game, template)) {
+            return;
+        }
+        gallery.preview = Some(preview);
+        gallery.staged = staged;
+    }
+
     /// Play the family-switch entrance: a family change replaces the
     /// entire bottom of the tab, so the whole save-view pane under the
     /// selector strip glides in.
@@ -334,10 +492,26 @@ impl State {
                 })
             }
             Message::Noop => None,
-            Message::Disconnect => Some(Effect::Disconnect),
+            Message::Disconnect => {
+                self.rule_set_error = None;
+                Some(Effect::Disconnect)
+            }
             Message::SetMatchType(mt) => Some(Effect::SetMatchType(mt)),
             Message::SetFrameDelay(d) => Some(Effect::SetFrameDelay(d)),
             Message::SetBlindSetup(v) => Some(Effect::SetBlindSetup(v)),
+            Message::PickRuleSet => Some(Effect::PickRuleSet),
+            Message::RuleSetLoaded(Ok(rule_set)) => {
+                self.rule_set_error = None;
+                Some(Effect::SetRuleSet(Some(rule_set)))
+            }
+            Message::RuleSetLoaded(Err(e)) => {
+                self.rule_set_error = Some(e);
+                None
+            }
+            Message::ClearRuleSet => {
+                self.rule_set_error = None;
+                Some(Effect::SetRuleSet(None))
+            }
             Message::Ready => Some(Effect::ReadyWithSave),
             Message::Unready => Some(Effect::Unready),
             Message::SaveEditor(msg) => {
@@ -372,7 +546,22 @@ impl State {
             | Message::SaveNewStart
             | Message::SaveNewDraftChanged(_)
             | Message::SaveNewTemplateSelected(..)
-            | Message::SaveNewConfirm) => self.update_save_manage(m, scanners, config, loadout),
+            | Message::SaveNewConfirm
+            | Message::SaveCompareStart
+            | Message::SaveCompareSelected(_)
+            | Message::SaveCompareClose
+            | Message::SaveImportBnlc(_)
+            | Message::SaveHistoryOpen
+            | Message::SaveHistoryClose
+            | Message::SaveHistoryRestore(_)
+            | Message::SaveGalleryOpen
+            | Message::SaveGalleryClose
+            | Message::SaveGallerySelected(..)
+            | Message::SaveGalleryEditor(_)
+            | Message::SaveGalleryBuildChanged(_)
+            | Message::SaveGalleryApplyBuild
+            | Message::SaveGalleryNameChanged(_)
+            | Message::SaveGalleryCreate) => self.update_save_manage(m, scanners, config, loadout),
         }
     }
 }
@@ -386,6 +575,9 @@ pub struct LobbyBandCtx<'a> {
     /// Derived ready-ladder projection (who's committed / match
     /// starting), same per-frame vintage as `lobby`.
     pub ready: crate::netplay::ReadyView,
+    /// The handshake's rule-set refusal, if the revealed saves broke
+    /// it. Live only: the exiting band has no Ready to explain.
+    pub refusal: Option<&'a crate::netplay::compat::Verdict>,
     /// True between "both sides exchanged StartMatch" and the PvP
     /// session taking over: the selector strip goes inert and the
     /// lobby shows its "Starting match…" chrome.
@@ -510,6 +702,8 @@ impl State {
                 handoff_pending: band.handoff_pending,
                 frame_delay: config.frame_delay,
                 downloads,
+                refusal: band.refusal.filter(|_| band.swap.shown()),
+                rule_set_error: self.rule_set_error.as_deref(),
             }
             .view()
         } else {
@@ -564,6 +758,11 @@ impl State {
                 Some((t!(lang, "save-open-folder"), roms_path)),
             );
         }
+        // Ahead of the empty-family card: a first save is what the
+        // gallery is for.
+        if let Some(gallery) = &self.gallery {
+            return save_manage::gallery_view(lang, scanners, loadout, gallery, streamer_mode);
+        }
         // Family selected but no save files anywhere in it.
         if let Some(family) = loadout.family {
             let saves = scanners.saves.read();
@@ -578,6 +777,19 @@ impl State {
                 );
             }
         }
+        // Streamer mode keeps the build off screen, and a diff is
+        // nothing but build.
+        if let (Some(compare), Some(loaded), false) = (&self.compare, loaded, streamer_mode) {
+            if compare.left == loaded.save_path {
+                return save_manage::compare_view(lang, loaded, compare);
+            }
+        }
+        // The summaries are build too.
+        if let (Some(history), Some(loaded), false) = (&self.history, loaded, streamer_mode) {
+            if history.save == loaded.save_path {
+                return save_manage::history_view(lang, history);
+            }
+        }
         self.save_editor(
             lang,
             loaded,
@@ -600,11 +812,19 @@ impl State {
         // reroute to Noop so a mid-spawn selection change can't
         // contradict the committed state, without the strip changing
         // shape.
-        let gate = move |m: loadout::Message| if inert { Message::Noop } else { Message::Loadout(m) };
+        let gate = move |m: loadout::Message| match m {
+            _ if inert => Message::Noop,
+            // A Legacy Collection slot is imported, not selected; the
+            // import selects the copy it makes.
+            loadout::Message::SaveSelected(loadout::SaveOption { bnlc: Some(slot), .. }) => {
+                Message::SaveImportBnlc(slot)
+            }
+            m => Message::Loadout(m),
+        };
         let game_row: Element<'a, Message> = loadout::game_row(loadout, lang, scanners, config, downloads).map(gate);
         let save_picker: Element<'a, Message> =
             Element::from(loadout::save_picker(loadout, lang, scanners, config).width(Length::Fill)).map(gate);
-        let save_row = self.save_action_row(lang, scanners, loadout, save_picker);
+        let save_row = self.save_action_row(lang, scanners, loadout, config, save_picker);
 
         container(column![game_row, save_row].spacing(6))
             .padding(style::PANE_PADDING)
@@ -1,9 +1,12 @@
 //! Save-file management for the Play tab: the duplicate / rename /
-//! delete / create-from-template flows — their inline-form state
+//! delete / create-from-template flows and the Legacy Collection
+//! import — their inline-form state
 //! ([`SaveAction`]), message handling, form views, and the on-disk
-//! file operations the App runs for the resulting Effects. Pure
-//! save-library concerns; nothing here touches netplay or the save
-//! view.
+//! file operations the App runs for the resulting Effects — plus
+//! comparing the selected save against another of the same game and
+//! browsing and restoring its earlier versions. Pure save-library
+//! concerns; nothing here touches netplay, and the save view only as
+//! the compare and history views stand in for it.
 
 use super::*;
 
@@ -21,6 +24,8 @@ pub enum SaveAction {
         draft: String,
     },
     ConfirmDelete,
+    /// Picking the save to compare the selected one against.
+    Comparing,
     /// Creating a new save. `template` is the template name (empty
     /// string is the default unnamed template); `draft` is the user's
     /// chosen filename. `game` is the concrete variant the save is
@@ -217,6 +222,127 @@ impl State {
                     Some(Effect::SaveNew { name, template, game })
                 }
             }
+            Message::SaveCompareStart => {
+                self.save_action = SaveAction::Comparing;
+                None
+            }
+            Message::SaveCompareSelected(other) => {
+                self.save_action = SaveAction::None;
+                Some(Effect::SaveCompare { other })
+            }
+            Message::SaveCompareClose => {
+                self.compare = None;
+                None
+            }
+            Message::SaveImportBnlc(slot) => {
+                let base = format!(
+                    "{} - {}",
+                    crate::library::game::display_name(&config.language, slot.game),
+                    slot.label()
+                );
+                let name = disambiguate_save_name(&config.saves_path(), &sanitize_filename(&base));
+                Some(Effect::SaveImportBnlc { slot, name })
+            }
+            Message::SaveHistoryOpen => Some(Effect::SaveHistoryOpen),
+            Message::SaveHistoryClose => {
+                self.history = None;
+                None
+            }
+            Message::SaveHistoryRestore(version) => {
+                // Back to the save view, which the restored file reloads
+                // into.
+                self.history = None;
+                Some(Effect::SaveHistoryRestore(version))
+            }
+            Message::SaveGalleryOpen => {
+                self.compare = None;
+                self.history = None;
+                self.save_action = SaveAction::None;
+                self.gallery = Some(Gallery {
+                    selected: None,
+                    preview: None,
+                    build: String::new(),
+                    staged: None,
+                    name: String::new(),
+                    auto_name: Some(String::new()),
+                });
+                // Open on the first template rather than an empty pane.
+                let first = creation_template_options(&config.language, loadout, scanners)
+                    .into_iter()
+                    .next()?;
+                let (game, template) = first.value;
+                self.update_save_manage(Message::SaveGallerySelected(game, template), scanners, config, loadout)
+            }
+            Message::SaveGalleryClose => {
+                self.gallery = None;
+                None
+            }
+            Message::SaveGallerySelected(game, template) => {
+                let gallery = self.gallery.as_mut()?;
+                gallery.selected = Some((game, template.clone()));
+                gallery.preview = None;
+                gallery.staged = None;
+                if gallery.auto_name.as_deref() == Some(gallery.name.as_str()) {
+                    let name = disambiguate_save_name(
+                        &config.saves_path(),
+                        &suggest_save_name(&config.language, game, Some(&template)),
+                    );
+                    gallery.name = name.clone();
+                    gallery.auto_name = Some(name);
+                }
+                Some(Effect::SaveGalleryPreview {
+                    game,
+                    template,
+                    build: None,
+                })
+            }
+            Message::SaveGalleryEditor(msg) => {
+                // Read-only: tab switches and scrolling land here, never
+                // an edit.
+                let preview = self.gallery.as_mut()?.preview.as_mut()?;
+                let (task, outcome) = preview.editor.update(&config.language, preview, &*msg);
+                match outcome {
+                    Some(tango_gamesupport::SaveEditorEvent::CopyText(s)) => Some(Effect::CopyText(s)),
+                    Some(tango_gamesupport::SaveEditorEvent::CopyImage(img)) => Some(Effect::CopyImage(img)),
+                    _ => Some(Effect::SaveEditorTask(task.map(Message::SaveGalleryEditor))),
+                }
+            }
+            Message::SaveGalleryBuildChanged(s) => {
+                self.gallery.as_mut()?.build = s;
+                None
+            }
+            Message::SaveGalleryApplyBuild => {
+                // Staged over a fresh load of the template each time, so
+                // a second code doesn't land on the first one's folder.
+                let gallery = self.gallery.as_mut()?;
+                let (game, template) = gallery.selected.clone()?;
+                let build = Some(gallery.build.trim().to_string()).filter(|b| !b.is_empty());
+                gallery.preview = None;
+                gallery.staged = None;
+                Some(Effect::SaveGalleryPreview { game, template, build })
+            }
+            Message::SaveGalleryNameChanged(s) => {
+                let gallery = self.gallery.as_mut()?;
+                if gallery.auto_name.as_deref() != Some(s.as_str()) {
+                    gallery.auto_name = None;
+                }
+                gallery.name = s;
+                None
+            }
+            Message::SaveGalleryCreate => {
+                let gallery = self.gallery.as_ref()?;
+                if !gallery.can_create() {
+                    return None;
+                }
+                let preview = gallery.preview.as_ref()?;
+                let effect = Effect::SaveNewFromSram {
+                    name: gallery.name.trim().to_string(),
+                    game: preview.game,
+                    sram: preview.editor.sram(preview),
+                };
+                self.gallery = None;
+                Some(effect)
+            }
             // Only the Save* family is routed here.
             _ => None,
         }
@@ -232,6 +358,7 @@ impl State {
         lang: &'a LanguageIdentifier,
         scanners: &'a Scanners,
         loadout: &'a Loadout,
+        config: &'a config::Config,
         save_picker: Element<'a, Message>,
     ) -> Element<'a, Message> {
         // The picker row fade-through morphs into whichever form
@@ -247,7 +374,7 @@ impl State {
             &self.save_action
         };
         let mut row_el: Element<'a, Message> =
-            self.save_action_row_inner(lang, scanners, loadout, save_picker, render_form, action);
+            self.save_action_row_inner(lang, scanners, loadout, config, save_picker, render_form, action);
         if let Some(phase) = form_swap {
             row_el = crate::ui::anim::swap_transform(row_el, phase, iced::Vector::new(24.0, 0.0), widgets::plate_color);
         }
@@ -259,6 +386,7 @@ impl State {
         lang: &'a LanguageIdentifier,
         scanners: &'a Scanners,
         loadout: &'a Loadout,
+        config: &'a config::Config,
         save_picker: Element<'a, Message>,
         render_form: bool,
         action: &'a SaveAction,
@@ -352,6 +480,32 @@ impl State {
                 .align_y(Alignment::Center)
                 .into()
             }
+            SaveAction::Comparing => {
+                // Same-game saves only: chip and part ids mean nothing
+                // across ROMs, so another variant's save can't be diffed.
+                let options: Vec<loadout::SaveOption> = loadout::save_options(loadout, lang, scanners, config)
+                    .into_iter()
+                    .filter(|o| {
+                        o.bnlc.is_none() && Some(o.game) == loadout.game && Some(&o.path) != loadout.save.as_ref()
+                    })
+                    .collect();
+                let placeholder = if options.is_empty() {
+                    t!(lang, "save-compare-none")
+                } else {
+                    t!(lang, "save-compare-pick")
+                };
+                row![
+                    widgets::picker(options, None::<loadout::SaveOption>, |o: loadout::SaveOption| {
+                        Message::SaveCompareSelected(o.path)
+                    })
+                    .placeholder(placeholder)
+                    .width(Length::Fill),
+                    save_action_cancel_button(lang),
+                ]
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .into()
+            }
             SaveAction::NewSave {
                 draft, game, template, ..
             } => {
@@ -415,12 +569,37 @@ impl State {
         let can_new = creation_games(loadout, scanners).iter().any(|g| {
             templates_for_game(g, loadout.patch.as_deref(), loadout.patch_version.as_ref(), scanners).is_some()
         });
-        widgets::icon_button_maybe(
-            Icon::FilePlus,
-            t!(lang, "save-new"),
-            can_new.then_some(Message::SaveNewStart),
-            STANDARD_PADDING,
-        )
+        row![
+            widgets::icon_button_maybe(
+                Icon::FilePlus,
+                t!(lang, "save-new"),
+                can_new.then_some(Message::SaveNewStart),
+                STANDARD_PADDING,
+            ),
+            widgets::icon_button_maybe(
+                Icon::LayoutGrid,
+                t!(lang, "save-gallery"),
+                can_new.then_some(Message::SaveGalleryOpen),
+                STANDARD_PADDING,
+            ),
+        ]
+        .spacing(4)
+        .into()
+    }
+}
+
+impl Gallery {
+    /// A save can be made once the preview is in, and the build staged
+    /// over it (if any) was accepted with every chip in the template's
+    /// pack.
+    fn can_create(&self) -> bool {
+        self.preview.is_some()
+            && !self.name.trim().is_empty()
+            && match &self.staged {
+                None => true,
+                Some(Ok(short)) => short.is_empty(),
+                Some(Err(_)) => false,
+            }
     }
 }
 
@@ -436,6 +615,12 @@ fn save_actions_menu<'a>(lang: &LanguageIdentifier, loadout: &Loadout) -> Elemen
         widgets::MenuItem::new(Icon::FolderOpen, t!(lang, "save-open-folder"), Message::SaveOpenFolder),
         widgets::MenuItem::new(Icon::Files, t!(lang, "save-duplicate"), Message::SaveDuplicateStart),
         widgets::MenuItem::new(Icon::PencilLine, t!(lang, "save-rename"), Message::SaveRenameStart),
+        widgets::MenuItem::new(
+            Icon::ArrowLeftRight,
+            t!(lang, "save-compare"),
+            Message::SaveCompareStart,
+        ),
+        widgets::MenuItem::new(Icon::History, t!(lang, "save-history"), Message::SaveHistoryOpen),
         widgets::MenuItem::danger(Icon::Trash, t!(lang, "save-delete"), Message::SaveDeleteStart),
     ];
     widgets::menu_button(
@@ -447,8 +632,293 @@ fn save_actions_menu<'a>(lang: &LanguageIdentifier, loadout: &Loadout) -> Elemen
     )
 }
 
+/// The compare view standing in for the save view: the two saves'
+/// names over the game's own diff of them, with a close button back to
+/// the editor.
+pub(super) fn compare_view<'a>(
+    lang: &'a LanguageIdentifier,
+    loaded: &'a selection::LoadedSave,
+    compare: &'a Compare,
+) -> Element<'a, Message> {
+    let stem = |p: &std::path::Path| {
+        p.file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    };
+    let header = row![
+        Icon::ArrowLeftRight.widget().size(TEXT_BODY),
+        text(t!(lang, "save-compare-title")).size(TEXT_BODY),
+        Space::new().width(Fill),
+        widgets::icon_button(
+            Icon::X,
+            t!(lang, "save-compare-close"),
+            Message::SaveCompareClose,
+            STANDARD_PADDING,
+        ),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+    let diff = loaded
+        .editor
+        .diff_view(
+            lang,
+            loaded,
+            &compare.right,
+            [stem(&compare.left), stem(&compare.right.save_path)],
+        )
+        .map(Message::SaveEditor);
+    container(column![header, iced::widget::scrollable(diff).height(Fill)].spacing(8))
+        .padding(style::PANE_PADDING)
+        .width(Fill)
+        .height(Fill)
+        .style(widgets::pane)
+        .into()
+}
+
+/// The history view standing in for the save view: one row per earlier
+/// version, newest first — when it was replaced, its navi and the gist
+/// of its folder — each with a button to put it back.
+pub(super) fn history_view<'a>(lang: &'a LanguageIdentifier, history: &'a History) -> Element<'a, Message> {
+    let header = row![
+        Icon::History.widget().size(TEXT_BODY),
+        text(t!(lang, "save-history-title")).size(TEXT_BODY),
+        Space::new().width(Fill),
+        widgets::icon_button(
+            Icon::X,
+            t!(lang, "save-history-close"),
+            Message::SaveHistoryClose,
+            STANDARD_PADDING,
+        ),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let body: Element<'a, Message> = if history.versions.is_empty() {
+        text(t!(lang, "save-history-empty"))
+            .style(widgets::muted_text_style)
+            .into()
+    } else {
+        let rows = history.versions.iter().map(|entry| {
+            let taken = std::time::UNIX_EPOCH
+                .checked_add(std::time::Duration::from_millis(entry.version.taken))
+                .map(|t| {
+                    chrono::DateTime::<chrono::Local>::from(t)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string()
+                })
+                .unwrap_or_else(|| "(?)".to_string());
+            let detail = match &entry.summary {
+                Some(summary) => history_summary(lang, summary),
+                None => t!(lang, "save-history-unreadable"),
+            };
+            row![
+                column![
+                    text(taken).size(TEXT_BODY),
+                    text(detail).size(TEXT_CAPTION).style(widgets::muted_text_style),
+                ]
+                .spacing(2)
+                .width(Fill),
+                widgets::labeled_icon_button(
+                    Icon::RotateCcw,
+                    t!(lang, "save-history-restore"),
+                    Message::SaveHistoryRestore(entry.version.clone()),
+                    STANDARD_PADDING,
+                    widgets::neutral,
+                ),
+            ]
+            .spacing(8)
+            .align_y(Alignment::Center)
+            .into()
+        });
+        iced::widget::scrollable(iced::widget::Column::with_children(rows).spacing(12))
+            .height(Fill)
+            .into()
+    };
+
+    container(column![header, body].spacing(8))
+        .padding(style::PANE_PADDING)
+        .width(Fill)
+        .height(Fill)
+        .style(widgets::pane)
+        .into()
+}
+
+/// The gallery standing in for the save view: every template the
+/// new-save form offers down the left, the picked one through the
+/// read-only save view on the right, and under it the build-code paste
+/// and the name to create the save under.
+pub(super) fn gallery_view<'a>(
+    lang: &'a LanguageIdentifier,
+    scanners: &Scanners,
+    loadout: &Loadout,
+    gallery: &'a Gallery,
+    streamer_mode: bool,
+) -> Element<'a, Message> {
+    let header = row![
+        Icon::LayoutGrid.widget().size(TEXT_BODY),
+        text(t!(lang, "save-gallery-title")).size(TEXT_BODY),
+        Space::new().width(Fill),
+        widgets::icon_button(
+            Icon::X,
+            t!(lang, "save-gallery-close"),
+            Message::SaveGalleryClose,
+            STANDARD_PADDING,
+        ),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let mut list = column![].spacing(2);
+    for (idx, option) in creation_template_options(lang, loadout, scanners)
+        .into_iter()
+        .enumerate()
+    {
+        let (game, template) = option.value.clone();
+        let selected = gallery.selected.as_ref() == Some(&option.value);
+        list = list.push(
+            button(text(option.label.clone()).size(TEXT_BODY))
+                .padding(style::ROW_PADDING)
+                .width(Fill)
+                .style(widgets::list_item(selected, idx))
+                .on_press(Message::SaveGallerySelected(game, template)),
+        );
+    }
+    let list = container(
+        iced::widget::scrollable(list)
+            .style(widgets::chunky_scrollable)
+            .height(Fill),
+    )
+    .width(Length::Fixed(220.0))
+    .height(Fill);
+
+    let preview: Element<'a, Message> = match &gallery.preview {
+        Some(preview) => preview
+            .editor
+            .view(lang, preview, streamer_mode, None, false, false)
+            .map(Message::SaveGalleryEditor),
+        None => container(
+            text(t!(lang, "save-gallery-loading"))
+                .size(TEXT_CAPTION)
+                .style(widgets::muted_text_style),
+        )
+        .center(Fill)
+        .into(),
+    };
+
+    let build_row = row![
+        text_input(&t!(lang, "save-gallery-build-placeholder"), &gallery.build)
+            .on_input(Message::SaveGalleryBuildChanged)
+            .on_submit(Message::SaveGalleryApplyBuild)
+            .style(widgets::chunky_text_input)
+            .padding(STANDARD_PADDING)
+            .width(Length::Fill),
+        widgets::labeled_icon_button_maybe(
+            Icon::ClipboardPaste,
+            t!(lang, "save-gallery-apply-build"),
+            gallery.selected.is_some().then_some(Message::SaveGalleryApplyBuild),
+            STANDARD_PADDING,
+            widgets::neutral,
+        ),
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+    let mut notes = column![].spacing(2);
+    match &gallery.staged {
+        Some(Err(refusal)) => {
+            notes = notes.push(
+                text(refusal.clone())
+                    .size(TEXT_CAPTION)
+                    .style(widgets::danger_text_style),
+            );
+        }
+        Some(Ok(short)) if !short.is_empty() => {
+            notes = notes.push(
+                text(t!(lang, "save-gallery-pack-short"))
+                    .size(TEXT_CAPTION)
+                    .style(widgets::danger_text_style),
+            );
+            for line in short {
+                notes = notes.push(text(line.clone()).size(TEXT_CAPTION));
+            }
+        }
+        Some(Ok(_)) => {
+            notes = notes.push(
+                text(t!(lang, "save-gallery-build-staged"))
+                    .size(TEXT_CAPTION)
+                    .style(widgets::muted_text_style),
+            );
+        }
+        None => {}
+    }
+    let create_row = row![
+        save_name_input(
+            lang,
+            &gallery.name,
+            Message::SaveGalleryNameChanged,
+            Message::SaveGalleryCreate
+        ),
+        if gallery.can_create() {
+            widgets::labeled_icon_button(
+                Icon::FilePlus,
+                t!(lang, "save-new-confirm"),
+                Message::SaveGalleryCreate,
+                STANDARD_PADDING,
+                widgets::primary_button,
+            )
+        } else {
+            widgets::labeled_icon_button_maybe(
+                Icon::FilePlus,
+                t!(lang, "save-new-confirm"),
+                None,
+                STANDARD_PADDING,
+                widgets::neutral,
+            )
+        },
+    ]
+    .spacing(8)
+    .align_y(Alignment::Center);
+
+    let detail = column![container(preview).height(Fill), build_row, notes, create_row]
+        .spacing(8)
+        .width(Fill);
+    container(column![header, row![list, detail].spacing(12).height(Fill)].spacing(8))
+        .padding(style::PANE_PADDING)
+        .width(Fill)
+        .height(Fill)
+        .style(widgets::pane)
+        .into()
+}
+
+/// How many of a folder's chips a history row names — the most-copied,
+/// which say the most about what the folder is.
+const HISTORY_FOLDER_CHIPS: usize = 3;
+
+/// "MegaMan · AreaGrab ×4, Recov300 ×3, Cannon ×2, …".
+fn history_summary(lang: &LanguageIdentifier, summary: &tango_gamesupport::SaveSummary) -> String {
+    let unnamed = || t!(lang, "save-history-unnamed");
+    let mut folder = summary.folder.clone();
+    // Stable, so equal counts keep the folder's own order.
+    folder.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    let mut chips: Vec<String> = folder
+        .iter()
+        .take(HISTORY_FOLDER_CHIPS)
+        .map(|(name, count)| format!("{} ×{count}", name.clone().unwrap_or_else(unnamed)))
+        .collect();
+    if folder.len() > HISTORY_FOLDER_CHIPS {
+        chips.push("…".to_string());
+    }
+    summary
+        .navi
+        .as_ref()
+        .map(|navi| navi.clone().unwrap_or_else(unnamed))
+        .into_iter()
+        .chain((!chips.is_empty()).then(|| chips.join(", ")))
+        .collect::<Vec<_>>()
+        .join(" · ")
+}
+
 /// The "× Cancel" button that ends every save-action form (rename / duplicate
-/// / delete / new) — identical across all four.
+/// / delete / new / compare) — identical across all of them.
 fn save_action_cancel_button<'a>(lang: &LanguageIdentifier) -> Element<'a, Message> {
     widgets::icon_button(
         Icon::X,
@@ -179,6 +179,11 @@ pub struct ReplaysState {
     /// otherwise — see [`Effect::AnalyzeReplay`]. The detail panel draws
     /// its HP pane from this; paths without an entry just don't get one.
     pub hp_charts: std::collections::HashMap<std::path::PathBuf, HpChart>,
+    /// Each focused replay's netcode health timeline, read once from its
+    /// `.net` sidecar on first focus — `None` for a replay that has none
+    /// (it wasn't played live here, or predates them), which is final.
+    /// [`HpChart`]s cook their health lanes from it.
+    pub net_health: std::collections::HashMap<std::path::PathBuf, Option<tango_session::health::NetHealth>>,
     /// Replays with an analysis in flight — presence stops a re-focus
     /// from stacking a second multi-second re-simulation.
     pub hp_pending: std::collections::HashSet<std::path::PathBuf>,
@@ -210,12 +215,26 @@ pub struct HpChart {
     /// the export's sections label/number accordingly. Gated on
     /// `complete` like `marks`.
     pub has_setup: bool,
+    /// The "top damage" lines per side (`[you, opponent]`), cooked from
+    /// the same stats — a prefix while the analysis runs, like the
+    /// rounds.
+    pub top_damage: [Vec<widgets::DamageLine>; 2],
+    /// Each round's per-side movement, for the positioning fields under
+    /// the chart. Empty per round on games that report no tiles.
+    pub movement: Vec<[Option<tango_match::analysis::Movement>; 2]>,
+    /// The per-side custom-screen timing, each round's and the match's,
+    /// for the table under the positioning fields.
+    pub custom_timing: widgets::CookedCustomTiming,
+    /// The netcode health timeline cut at the same rounds, empty when
+    /// the replay has none.
+    pub net: Vec<widgets::CookedNetRound>,
 }
 
 impl HpChart {
     fn new(
         stats: &tango_match::analysis::MatchStats,
         loaded: Option<&crate::selection::LoadedSave>,
+        health: Option<&tango_session::health::NetHealth>,
         total_ticks: Option<u32>,
         complete: bool,
     ) -> Self {
@@ -227,6 +246,10 @@ impl HpChart {
         Self {
             rounds,
             max_hp,
+            top_damage: widgets::cook_top_damage(stats, [loaded, loaded], crate::session::TOP_DAMAGE_LINES),
+            movement: widgets::cook_movement(stats, total_ticks),
+            custom_timing: widgets::cook_custom_timing(stats, total_ticks),
+            net: health.map_or_else(Vec::new, |h| widgets::cook_net_rounds(stats, h, total_ticks)),
             marks: if complete { stats.round_marks() } else { vec![] },
             complete,
             has_setup: complete && stats.has_setup(),
@@ -386,6 +409,9 @@ impl ReplaysState {
                 // previous focus), and only re-simulate when there isn't
                 // one. Failures clear `hp_pending` via the result message,
                 // so a later focus retries.
+                self.net_health
+                    .entry(p.clone())
+                    .or_insert_with(|| crate::library::replays::load_net_health(&p));
                 if !self.hp_charts.contains_key(&p) && !self.hp_pending.contains(&p) {
                     if let Some(stats) =
                         crate::library::replays::load_match_stats(&config.cache_path(), &config.replays_path(), &p)
@@ -502,7 +528,13 @@ impl ReplaysState {
         }
         self.hp_charts.insert(
             path,
-            HpChart::new(stats, self.loaded.as_ref(), self.loaded_total_ticks, complete),
+            HpChart::new(
+                stats,
+                self.loaded.as_ref(),
+                self.net_health.get(&path).and_then(Option::as_ref),
+                self.loaded_total_ticks,
+                complete,
+            ),
         );
     }
 
@@ -1419,7 +1451,51 @@ fn replay_detail<'a>(
         );
         // No pane padding: the chart's own per-round inset panels are the
         // content, so the canvas runs edge to edge and the pane background
-        // only peeks through the round dividers.
+        // only peeks through the round dividers. The damage lines under
+        // it are text, and get the usual padding.
+        let damage = chart.and_then(|c| {
+            widgets::top_damage(
+                lang,
+                &c.top_damage,
+                [t!(lang, "play-you"), t!(lang, "play-opponent")],
+            )
+        });
+        // One small field per round, side by side, when the game reports
+        // tiles at all.
+        let fields = chart
+            .filter(|c| c.movement.iter().flatten().any(Option::is_some))
+            .map(|c| {
+                let mut fields = row![].spacing(16);
+                for (i, m) in c.movement.iter().enumerate() {
+                    fields = fields.push(widgets::field_heatmap(lang, i, m));
+                }
+                column![
+                    text(t!(lang, "movement-title"))
+                        .size(TEXT_CAPTION)
+                        .style(widgets::muted_text_style),
+                    fields,
+                ]
+                .spacing(4)
+            });
+        let custom = chart.and_then(|c| widgets::custom_timing_table(lang, &c.custom_timing));
+        // The netcode lanes sit flush under the graph, on the same round
+        // segments, when the match was played live here.
+        let net = chart
+            .filter(|c| c.net.iter().any(|r| !r.points.is_empty()))
+            .map(|c| widgets::net_health_graph(&c.net, widgets::net_health_labels(lang), 1.0, DETAIL_NET_GRAPH_H));
+        let mut body = column![body];
+        if let Some(net) = net {
+            body = body.push(net);
+        }
+        if let Some(damage) = damage {
+            body = body.push(container(damage).padding(style::PANE_PADDING));
+        }
+        if let Some(fields) = fields {
+            body = body.push(container(fields).padding(style::PANE_PADDING));
+        }
+        if let Some(custom) = custom {
+            body = body.push(container(custom).padding(style::PANE_PADDING));
+        }
         container(body).width(Fill).style(widgets::pane).into()
     };
 
@@ -1497,6 +1573,10 @@ fn replay_detail<'a>(
 /// renders in.
 const DETAIL_HP_GRAPH_H: f32 = 72.0;
 
+/// Height of the netcode health lanes under it: five lanes, and room
+/// for the five-line hover readout.
+const DETAIL_NET_GRAPH_H: f32 = 72.0;
+
 /// Everything the free-text search matches against, joined into one
 /// lowercased blob: both sides' nicknames, game names (raw family
 /// plus the localized display/short names, so "exe6" and "battle
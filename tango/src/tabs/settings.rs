@@ -201,6 +201,18 @@ pub enum Message {
     /// next PvP session is installed.
     ToggleShowOpponentSetup(bool),
     PatchRepoChanged(String),
+    /// The Nth additional patch repo's location was edited.
+    ExtraPatchRepoChanged(usize, String),
+    /// "Add repository" clicked: appends a blank row to type a URL or
+    /// path into.
+    AddExtraPatchRepo,
+    /// "Add folder…" clicked. The App intercepts this to open a folder
+    /// picker, which comes back as `PatchRepoFolderPicked`.
+    OpenPatchRepoFolderPicker,
+    /// Folder picker for a directory repo resolved.
+    PatchRepoFolderPicked(Option<std::path::PathBuf>),
+    /// User clicked Remove on the Nth additional patch repo.
+    RemoveExtraPatchRepo(usize),
     /// "Change…" clicked next to the data folder. The App intercepts this
     /// (before `State::update`) to open an async folder picker, which comes
     /// back as `DataFolderPicked`.
@@ -273,6 +285,9 @@ pub enum ConfigChange {
     RelayMode(config::RelayMode),
     ShowOpponentSetup(bool),
     PatchRepo(String),
+    ExtraPatchRepo(usize, String),
+    AddExtraPatchRepo(String),
+    RemoveExtraPatchRepo(usize),
     /// New root data folder picked. The App points `config.data_path` at it,
     /// creates the standard subfolders, re-scans, and re-points the patch
     /// autoupdater.
@@ -330,12 +345,21 @@ impl State {
             Message::RelayModeChanged(m) => Some(ConfigChange::RelayMode(m)),
             Message::ToggleShowOpponentSetup(b) => Some(ConfigChange::ShowOpponentSetup(b)),
             Message::PatchRepoChanged(s) => Some(ConfigChange::PatchRepo(s)),
+            Message::ExtraPatchRepoChanged(i, s) => Some(ConfigChange::ExtraPatchRepo(i, s)),
+            Message::AddExtraPatchRepo => Some(ConfigChange::AddExtraPatchRepo(String::new())),
+            Message::PatchRepoFolderPicked(Some(path)) => {
+                Some(ConfigChange::AddExtraPatchRepo(path.to_string_lossy().into_owned()))
+            }
+            Message::RemoveExtraPatchRepo(i) => Some(ConfigChange::RemoveExtraPatchRepo(i)),
             // Intercepted by the App before it reaches here (it opens the
             // folder picker); the arm exists only for exhaustiveness.
             Message::OpenDataFolderPicker => None,
             Message::DataFolderPicked(Some(path)) => Some(ConfigChange::DataPath(path)),
             // Dialog dismissed — nothing to change.
             Message::DataFolderPicked(None) => None,
+            // Intercepted by the App, like `OpenDataFolderPicker`.
+            Message::OpenPatchRepoFolderPicker => None,
+            Message::PatchRepoFolderPicked(None) => None,
             Message::TogglePatchAutoupdate(b) => Some(ConfigChange::PatchAutoupdate(b)),
             Message::VideoFilterChanged(s) => Some(ConfigChange::VideoFilter(s)),
             Message::ToggleFractionalScaling(b) => Some(ConfigChange::FractionalScaling(b)),
@@ -621,23 +645,64 @@ fn settings_general<'a>(lang: &'a LanguageIdentifier, config: &'a config::Config
                 .align_y(Alignment::Center),
             )],
         ),
-        settings_group(
-            t!(lang, "settings-group-patches"),
-            vec![
-                option_row::<Message>(
-                    t!(lang, "settings-patch-repo"),
-                    text_input("", &config.patch_repo)
-                        .on_input(Message::PatchRepoChanged)
-                        .padding(STANDARD_PADDING)
-                        .width(Length::Fixed(380.0))
-                        .style(widgets::chunky_text_input),
-                ),
-                option_row(
-                    t!(lang, "settings-enable-patch-autoupdate"),
-                    toggle(config.enable_patch_autoupdate, Message::TogglePatchAutoupdate),
-                ),
-            ],
-        ),
+        settings_group(t!(lang, "settings-group-patches"), {
+            let mut rows = vec![option_row::<Message>(
+                t!(lang, "settings-patch-repo"),
+                text_input("", &config.patch_repo)
+                    .on_input(Message::PatchRepoChanged)
+                    .padding(STANDARD_PADDING)
+                    .width(Length::Fixed(380.0))
+                    .style(widgets::chunky_text_input),
+            )];
+            // Narrower than the main repo's field by the remove
+            // button and its gap, so the right edges line up.
+            for (i, repo) in config.extra_patch_repos.iter().enumerate() {
+                rows.push(option_row(
+                    t!(lang, "settings-extra-patch-repo"),
+                    row![
+                        text_input(&t!(lang, "settings-extra-patch-repo-placeholder"), repo)
+                            .on_input(move |s| Message::ExtraPatchRepoChanged(i, s))
+                            .padding(STANDARD_PADDING)
+                            .width(Length::Fixed(340.0))
+                            .style(widgets::chunky_text_input),
+                        widgets::icon_button(
+                            Icon::Trash2,
+                            t!(lang, "settings-extra-patch-repo-remove"),
+                            Message::RemoveExtraPatchRepo(i),
+                            STANDARD_PADDING,
+                        ),
+                    ]
+                    .spacing(8)
+                    .align_y(Alignment::Center),
+                ));
+            }
+            rows.push(option_row(
+                t!(lang, "settings-extra-patch-repos"),
+                row![
+                    widgets::labeled_icon_button(
+                        Icon::Plus,
+                        t!(lang, "settings-extra-patch-repo-add"),
+                        Message::AddExtraPatchRepo,
+                        STANDARD_PADDING,
+                        widgets::neutral,
+                    ),
+                    widgets::labeled_icon_button(
+                        Icon::FolderOpen,
+                        t!(lang, "settings-extra-patch-repo-add-folder"),
+                        Message::OpenPatchRepoFolderPicker,
+                        STANDARD_PADDING,
+                        widgets::neutral,
+                    ),
+                ]
+                .spacing(8)
+                .align_y(Alignment::Center),
+            ));
+            rows.push(option_row(
+                t!(lang, "settings-enable-patch-autoupdate"),
+                toggle(config.enable_patch_autoupdate, Message::TogglePatchAutoupdate),
+            ));
+            rows
+        }),
         settings_group(
             t!(lang, "settings-group-updates"),
             vec![
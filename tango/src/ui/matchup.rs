@@ -111,8 +111,8 @@ pub fn cook_hp_rounds(
                 // One point draws nothing but implies a shape; leave the
                 // segment bare rather than plotting a lone dot.
                 trace: if trace.len() >= 2 { trace } else { vec![] },
-                custom: stats
-                    .custom
+                // The local side's screen: the bands mark "you" picking.
+                custom: stats.custom[0]
                     .iter()
                     .filter(|&&(a, _)| within(a))
                     .map(|&(a, b)| (x_of(a), x_of(b)))
@@ -131,3 +131,352 @@ pub fn cook_hp_rounds(
         .collect();
     (rounds, max_hp)
 }
+
+/// Cook a match's netcode health timeline for [`net_health_graph`],
+/// cut at the same round spans [`cook_hp_rounds`] cuts the stats at
+/// (pass the same `total_ticks`), so each round's slice lands on its HP
+/// segment. Samples outside every round (a setup section, the tail past
+/// the last one) aren't drawn — the HP graph has nowhere to put them
+/// either.
+pub fn cook_net_rounds(
+    stats: &tango_match::analysis::MatchStats,
+    health: &tango_session::health::NetHealth,
+    total_ticks: Option<u32>,
+) -> Vec<CookedNetRound> {
+    (0..stats.rounds.len())
+        .map(|i| {
+            let Some((start, end)) = stats.round_span(i, total_ticks) else {
+                return CookedNetRound {
+                    points: vec![],
+                    weight: 0.0,
+                };
+            };
+            let span = (end - start).max(1) as f32;
+            CookedNetRound {
+                points: health
+                    .span(start, end)
+                    .iter()
+                    .map(|s| NetPoint {
+                        x: (s.tick - start) as f32 / span,
+                        tps: s.tps,
+                        fps_target: s.fps_target,
+                        skew: s.skew,
+                        lead: s.lead,
+                        depth: s.depth,
+                        ping_ms: s.ping_ms.map(u128::from),
+                    })
+                    .collect(),
+                weight: span,
+            }
+        })
+        .collect()
+}
+
+/// The [`net_health_graph`] lane labels, top to bottom.
+pub fn net_health_labels(lang: &unic_langid::LanguageIdentifier) -> [String; 5] {
+    [
+        crate::i18n::t!(lang, "net-health-tps"),
+        crate::i18n::t!(lang, "net-health-skew"),
+        crate::i18n::t!(lang, "net-health-lead"),
+        crate::i18n::t!(lang, "net-health-depth"),
+        crate::i18n::t!(lang, "net-health-ping"),
+    ]
+}
+
+/// One line of a "top damage" list: a damage source's label and icon,
+/// resolved the way [`ChipUseMark`]s are, with what it dealt.
+#[derive(Clone)]
+pub struct DamageLine {
+    pub label: DamageLabel,
+    pub icon: Option<iced::widget::image::Handle>,
+    pub damage: u32,
+    pub hits: u32,
+}
+
+/// What a [`DamageLine`] names: a chip, resolved at cooking time (the
+/// saves may be gone by the time it's drawn), or one of the
+/// non-chip sources, translated when drawn.
+#[derive(Clone)]
+pub enum DamageLabel {
+    Chip(String),
+    Other,
+}
+
+/// The `limit` sources that dealt the most damage per side (`[you,
+/// opponent]`) over the whole match, chip names resolved per side
+/// through `loadeds` like [`cook_hp_rounds`]. Empty per side when the
+/// match recorded no hits.
+pub fn cook_top_damage(
+    stats: &tango_match::analysis::MatchStats,
+    loadeds: [Option<&tango_gamesupport::LoadedSave>; 2],
+    limit: usize,
+) -> [Vec<DamageLine>; 2] {
+    use tango_match::analysis::DamageSource;
+    [0, 1].map(|side| {
+        stats
+            .damage_totals(side, None)
+            .into_iter()
+            .take(limit)
+            .map(|total| {
+                let (label, icon) = match total.source {
+                    DamageSource::Chip(id) => {
+                        let chip = loadeds[side]
+                            .and_then(|l| l.chips.get(id as usize))
+                            .cloned()
+                            .unwrap_or_default();
+                        (
+                            DamageLabel::Chip(chip.name.unwrap_or_else(|| "???".to_string())),
+                            chip.icon,
+                        )
+                    }
+                    DamageSource::Other => (DamageLabel::Other, None),
+                };
+                DamageLine {
+                    label,
+                    icon,
+                    damage: total.damage,
+                    hits: total.hits,
+                }
+            })
+            .collect()
+    })
+}
+
+/// The two sides' [`DamageLine`]s as a compact pair of columns under a
+/// caption — the results card and the replay detail pane both show it
+/// beneath the HP graph. `None` when neither side landed a hit.
+pub fn top_damage<'a, M: 'a>(
+    lang: &unic_langid::LanguageIdentifier,
+    lines: &'a [Vec<DamageLine>; 2],
+    names: [String; 2],
+) -> Option<iced::Element<'a, M>> {
+    use crate::ui::style::TEXT_CAPTION;
+    use iced::widget::text;
+    use sweeten::widget::{column, row};
+
+    if lines.iter().all(|l| l.is_empty()) {
+        return None;
+    }
+    let side = |name: String, lines: &'a [DamageLine]| {
+        let mut col = column![text(name).size(TEXT_CAPTION).style(muted_text_style)].spacing(2);
+        for line in lines {
+            let mut r = row![].spacing(4).align_y(iced::Alignment::Center);
+            if let Some(icon) = &line.icon {
+                r = r.push(
+                    iced::widget::image(icon.clone())
+                        .width(14)
+                        .height(14)
+                        .filter_method(iced::widget::image::FilterMethod::Nearest),
+                );
+            }
+            let label = match &line.label {
+                DamageLabel::Chip(name) => name.clone(),
+                DamageLabel::Other => crate::i18n::t!(lang, "damage-source-other"),
+            };
+            r = r.push(text(label).size(TEXT_CAPTION).width(iced::Fill)).push(
+                text(crate::i18n::t!(
+                    lang,
+                    "damage-total",
+                    damage = line.damage as i64,
+                    hits = line.hits as i64
+                ))
+                .size(TEXT_CAPTION)
+                .style(muted_text_style),
+            );
+            col = col.push(r);
+        }
+        col.width(iced::Fill)
+    };
+    let [you, them] = names;
+    Some(
+        column![
+            text(crate::i18n::t!(lang, "damage-top"))
+                .size(TEXT_CAPTION)
+                .style(muted_text_style),
+            row![side(you, &lines[0]), side(them, &lines[1])].spacing(16),
+        ]
+        .spacing(4)
+        .into(),
+    )
+}
+
+/// Each round's [`Movement`](tango_match::analysis::Movement) per side
+/// (`[you, opponent]`), over the same spans [`cook_hp_rounds`] cuts the
+/// chart at. `None` for a side with no tile reading in the round — every
+/// side on games that report no tiles.
+pub fn cook_movement(
+    stats: &tango_match::analysis::MatchStats,
+    total_ticks: Option<u32>,
+) -> Vec<[Option<tango_match::analysis::Movement>; 2]> {
+    (0..stats.rounds.len())
+        .map(|i| match stats.round_span(i, total_ticks) {
+            Some(span) => [stats.movement(0, span), stats.movement(1, span)],
+            None => [None, None],
+        })
+        .collect()
+}
+
+/// A match's custom-screen timings per side (`[you, opponent]`), cooked
+/// for [`custom_timing_table`].
+#[derive(Clone, Default)]
+pub struct CookedCustomTiming {
+    /// Each round's, over the same spans [`cook_hp_rounds`] cuts the
+    /// chart at. A round with no span gets empty timings.
+    pub rounds: Vec<[tango_match::analysis::CustomTiming; 2]>,
+    /// The whole match's.
+    pub whole: [tango_match::analysis::CustomTiming; 2],
+}
+
+pub fn cook_custom_timing(stats: &tango_match::analysis::MatchStats, total_ticks: Option<u32>) -> CookedCustomTiming {
+    CookedCustomTiming {
+        rounds: (0..stats.rounds.len())
+            .map(|i| [0, 1].map(|side| stats.custom_timing(side, i, total_ticks).unwrap_or_default()))
+            .collect(),
+        whole: [0, 1].map(|side| stats.match_custom_timing(side, total_ticks)),
+    }
+}
+
+/// The custom-screen timings as a small table: a row per round and a
+/// match row folding them all, each cell holding both sides — you in
+/// [`FIELD_RED`], the opponent in [`FIELD_BLUE`]. Times are in seconds
+/// of match ticks. `None` when neither side ever opened the screen.
+pub fn custom_timing_table<'a, M: 'a>(
+    lang: &unic_langid::LanguageIdentifier,
+    timing: &CookedCustomTiming,
+) -> Option<iced::Element<'a, M>> {
+    use crate::ui::style::TEXT_CAPTION;
+    use iced::widget::text;
+    use sweeten::widget::{column, row};
+    use tango_match::analysis::CustomTiming;
+
+    if timing.whole.iter().all(|t| t.windows.is_empty()) {
+        return None;
+    }
+    let seconds = |ticks: Option<f32>| match ticks {
+        Some(ticks) => crate::i18n::t!(lang, "custom-timing-seconds", seconds = format!("{:.1}", ticks / 60.0)),
+        None => "—".to_string(),
+    };
+    let count = |n: Option<f32>| n.map_or_else(|| "—".to_string(), |n| format!("{n:.1}"));
+    let columns: [(String, &dyn Fn(&CustomTiming) -> String); 4] = [
+        (crate::i18n::t!(lang, "custom-timing-mean"), &|t| {
+            seconds(t.mean_window())
+        }),
+        (crate::i18n::t!(lang, "custom-timing-max"), &|t| {
+            seconds(t.max_window().map(|w| w as f32))
+        }),
+        (crate::i18n::t!(lang, "custom-timing-first-chip"), &|t| {
+            seconds(t.mean_first_chip())
+        }),
+        (crate::i18n::t!(lang, "custom-timing-chips"), &|t| count(t.mean_chips())),
+    ];
+    let caption = |s: String| text(s).size(TEXT_CAPTION).style(muted_text_style);
+    let label_width = iced::Length::Fixed(64.0);
+
+    let mut header = row![caption(String::new()).width(label_width)].spacing(8);
+    for (title, _) in &columns {
+        header = header.push(caption(title.clone()).width(iced::Fill));
+    }
+    let line = |label: String, timing: &[CustomTiming; 2]| {
+        let mut r = row![caption(label).width(label_width)].spacing(8);
+        for (_, cell) in &columns {
+            r = r.push(
+                row![
+                    text(cell(&timing[0])).size(TEXT_CAPTION).color(FIELD_RED),
+                    caption("/".to_string()),
+                    text(cell(&timing[1])).size(TEXT_CAPTION).color(FIELD_BLUE),
+                ]
+                .spacing(4)
+                .width(iced::Fill),
+            );
+        }
+        r
+    };
+    let mut table = column![caption(crate::i18n::t!(lang, "custom-timing-title")), header].spacing(2);
+    for (i, round) in timing.rounds.iter().enumerate() {
+        table = table.push(line(
+            crate::i18n::t!(lang, "movement-round", number = (i + 1) as i64),
+            round,
+        ));
+    }
+    Some(
+        table
+            .push(line(crate::i18n::t!(lang, "custom-timing-match"), &timing.whole))
+            .into(),
+    )
+}
+
+/// One round's occupancy as a small 6×3 field: each tile tinted by how
+/// long each side stood on it, relative to the round's busiest tile —
+/// you in [`FIELD_RED`], the opponent in [`FIELD_BLUE`] — with each
+/// side's move count and back-column share beneath.
+pub fn field_heatmap<'a, M: 'a>(
+    lang: &unic_langid::LanguageIdentifier,
+    round: usize,
+    movement: &[Option<tango_match::analysis::Movement>; 2],
+) -> iced::Element<'a, M> {
+    use crate::ui::style::TEXT_CAPTION;
+    use iced::widget::{container, text, Space};
+    use sweeten::widget::{column, row};
+    use tango_match::analysis::{FIELD_COLUMNS, FIELD_ROWS};
+
+    const CELL: f32 = 14.0;
+    let busiest = movement
+        .iter()
+        .flatten()
+        .flat_map(|m| m.occupancy.iter().flatten().copied())
+        .max()
+        .unwrap_or(0)
+        .max(1) as f32;
+    let mut grid = column![].spacing(1);
+    for y in 0..FIELD_ROWS {
+        let mut cells = row![].spacing(1);
+        for x in 0..FIELD_COLUMNS {
+            let share = |side: usize| movement[side].map_or(0.0, |m| m.occupancy[y][x] as f32 / busiest);
+            let (you, them) = (share(0), share(1));
+            let weight = (you + them).max(f32::EPSILON);
+            let tint = iced::Color {
+                r: (FIELD_RED.r * you + FIELD_BLUE.r * them) / weight,
+                g: (FIELD_RED.g * you + FIELD_BLUE.g * them) / weight,
+                b: (FIELD_RED.b * you + FIELD_BLUE.b * them) / weight,
+                // Never fully clear, so the empty field still reads as a
+                // field.
+                a: you.max(them).max(0.08),
+            };
+            cells = cells.push(
+                container(Space::new().width(CELL).height(CELL)).style(move |_: &iced::Theme| {
+                    iced::widget::container::Style {
+                        background: Some(iced::Background::Color(tint)),
+                        border: iced::Border {
+                            radius: 2.0.into(),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    }
+                }),
+            );
+        }
+        grid = grid.push(cells);
+    }
+    let summary = |m: &Option<tango_match::analysis::Movement>| {
+        let text_ = match m {
+            Some(m) => crate::i18n::t!(
+                lang,
+                "movement-summary",
+                moves = m.moves as i64,
+                back = (m.back_row_ticks as f32 * 100.0 / m.total_ticks().max(1) as f32).round() as i64
+            ),
+            None => "—".to_string(),
+        };
+        text(text_).size(TEXT_CAPTION)
+    };
+    column![
+        text(crate::i18n::t!(lang, "movement-round", number = (round + 1) as i64))
+            .size(TEXT_CAPTION)
+            .style(muted_text_style),
+        grid,
+        summary(&movement[0]).color(FIELD_RED),
+        summary(&movement[1]).color(FIELD_BLUE),
+    ]
+    .spacing(3)
+    .into()
+}
@@ -3,7 +3,8 @@
 //! covers both without call sites caring which side of the gamesupport
 //! boundary a widget lives on. The HUD chrome (`hud_bar`,
 //! `hud_scanline_top`, `cyber_backdrop`, `panel`), the nav tabs, the
-//! ⋮ [`MenuButton`], and the match-analysis chart are all app-only.
+//! ⋮ [`MenuButton`], the match-analysis chart and the netcode health timeline are all
+//! app-only.
 
 pub use tango_ui::widgets::*;
 
@@ -16,6 +17,9 @@ use sweeten::widget::{column, row};
 mod match_graph;
 pub use match_graph::*;
 
+mod net_graph;
+pub use net_graph::*;
+
 /// The stats-to-chart cooking [`hp_match_graph`] draws from.
 pub use super::matchup::*;
 
@@ -0,0 +1,411 @@
+//! The netcode health vocabulary the live PvP telemetry deck and a
+//! match's recorded health timeline share — the green/amber/red tones,
+//! the chart spans, the value formats — and the timeline chart itself,
+//! drawn under the HP graph on the same round segments.
+
+use super::*;
+
+// Each metric's full-height value span (sample saturates into it). Chosen to
+// line up with the tone thresholds so a point's height roughly tracks its color.
+const TPS_SPAN: f32 = 8.0; // fps below target = floor of the chart
+const SKEW_SPAN: i32 = 8; // ± about parity; 0 sits mid-height
+const LEAD_SPAN: i32 = 24; // ± about zero; saturates well before the overflow bail
+const DEPTH_SPAN: u32 = 8;
+const PING_SPAN: u128 = 200;
+
+/// Semantic tone for a PvP telemetry value. The icon always rides
+/// muted; only the value picks up `Good`/`Warn`/`Bad` so color reads
+/// as "this number means something is healthy / borderline / wrong"
+/// rather than mere decoration.
+#[derive(Clone, Copy)]
+pub enum StatTone {
+    Muted,
+    Good,
+    Warn,
+    Bad,
+}
+
+pub fn stat_tone_color(theme: &iced::Theme, tone: StatTone) -> iced::Color {
+    match tone {
+        StatTone::Muted => muted_color(theme),
+        StatTone::Good => theme.extended_palette().success.strong.color,
+        // Amber lives outside iced's default palette, so hardcode a
+        // tone that reads on both the dark navy and light parchment
+        // HUD plates.
+        StatTone::Warn => iced::Color::from_rgb(0.92, 0.67, 0.18),
+        StatTone::Bad => theme.extended_palette().danger.strong.color,
+    }
+}
+
+// Health tone per metric. Shared by the instrument-panel cells, the
+// popover sparklines and the match's health timeline, so the value
+// readout and the chart points always agree on green/amber/red.
+
+/// TPS vs the live fps target: green at/near rate, amber as it dips, red when
+/// it falls well behind (visible netplay stutter). Muted before a target exists.
+pub fn tone_for_tps(tps: f32, fps_target: f32) -> StatTone {
+    if fps_target <= 0.0 {
+        StatTone::Muted
+    } else if tps >= fps_target - 1.0 {
+        StatTone::Good
+    } else if tps >= fps_target - 5.0 {
+        StatTone::Warn
+    } else {
+        StatTone::Bad
+    }
+}
+
+/// Clock skew: green near parity, amber drifting, red far out, by `|skew|`.
+pub fn tone_for_skew(skew: i32) -> StatTone {
+    match skew.unsigned_abs() {
+        0..=3 => StatTone::Good,
+        4..=7 => StatTone::Warn,
+        _ => StatTone::Bad,
+    }
+}
+
+/// Local lead by `|lead|`: green at a healthy steady lead, amber as it climbs,
+/// red when it runs far from zero in either direction (the remote is lagging and
+/// we're heading toward the bail, or we've fallen behind it).
+pub fn tone_for_lead(lead: i32) -> StatTone {
+    match lead.unsigned_abs() {
+        0..=8 => StatTone::Good,
+        9..=16 => StatTone::Warn,
+        _ => StatTone::Bad,
+    }
+}
+
+/// Rollback depth: green shallow, amber climbing, red when speculation runs deep.
+pub fn tone_for_depth(depth: u32) -> StatTone {
+    match depth {
+        0..=2 => StatTone::Good,
+        3..=5 => StatTone::Warn,
+        _ => StatTone::Bad,
+    }
+}
+
+/// Latency band: green under 80 ms, amber under 140 ms, red beyond.
+pub fn tone_for_ping(ping_ms: u128) -> StatTone {
+    if ping_ms < 80 {
+        StatTone::Good
+    } else if ping_ms < 140 {
+        StatTone::Warn
+    } else {
+        StatTone::Bad
+    }
+}
+
+// Value formatting for the telemetry readouts.
+
+/// Signed skew in a 3-wide field; bare `0` at parity reads calmer than `+0`.
+pub fn fmt_skew(skew: i32) -> String {
+    if skew == 0 {
+        "0".to_string()
+    } else {
+        format!("{skew:+}")
+    }
+}
+/// Signed local lead in ticks; bare `0` at zero reads calmer than `+0`.
+pub fn fmt_lead(lead: i32) -> String {
+    if lead == 0 {
+        "0".to_string()
+    } else {
+        format!("{lead:+}")
+    }
+}
+/// Rollback depth.
+pub fn fmt_depth(depth: u32) -> String {
+    format!("{depth}")
+}
+/// Latency in ms.
+pub fn fmt_ping(ping_ms: u128) -> String {
+    format!("{ping_ms} ms")
+}
+
+// A reading as a chart point: `(height fraction in 0..=1, tone)`, against
+// the spans above. `None` where the reading means nothing to plot.
+
+/// TPS against its target, floored at `target − TPS_SPAN`. `None` before
+/// a target exists.
+pub fn tps_point(tps: f32, fps_target: f32) -> Option<(f32, StatTone)> {
+    (fps_target > 0.0).then(|| {
+        let yf = (tps - (fps_target - TPS_SPAN)) / TPS_SPAN;
+        (yf.clamp(0.0, 1.0), tone_for_tps(tps, fps_target))
+    })
+}
+
+/// Skew about mid-height parity.
+pub fn skew_point(skew: i32) -> (f32, StatTone) {
+    let yf = (skew.clamp(-SKEW_SPAN, SKEW_SPAN) as f32 + SKEW_SPAN as f32) / (2.0 * SKEW_SPAN as f32);
+    (yf, tone_for_skew(skew))
+}
+
+/// Lead about mid-height zero.
+pub fn lead_point(lead: i32) -> (f32, StatTone) {
+    let yf = (lead.clamp(-LEAD_SPAN, LEAD_SPAN) as f32 + LEAD_SPAN as f32) / (2.0 * LEAD_SPAN as f32);
+    (yf, tone_for_lead(lead))
+}
+
+/// Rollback depth up from the floor.
+pub fn depth_point(depth: u32) -> (f32, StatTone) {
+    (depth.min(DEPTH_SPAN) as f32 / DEPTH_SPAN as f32, tone_for_depth(depth))
+}
+
+/// Ping up from the floor.
+pub fn ping_point(ping_ms: u128) -> (f32, StatTone) {
+    (ping_ms.min(PING_SPAN) as f32 / PING_SPAN as f32, tone_for_ping(ping_ms))
+}
+
+/// One netcode health sample cooked for [`net_health_graph`]: x 0..=1
+/// within its round, the readings as recorded.
+#[derive(Clone, Copy)]
+pub struct NetPoint {
+    pub x: f32,
+    pub tps: f32,
+    pub fps_target: f32,
+    pub skew: i32,
+    pub lead: i32,
+    pub depth: u32,
+    pub ping_ms: Option<u128>,
+}
+
+/// A round's slice of the health timeline, on the same segment as the
+/// HP graph's round — `weight` is that round's tick span.
+#[derive(Clone)]
+pub struct CookedNetRound {
+    pub points: Vec<NetPoint>,
+    pub weight: f32,
+}
+
+/// How many metric lanes the timeline stacks.
+const NET_LANES: usize = 5;
+
+/// A match's netcode health as five thin lanes — TPS, skew, lead,
+/// rollback depth, ping, top to bottom, in the telemetry deck's order
+/// — over the HP graph's round segments, so a lag spike sits straight
+/// under the exchange it hit. Each lane is the deck's sparkline
+/// stretched across the match: tone-colored segments, a tone wash
+/// under the one-sided metrics, a parity line under the two-sided
+/// ones. Hovering reads out every lane at the cursor, labelled by
+/// `labels` in lane order. `sweep` reveals left to right like the HP
+/// graph's; there's no zoom, so it lines up with that graph at rest.
+pub fn net_health_graph<'a, M: 'a>(
+    rounds: &'a [CookedNetRound],
+    labels: [String; NET_LANES],
+    sweep: f32,
+    height: f32,
+) -> Element<'a, M> {
+    use iced::widget::canvas;
+
+    struct NetHealthGraph<'a> {
+        rounds: &'a [CookedNetRound],
+        labels: [String; NET_LANES],
+        sweep: f32,
+    }
+
+    /// Lane `i`'s plot of a sample.
+    fn lane(i: usize, p: &NetPoint) -> Option<(f32, StatTone)> {
+        match i {
+            0 => tps_point(p.tps, p.fps_target),
+            1 => Some(skew_point(p.skew)),
+            2 => Some(lead_point(p.lead)),
+            3 => Some(depth_point(p.depth)),
+            _ => p.ping_ms.map(ping_point),
+        }
+    }
+    /// Which lanes are one-sided — washed down to their floor, like the
+    /// deck's tps/depth/ping cards — rather than drawn about a midline.
+    const ONE_SIDED: [bool; NET_LANES] = [true, false, false, true, true];
+
+    fn readout(i: usize, p: &NetPoint) -> Option<(String, StatTone)> {
+        match i {
+            0 => (p.fps_target > 0.0).then(|| (format!("{:.2}", p.tps), tone_for_tps(p.tps, p.fps_target))),
+            1 => Some((fmt_skew(p.skew), tone_for_skew(p.skew))),
+            2 => Some((fmt_lead(p.lead), tone_for_lead(p.lead))),
+            3 => Some((fmt_depth(p.depth), tone_for_depth(p.depth))),
+            _ => p.ping_ms.map(|ms| (fmt_ping(ms), tone_for_ping(ms))),
+        }
+    }
+
+    impl<M> canvas::Program<M> for NetHealthGraph<'_> {
+        /// Whether the cursor was over the chart on the last move, so
+        /// leaving it redraws the readout away.
+        type State = bool;
+
+        fn update(
+            &self,
+            hovered: &mut bool,
+            event: &iced::Event,
+            bounds: iced::Rectangle,
+            cursor: iced::mouse::Cursor,
+        ) -> Option<canvas::Action<M>> {
+            let iced::Event::Mouse(iced::mouse::Event::CursorMoved { .. }) = event else {
+                return None;
+            };
+            let over = cursor.is_over(bounds);
+            let was_over = std::mem::replace(hovered, over);
+            (over || was_over).then(canvas::Action::request_redraw)
+        }
+
+        fn draw(
+            &self,
+            _hovered: &bool,
+            renderer: &iced::Renderer,
+            theme: &Theme,
+            bounds: iced::Rectangle,
+            cursor: iced::mouse::Cursor,
+        ) -> Vec<canvas::Geometry> {
+            use canvas::{Frame, LineCap, Path, Stroke};
+            use iced::Point;
+
+            let mut frame = Frame::new(renderer, bounds.size());
+            let palette = theme.extended_palette();
+            let text_color = theme.palette().text;
+            let (w, h) = (bounds.width, bounds.height);
+            const PAD: f32 = 2.0;
+            const GAP: f32 = 3.0;
+            let lane_h = h / NET_LANES as f32;
+            let y_at =
+                |lane: usize, yf: f32| lane as f32 * lane_h + PAD + (1.0 - yf.clamp(0.0, 1.0)) * (lane_h - 2.0 * PAD);
+
+            // The HP graph's segment layout, so the two line up tick for
+            // tick.
+            let total: f32 = self.rounds.iter().map(|r| r.weight.max(1.0)).sum::<f32>().max(1.0);
+            let sweep_px = self.sweep.clamp(0.0, 1.0) * w;
+            let mut segments: Vec<(f32, f32)> = Vec::with_capacity(self.rounds.len());
+            let mut seg_x = 0.0f32;
+            for (i, round) in self.rounds.iter().enumerate() {
+                let seg_w = round.weight.max(1.0) / total * w;
+                segments.push((seg_x, seg_w));
+                let x_at = |xf: f32| seg_x + xf.clamp(0.0, 1.0) * seg_w;
+                let local_sweep = if self.sweep >= 1.0 {
+                    1.0
+                } else {
+                    ((sweep_px - seg_x) / seg_w).clamp(0.0, 1.0)
+                };
+                let trail = if i + 1 == self.rounds.len() { 0.0 } else { GAP };
+                for lane_i in 0..NET_LANES {
+                    let top = lane_i as f32 * lane_h;
+                    frame.fill(
+                        &Path::rounded_rectangle(
+                            Point::new(seg_x, top + 0.5),
+                            iced::Size::new((seg_w - trail).max(1.0), lane_h - 1.0),
+                            2.0.into(),
+                        ),
+                        iced::Color {
+                            a: if palette.is_dark { 0.10 } else { 0.05 },
+                            ..text_color
+                        },
+                    );
+                    let reference = if ONE_SIDED[lane_i] { 0.0 } else { 0.5 };
+                    // TPS has no meaningful zero (its floor is target − span).
+                    if lane_i != 0 {
+                        let ry = y_at(lane_i, reference);
+                        frame.stroke(
+                            &Path::line(Point::new(seg_x, ry), Point::new(seg_x + seg_w - trail, ry)),
+                            Stroke::default()
+                                .with_color(iced::Color { a: 0.22, ..text_color })
+                                .with_width(1.0),
+                        );
+                    }
+                    let visible: Vec<_> = round.points.iter().take_while(|p| p.x <= local_sweep).collect();
+                    for pair in visible.windows(2) {
+                        let (Some((y0, _)), Some((y1, tone))) = (lane(lane_i, pair[0]), lane(lane_i, pair[1])) else {
+                            continue;
+                        };
+                        let (x0, x1) = (x_at(pair[0].x), x_at(pair[1].x));
+                        let color = stat_tone_color(theme, tone);
+                        if ONE_SIDED[lane_i] {
+                            let base = y_at(lane_i, 0.0);
+                            let area = Path::new(|b| {
+                                b.move_to(Point::new(x0, y_at(lane_i, y0)));
+                                b.line_to(Point::new(x1, y_at(lane_i, y1)));
+                                b.line_to(Point::new(x1, base));
+                                b.line_to(Point::new(x0, base));
+                                b.close();
+                            });
+                            frame.fill(&area, iced::Color { a: 0.3, ..color });
+                        }
+                        frame.stroke(
+                            &Path::line(Point::new(x0, y_at(lane_i, y0)), Point::new(x1, y_at(lane_i, y1))),
+                            Stroke::default()
+                                .with_color(color)
+                                .with_width(1.0)
+                                .with_line_cap(LineCap::Round),
+                        );
+                    }
+                }
+                seg_x += seg_w;
+            }
+
+            // Hover readout: the sample nearest the cursor in the hovered
+            // round, every lane's value beside its label.
+            if let Some(pos) = cursor.position_in(bounds) {
+                let hovered = segments
+                    .iter()
+                    .zip(self.rounds)
+                    .find(|((sx, sw), _)| pos.x >= *sx && pos.x < sx + sw && pos.x <= sweep_px);
+                if let Some((&(sx, sw), round)) = hovered {
+                    let xf = ((pos.x - sx) / sw).clamp(0.0, 1.0);
+                    let nearest = round
+                        .points
+                        .iter()
+                        .min_by(|a, b| (a.x - xf).abs().total_cmp(&(b.x - xf).abs()));
+                    if let Some(p) = nearest {
+                        let px = sx + p.x * sw;
+                        frame.stroke(
+                            &Path::line(Point::new(px, 0.0), Point::new(px, h)),
+                            Stroke::default()
+                                .with_color(iced::Color { a: 0.35, ..text_color })
+                                .with_width(1.0),
+                        );
+                        let lines: Vec<(String, iced::Color)> = (0..NET_LANES)
+                            .filter_map(|i| {
+                                readout(i, p).map(|(value, tone)| {
+                                    (format!("{} {}", self.labels[i], value), stat_tone_color(theme, tone))
+                                })
+                            })
+                            .collect();
+                        const PITCH: f32 = 14.0;
+                        // Same per-char estimate as the HP readout.
+                        let box_w = lines
+                            .iter()
+                            .map(|(s, _)| 10.0 + s.chars().count() as f32 * 7.0)
+                            .fold(1.0f32, f32::max);
+                        let box_h = lines.len() as f32 * PITCH + 2.0;
+                        let bx = if px + 10.0 + box_w > w {
+                            px - 10.0 - box_w
+                        } else {
+                            px + 10.0
+                        };
+                        let by = (pos.y - box_h / 2.0).clamp(0.0, (h - box_h).max(0.0));
+                        frame.fill(
+                            &Path::rounded_rectangle(Point::new(bx, by), iced::Size::new(box_w, box_h), 4.0.into()),
+                            iced::Color {
+                                a: 0.92,
+                                ..theme.palette().background
+                            },
+                        );
+                        for (i, (content, color)) in lines.into_iter().enumerate() {
+                            frame.fill_text(canvas::Text {
+                                content,
+                                position: Point::new(bx + 5.0, by + 1.0 + PITCH / 2.0 + i as f32 * PITCH),
+                                color,
+                                size: 11.0.into(),
+                                align_y: iced::alignment::Vertical::Center.into(),
+                                ..Default::default()
+                            });
+                        }
+                    }
+                }
+            }
+
+            vec![frame.into_geometry()]
+        }
+    }
+
+    iced::widget::canvas::Canvas::new(NetHealthGraph { rounds, labels, sweep })
+        .width(Length::Fill)
+        .height(Length::Fixed(height))
+        .into()
+}